@@ -66,16 +66,19 @@ from daft.convert import (
 from daft.daft import ImageFormat, ImageMode, ResourceRequest
 from daft.dataframe import DataFrame
 from daft.datatype import DataType, TimeUnit
-from daft.expressions import Expression, col, lit
+from daft.expressions import Expression, col, dense_rank, lit, rank, row_number
 from daft.io import (
     DataCatalogTable,
     DataCatalogType,
     from_glob_path,
+    read_avro,
     read_csv,
     read_delta_lake,
     read_hudi,
     read_iceberg,
+    read_ipc,
     read_json,
+    read_orc,
     read_parquet,
     read_sql,
 )
@@ -93,6 +96,9 @@ __all__ = [
     "from_glob_path",
     "read_csv",
     "read_json",
+    "read_ipc",
+    "read_avro",
+    "read_orc",
     "read_parquet",
     "read_hudi",
     "read_iceberg",
@@ -107,6 +113,9 @@ __all__ = [
     "ImageMode",
     "ImageFormat",
     "lit",
+    "row_number",
+    "rank",
+    "dense_rank",
     "Series",
     "TimeUnit",
     "register_viz_hook",
@@ -245,6 +245,7 @@ def set_execution_config(
     scan_tasks_max_size_bytes: int | None = None,
     broadcast_join_size_bytes_threshold: int | None = None,
     parquet_split_row_groups_max_files: int | None = None,
+    orc_split_stripes_max_files: int | None = None,
     sort_merge_join_sort_with_aligned_boundaries: bool | None = None,
     sample_size_for_sort: int | None = None,
     num_preview_rows: int | None = None,
@@ -253,6 +254,7 @@ def set_execution_config(
     parquet_inflation_factor: float | None = None,
     csv_target_filesize: int | None = None,
     csv_inflation_factor: float | None = None,
+    split_csv_by_byte_ranges: bool | None = None,
     shuffle_aggregation_default_partitions: int | None = None,
     read_sql_partition_size_bytes: int | None = None,
 ) -> DaftContext:
@@ -267,10 +269,12 @@ def set_execution_config(
             which leads to bigger but fewer partitions. (Defaults to 96 MiB)
         scan_tasks_max_size_bytes: Maximum size in bytes when merging ScanTasks when reading files from storage.
             Increasing this value will increase the upper bound of the size of merged ScanTasks, which leads to bigger but
-            fewer partitions. (Defaults to 384 MiB)
+            fewer partitions. Large uncompressed NDJSON files, and CSV files if `split_csv_by_byte_ranges` is set, are
+            split into ScanTasks of at most this size. (Defaults to 384 MiB)
         broadcast_join_size_bytes_threshold: If one side of a join is smaller than this threshold, a broadcast join will be used.
             Default is 10 MiB.
         parquet_split_row_groups_max_files: Maximum number of files to read in which the row group splitting should happen. (Defaults to 10)
+        orc_split_stripes_max_files: Maximum number of files to read in which the ORC stripe splitting should happen. (Defaults to 10)
         sort_merge_join_sort_with_aligned_boundaries: Whether to use a specialized algorithm for sorting both sides of a
             sort-merge join such that they have aligned boundaries. This can lead to a faster merge-join at the cost of
             more skewed sorted join inputs, increasing the risk of OOMs.
@@ -283,6 +287,9 @@ def set_execution_config(
         parquet_inflation_factor: Inflation Factor of parquet files (In-Memory-Size / File-Size) ratio. Defaults to 3.0
         csv_target_filesize: Target File Size when writing out CSV Files. Defaults to 512MB
         csv_inflation_factor: Inflation Factor of CSV files (In-Memory-Size / File-Size) ratio. Defaults to 0.5
+        split_csv_by_byte_ranges: Whether to split large uncompressed CSV files into byte ranges of at most `scan_tasks_max_size_bytes`
+            that are read in parallel, like NDJSON files are. Where a record starts within a CSV file is guessed, which can go
+            wrong if quoted fields contain line breaks. Defaults to False
         shuffle_aggregation_default_partitions: Minimum number of partitions to create when performing aggregations. Defaults to 200, unless the number of input partitions is less than 200.
         read_sql_partition_size_bytes: Target size of partition when reading from SQL databases. Defaults to 512MB
     """
@@ -295,6 +302,7 @@ def set_execution_config(
             scan_tasks_max_size_bytes=scan_tasks_max_size_bytes,
             broadcast_join_size_bytes_threshold=broadcast_join_size_bytes_threshold,
             parquet_split_row_groups_max_files=parquet_split_row_groups_max_files,
+            orc_split_stripes_max_files=orc_split_stripes_max_files,
             sort_merge_join_sort_with_aligned_boundaries=sort_merge_join_sort_with_aligned_boundaries,
             sample_size_for_sort=sample_size_for_sort,
             num_preview_rows=num_preview_rows,
@@ -303,6 +311,7 @@ def set_execution_config(
             parquet_inflation_factor=parquet_inflation_factor,
             csv_target_filesize=csv_target_filesize,
             csv_inflation_factor=csv_inflation_factor,
+            split_csv_by_byte_ranges=split_csv_by_byte_ranges,
             shuffle_aggregation_default_partitions=shuffle_aggregation_default_partitions,
             read_sql_partition_size_bytes=read_sql_partition_size_bytes,
         )
@@ -87,6 +87,10 @@ class JoinType(Enum):
     Inner: int
     Left: int
     Right: int
+    Outer: int
+    Semi: int
+    Anti: int
+    Cross: int
 
     @staticmethod
     def from_join_type_str(join_type: str) -> JoinType:
@@ -173,6 +177,9 @@ class FileFormat(Enum):
     Parquet: int
     Csv: int
     Json: int
+    ArrowIpc: int
+    Avro: int
+    Orc: int
 
 class ParquetSourceConfig:
     """
@@ -199,6 +206,13 @@ class CsvSourceConfig:
     quote: str | None
     escape_char: str | None
     comment: str | None
+    null_values: list[str] | None
+    true_values: list[str] | None
+    false_values: list[str] | None
+    skip_rows: int
+    ragged_rows: RaggedRows
+    mode: ParseMode
+    corrupt_record_column: str
     buffer_size: int | None
     chunk_size: int | None
 
@@ -210,6 +224,13 @@ class CsvSourceConfig:
         quote: str | None,
         escape_char: str | None,
         comment: str | None,
+        null_values: list[str] | None = None,
+        true_values: list[str] | None = None,
+        false_values: list[str] | None = None,
+        skip_rows: int = 0,
+        ragged_rows: str | None = None,
+        mode: str | None = None,
+        corrupt_record_column: str | None = None,
         buffer_size: int | None = None,
         chunk_size: int | None = None,
     ): ...
@@ -219,6 +240,8 @@ class JsonSourceConfig:
     Configuration of a JSON data source.
     """
 
+    mode: ParseMode
+    corrupt_record_column: str
     buffer_size: int | None
     chunk_size: int | None
 
@@ -226,8 +249,31 @@ class JsonSourceConfig:
         self,
         buffer_size: int | None = None,
         chunk_size: int | None = None,
+        mode: str | None = None,
+        corrupt_record_column: str | None = None,
     ): ...
 
+class ArrowIpcSourceConfig:
+    """
+    Configuration of an Arrow IPC data source.
+    """
+
+    def __init__(self): ...
+
+class AvroSourceConfig:
+    """
+    Configuration of an Avro data source.
+    """
+
+    def __init__(self): ...
+
+class OrcSourceConfig:
+    """
+    Configuration of an ORC data source.
+    """
+
+    def __init__(self): ...
+
 class DatabaseSourceConfig:
     """
     Configuration of a database data source.
@@ -240,10 +286,18 @@ class DatabaseSourceConfig:
 
 class FileFormatConfig:
     """
-    Configuration for parsing a particular file format (Parquet, CSV, JSON).
+    Configuration for parsing a particular file format (Parquet, CSV, JSON, Arrow IPC, Avro, ORC).
     """
 
-    config: ParquetSourceConfig | CsvSourceConfig | JsonSourceConfig | DatabaseSourceConfig
+    config: (
+        ParquetSourceConfig
+        | CsvSourceConfig
+        | JsonSourceConfig
+        | ArrowIpcSourceConfig
+        | AvroSourceConfig
+        | OrcSourceConfig
+        | DatabaseSourceConfig
+    )
 
     @staticmethod
     def from_parquet_config(config: ParquetSourceConfig) -> FileFormatConfig:
@@ -264,6 +318,24 @@ class FileFormatConfig:
         """
         ...
     @staticmethod
+    def from_arrow_ipc_config(config: ArrowIpcSourceConfig) -> FileFormatConfig:
+        """
+        Create an Arrow IPC file format config.
+        """
+        ...
+    @staticmethod
+    def from_avro_config(config: AvroSourceConfig) -> FileFormatConfig:
+        """
+        Create an Avro file format config.
+        """
+        ...
+    @staticmethod
+    def from_orc_config(config: OrcSourceConfig) -> FileFormatConfig:
+        """
+        Create an ORC file format config.
+        """
+        ...
+    @staticmethod
     def from_database_config(config: DatabaseSourceConfig) -> FileFormatConfig:
         """
         Create a database file format config.
@@ -287,6 +359,8 @@ class CsvConvertOptions:
     column_names: list[str] | None
     schema: PySchema | None
     predicate: PyExpr | None
+    mode: ParseMode
+    corrupt_record_column: str
 
     def __init__(
         self,
@@ -295,8 +369,28 @@ class CsvConvertOptions:
         column_names: list[str] | None = None,
         schema: PySchema | None = None,
         predicate: PyExpr | None = None,
+        mode: str | None = None,
+        corrupt_record_column: str | None = None,
     ): ...
 
+class ParseMode(Enum):
+    """
+    How to handle malformed CSV rows or JSON lines.
+    """
+
+    FailFast: int
+    DropMalformed: int
+    Permissive: int
+
+class RaggedRows(Enum):
+    """
+    How to handle CSV rows with fewer or more fields than the schema.
+    """
+
+    Error: int
+    Fill: int
+    Reject: int
+
 class CsvParseOptions:
     """
     Options for parsing CSV files.
@@ -308,6 +402,12 @@ class CsvParseOptions:
     quote: str | None
     escape_char: str | None
     comment: str | None
+    multi_byte_delimiter: str | None
+    null_values: list[str] | None
+    true_values: list[str] | None
+    false_values: list[str] | None
+    skip_rows: int
+    ragged_rows: RaggedRows
 
     def __init__(
         self,
@@ -317,6 +417,11 @@ class CsvParseOptions:
         quote: str | None = None,
         escape_char: str | None = None,
         comment: str | None = None,
+        null_values: list[str] | None = None,
+        true_values: list[str] | None = None,
+        false_values: list[str] | None = None,
+        skip_rows: int = 0,
+        ragged_rows: str | None = None,
     ): ...
 
 class CsvReadOptions:
@@ -341,12 +446,17 @@ class JsonConvertOptions:
     limit: int | None
     include_columns: list[str] | None
     schema: PySchema | None
+    mode: ParseMode
+    corrupt_record_column: str
 
     def __init__(
         self,
         limit: int | None = None,
         include_columns: list[str] | None = None,
         schema: PySchema | None = None,
+        predicate: PyExpr | None = None,
+        mode: str | None = None,
+        corrupt_record_column: str | None = None,
     ): ...
 
 class JsonParseOptions:
@@ -656,6 +766,15 @@ class ScanOperatorHandle:
         schema_hint: PySchema | None = None,
     ) -> ScanOperatorHandle: ...
     @staticmethod
+    def sql_scan(
+        sql: str,
+        url: str,
+        storage_config: StorageConfig,
+        partition_col: str | None,
+        num_partitions: int | None,
+        partition_size_bytes: int,
+    ) -> ScanOperatorHandle: ...
+    @staticmethod
     def from_python_scan_operator(operator: ScanOperator) -> ScanOperatorHandle: ...
 
 class PartitionField:
@@ -699,6 +818,14 @@ class Pushdowns:
     partition_filters: PyExpr | None
     limit: int | None
 
+    def __init__(
+        self,
+        columns: list[str] | None = None,
+        filters: PyExpr | None = None,
+        partition_filters: PyExpr | None = None,
+        limit: int | None = None,
+    ): ...
+
 def read_parquet(
     uri: str,
     columns: list[str] | None = None,
@@ -768,6 +895,13 @@ def read_csv_schema(
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
 ): ...
+def write_csv(
+    table: PyTable,
+    uri: str,
+    parse_options: CsvParseOptions | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
 def read_json(
     uri: str,
     convert_options: JsonConvertOptions | None = None,
@@ -783,6 +917,49 @@ def read_json_schema(
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
 ): ...
+def read_ipc(
+    uri: str,
+    columns: list[str] | None = None,
+    num_rows: int | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_ipc_schema(
+    uri: str,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_avro(
+    uri: str,
+    columns: list[str] | None = None,
+    num_rows: int | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_avro_schema(
+    uri: str,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_orc(
+    uri: str,
+    columns: list[str] | None = None,
+    num_rows: int | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_orc_schema(
+    uri: str,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def write_json(
+    table: PyTable,
+    uri: str,
+    line_delimited: bool | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
 
 class PyTimeUnit:
     @staticmethod
@@ -918,6 +1095,21 @@ class PyExpr:
     def any_value(self, ignore_nulls: bool) -> PyExpr: ...
     def agg_list(self) -> PyExpr: ...
     def agg_concat(self) -> PyExpr: ...
+    def count_distinct(self) -> PyExpr: ...
+    def sum_distinct(self) -> PyExpr: ...
+    def agg_list_distinct(self) -> PyExpr: ...
+    def variance(self, ddof: int) -> PyExpr: ...
+    def stddev(self, ddof: int) -> PyExpr: ...
+    def skew(self) -> PyExpr: ...
+    def covar(self, other: PyExpr, ddof: int) -> PyExpr: ...
+    def corr(self, other: PyExpr) -> PyExpr: ...
+    def approx_sketch(self) -> PyExpr: ...
+    def merge_sketch(self) -> PyExpr: ...
+    def approx_percentiles(self, percentiles: list[float], force_list_output: bool) -> PyExpr: ...
+    def sketch_percentile(self, percentiles: list[float], force_list_output: bool) -> PyExpr: ...
+    def approx_count_distinct(self) -> PyExpr: ...
+    def lag(self, offset: int, default: PyExpr | None) -> PyExpr: ...
+    def lead(self, offset: int, default: PyExpr | None) -> PyExpr: ...
     def explode(self) -> PyExpr: ...
     def __abs__(self) -> PyExpr: ...
     def __add__(self, other: PyExpr) -> PyExpr: ...
@@ -997,6 +1189,9 @@ class PyExpr:
 def eq(expr1: PyExpr, expr2: PyExpr) -> bool: ...
 def col(name: str) -> PyExpr: ...
 def lit(item: Any) -> PyExpr: ...
+def row_number() -> PyExpr: ...
+def rank() -> PyExpr: ...
+def dense_rank() -> PyExpr: ...
 def date_lit(item: int) -> PyExpr: ...
 def time_lit(item: int, tu: PyTimeUnit) -> PyExpr: ...
 def timestamp_lit(item: int, tu: PyTimeUnit, tz: str | None) -> PyExpr: ...
@@ -1116,11 +1311,20 @@ class PyTable:
     def sort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PyTable: ...
     def argsort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PySeries: ...
     def agg(self, to_agg: list[PyExpr], group_by: list[PyExpr]) -> PyTable: ...
-    def hash_join(self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr]) -> PyTable: ...
+    def hash_join(self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr], how: JoinType) -> PyTable: ...
     def sort_merge_join(
-        self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr], is_sorted: bool
+        self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr], how: JoinType, is_sorted: bool
     ) -> PyTable: ...
     def explode(self, to_explode: list[PyExpr]) -> PyTable: ...
+    def window(
+        self,
+        to_window: list[PyExpr],
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+        frame_start: int | None,
+        frame_end: int | None,
+    ) -> PyTable: ...
     def head(self, num: int) -> PyTable: ...
     def sample_by_fraction(self, fraction: float, with_replacement: bool, seed: int | None) -> PyTable: ...
     def sample_by_size(self, size: int, with_replacement: bool, seed: int | None) -> PyTable: ...
@@ -1150,6 +1354,11 @@ class PyTable:
     @staticmethod
     def empty(schema: PySchema | None = None) -> PyTable: ...
 
+class PyTableStream:
+    def __iter__(self) -> PyTableStream: ...
+    def __next__(self) -> PyTable: ...
+    def close(self) -> None: ...
+
 class PyMicroPartition:
     def schema(self) -> PySchema: ...
     def column_names(self) -> list[str]: ...
@@ -1161,6 +1370,8 @@ class PyMicroPartition:
     @staticmethod
     def from_scan_task(scan_task: ScanTask) -> PyMicroPartition: ...
     @staticmethod
+    def stream_scan_task(scan_task: ScanTask, max_tables_in_flight: int = 1) -> PyTableStream: ...
+    @staticmethod
     def from_tables(tables: list[PyTable]) -> PyMicroPartition: ...
     @staticmethod
     def from_arrow_record_batches(record_batches: list[pyarrow.RecordBatch], schema: PySchema) -> PyMicroPartition: ...
@@ -1175,11 +1386,22 @@ class PyMicroPartition:
     def sort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PyMicroPartition: ...
     def argsort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PySeries: ...
     def agg(self, to_agg: list[PyExpr], group_by: list[PyExpr]) -> PyMicroPartition: ...
-    def hash_join(self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr]) -> PyMicroPartition: ...
+    def hash_join(
+        self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr], how: JoinType
+    ) -> PyMicroPartition: ...
     def sort_merge_join(
-        self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr], is_sorted: bool
+        self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr], how: JoinType, is_sorted: bool
     ) -> PyMicroPartition: ...
     def explode(self, to_explode: list[PyExpr]) -> PyMicroPartition: ...
+    def window(
+        self,
+        to_window: list[PyExpr],
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+        frame_start: int | None,
+        frame_end: int | None,
+    ) -> PyMicroPartition: ...
     def head(self, num: int) -> PyMicroPartition: ...
     def sample_by_fraction(self, fraction: float, with_replacement: bool, seed: int | None) -> PyMicroPartition: ...
     def sample_by_size(self, size: int, with_replacement: bool, seed: int | None) -> PyMicroPartition: ...
@@ -1190,6 +1412,14 @@ class PyMicroPartition:
         self, partition_keys: list[PyExpr], boundaries: PyTable, descending: list[bool]
     ) -> list[PyMicroPartition]: ...
     def partition_by_value(self, exprs: list[PyExpr]) -> tuple[list[PyMicroPartition], PyMicroPartition]: ...
+    def write_parquet(
+        self,
+        root_dir: str,
+        compression: str | None = None,
+        io_config: IOConfig | None = None,
+        execution_config: PyDaftExecutionConfig | None = None,
+        multithreaded_io: bool | None = None,
+    ) -> PyMicroPartition: ...
     def add_monotonically_increasing_id(self, partition_num: int, column_name: str) -> PyMicroPartition: ...
     def __repr__(self) -> str: ...
     def __len__(self) -> int: ...
@@ -1281,6 +1511,14 @@ class LogicalPlanBuilder:
     def distinct(self) -> LogicalPlanBuilder: ...
     def sample(self, fraction: float, with_replacement: bool, seed: int | None) -> LogicalPlanBuilder: ...
     def aggregate(self, agg_exprs: list[PyExpr], groupby_exprs: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def window(
+        self,
+        window_exprs: list[PyExpr],
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+        frame: tuple[int | None, int | None] | None,
+    ) -> LogicalPlanBuilder: ...
     def join(
         self,
         right: LogicalPlanBuilder,
@@ -1321,6 +1559,7 @@ class PyDaftExecutionConfig:
         scan_tasks_max_size_bytes: int | None = None,
         broadcast_join_size_bytes_threshold: int | None = None,
         parquet_split_row_groups_max_files: int | None = None,
+        orc_split_stripes_max_files: int | None = None,
         sort_merge_join_sort_with_aligned_boundaries: bool | None = None,
         sample_size_for_sort: int | None = None,
         num_preview_rows: int | None = None,
@@ -1329,6 +1568,7 @@ class PyDaftExecutionConfig:
         parquet_inflation_factor: float | None = None,
         csv_target_filesize: int | None = None,
         csv_inflation_factor: float | None = None,
+        split_csv_by_byte_ranges: bool | None = None,
         shuffle_aggregation_default_partitions: int | None = None,
         read_sql_partition_size_bytes: int | None = None,
     ) -> PyDaftExecutionConfig: ...
@@ -1355,6 +1595,8 @@ class PyDaftExecutionConfig:
     @property
     def csv_inflation_factor(self) -> float: ...
     @property
+    def split_csv_by_byte_ranges(self) -> bool: ...
+    @property
     def shuffle_aggregation_default_partitions(self) -> int: ...
     @property
     def read_sql_partition_size_bytes(self) -> int: ...
@@ -1378,6 +1620,23 @@ def io_glob(
     page_size: int | None = None,
     limit: int | None = None,
 ) -> list[dict]: ...
+def io_put(
+    path: str,
+    data: bytes,
+    multithreaded_io: bool | None = None,
+    io_config: IOConfig | None = None,
+) -> None: ...
+def io_delete(
+    path: str,
+    multithreaded_io: bool | None = None,
+    io_config: IOConfig | None = None,
+) -> None: ...
+def io_copy(
+    src: str,
+    dest: str,
+    multithreaded_io: bool | None = None,
+    io_config: IOConfig | None = None,
+) -> None: ...
 
 class SystemInfo:
     """
@@ -881,18 +881,26 @@ class DataFrame:
             on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on [use if the keys on the left and right side match.]. Defaults to None.
             left_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on left DataFrame.. Defaults to None.
             right_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on right DataFrame. Defaults to None.
-            how (str, optional): what type of join to performing, currently only `inner` is supported. Defaults to "inner".
+            how (str, optional): what type of join to perform; currently "inner", "left", "right", "outer", "semi", "anti" and "cross" are supported.
+                Semi and anti joins only keep the columns of the current DataFrame, and cross joins must not be given any join keys. Defaults to "inner".
             strategy (Optional[str]): The join strategy (algorithm) to use; currently "hash", "sort_merge", "broadcast", and None are supported, where None
                 chooses the join strategy automatically during query optimization. The default is None.
 
         Raises:
             ValueError: if `on` is passed in and `left_on` or `right_on` is not None.
             ValueError: if `on` is None but both `left_on` and `right_on` are not defined.
+            ValueError: if join keys are passed in for a cross join.
 
         Returns:
             DataFrame: Joined DataFrame.
         """
-        if on is None:
+        join_type = JoinType.from_join_type_str(how)
+        if join_type == JoinType.Cross:
+            if on is not None or left_on is not None or right_on is not None:
+                raise ValueError("Cross joins do not take `on`, `left_on` or `right_on`")
+            left_on = []
+            right_on = []
+        elif on is None:
             if left_on is None or right_on is None:
                 raise ValueError("If `on` is None then both `left_on` and `right_on` must not be None")
         else:
@@ -900,9 +908,6 @@ class DataFrame:
                 raise ValueError("If `on` is not None then both `left_on` and `right_on` must be None")
             left_on = on
             right_on = on
-        join_type = JoinType.from_join_type_str(how)
-        if join_type != JoinType.Inner:
-            raise ValueError(f"Only inner joins are currently supported, but got: {how}")
         join_strategy = JoinStrategy.from_join_strategy_str(strategy) if strategy is not None else None
 
         left_exprs = self.__column_input_to_expression(tuple(left_on) if isinstance(left_on, list) else (left_on,))
@@ -1200,6 +1205,74 @@ class DataFrame:
         """
         return self._agg(self._inputs_to_expressions(to_agg), group_by=None)
 
+    @DataframePublicAPI
+    def window(
+        self,
+        *to_window: ColumnInputOrListType,
+        partition_by: Optional[Union[ColumnInputType, List[ColumnInputType]]] = None,
+        order_by: Optional[Union[ColumnInputType, List[ColumnInputType]]] = None,
+        desc: Union[bool, List[bool]] = False,
+        frame: Optional[Tuple[Optional[int], Optional[int]]] = None,
+    ) -> "DataFrame":
+        """Evaluates window functions over partitions of the DataFrame, appending one column per window expression
+
+        Every window expression is evaluated over the rows that share its values of ``partition_by``, ordered by
+        ``order_by``. Window expressions can either be window functions (:func:`daft.row_number`, :func:`daft.rank`,
+        :func:`daft.dense_rank`, :meth:`Expression.lag <daft.Expression.lag>` and
+        :meth:`Expression.lead <daft.Expression.lead>`) or ``count``, ``sum``, ``mean``, ``min`` and ``max``
+        aggregations, which are computed over a frame of rows around each row.
+
+        Example:
+            >>> df = df.window(
+            >>>     daft.row_number().alias("row_number"),
+            >>>     col("x").lag(1).alias("prev_x"),
+            >>>     col("x").sum().alias("running_sum"),
+            >>>     col("x").mean().alias("moving_avg"),
+            >>>     partition_by="group",
+            >>>     order_by="date",
+            >>> )
+            >>> # Mean over the previous, current and next rows.
+            >>> df = df.window(col("x").mean().alias("centered_avg"), order_by="date", frame=(-1, 1))
+
+        Note:
+            * All rows of a window partition are moved to the same partition, so windows without ``partition_by``
+              are evaluated on a single partition.
+            * The output is sorted by ``partition_by`` and ``order_by`` within each partition.
+
+        Args:
+            *to_window (Expression): window expressions to evaluate; each becomes a new column.
+            partition_by (Union[ColumnInputType, List[ColumnInputType]], optional): columns to partition the
+                windows by. Defaults to None, which evaluates every expression over the entire DataFrame.
+            order_by (Union[ColumnInputType, List[ColumnInputType]], optional): columns to order rows by within
+                each window partition. Defaults to None.
+            desc (Union[bool, List[bool]), optional): Order by descending order. Defaults to False.
+            frame (Tuple[Optional[int], Optional[int]], optional): start and end of the frame of rows that
+                aggregations are computed over, as offsets relative to the current row, where ``None`` means
+                unbounded. Defaults to ``(None, 0)`` if ``order_by`` is set (a running aggregation), and
+                ``(None, None)`` otherwise (an aggregation over the whole window partition).
+
+        Returns:
+            DataFrame: DataFrame with the window expression columns appended.
+        """
+        if partition_by is None:
+            partition_by = []
+        elif not isinstance(partition_by, list):
+            partition_by = [partition_by]
+        if order_by is None:
+            order_by = []
+        elif not isinstance(order_by, list):
+            order_by = [order_by]
+        if not isinstance(desc, list):
+            desc = [desc] * len(order_by)
+        builder = self._builder.window(
+            to_window=self._inputs_to_expressions(to_window),
+            partition_by=self.__column_input_to_expression(partition_by),
+            order_by=self.__column_input_to_expression(order_by),
+            descending=desc,
+            frame=frame,
+        )
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def groupby(self, *group_by: ColumnInputOrListType) -> "GroupedDataFrame":
         """Performs a GroupBy on the DataFrame for aggregation
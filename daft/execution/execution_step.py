@@ -584,6 +584,37 @@ class Sample(SingleOutputInstruction):
         ]
 
 
+@dataclass(frozen=True)
+class Window(SingleOutputInstruction):
+    to_window: ExpressionsProjection
+    partition_by: ExpressionsProjection
+    order_by: ExpressionsProjection
+    descending: list[bool]
+    frame_start: int | None
+    frame_end: int | None
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        [input] = inputs
+        result = input.window(
+            self.to_window,
+            self.partition_by,
+            self.order_by,
+            self.descending,
+            self.frame_start,
+            self.frame_end,
+        )
+        return [result]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        [input_meta] = input_metadatas
+        return [
+            PartialPartitionMetadata(
+                num_rows=input_meta.num_rows,
+                size_bytes=None,
+            )
+        ]
+
+
 @dataclass(frozen=True)
 class MonotonicallyIncreasingId(SingleOutputInstruction):
     partition_num: int
@@ -139,6 +139,30 @@ def sort(
     )
 
 
+def window(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    window_exprs: list[PyExpr],
+    partition_by: list[PyExpr],
+    order_by: list[PyExpr],
+    descending: list[bool],
+    frame_start: int | None,
+    frame_end: int | None,
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    window_step = execution_step.Window(
+        to_window=ExpressionsProjection([Expression._from_pyexpr(expr) for expr in window_exprs]),
+        partition_by=ExpressionsProjection([Expression._from_pyexpr(expr) for expr in partition_by]),
+        order_by=ExpressionsProjection([Expression._from_pyexpr(expr) for expr in order_by]),
+        descending=descending,
+        frame_start=frame_start,
+        frame_end=frame_end,
+    )
+    return physical_plan.pipeline_instruction(
+        child_plan=input,
+        pipeable_instruction=window_step,
+        resource_request=ResourceRequest(),
+    )
+
+
 def split_by_hash(
     input: physical_plan.InProgressPhysicalPlan[PartitionT],
     num_partitions: int,
@@ -1,5 +1,5 @@
 from __future__ import annotations
 
-from .expressions import Expression, ExpressionsProjection, col, lit
+from .expressions import Expression, ExpressionsProjection, col, dense_rank, lit, rank, row_number
 
-__all__ = ["Expression", "ExpressionsProjection", "col", "lit"]
+__all__ = ["Expression", "ExpressionsProjection", "col", "dense_rank", "lit", "rank", "row_number"]
@@ -15,7 +15,10 @@ from daft.daft import PyExpr as _PyExpr
 from daft.daft import col as _col
 from daft.daft import date_lit as _date_lit
 from daft.daft import decimal_lit as _decimal_lit
+from daft.daft import dense_rank as _dense_rank
 from daft.daft import lit as _lit
+from daft.daft import rank as _rank
+from daft.daft import row_number as _row_number
 from daft.daft import series_lit as _series_lit
 from daft.daft import time_lit as _time_lit
 from daft.daft import timestamp_lit as _timestamp_lit
@@ -114,6 +117,40 @@ def col(name: str) -> Expression:
     return Expression._from_pyexpr(_col(name))
 
 
+def row_number() -> Expression:
+    """Window function that numbers the rows of each window partition, starting from 1
+
+    Example:
+        >>> df.window(row_number().alias("row_number"), partition_by="group", order_by="date")
+
+    Returns:
+        Expression: Expression that can only be evaluated in :meth:`DataFrame.window <daft.DataFrame.window>`
+    """
+    return Expression._from_pyexpr(_row_number())
+
+
+def rank() -> Expression:
+    """Window function that ranks the rows of each window partition by ``order_by``, starting from 1
+
+    Rows with equal ``order_by`` values get the same rank, and leave a gap in the ranks after them.
+
+    Returns:
+        Expression: Expression that can only be evaluated in :meth:`DataFrame.window <daft.DataFrame.window>`
+    """
+    return Expression._from_pyexpr(_rank())
+
+
+def dense_rank() -> Expression:
+    """Window function that ranks the rows of each window partition by ``order_by``, starting from 1
+
+    Rows with equal ``order_by`` values get the same rank, without leaving gaps in the ranks after them.
+
+    Returns:
+        Expression: Expression that can only be evaluated in :meth:`DataFrame.window <daft.DataFrame.window>`
+    """
+    return Expression._from_pyexpr(_dense_rank())
+
+
 class Expression:
     _expr: _PyExpr = None  # type: ignore
 
@@ -455,6 +492,128 @@ class Expression:
         expr = self._expr.agg_concat()
         return Expression._from_pyexpr(expr)
 
+    def count_distinct(self) -> Expression:
+        """Counts the number of non-null distinct values in the expression"""
+        expr = self._expr.count_distinct()
+        return Expression._from_pyexpr(expr)
+
+    def sum_distinct(self) -> Expression:
+        """Calculates the sum of the non-null distinct values in the expression"""
+        expr = self._expr.sum_distinct()
+        return Expression._from_pyexpr(expr)
+
+    def agg_list_distinct(self) -> Expression:
+        """Aggregates the non-null distinct values in the expression into a list"""
+        expr = self._expr.agg_list_distinct()
+        return Expression._from_pyexpr(expr)
+
+    def variance(self, ddof: int = 1) -> Expression:
+        """Calculates the variance of the non-null values in the expression
+
+        Args:
+            ddof: delta degrees of freedom, the divisor used is ``N - ddof``. Defaults to 1 (sample variance).
+        """
+        expr = self._expr.variance(ddof)
+        return Expression._from_pyexpr(expr)
+
+    def stddev(self, ddof: int = 1) -> Expression:
+        """Calculates the standard deviation of the non-null values in the expression
+
+        Args:
+            ddof: delta degrees of freedom, the divisor used is ``N - ddof``. Defaults to 1 (sample standard deviation).
+        """
+        expr = self._expr.stddev(ddof)
+        return Expression._from_pyexpr(expr)
+
+    def skew(self) -> Expression:
+        """Calculates the population skewness of the non-null values in the expression"""
+        expr = self._expr.skew()
+        return Expression._from_pyexpr(expr)
+
+    def covar(self, other: Expression, ddof: int = 1) -> Expression:
+        """Calculates the covariance between this expression and ``other``, over rows where both are non-null
+
+        Args:
+            other: the expression to compute the covariance with
+            ddof: delta degrees of freedom, the divisor used is ``N - ddof``. Defaults to 1 (sample covariance).
+        """
+        other = Expression._to_expression(other)
+        expr = self._expr.covar(other._expr, ddof)
+        return Expression._from_pyexpr(expr)
+
+    def corr(self, other: Expression) -> Expression:
+        """Calculates the Pearson correlation coefficient between this expression and ``other``, over rows where
+        both are non-null
+
+        Args:
+            other: the expression to compute the correlation with
+        """
+        other = Expression._to_expression(other)
+        expr = self._expr.corr(other._expr)
+        return Expression._from_pyexpr(expr)
+
+    def approx_count_distinct(self) -> Expression:
+        """Calculates the approximate number of non-null distinct values in the expression
+
+        Values are hashed into a `HyperLogLog <https://en.wikipedia.org/wiki/HyperLogLog>`_ sketch per partition,
+        so estimates have a standard error of about 1.6% but never require shuffling the values themselves.
+
+        Example:
+            >>> df = daft.from_pydict({"values": [1, 2, 2, None]})
+            >>> df = df.agg(col("values").approx_count_distinct().alias("distinct_values"))
+        """
+        expr = self._expr.approx_count_distinct()
+        return Expression._from_pyexpr(expr)
+
+    def approx_percentiles(self, percentiles: builtins.float | builtins.list[builtins.float]) -> Expression:
+        """Calculates the approximate percentile(s) for a column of numeric values
+
+        Percentiles are computed from a `DDSketch <https://arxiv.org/pdf/1908.10693.pdf>`_ built over each
+        partition, which guarantees a relative error of at most 1% with respect to the true percentile value.
+        Null values are ignored, and an aggregation over only nulls returns null.
+
+        Example:
+            >>> df = daft.from_pydict({"scores": [1, 2, 3, 4, 5]})
+            >>> df = df.agg(
+            ...     col("scores").approx_percentiles(0.5).alias("median"),
+            ...     col("scores").approx_percentiles([0.25, 0.75]).alias("quartiles"),
+            ... )
+
+        Args:
+            percentiles: the percentile(s) to compute, each between 0.0 and 1.0 inclusive. If a single float is
+                provided the result is a Float64 column, and if a list is provided the result is a fixed-size
+                list of Float64 with one entry per requested percentile.
+        """
+        if isinstance(percentiles, builtins.list):
+            expr = self._expr.approx_percentiles([builtins.float(p) for p in percentiles], True)
+        else:
+            expr = self._expr.approx_percentiles([builtins.float(percentiles)], False)
+        return Expression._from_pyexpr(expr)
+
+    def lag(self, offset: int = 1, default: object = None) -> Expression:
+        """Window function that takes the value of the expression ``offset`` rows before the current row in its
+        window partition, or ``default`` if there is no such row
+
+        Args:
+            offset: number of rows to look back. Defaults to 1.
+            default: value to use when there is no row ``offset`` rows back. Defaults to None (null).
+        """
+        default_expr = Expression._to_expression(default)._expr if default is not None else None
+        expr = self._expr.lag(offset, default_expr)
+        return Expression._from_pyexpr(expr)
+
+    def lead(self, offset: int = 1, default: object = None) -> Expression:
+        """Window function that takes the value of the expression ``offset`` rows after the current row in its
+        window partition, or ``default`` if there is no such row
+
+        Args:
+            offset: number of rows to look ahead. Defaults to 1.
+            default: value to use when there is no row ``offset`` rows ahead. Defaults to None (null).
+        """
+        default_expr = Expression._to_expression(default)._expr if default is not None else None
+        expr = self._expr.lead(offset, default_expr)
+        return Expression._from_pyexpr(expr)
+
     def _explode(self) -> Expression:
         expr = self._expr.explode()
         return Expression._from_pyexpr(expr)
@@ -9,11 +9,14 @@ from daft.daft import (
     S3Config,
     set_io_pool_num_threads,
 )
+from daft.io._avro import read_avro
 from daft.io._csv import read_csv
 from daft.io._delta_lake import read_delta_lake
 from daft.io._hudi import read_hudi
 from daft.io._iceberg import read_iceberg
+from daft.io._ipc import read_ipc
 from daft.io._json import read_json
+from daft.io._orc import read_orc
 from daft.io._parquet import read_parquet
 from daft.io._sql import read_sql
 from daft.io.catalog import DataCatalogTable, DataCatalogType
@@ -37,6 +40,9 @@ if sys.platform == "linux":
 __all__ = [
     "read_csv",
     "read_json",
+    "read_ipc",
+    "read_avro",
+    "read_orc",
     "from_glob_path",
     "read_parquet",
     "read_hudi",
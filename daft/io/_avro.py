@@ -0,0 +1,56 @@
+# isort: dont-add-import: from __future__ import annotations
+
+from typing import Dict, List, Optional, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.daft import (
+    AvroSourceConfig,
+    FileFormatConfig,
+    IOConfig,
+    NativeStorageConfig,
+    StorageConfig,
+)
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.io.common import get_tabular_files_scan
+
+
+@PublicAPI
+def read_avro(
+    path: Union[str, List[str]],
+    schema_hints: Optional[Dict[str, DataType]] = None,
+    io_config: Optional["IOConfig"] = None,
+) -> DataFrame:
+    """Creates a DataFrame from Avro object container file(s)
+
+    Avro unions of ``null`` and a single other type are read as nullable columns of that type, other
+    unions are read as structs with one field per branch, and Avro records, arrays, maps, enums and
+    logical types are mapped to the corresponding Daft types. Files written with different (but
+    compatible) schemas are resolved against the schema of the DataFrame, filling in missing columns
+    with nulls.
+
+    Example:
+        >>> df = daft.read_avro("/path/to/file.avro")
+        >>> df = daft.read_avro("/path/to/directory")
+        >>> df = daft.read_avro("/path/to/files-*.avro")
+        >>> df = daft.read_avro("s3://path/to/files-*.avro")
+
+    Args:
+        path (str): Path to Avro files (allows for wildcards)
+        schema_hints (dict[str, DataType]): A mapping between column names and datatypes - passing this option
+            will override the specified columns on the inferred schema with the specified DataTypes
+        io_config (IOConfig): Config to be used with the native downloader
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of Avro filepaths")
+
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    file_format_config = FileFormatConfig.from_avro_config(AvroSourceConfig())
+    storage_config = StorageConfig.native(NativeStorageConfig(True, io_config))
+    builder = get_tabular_files_scan(path, schema_hints, file_format_config, storage_config=storage_config)
+    return DataFrame(builder)
@@ -28,6 +28,13 @@ def read_csv(
     quote: Optional[str] = None,
     escape_char: Optional[str] = None,
     comment: Optional[str] = None,
+    null_values: Optional[List[str]] = None,
+    true_values: Optional[List[str]] = None,
+    false_values: Optional[List[str]] = None,
+    skip_rows: int = 0,
+    ragged_rows: str = "error",
+    mode: str = "fail_fast",
+    corrupt_record_column: str = "_corrupt_record",
     io_config: Optional["IOConfig"] = None,
     use_native_downloader: bool = True,
     _buffer_size: Optional[int] = None,
@@ -46,10 +53,23 @@ def read_csv(
         schema_hints (dict[str, DataType]): A mapping between column names and datatypes - passing this option
             will override the specified columns on the inferred schema with the specified DataTypes
         has_headers (bool): Whether the CSV has a header or not, defaults to True
-        delimiter (Str): Delimiter used in the CSV, defaults to ","
+        delimiter (Str): Delimiter used in the CSV, which may be longer than one character, defaults to ","
         doubled_quote (bool): Whether to support double quote escapes, defaults to True
         escape_char (str): Character to use as the escape character for double quotes, or defaults to `"`
         comment (str): Character to treat as the start of a comment line, or None to not support comments
+        null_values (list[str]): Values, in addition to empty fields, to parse as null
+        true_values (list[str]): Values, in addition to "true", to parse as true
+        false_values (list[str]): Values, in addition to "false", to parse as false
+        skip_rows (int): Number of lines to skip at the start of each file, before the header, defaults to 0
+        ragged_rows (str): How to handle rows with fewer or more fields than the header: "error" fails the read,
+            "fill" fills missing fields with nulls and drops extra fields, and "reject" nulls out all fields of the
+            row and keeps the raw row in a ``_rejected_row`` column. Defaults to "error"
+        mode (str): How to handle malformed rows, i.e. rows with the wrong number of fields or with values that can't be
+            parsed as the type of their column: "fail_fast" fails the read on rows with the wrong number of fields and
+            reads unparseable values as nulls, "drop_malformed" drops malformed rows, and "permissive" nulls out the
+            unparseable fields and keeps the raw row and the error in a struct column. Defaults to "fail_fast"
+        corrupt_record_column (str): Name of the column holding the malformed rows in "permissive" mode, defaults to
+            "_corrupt_record"
         io_config (IOConfig): Config to be used with the native downloader
         use_native_downloader: Whether to use the native downloader instead of PyArrow for reading Parquet. This
             is currently experimental.
@@ -76,6 +96,13 @@ def read_csv(
         quote=quote,
         escape_char=escape_char,
         comment=comment,
+        null_values=null_values,
+        true_values=true_values,
+        false_values=false_values,
+        skip_rows=skip_rows,
+        ragged_rows=ragged_rows,
+        mode=mode,
+        corrupt_record_column=corrupt_record_column,
         buffer_size=_buffer_size,
         chunk_size=_chunk_size,
     )
@@ -0,0 +1,53 @@
+# isort: dont-add-import: from __future__ import annotations
+
+from typing import Dict, List, Optional, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.daft import (
+    ArrowIpcSourceConfig,
+    FileFormatConfig,
+    IOConfig,
+    NativeStorageConfig,
+    StorageConfig,
+)
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.io.common import get_tabular_files_scan
+
+
+@PublicAPI
+def read_ipc(
+    path: Union[str, List[str]],
+    schema_hints: Optional[Dict[str, DataType]] = None,
+    io_config: Optional["IOConfig"] = None,
+) -> DataFrame:
+    """Creates a DataFrame from Arrow IPC (Feather v2) file(s)
+
+    Both the random-access IPC file format (``.arrow``/``.feather``) and the IPC streaming format
+    (``.arrows``) are supported.
+
+    Example:
+        >>> df = daft.read_ipc("/path/to/file.arrow")
+        >>> df = daft.read_ipc("/path/to/directory")
+        >>> df = daft.read_ipc("/path/to/files-*.feather")
+        >>> df = daft.read_ipc("s3://path/to/files-*.arrow")
+
+    Args:
+        path (str): Path to Arrow IPC files (allows for wildcards)
+        schema_hints (dict[str, DataType]): A mapping between column names and datatypes - passing this option
+            will override the specified columns on the inferred schema with the specified DataTypes
+        io_config (IOConfig): Config to be used with the native downloader
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of Arrow IPC filepaths")
+
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    file_format_config = FileFormatConfig.from_arrow_ipc_config(ArrowIpcSourceConfig())
+    storage_config = StorageConfig.native(NativeStorageConfig(True, io_config))
+    builder = get_tabular_files_scan(path, schema_hints, file_format_config, storage_config=storage_config)
+    return DataFrame(builder)
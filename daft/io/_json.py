@@ -23,11 +23,16 @@ def read_json(
     schema_hints: Optional[Dict[str, DataType]] = None,
     io_config: Optional["IOConfig"] = None,
     use_native_downloader: bool = True,
+    mode: str = "fail_fast",
+    corrupt_record_column: str = "_corrupt_record",
     _buffer_size: Optional[int] = None,
     _chunk_size: Optional[int] = None,
 ) -> DataFrame:
     """Creates a DataFrame from line-delimited JSON file(s)
 
+    Files holding a single top-level JSON array of objects, or concatenated (e.g. pretty-printed, multi-line) JSON
+    objects, are also supported, and are detected from their first line.
+
     Example:
         >>> df = daft.read_json("/path/to/file.json")
         >>> df = daft.read_json("/path/to/directory")
@@ -41,6 +46,12 @@ def read_json(
         io_config (IOConfig): Config to be used with the native downloader
         use_native_downloader: Whether to use the native downloader instead of PyArrow for reading Parquet. This
             is currently experimental.
+        mode (str): How to handle malformed lines, i.e. lines that aren't JSON objects or with values that can't be
+            parsed as the type of their column: "fail_fast" fails the read on lines that aren't JSON objects and reads
+            unparseable values as nulls, "drop_malformed" drops malformed lines, and "permissive" nulls out the
+            unparseable fields and keeps the raw line and the error in a struct column. Defaults to "fail_fast"
+        corrupt_record_column (str): Name of the column holding the malformed lines in "permissive" mode, defaults to
+            "_corrupt_record"
 
     returns:
         DataFrame: parsed DataFrame
@@ -50,7 +61,9 @@ def read_json(
 
     io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
-    json_config = JsonSourceConfig(_buffer_size, _chunk_size)
+    json_config = JsonSourceConfig(
+        _buffer_size, _chunk_size, mode=mode, corrupt_record_column=corrupt_record_column
+    )
     file_format_config = FileFormatConfig.from_json_config(json_config)
     if use_native_downloader:
         storage_config = StorageConfig.native(NativeStorageConfig(True, io_config))
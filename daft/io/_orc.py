@@ -0,0 +1,53 @@
+# isort: dont-add-import: from __future__ import annotations
+
+from typing import Dict, List, Optional, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.daft import (
+    FileFormatConfig,
+    IOConfig,
+    NativeStorageConfig,
+    OrcSourceConfig,
+    StorageConfig,
+)
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.io.common import get_tabular_files_scan
+
+
+@PublicAPI
+def read_orc(
+    path: Union[str, List[str]],
+    schema_hints: Optional[Dict[str, DataType]] = None,
+    io_config: Optional["IOConfig"] = None,
+) -> DataFrame:
+    """Creates a DataFrame from ORC file(s)
+
+    Stripes whose statistics cannot match a filter on the DataFrame are skipped, and large files are
+    split into multiple scan tasks along stripe boundaries.
+
+    Example:
+        >>> df = daft.read_orc("/path/to/file.orc")
+        >>> df = daft.read_orc("/path/to/directory")
+        >>> df = daft.read_orc("/path/to/files-*.orc")
+        >>> df = daft.read_orc("s3://path/to/files-*.orc")
+
+    Args:
+        path (str): Path to ORC files (allows for wildcards)
+        schema_hints (dict[str, DataType]): A mapping between column names and datatypes - passing this option
+            will override the specified columns on the inferred schema with the specified DataTypes
+        io_config (IOConfig): Config to be used with the native downloader
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of ORC filepaths")
+
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    file_format_config = FileFormatConfig.from_orc_config(OrcSourceConfig())
+    storage_config = StorageConfig.native(NativeStorageConfig(True, io_config))
+    builder = get_tabular_files_scan(path, schema_hints, file_format_config, storage_config=storage_config)
+    return DataFrame(builder)
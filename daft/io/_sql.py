@@ -5,7 +5,12 @@ from typing import TYPE_CHECKING, Callable, Optional, Union
 
 from daft import context
 from daft.api_annotations import PublicAPI
-from daft.daft import PythonStorageConfig, ScanOperatorHandle, StorageConfig
+from daft.daft import (
+    NativeStorageConfig,
+    PythonStorageConfig,
+    ScanOperatorHandle,
+    StorageConfig,
+)
 from daft.dataframe import DataFrame
 from daft.logical.builder import LogicalPlanBuilder
 from daft.sql.sql_connection import SQLConnection
@@ -42,7 +47,9 @@ def read_sql(
             If the database or column type lacks percentile calculation support, Daft partitions the query using equal ranges between the column's minimum and maximum values.
 
         #. Execution:
-            Daft executes SQL queries using using `ConnectorX <https://sfu-db.github.io/connector-x/intro.html>`_ or `SQLAlchemy <https://docs.sqlalchemy.org/en/20/orm/quickstart.html#create-an-engine>`_,
+            SQLite (``sqlite://``) and PostgreSQL (``postgres://`` or ``postgresql://``) database URLs are read natively by Daft,
+            in which case partitions are computed from equal ranges between the partition column's minimum and maximum values.
+            Otherwise, Daft executes SQL queries using `ConnectorX <https://sfu-db.github.io/connector-x/intro.html>`_ or `SQLAlchemy <https://docs.sqlalchemy.org/en/20/orm/quickstart.html#create-an-engine>`_,
             preferring ConnectorX unless a SQLAlchemy connection factory is specified or the database dialect is unsupported by ConnectorX.
 
     Example:
@@ -78,8 +85,19 @@ def read_sql(
         raise ValueError("Failed to execute sql: partition_col must be specified when num_partitions is specified")
 
     io_config = context.get_context().daft_planning_config.default_io_config
-    storage_config = StorageConfig.python(PythonStorageConfig(io_config))
 
+    if isinstance(conn, str) and _is_natively_supported(conn):
+        handle = ScanOperatorHandle.sql_scan(
+            sql,
+            conn,
+            StorageConfig.native(NativeStorageConfig(True, io_config)),
+            partition_col,
+            num_partitions,
+            context.get_context().daft_execution_config.read_sql_partition_size_bytes,
+        )
+        return DataFrame(LogicalPlanBuilder.from_tabular_scan(scan_operator=handle))
+
+    storage_config = StorageConfig.python(PythonStorageConfig(io_config))
     sql_conn = SQLConnection.from_url(conn) if isinstance(conn, str) else SQLConnection.from_connection_factory(conn)
     sql_operator = SQLScanOperator(
         sql,
@@ -92,3 +110,8 @@ def read_sql(
     builder = LogicalPlanBuilder.from_tabular_scan(scan_operator=handle)
 
     return DataFrame(builder)
+
+
+def _is_natively_supported(url: str) -> bool:
+    sql_conn = SQLConnection.from_url(url)
+    return sql_conn.dialect in ("sqlite", "postgres", "postgresql") and sql_conn.driver == ""
@@ -42,6 +42,12 @@ class ScanOperator(abc.ABC):
     def can_absorb_select(self) -> bool:
         raise NotImplementedError()
 
+    def can_absorb_aggregation(self) -> bool:
+        """Whether the row counts and column statistics of this operator's scan tasks are exact, so that counts and
+        min/max aggregations (optionally grouped by partition columns) can be computed from them without reading data.
+        """
+        return False
+
     @abc.abstractmethod
     def multiline_display(self) -> list[str]:
         raise NotImplementedError()
@@ -110,7 +110,7 @@ class LogicalPlanBuilder:
         return LogicalPlanBuilder(builder)
 
     def count(self) -> LogicalPlanBuilder:
-        # TODO(Clark): Add dedicated logical/physical ops when introducing metadata-based count optimizations.
+        # Scans that can derive row counts from metadata absorb this aggregation (see the PushDownAggregation rule).
         first_col = col(self.schema().column_names()[0])
         builder = self._builder.aggregate([first_col.count(CountMode.All)._expr], [])
         builder = builder.project([first_col.alias("count")._expr], ResourceRequest())
@@ -153,6 +153,23 @@ class LogicalPlanBuilder:
         builder = self._builder.aggregate([expr._expr for expr in to_agg], group_by_pyexprs)
         return LogicalPlanBuilder(builder)
 
+    def window(
+        self,
+        to_window: list[Expression],
+        partition_by: list[Expression],
+        order_by: list[Expression],
+        descending: list[bool],
+        frame: tuple[int | None, int | None] | None,
+    ) -> LogicalPlanBuilder:
+        builder = self._builder.window(
+            [expr._expr for expr in to_window],
+            [expr._expr for expr in partition_by],
+            [expr._expr for expr in order_by],
+            descending,
+            frame,
+        )
+        return LogicalPlanBuilder(builder)
+
     def map_groups(self, udf: Expression, group_by: list[Expression] | None) -> LogicalPlanBuilder:
         group_by_pyexprs = [expr._expr for expr in group_by] if group_by is not None else []
         builder = self._builder.aggregate([udf._expr], group_by_pyexprs)
@@ -166,21 +183,14 @@ class LogicalPlanBuilder:
         how: JoinType = JoinType.Inner,
         strategy: JoinStrategy | None = None,
     ) -> LogicalPlanBuilder:
-        if how == JoinType.Left:
-            raise NotImplementedError("Left join not implemented.")
-        elif how == JoinType.Right:
-            raise NotImplementedError("Right join not implemented.")
-        elif how == JoinType.Inner:
-            builder = self._builder.join(
-                right._builder,
-                [expr._expr for expr in left_on],
-                [expr._expr for expr in right_on],
-                how,
-                strategy,
-            )
-            return LogicalPlanBuilder(builder)
-        else:
-            raise NotImplementedError(f"{how} join not implemented.")
+        builder = self._builder.join(
+            right._builder,
+            [expr._expr for expr in left_on],
+            [expr._expr for expr in right_on],
+            how,
+            strategy,
+        )
+        return LogicalPlanBuilder(builder)
 
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder:  # type: ignore[override]
         builder = self._builder.concat(other._builder)
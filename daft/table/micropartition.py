@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import logging
-from typing import TYPE_CHECKING, Any
+from typing import TYPE_CHECKING, Any, Iterator
 
 import pyarrow as pa
 
@@ -14,6 +14,7 @@ from daft.daft import (
     JsonConvertOptions,
     JsonParseOptions,
     JsonReadOptions,
+    PyDaftExecutionConfig,
 )
 from daft.daft import PyMicroPartition as _PyMicroPartition
 from daft.daft import PyTable as _PyTable
@@ -79,6 +80,17 @@ class MicroPartition:
         assert isinstance(scan_task, _ScanTask)
         return MicroPartition._from_pymicropartition(_PyMicroPartition.from_scan_task(scan_task))
 
+    @staticmethod
+    def _stream_scan_task(scan_task: _ScanTask, max_tables_in_flight: int = 1) -> Iterator[Table]:
+        """Streams the Tables of a ScanTask as they're read, with at most `max_tables_in_flight` read ahead"""
+        assert isinstance(scan_task, _ScanTask)
+        stream = _PyMicroPartition.stream_scan_task(scan_task, max_tables_in_flight)
+        try:
+            for pyt in stream:
+                yield Table._from_pytable(pyt)
+        finally:
+            stream.close()
+
     @staticmethod
     def _from_pytable(pyt: _PyTable) -> MicroPartition:
         assert isinstance(pyt, _PyTable)
@@ -225,6 +237,31 @@ class MicroPartition:
     def quantiles(self, num: int) -> MicroPartition:
         return MicroPartition._from_pymicropartition(self._micropartition.quantiles(num))
 
+    def window(
+        self,
+        to_window: ExpressionsProjection,
+        partition_by: ExpressionsProjection,
+        order_by: ExpressionsProjection,
+        descending: list[bool],
+        frame_start: int | None = None,
+        frame_end: int | None = None,
+    ) -> MicroPartition:
+        """Evaluates window functions over the rows of each window partition.
+
+        The result contains the input columns followed by one column per window expression, with rows
+        sorted by the partitioning and ordering keys.
+        """
+        return MicroPartition._from_pymicropartition(
+            self._micropartition.window(
+                [e._expr for e in to_window],
+                [e._expr for e in partition_by],
+                [e._expr for e in order_by],
+                descending,
+                frame_start,
+                frame_end,
+            )
+        )
+
     def explode(self, columns: ExpressionsProjection) -> MicroPartition:
         """NOTE: Expressions here must be Explode expressions (Expression._explode())"""
         to_explode_pyexprs = [e._expr for e in columns]
@@ -237,8 +274,6 @@ class MicroPartition:
         right_on: ExpressionsProjection,
         how: JoinType = JoinType.Inner,
     ) -> MicroPartition:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -251,7 +286,7 @@ class MicroPartition:
         right_exprs = [e._expr for e in right_on]
 
         return MicroPartition._from_pymicropartition(
-            self._micropartition.hash_join(right._micropartition, left_on=left_exprs, right_on=right_exprs)
+            self._micropartition.hash_join(right._micropartition, left_on=left_exprs, right_on=right_exprs, how=how)
         )
 
     def sort_merge_join(
@@ -262,8 +297,6 @@ class MicroPartition:
         how: JoinType = JoinType.Inner,
         is_sorted: bool = False,
     ) -> MicroPartition:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -277,7 +310,7 @@ class MicroPartition:
 
         return MicroPartition._from_pymicropartition(
             self._micropartition.sort_merge_join(
-                right._micropartition, left_on=left_exprs, right_on=right_exprs, is_sorted=is_sorted
+                right._micropartition, left_on=left_exprs, right_on=right_exprs, how=how, is_sorted=is_sorted
             )
         )
 
@@ -322,6 +355,17 @@ class MicroPartition:
             values
         )
 
+    def write_parquet(
+        self,
+        root_dir: str,
+        compression: str | None = None,
+        io_config: IOConfig | None = None,
+        execution_config: PyDaftExecutionConfig | None = None,
+    ) -> MicroPartition:
+        return MicroPartition._from_pymicropartition(
+            self._micropartition.write_parquet(root_dir, compression, io_config, execution_config)
+        )
+
     def add_monotonically_increasing_id(self, partition_num: int, column_name: str) -> MicroPartition:
         return MicroPartition._from_pymicropartition(
             self._micropartition.add_monotonically_increasing_id(partition_num, column_name)
@@ -309,6 +309,31 @@ class Table:
     def quantiles(self, num: int) -> Table:
         return Table._from_pytable(self._table.quantiles(num))
 
+    def window(
+        self,
+        to_window: ExpressionsProjection,
+        partition_by: ExpressionsProjection,
+        order_by: ExpressionsProjection,
+        descending: list[bool],
+        frame_start: int | None = None,
+        frame_end: int | None = None,
+    ) -> Table:
+        """Evaluates window functions over the rows of each window partition.
+
+        The result contains the input columns followed by one column per window expression, with rows
+        sorted by the partitioning and ordering keys.
+        """
+        return Table._from_pytable(
+            self._table.window(
+                [e._expr for e in to_window],
+                [e._expr for e in partition_by],
+                [e._expr for e in order_by],
+                descending,
+                frame_start,
+                frame_end,
+            )
+        )
+
     def explode(self, columns: ExpressionsProjection) -> Table:
         """NOTE: Expressions here must be Explode expressions (Expression._explode())"""
         to_explode_pyexprs = [e._expr for e in columns]
@@ -321,8 +346,6 @@ class Table:
         right_on: ExpressionsProjection,
         how: JoinType = JoinType.Inner,
     ) -> Table:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -334,7 +357,7 @@ class Table:
         left_exprs = [e._expr for e in left_on]
         right_exprs = [e._expr for e in right_on]
 
-        return Table._from_pytable(self._table.hash_join(right._table, left_on=left_exprs, right_on=right_exprs))
+        return Table._from_pytable(self._table.hash_join(right._table, left_on=left_exprs, right_on=right_exprs, how=how))
 
     def sort_merge_join(
         self,
@@ -344,8 +367,6 @@ class Table:
         how: JoinType = JoinType.Inner,
         is_sorted: bool = False,
     ) -> Table:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -358,7 +379,9 @@ class Table:
         right_exprs = [e._expr for e in right_on]
 
         return Table._from_pytable(
-            self._table.sort_merge_join(right._table, left_on=left_exprs, right_on=right_exprs, is_sorted=is_sorted)
+            self._table.sort_merge_join(
+                right._table, left_on=left_exprs, right_on=right_exprs, how=how, is_sorted=is_sorted
+            )
         )
 
     def partition_by_hash(self, exprs: ExpressionsProjection, num_partitions: int) -> list[Table]:
@@ -455,10 +455,8 @@ def write_tabular(
     TARGET_ROW_GROUP_SIZE = execution_config.parquet_target_row_group_size
 
     if file_format == FileFormat.Parquet:
-        format = pads.ParquetFileFormat()
-        inflation_factor = execution_config.parquet_inflation_factor
-        target_file_size = execution_config.parquet_target_filesize
-        opts = format.make_write_options(compression=compression)
+        # Parquet is written natively, which sizes the files and row groups itself.
+        pass
     elif file_format == FileFormat.Csv:
         format = pads.CsvFileFormat()
         opts = None
@@ -468,11 +466,25 @@ def write_tabular(
     else:
         raise ValueError(f"Unsupported file format {file_format}")
 
+    native_prefix = "" if is_local_fs else f"{canonicalized_protocol}://"
+    native_root = f"{native_prefix}{resolved_path}"
+
     for i, (tab, pf) in enumerate(zip(tables_to_write, part_keys_postfix_per_table)):
         full_path = resolved_path
         if pf is not None and len(pf) > 0:
             full_path = f"{full_path}/{pf}"
 
+        if file_format == FileFormat.Parquet:
+            native_path = native_root if pf is None or len(pf) == 0 else f"{native_root}/{pf}"
+            written = tab.write_parquet(
+                native_path, compression=compression, io_config=io_config, execution_config=execution_config
+            )
+            # Report the paths without their protocol, like the pyarrow writes do.
+            written_paths = [path[len(native_prefix) :] for path in written.get_column("path").to_pylist()]
+            visited_paths.extend(written_paths)
+            partition_idx.extend([i] * len(written_paths))
+            continue
+
         arrow_table = tab.to_arrow()
 
         size_bytes = arrow_table.nbytes
@@ -28,12 +28,14 @@ pub struct DaftExecutionConfig {
     pub sort_merge_join_sort_with_aligned_boundaries: bool,
     pub sample_size_for_sort: usize,
     pub parquet_split_row_groups_max_files: usize,
+    pub orc_split_stripes_max_files: usize,
     pub num_preview_rows: usize,
     pub parquet_target_filesize: usize,
     pub parquet_target_row_group_size: usize,
     pub parquet_inflation_factor: f64,
     pub csv_target_filesize: usize,
     pub csv_inflation_factor: f64,
+    pub split_csv_by_byte_ranges: bool,
     pub shuffle_aggregation_default_partitions: usize,
     pub read_sql_partition_size_bytes: usize,
 }
@@ -47,12 +49,14 @@ impl Default for DaftExecutionConfig {
             sort_merge_join_sort_with_aligned_boundaries: false,
             sample_size_for_sort: 20,
             parquet_split_row_groups_max_files: 10,
+            orc_split_stripes_max_files: 10,
             num_preview_rows: 8,
             parquet_target_filesize: 512 * 1024 * 1024, // 512MB
             parquet_target_row_group_size: 128 * 1024 * 1024, // 128MB
             parquet_inflation_factor: 3.0,
             csv_target_filesize: 512 * 1024 * 1024, // 512MB
             csv_inflation_factor: 0.5,
+            split_csv_by_byte_ranges: false,
             shuffle_aggregation_default_partitions: 200,
             read_sql_partition_size_bytes: 512 * 1024 * 1024, // 512MB
         }
@@ -81,6 +81,7 @@ impl PyDaftExecutionConfig {
         scan_tasks_max_size_bytes: Option<usize>,
         broadcast_join_size_bytes_threshold: Option<usize>,
         parquet_split_row_groups_max_files: Option<usize>,
+        orc_split_stripes_max_files: Option<usize>,
         sort_merge_join_sort_with_aligned_boundaries: Option<bool>,
         sample_size_for_sort: Option<usize>,
         num_preview_rows: Option<usize>,
@@ -89,6 +90,7 @@ impl PyDaftExecutionConfig {
         parquet_inflation_factor: Option<f64>,
         csv_target_filesize: Option<usize>,
         csv_inflation_factor: Option<f64>,
+        split_csv_by_byte_ranges: Option<bool>,
         shuffle_aggregation_default_partitions: Option<usize>,
         read_sql_partition_size_bytes: Option<usize>,
     ) -> PyResult<PyDaftExecutionConfig> {
@@ -106,6 +108,9 @@ impl PyDaftExecutionConfig {
         if let Some(parquet_split_row_groups_max_files) = parquet_split_row_groups_max_files {
             config.parquet_split_row_groups_max_files = parquet_split_row_groups_max_files;
         }
+        if let Some(orc_split_stripes_max_files) = orc_split_stripes_max_files {
+            config.orc_split_stripes_max_files = orc_split_stripes_max_files;
+        }
         if let Some(sort_merge_join_sort_with_aligned_boundaries) =
             sort_merge_join_sort_with_aligned_boundaries
         {
@@ -133,6 +138,9 @@ impl PyDaftExecutionConfig {
         if let Some(csv_inflation_factor) = csv_inflation_factor {
             config.csv_inflation_factor = csv_inflation_factor;
         }
+        if let Some(split_csv_by_byte_ranges) = split_csv_by_byte_ranges {
+            config.split_csv_by_byte_ranges = split_csv_by_byte_ranges;
+        }
         if let Some(shuffle_aggregation_default_partitions) = shuffle_aggregation_default_partitions
         {
             config.shuffle_aggregation_default_partitions = shuffle_aggregation_default_partitions;
@@ -201,6 +209,11 @@ impl PyDaftExecutionConfig {
         Ok(self.config.csv_inflation_factor)
     }
 
+    #[getter]
+    fn get_split_csv_by_byte_ranges(&self) -> PyResult<bool> {
+        Ok(self.config.split_csv_by_byte_ranges)
+    }
+
     #[getter]
     fn get_shuffle_aggregation_default_partitions(&self) -> PyResult<usize> {
         Ok(self.config.shuffle_aggregation_default_partitions)
@@ -0,0 +1,607 @@
+use arrow2::{
+    array::{
+        Array, BooleanArray, ListArray, MapArray, MutableArray, MutableBinaryArray,
+        MutableBooleanArray, MutablePrimitiveArray, MutableUtf8Array, NullArray, StructArray,
+    },
+    bitmap::MutableBitmap,
+    datatypes::{DataType, Field, TimeUnit},
+    error::{Error, Result},
+    io::avro::avro_schema::schema::{
+        BytesLogical, Enum, Fixed, FixedLogical, IntLogical, LongLogical, Record,
+        Schema as AvroSchema,
+    },
+    offset::OffsetsBuffer,
+};
+
+/// Timezone of Avro's (UTC-adjusted) `timestamp-*` logical types.
+const UTC: &str = "+00:00";
+
+/// Decodes the Avro binary encoding of values of one Avro schema into an Arrow array.
+///
+/// Avro types are mapped onto types that Daft supports:
+/// * `enum` and `string` (including `uuid`) become Utf8, `bytes` and `fixed` become Binary.
+/// * `date`, `time-*` and `timestamp-*` become Date32, Time64(us) and Timestamp.
+/// * `decimal` becomes Decimal, `map` becomes a Map with Utf8 keys, `record` becomes a Struct.
+/// * A union of `null` with a single other type becomes a nullable column of that type, and any
+///   other union becomes a Struct with one `member{i}` field per non-null branch, of which only the
+///   branch that was written is valid.
+///
+/// Arrow fields are always nullable and named following Daft's conventions (`item` for list and
+/// map children), so the decoded arrays can be turned into Daft Series without casting.
+pub(crate) enum ColumnDecoder {
+    Null(usize),
+    Boolean(MutableBooleanArray),
+    Int(MutablePrimitiveArray<i32>),
+    Long {
+        values: MutablePrimitiveArray<i64>,
+        multiplier: i64,
+    },
+    Float(MutablePrimitiveArray<f32>),
+    Double(MutablePrimitiveArray<f64>),
+    Binary {
+        values: MutableBinaryArray<i64>,
+        fixed_size: Option<usize>,
+    },
+    Utf8(MutableUtf8Array<i64>),
+    Enum {
+        symbols: Vec<String>,
+        values: MutableUtf8Array<i64>,
+    },
+    Decimal {
+        values: MutablePrimitiveArray<i128>,
+        fixed_size: Option<usize>,
+    },
+    List {
+        offsets: Vec<i64>,
+        validity: MutableBitmap,
+        items: Box<ColumnDecoder>,
+    },
+    Map {
+        offsets: Vec<i32>,
+        validity: MutableBitmap,
+        keys: MutableUtf8Array<i64>,
+        values: Box<ColumnDecoder>,
+    },
+    Struct {
+        fields: Vec<(String, ColumnDecoder)>,
+        validity: MutableBitmap,
+    },
+    Nullable {
+        null_branch: i64,
+        inner: Box<ColumnDecoder>,
+    },
+    Union {
+        /// Maps each branch of the union to its member, or `None` for the `null` branch.
+        branches: Vec<Option<usize>>,
+        members: Vec<ColumnDecoder>,
+        validity: MutableBitmap,
+    },
+}
+
+impl ColumnDecoder {
+    pub fn try_new(schema: &AvroSchema) -> Result<Self> {
+        Ok(match schema {
+            AvroSchema::Null => Self::Null(0),
+            AvroSchema::Boolean => Self::Boolean(MutableBooleanArray::new()),
+            AvroSchema::Int(logical) => match logical {
+                None => Self::Int(MutablePrimitiveArray::new()),
+                Some(IntLogical::Date) => {
+                    Self::Int(MutablePrimitiveArray::new().to(DataType::Date32))
+                }
+                Some(IntLogical::Time) => Self::Long {
+                    values: MutablePrimitiveArray::new()
+                        .to(DataType::Time64(TimeUnit::Microsecond)),
+                    multiplier: 1000,
+                },
+            },
+            AvroSchema::Long(logical) => {
+                let data_type = match logical {
+                    None => DataType::Int64,
+                    Some(LongLogical::Time) => DataType::Time64(TimeUnit::Microsecond),
+                    Some(LongLogical::TimestampMillis) => {
+                        DataType::Timestamp(TimeUnit::Millisecond, Some(UTC.to_string()))
+                    }
+                    Some(LongLogical::TimestampMicros) => {
+                        DataType::Timestamp(TimeUnit::Microsecond, Some(UTC.to_string()))
+                    }
+                    Some(LongLogical::LocalTimestampMillis) => {
+                        DataType::Timestamp(TimeUnit::Millisecond, None)
+                    }
+                    Some(LongLogical::LocalTimestampMicros) => {
+                        DataType::Timestamp(TimeUnit::Microsecond, None)
+                    }
+                };
+                Self::Long {
+                    values: MutablePrimitiveArray::new().to(data_type),
+                    multiplier: 1,
+                }
+            }
+            AvroSchema::Float => Self::Float(MutablePrimitiveArray::new()),
+            AvroSchema::Double => Self::Double(MutablePrimitiveArray::new()),
+            AvroSchema::Bytes(logical) => match logical {
+                None => Self::Binary {
+                    values: MutableBinaryArray::new(),
+                    fixed_size: None,
+                },
+                Some(BytesLogical::Decimal(precision, scale)) => Self::Decimal {
+                    values: MutablePrimitiveArray::new().to(DataType::Decimal(*precision, *scale)),
+                    fixed_size: None,
+                },
+            },
+            AvroSchema::String(_) => Self::Utf8(MutableUtf8Array::new()),
+            AvroSchema::Enum(Enum { symbols, .. }) => Self::Enum {
+                symbols: symbols.clone(),
+                values: MutableUtf8Array::new(),
+            },
+            AvroSchema::Fixed(Fixed { size, logical, .. }) => match logical {
+                Some(FixedLogical::Decimal(precision, scale)) => Self::Decimal {
+                    values: MutablePrimitiveArray::new().to(DataType::Decimal(*precision, *scale)),
+                    fixed_size: Some(*size),
+                },
+                // Daft has no interval type, so durations are kept as their raw 12 bytes.
+                Some(FixedLogical::Duration) | None => Self::Binary {
+                    values: MutableBinaryArray::new(),
+                    fixed_size: Some(*size),
+                },
+            },
+            AvroSchema::Array(items) => Self::List {
+                offsets: vec![0],
+                validity: MutableBitmap::new(),
+                items: Box::new(Self::try_new(items)?),
+            },
+            AvroSchema::Map(values) => Self::Map {
+                offsets: vec![0],
+                validity: MutableBitmap::new(),
+                keys: MutableUtf8Array::new(),
+                values: Box::new(Self::try_new(values)?),
+            },
+            AvroSchema::Record(Record { fields, .. }) => Self::Struct {
+                fields: fields
+                    .iter()
+                    .map(|field| Ok((field.name.clone(), Self::try_new(&field.schema)?)))
+                    .collect::<Result<_>>()?,
+                validity: MutableBitmap::new(),
+            },
+            AvroSchema::Union(schemas) => {
+                let null_branch = schemas.iter().position(|s| matches!(s, AvroSchema::Null));
+                match (null_branch, schemas.as_slice()) {
+                    (Some(null_branch), [_, _]) => Self::Nullable {
+                        null_branch: null_branch as i64,
+                        inner: Box::new(Self::try_new(&schemas[1 - null_branch])?),
+                    },
+                    _ => {
+                        let mut members = vec![];
+                        let branches = schemas
+                            .iter()
+                            .map(|schema| match schema {
+                                AvroSchema::Null => Ok(None),
+                                _ => {
+                                    members.push(Self::try_new(schema)?);
+                                    Ok(Some(members.len() - 1))
+                                }
+                            })
+                            .collect::<Result<_>>()?;
+                        Self::Union {
+                            branches,
+                            members,
+                            validity: MutableBitmap::new(),
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Self::Null(_) => DataType::Null,
+            Self::Boolean(values) => values.data_type().clone(),
+            Self::Int(values) => values.data_type().clone(),
+            Self::Long { values, .. } => values.data_type().clone(),
+            Self::Float(values) => values.data_type().clone(),
+            Self::Double(values) => values.data_type().clone(),
+            Self::Binary { values, .. } => values.data_type().clone(),
+            Self::Utf8(values) | Self::Enum { values, .. } => values.data_type().clone(),
+            Self::Decimal { values, .. } => values.data_type().clone(),
+            Self::List { items, .. } => {
+                DataType::LargeList(Box::new(Field::new("item", items.data_type(), true)))
+            }
+            Self::Map { values, .. } => Self::map_data_type(values.data_type()),
+            Self::Struct { fields, .. } => DataType::Struct(
+                fields
+                    .iter()
+                    .map(|(name, decoder)| Field::new(name, decoder.data_type(), true))
+                    .collect(),
+            ),
+            Self::Nullable { inner, .. } => inner.data_type(),
+            Self::Union { members, .. } => DataType::Struct(
+                members
+                    .iter()
+                    .enumerate()
+                    .map(|(i, decoder)| Field::new(format!("member{i}"), decoder.data_type(), true))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn map_data_type(value_type: DataType) -> DataType {
+        let entries = DataType::Struct(vec![
+            Field::new("key", DataType::LargeUtf8, true),
+            Field::new("value", value_type, true),
+        ]);
+        DataType::Map(Box::new(Field::new("item", entries, true)), false)
+    }
+
+    /// Decodes a single value from the front of `buf`.
+    pub fn decode(&mut self, buf: &mut &[u8]) -> Result<()> {
+        match self {
+            Self::Null(len) => *len += 1,
+            Self::Boolean(values) => {
+                let byte = read_fixed(buf, 1)?[0];
+                values.push(Some(byte != 0));
+            }
+            Self::Int(values) => {
+                let value = read_long(buf)?;
+                let value = i32::try_from(value)
+                    .map_err(|_| oos(format!("Avro int {value} overflows 32 bits")))?;
+                values.push(Some(value));
+            }
+            Self::Long { values, multiplier } => {
+                values.push(Some(read_long(buf)? * *multiplier));
+            }
+            Self::Float(values) => {
+                let bytes = read_fixed(buf, 4)?;
+                values.push(Some(f32::from_le_bytes(bytes.try_into().unwrap())));
+            }
+            Self::Double(values) => {
+                let bytes = read_fixed(buf, 8)?;
+                values.push(Some(f64::from_le_bytes(bytes.try_into().unwrap())));
+            }
+            Self::Binary { values, fixed_size } => {
+                let bytes = match fixed_size {
+                    Some(size) => read_fixed(buf, *size)?,
+                    None => read_bytes(buf)?,
+                };
+                values.push(Some(bytes));
+            }
+            Self::Utf8(values) => {
+                let bytes = read_bytes(buf)?;
+                let value = std::str::from_utf8(bytes)
+                    .map_err(|_| oos("Avro string is not valid UTF-8"))?;
+                values.push(Some(value));
+            }
+            Self::Enum { symbols, values } => {
+                let index = read_long(buf)?;
+                let symbol = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| symbols.get(index))
+                    .ok_or_else(|| oos(format!("Avro enum index {index} out of range")))?;
+                values.push(Some(symbol.as_str()));
+            }
+            Self::Decimal { values, fixed_size } => {
+                let bytes = match fixed_size {
+                    Some(size) => read_fixed(buf, *size)?,
+                    None => read_bytes(buf)?,
+                };
+                values.push(Some(decode_decimal(bytes)?));
+            }
+            Self::List {
+                offsets,
+                validity,
+                items,
+            } => {
+                let mut len = 0;
+                read_blocks(buf, |buf| {
+                    len += 1;
+                    items.decode(buf)
+                })?;
+                offsets.push(offsets.last().unwrap() + len);
+                validity.push(true);
+            }
+            Self::Map {
+                offsets,
+                validity,
+                keys,
+                values,
+            } => {
+                let mut len = 0;
+                read_blocks(buf, |buf| {
+                    len += 1;
+                    let key = std::str::from_utf8(read_bytes(buf)?)
+                        .map_err(|_| oos("Avro map key is not valid UTF-8"))?;
+                    keys.push(Some(key));
+                    values.decode(buf)
+                })?;
+                offsets.push(offsets.last().unwrap() + len);
+                validity.push(true);
+            }
+            Self::Struct { fields, validity } => {
+                for (_, decoder) in fields.iter_mut() {
+                    decoder.decode(buf)?;
+                }
+                validity.push(true);
+            }
+            Self::Nullable { null_branch, inner } => {
+                if read_long(buf)? == *null_branch {
+                    inner.push_null();
+                } else {
+                    inner.decode(buf)?;
+                }
+            }
+            Self::Union {
+                branches,
+                members,
+                validity,
+            } => {
+                let branch = read_long(buf)?;
+                let member = usize::try_from(branch)
+                    .ok()
+                    .and_then(|branch| branches.get(branch))
+                    .ok_or_else(|| oos(format!("Avro union branch {branch} out of range")))?;
+                for (i, decoder) in members.iter_mut().enumerate() {
+                    if *member == Some(i) {
+                        decoder.decode(buf)?;
+                    } else {
+                        decoder.push_null();
+                    }
+                }
+                validity.push(member.is_some());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn push_null(&mut self) {
+        match self {
+            Self::Null(len) => *len += 1,
+            Self::Boolean(values) => values.push_null(),
+            Self::Int(values) => values.push_null(),
+            Self::Long { values, .. } => values.push_null(),
+            Self::Float(values) => values.push_null(),
+            Self::Double(values) => values.push_null(),
+            Self::Binary { values, .. } => values.push_null(),
+            Self::Utf8(values) | Self::Enum { values, .. } => values.push_null(),
+            Self::Decimal { values, .. } => values.push_null(),
+            Self::List {
+                offsets, validity, ..
+            } => {
+                offsets.push(*offsets.last().unwrap());
+                validity.push(false);
+            }
+            Self::Map {
+                offsets, validity, ..
+            } => {
+                offsets.push(*offsets.last().unwrap());
+                validity.push(false);
+            }
+            Self::Struct { fields, validity } => {
+                fields
+                    .iter_mut()
+                    .for_each(|(_, decoder)| decoder.push_null());
+                validity.push(false);
+            }
+            Self::Nullable { inner, .. } => inner.push_null(),
+            Self::Union {
+                members, validity, ..
+            } => {
+                members.iter_mut().for_each(|decoder| decoder.push_null());
+                validity.push(false);
+            }
+        }
+    }
+
+    pub fn finish(self) -> Box<dyn Array> {
+        let data_type = self.data_type();
+        match self {
+            Self::Null(len) => NullArray::new(DataType::Null, len).boxed(),
+            Self::Boolean(values) => BooleanArray::from(values).boxed(),
+            Self::Int(mut values) => values.as_box(),
+            Self::Long { mut values, .. } => values.as_box(),
+            Self::Float(mut values) => values.as_box(),
+            Self::Double(mut values) => values.as_box(),
+            Self::Binary { mut values, .. } => values.as_box(),
+            Self::Utf8(mut values) | Self::Enum { mut values, .. } => values.as_box(),
+            Self::Decimal { mut values, .. } => values.as_box(),
+            Self::List {
+                offsets,
+                validity,
+                items,
+            } => ListArray::<i64>::new(
+                data_type,
+                OffsetsBuffer::try_from(offsets).unwrap(),
+                items.finish(),
+                validity.into(),
+            )
+            .boxed(),
+            Self::Map {
+                offsets,
+                validity,
+                mut keys,
+                values,
+            } => {
+                let DataType::Map(entries, _) = &data_type else {
+                    unreachable!()
+                };
+                let entries = StructArray::new(
+                    entries.data_type().clone(),
+                    vec![keys.as_box(), values.finish()],
+                    None,
+                );
+                MapArray::new(
+                    data_type,
+                    OffsetsBuffer::try_from(offsets).unwrap(),
+                    entries.boxed(),
+                    validity.into(),
+                )
+                .boxed()
+            }
+            Self::Struct { fields, validity } => StructArray::new(
+                data_type,
+                fields
+                    .into_iter()
+                    .map(|(_, decoder)| decoder.finish())
+                    .collect(),
+                validity.into(),
+            )
+            .boxed(),
+            Self::Nullable { inner, .. } => inner.finish(),
+            Self::Union {
+                members, validity, ..
+            } => StructArray::new(
+                data_type,
+                members
+                    .into_iter()
+                    .map(|decoder| decoder.finish())
+                    .collect(),
+                validity.into(),
+            )
+            .boxed(),
+        }
+    }
+}
+
+/// Skips over a single value of the given schema at the front of `buf`.
+pub(crate) fn skip(schema: &AvroSchema, buf: &mut &[u8]) -> Result<()> {
+    match schema {
+        AvroSchema::Null => {}
+        AvroSchema::Boolean => {
+            read_fixed(buf, 1)?;
+        }
+        AvroSchema::Int(_) | AvroSchema::Long(_) | AvroSchema::Enum(_) => {
+            read_long(buf)?;
+        }
+        AvroSchema::Float => {
+            read_fixed(buf, 4)?;
+        }
+        AvroSchema::Double => {
+            read_fixed(buf, 8)?;
+        }
+        AvroSchema::Bytes(_) | AvroSchema::String(_) => {
+            read_bytes(buf)?;
+        }
+        AvroSchema::Fixed(Fixed { size, .. }) => {
+            read_fixed(buf, *size)?;
+        }
+        AvroSchema::Array(items) => read_blocks(buf, |buf| skip(items, buf))?,
+        AvroSchema::Map(values) => read_blocks(buf, |buf| {
+            read_bytes(buf)?;
+            skip(values, buf)
+        })?,
+        AvroSchema::Record(Record { fields, .. }) => {
+            for field in fields {
+                skip(&field.schema, buf)?;
+            }
+        }
+        AvroSchema::Union(schemas) => {
+            let branch = read_long(buf)?;
+            let schema = usize::try_from(branch)
+                .ok()
+                .and_then(|branch| schemas.get(branch))
+                .ok_or_else(|| oos(format!("Avro union branch {branch} out of range")))?;
+            skip(schema, buf)?;
+        }
+    }
+    Ok(())
+}
+
+fn oos(message: impl Into<String>) -> Error {
+    Error::OutOfSpec(message.into())
+}
+
+/// Reads a zig-zag encoded variable-length `int` or `long`.
+fn read_long(buf: &mut &[u8]) -> Result<i64> {
+    let mut value = 0u64;
+    for (i, byte) in buf.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7F) << (i * 7);
+        if byte & 0x80 == 0 {
+            *buf = &buf[i + 1..];
+            return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+        }
+    }
+    Err(oos("Invalid or truncated Avro varint"))
+}
+
+fn read_fixed<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if buf.len() < len {
+        return Err(oos("Truncated Avro value"));
+    }
+    let (value, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(value)
+}
+
+fn read_bytes<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let len = read_long(buf)?;
+    let len = usize::try_from(len).map_err(|_| oos(format!("Negative Avro bytes length {len}")))?;
+    read_fixed(buf, len)
+}
+
+/// Reads the blocks of an Avro `array` or `map`, calling `read_item` once per item.
+fn read_blocks<F: FnMut(&mut &[u8]) -> Result<()>>(
+    buf: &mut &[u8],
+    mut read_item: F,
+) -> Result<()> {
+    loop {
+        let count = read_long(buf)?;
+        if count == 0 {
+            return Ok(());
+        }
+        if count < 0 {
+            // A negative count is followed by the size of the block in bytes.
+            read_long(buf)?;
+        }
+        for _ in 0..count.unsigned_abs() {
+            read_item(buf)?;
+        }
+    }
+}
+
+/// Decodes the big-endian two's-complement unscaled value of an Avro `decimal`.
+fn decode_decimal(bytes: &[u8]) -> Result<i128> {
+    if bytes.len() > 16 {
+        return Err(oos(format!(
+            "Avro decimals of {} bytes are not supported",
+            bytes.len()
+        )));
+    }
+    let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+        0xFF
+    } else {
+        0
+    };
+    let mut be = [fill; 16];
+    be[16 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i128::from_be_bytes(be))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_decimal, read_long};
+
+    #[test]
+    fn test_read_long() {
+        for (bytes, expected) in [
+            (vec![0x00], 0),
+            (vec![0x01], -1),
+            (vec![0x02], 1),
+            (vec![0x7F], -64),
+            (vec![0x80, 0x01], 64),
+            (
+                vec![0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+                i64::MAX,
+            ),
+        ] {
+            let mut buf = bytes.as_slice();
+            assert_eq!(read_long(&mut buf).unwrap(), expected);
+            assert!(buf.is_empty());
+        }
+        assert!(read_long(&mut [0x80].as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_decode_decimal() {
+        assert_eq!(decode_decimal(&[0x01, 0x00]).unwrap(), 256);
+        assert_eq!(decode_decimal(&[0xFF, 0x00]).unwrap(), -256);
+        assert_eq!(decode_decimal(&[]).unwrap(), 0);
+    }
+}
@@ -0,0 +1,49 @@
+#![feature(let_chains)]
+use common_error::DaftError;
+use snafu::Snafu;
+
+mod decode;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod read;
+pub mod schema;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+pub use read::{read_avro, read_avro_bulk};
+pub use schema::read_avro_schema;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+    #[snafu(display("Unable to read Avro data from \"{}\": {}", path, source))]
+    AvroError {
+        path: String,
+        source: arrow2::error::Error,
+    },
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> DaftError {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => DaftError::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Error::IOError { source: err }
+    }
+}
+
+#[cfg(feature = "python")]
+pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_avro))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_avro_schema))?;
+    Ok(())
+}
@@ -0,0 +1,470 @@
+use std::sync::Arc;
+
+use arrow2::{
+    datatypes::{Field as ArrowField, Schema as ArrowSchema},
+    io::avro::avro_schema::{
+        self,
+        file::Block,
+        schema::{Field as AvroField, Record},
+    },
+};
+use async_compat::{Compat, CompatExt};
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    schema::{Schema, SchemaRef},
+    utils::arrow::cast_array_for_daft_if_needed,
+    Series,
+};
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{get_runtime, GetResult, IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{StreamExt, TryStreamExt};
+use snafu::{futures::TryFutureExt, ResultExt};
+use tokio::{
+    fs::File,
+    io::{AsyncRead, BufReader},
+};
+use tokio_util::io::StreamReader;
+
+use crate::{
+    decode::{skip, ColumnDecoder},
+    AvroSnafu,
+};
+
+/// Reads an Avro object container file into a [`Table`].
+///
+/// If a `reader_schema` is provided, the file's (writer) schema is resolved against it: columns of
+/// the reader schema that the file doesn't contain are filled with nulls, and columns whose type
+/// differs are cast to the reader's type.
+#[allow(clippy::too_many_arguments)]
+pub fn read_avro(
+    uri: &str,
+    columns: Option<&[&str]>,
+    num_rows: Option<usize>,
+    predicate: Option<ExprRef>,
+    reader_schema: Option<SchemaRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> DaftResult<Table> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        read_avro_single(
+            uri,
+            columns.map(|cols| cols.iter().map(|col| col.to_string()).collect()),
+            num_rows,
+            predicate,
+            reader_schema,
+            io_client,
+            io_stats,
+        )
+        .await
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_avro_bulk(
+    uris: &[&str],
+    columns: Option<&[&str]>,
+    num_rows: Option<usize>,
+    predicate: Option<ExprRef>,
+    reader_schema: Option<SchemaRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Table>> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    let owned_columns: Option<Vec<String>> =
+        columns.map(|cols| cols.iter().map(|col| col.to_string()).collect());
+    let tables = runtime_handle.block_on(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
+            let (uri, columns, predicate, reader_schema, io_client, io_stats) = (
+                uri.to_string(),
+                owned_columns.clone(),
+                predicate.clone(),
+                reader_schema.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_avro_single(
+                    &uri,
+                    columns,
+                    num_rows,
+                    predicate,
+                    reader_schema,
+                    io_client,
+                    io_stats,
+                )
+                .await
+            })
+            .context(crate::JoinSnafu)
+        }));
+        task_stream
+            .buffered(num_parallel_tasks)
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+async fn read_avro_single(
+    uri: &str,
+    columns: Option<Vec<String>>,
+    num_rows: Option<usize>,
+    predicate: Option<ExprRef>,
+    reader_schema: Option<SchemaRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Table> {
+    let original_columns = columns.clone();
+    let original_num_rows = num_rows;
+    let mut columns = columns;
+    let mut num_rows = num_rows;
+    if let Some(ref pred) = predicate {
+        // The limit can only be applied after filtering, which may need extra columns.
+        num_rows = None;
+        if let Some(req_columns) = columns.as_mut() {
+            for c in get_required_columns(pred) {
+                if !req_columns.contains(&c) {
+                    req_columns.push(c);
+                }
+            }
+        }
+    }
+
+    // Avro blocks aren't indexed, so they're streamed from the start of the file.
+    let mut reader = open_reader(uri, io_client, io_stats).await?;
+    // Without a column projection, only the columns of the reader schema need to be decoded.
+    let decoded_columns = columns
+        .clone()
+        .or_else(|| reader_schema.as_ref().map(|schema| schema.names()));
+    let mut table = decode_file(uri, &mut reader, decoded_columns.as_deref(), num_rows).await?;
+    table = resolve_schema(uri, table, columns.as_deref(), reader_schema.as_ref())?;
+
+    if let Some(predicate) = predicate {
+        table = table.filter(&[predicate])?;
+        if let Some(oc) = original_columns {
+            table = table.get_columns(oc.as_slice())?;
+        }
+        if let Some(nr) = original_num_rows {
+            table = table.head(nr)?;
+        }
+    }
+    Ok(table)
+}
+
+/// Opens a reader that streams the whole file.
+pub(crate) async fn open_reader(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Compat<Box<dyn AsyncRead + Unpin + Send>>> {
+    let reader: Box<dyn AsyncRead + Unpin + Send> = match io_client
+        .single_url_get(uri.to_string(), None, io_stats)
+        .await?
+    {
+        GetResult::File(file) => Box::new(BufReader::new(File::open(file.path).await?)),
+        GetResult::Stream(stream, _, _) => Box::new(StreamReader::new(stream)),
+    };
+    Ok(reader.compat())
+}
+
+/// Decodes the rows of an Avro object container file, keeping only the requested columns (if they
+/// exist in the file) and stopping once `num_rows` rows have been read. If none of the requested
+/// columns exist, the rows are only counted from the block headers.
+async fn decode_file<R: futures::AsyncRead + Unpin + Send>(
+    uri: &str,
+    reader: &mut R,
+    columns: Option<&[String]>,
+    num_rows: Option<usize>,
+) -> DaftResult<Table> {
+    let metadata = avro_schema::read_async::read_metadata(reader)
+        .await
+        .map_err(arrow2::error::Error::from)
+        .context(AvroSnafu { path: uri })?;
+
+    let is_projected = |field: &AvroField| {
+        columns.map_or(true, |columns| columns.iter().any(|c| c == &field.name))
+    };
+    let mut decoders = metadata
+        .record
+        .fields
+        .iter()
+        .map(|field| {
+            is_projected(field)
+                .then(|| ColumnDecoder::try_new(&field.schema))
+                .transpose()
+        })
+        .collect::<arrow2::error::Result<Vec<_>>>()
+        .context(AvroSnafu { path: uri })?;
+
+    let should_decode = decoders.iter().any(Option::is_some);
+    let mut remaining = num_rows.unwrap_or(usize::MAX);
+    let mut rows_read = 0;
+    let blocks = avro_schema::read_async::block_stream(reader, metadata.marker).await;
+    futures::pin_mut!(blocks);
+    let mut block = Block::new(0, vec![]);
+    // Dropping the stream once the limit is reached stops reading the rest of the file.
+    while remaining > 0
+        && let Some(mut compressed) = blocks
+            .try_next()
+            .await
+            .map_err(arrow2::error::Error::from)
+            .context(AvroSnafu { path: uri })?
+    {
+        let rows = compressed.number_of_rows.min(remaining);
+        if should_decode {
+            avro_schema::read_async::decompress_block(
+                &mut compressed,
+                &mut block,
+                metadata.compression,
+            )
+            .map_err(arrow2::error::Error::from)
+            .context(AvroSnafu { path: uri })?;
+            let mut data = block.data.as_slice();
+            for _ in 0..rows {
+                decode_row(&metadata.record, &mut decoders, &mut data)
+                    .context(AvroSnafu { path: uri })?;
+            }
+        }
+        rows_read += rows;
+        remaining -= rows;
+    }
+
+    let (fields, arrays): (Vec<_>, Vec<_>) = metadata
+        .record
+        .fields
+        .iter()
+        .zip(decoders)
+        .filter_map(|(field, decoder)| {
+            let decoder = decoder?;
+            let field = ArrowField::new(&field.name, decoder.data_type(), true);
+            Some((field, decoder.finish()))
+        })
+        .unzip();
+    let daft_schema = Arc::new(Schema::try_from(&ArrowSchema::from(fields))?);
+    let all_series = arrays
+        .into_iter()
+        .zip(daft_schema.fields.values())
+        .map(|(array, field)| {
+            Series::try_from_field_and_arrow_array(
+                Arc::new(field.clone()),
+                cast_array_for_daft_if_needed(array),
+            )
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new_with_size(daft_schema, all_series, rows_read)
+}
+
+fn decode_row(
+    record: &Record,
+    decoders: &mut [Option<ColumnDecoder>],
+    data: &mut &[u8],
+) -> arrow2::error::Result<()> {
+    for (field, decoder) in record.fields.iter().zip(decoders.iter_mut()) {
+        match decoder {
+            Some(decoder) => decoder.decode(data)?,
+            None => skip(&field.schema, data)?,
+        }
+    }
+    Ok(())
+}
+
+/// Resolves the columns decoded with the file's writer schema to the requested columns of the
+/// reader schema.
+fn resolve_schema(
+    uri: &str,
+    table: Table,
+    columns: Option<&[String]>,
+    reader_schema: Option<&SchemaRef>,
+) -> DaftResult<Table> {
+    let Some(reader_schema) = reader_schema else {
+        if let Some(columns) = columns {
+            if let Some(missing) = columns.iter().find(|c| table.schema.get_field(c).is_err()) {
+                return Err(DaftError::FieldNotFound(format!(
+                    "Column {missing} not found in Avro file {uri}"
+                )));
+            }
+            return table.get_columns(columns);
+        }
+        return Ok(table);
+    };
+
+    let names = match columns {
+        Some(columns) => columns.to_vec(),
+        None => reader_schema.names(),
+    };
+    let fields = names
+        .iter()
+        .map(|name| reader_schema.get_field(name).cloned())
+        .collect::<DaftResult<Vec<_>>>()?;
+    let all_series = fields
+        .iter()
+        .map(|field| match table.get_column(&field.name) {
+            Ok(series) if series.data_type() == &field.dtype => Ok(series.clone()),
+            Ok(series) => series.cast(&field.dtype),
+            Err(_) => Ok(Series::full_null(&field.name, &field.dtype, table.len())),
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new(Schema::new(fields)?, all_series)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, sync::Arc};
+
+    use arrow2::{
+        array::{Int64Array, Utf8Array},
+        io::avro::avro_schema::{
+            file::{Block, CompressedBlock},
+            schema::{Field, Record, Schema as AvroSchema},
+            write::{compress, encode::zigzag_encode, write_block, write_metadata},
+        },
+    };
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{DataType, Field as DaftField},
+        schema::Schema,
+    };
+    use daft_io::{IOClient, IOConfig};
+
+    use super::read_avro;
+    use crate::read_avro_schema;
+
+    /// Writes 3 blocks of 10 rows of `{"a": long, "b": ["null", "string"]}` records.
+    fn write_avro(path: &std::path::Path) {
+        let record = Record::new(
+            "test",
+            vec![
+                Field::new("a", AvroSchema::Long(None)),
+                Field::new(
+                    "b",
+                    AvroSchema::Union(vec![AvroSchema::Null, AvroSchema::String(None)]),
+                ),
+            ],
+        );
+        let mut file = std::fs::File::create(path).unwrap();
+        write_metadata(&mut file, record, None).unwrap();
+        for batch in 0..3 {
+            let mut data = vec![];
+            for i in 0..10 {
+                zigzag_encode(batch * 10 + i, &mut data).unwrap();
+                if i % 2 == 0 {
+                    zigzag_encode(0, &mut data).unwrap();
+                } else {
+                    let value = format!("{batch}-{i}");
+                    zigzag_encode(1, &mut data).unwrap();
+                    zigzag_encode(value.len() as i64, &mut data).unwrap();
+                    data.write_all(value.as_bytes()).unwrap();
+                }
+            }
+            let mut block = Block::new(10, data);
+            let mut compressed = CompressedBlock::default();
+            compress(&mut block, &mut compressed, None).unwrap();
+            write_block(&mut file, &compressed).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_avro_read_local() -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.avro");
+        write_avro(&path);
+        let uri = path.to_str().unwrap();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let schema = read_avro_schema(uri, io_client.clone(), None)?;
+        assert_eq!(schema.names(), vec!["a", "b"]);
+        assert_eq!(schema.get_field("b")?.dtype, DataType::Utf8);
+
+        let table = read_avro(uri, None, None, None, None, io_client.clone(), None, true)?;
+        assert_eq!(table.len(), 30);
+        assert_eq!(table.schema.as_ref(), &schema);
+        let b = table.get_column("b")?.to_arrow();
+        let b = b.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+        assert_eq!(b.get(0), None);
+        assert_eq!(b.get(13), Some("1-3"));
+
+        let table = read_avro(
+            uri,
+            Some(&["b", "a"]),
+            Some(15),
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.len(), 15);
+        assert_eq!(table.column_names(), vec!["b", "a"]);
+        let a = table.get_column("a")?.to_arrow();
+        assert_eq!(
+            a.as_any().downcast_ref::<Int64Array>().unwrap(),
+            &Int64Array::from_iter((0..15).map(Some))
+        );
+
+        assert!(read_avro(
+            uri,
+            Some(&["c"]),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true
+        )
+        .is_err());
+
+        // Columns missing from the file are filled with nulls, and others are cast to the reader schema.
+        let reader_schema = Arc::new(Schema::new(vec![
+            DaftField::new("a", DataType::Float64),
+            DaftField::new("c", DataType::Boolean),
+        ])?);
+        let table = read_avro(
+            uri,
+            None,
+            Some(5),
+            None,
+            Some(reader_schema.clone()),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema, reader_schema);
+        assert_eq!(table.len(), 5);
+        assert_eq!(table.get_column("c")?.to_arrow().null_count(), 5);
+
+        // Without any of the requested columns in the file, the rows are still counted.
+        let reader_schema = Arc::new(Schema::new(vec![DaftField::new("c", DataType::Boolean)])?);
+        let table = read_avro(
+            uri,
+            None,
+            None,
+            None,
+            Some(reader_schema.clone()),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema, reader_schema);
+        assert_eq!(table.len(), 30);
+        assert_eq!(table.get_column("c")?.to_arrow().null_count(), 30);
+        let table = read_avro(
+            uri,
+            Some(&["c"]),
+            Some(12),
+            None,
+            Some(reader_schema),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_eq!(table.len(), 12);
+        Ok(())
+    }
+}
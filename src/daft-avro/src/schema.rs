@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use arrow2::{
+    datatypes::{Field as ArrowField, Schema as ArrowSchema},
+    io::avro::avro_schema,
+};
+use common_error::DaftResult;
+use daft_core::schema::Schema;
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use snafu::ResultExt;
+
+use crate::{decode::ColumnDecoder, read::open_reader, AvroSnafu};
+
+/// Size of the speculative read of the header of an Avro file, which holds its schema.
+const HEADER_READ_SIZE: usize = 64 * 1024;
+
+pub fn read_avro_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let runtime_handle = get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async { read_avro_schema_single(uri, io_client, io_stats).await })
+}
+
+pub(crate) async fn read_avro_schema_single(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+    let header = io_client
+        .single_url_get(
+            uri.to_string(),
+            Some(0..size.min(HEADER_READ_SIZE)),
+            io_stats.clone(),
+        )
+        .await?
+        .bytes()
+        .await?;
+    let metadata = match avro_schema::read::read_metadata(&mut header.as_ref()) {
+        Ok(metadata) => metadata,
+        // The header didn't fit in the speculative read, so stream the file until the end of it.
+        Err(_) if size > HEADER_READ_SIZE => {
+            let mut reader = open_reader(uri, io_client, io_stats).await?;
+            avro_schema::read_async::read_metadata(&mut reader)
+                .await
+                .map_err(arrow2::error::Error::from)
+                .context(AvroSnafu { path: uri })?
+        }
+        Err(err) => Err(arrow2::error::Error::from(err)).context(AvroSnafu { path: uri })?,
+    };
+    let fields = metadata
+        .record
+        .fields
+        .iter()
+        .map(|field| {
+            let data_type = ColumnDecoder::try_new(&field.schema)?.data_type();
+            Ok(ArrowField::new(&field.name, data_type, true))
+        })
+        .collect::<arrow2::error::Result<Vec<_>>>()
+        .context(AvroSnafu { path: uri })?;
+    Schema::try_from(&ArrowSchema::from(fields))
+}
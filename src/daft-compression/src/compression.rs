@@ -2,17 +2,25 @@ use async_compression::tokio::bufread::{
     BrotliDecoder, BzDecoder, DeflateDecoder, GzipDecoder, LzmaDecoder, XzDecoder, ZlibDecoder,
     ZstdDecoder,
 };
+use async_compression::tokio::write::{
+    BrotliEncoder, BzEncoder, DeflateEncoder, GzipEncoder, LzmaEncoder, XzEncoder, ZlibEncoder,
+    ZstdEncoder,
+};
 use std::{path::PathBuf, pin::Pin};
-use tokio::io::{AsyncBufRead, AsyncRead};
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncWrite};
+use tokio_util::io::StreamReader;
 use url::Url;
 
-#[derive(Debug)]
+use crate::snappy::{decode_framed, SnappyFrameEncoder};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CompressionCodec {
     Brotli,
     Bz,
     Deflate,
     Gzip,
     Lzma,
+    Snappy,
     Xz,
     Zlib,
     Zstd,
@@ -42,7 +50,7 @@ impl CompressionCodec {
             "xz" => Some(Xz),
             "zl" => Some(Zlib),
             "zstd" | "zst" => Some(Zstd),
-            "snappy" => todo!("Snappy compression support not yet implemented"),
+            "snappy" | "sz" => Some(Snappy),
             _ => None,
         }
     }
@@ -58,9 +66,114 @@ impl CompressionCodec {
             Deflate => Box::pin(DeflateDecoder::new(reader)),
             Gzip => Box::pin(GzipDecoder::new(reader)),
             Lzma => Box::pin(LzmaDecoder::new(reader)),
+            Snappy => Box::pin(StreamReader::new(decode_framed(reader))),
             Xz => Box::pin(XzDecoder::new(reader)),
             Zlib => Box::pin(ZlibDecoder::new(reader)),
             Zstd => Box::pin(ZstdDecoder::new(reader)),
         }
     }
+
+    pub fn to_encoder<T: AsyncWrite + Send + 'static>(
+        &self,
+        writer: T,
+    ) -> Pin<Box<dyn AsyncWrite + Send>> {
+        use CompressionCodec::*;
+        match self {
+            Brotli => Box::pin(BrotliEncoder::new(writer)),
+            Bz => Box::pin(BzEncoder::new(writer)),
+            Deflate => Box::pin(DeflateEncoder::new(writer)),
+            Gzip => Box::pin(GzipEncoder::new(writer)),
+            Lzma => Box::pin(LzmaEncoder::new(writer)),
+            Snappy => Box::pin(SnappyFrameEncoder::new(writer)),
+            Xz => Box::pin(XzEncoder::new(writer)),
+            Zlib => Box::pin(ZlibEncoder::new(writer)),
+            Zstd => Box::pin(ZstdEncoder::new(writer)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use rstest::rstest;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::CompressionCodec;
+
+    async fn encode(codec: CompressionCodec, data: &[u8]) -> Vec<u8> {
+        let (writer, mut reader) = tokio::io::duplex(4096);
+        let mut encoder = codec.to_encoder(writer);
+        let write = async move {
+            encoder.write_all(data).await.unwrap();
+            encoder.shutdown().await.unwrap();
+        };
+        let mut compressed = vec![];
+        let read = reader.read_to_end(&mut compressed);
+        let (_, read_result) = tokio::join!(write, read);
+        read_result.unwrap();
+        compressed
+    }
+
+    async fn decode(codec: CompressionCodec, compressed: Vec<u8>) -> Vec<u8> {
+        let mut decoded = vec![];
+        codec
+            .to_decoder(Cursor::new(compressed))
+            .read_to_end(&mut decoded)
+            .await
+            .unwrap();
+        decoded
+    }
+
+    fn test_data() -> Vec<u8> {
+        // Mix of compressible and incompressible data spanning several Snappy blocks.
+        let mut data = b"daft ".repeat(50_000);
+        data.extend((0..200_000u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8));
+        data
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_round_trip(
+        #[values("br", "bz2", "deflate", "gz", "lzma", "snappy", "xz", "zl", "zst")]
+        extension: &str,
+    ) {
+        let codec = CompressionCodec::from_extension(extension).unwrap();
+        for data in [vec![], b"a".to_vec(), test_data()] {
+            let compressed = encode(codec, &data).await;
+            assert_eq!(decode(codec, compressed).await, data);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snappy_compatible_with_snap() {
+        use std::io::{Read, Write};
+
+        let data = test_data();
+
+        let mut writer = snap::write::FrameEncoder::new(vec![]);
+        writer.write_all(&data).unwrap();
+        let compressed = writer.into_inner().unwrap();
+        assert_eq!(decode(CompressionCodec::Snappy, compressed).await, data);
+
+        let compressed = encode(CompressionCodec::Snappy, &data).await;
+        let mut decoded = vec![];
+        snap::read::FrameDecoder::new(compressed.as_slice())
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[tokio::test]
+    async fn test_snappy_rejects_corrupt_checksum() {
+        let mut compressed = encode(CompressionCodec::Snappy, b"hello snappy").await;
+        // The first chunk after the 10 byte stream identifier starts with a 4 byte header and a checksum.
+        compressed[14] ^= 0xff;
+        let mut decoded = vec![];
+        let result = CompressionCodec::Snappy
+            .to_decoder(std::io::Cursor::new(compressed))
+            .read_to_end(&mut decoded)
+            .await;
+        assert!(result.is_err());
+    }
 }
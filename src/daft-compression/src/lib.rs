@@ -1,4 +1,5 @@
-//! Utilities for async decompression of data.
+//! Utilities for async compression and decompression of data.
 pub mod compression;
+mod snappy;
 
 pub use compression::CompressionCodec;
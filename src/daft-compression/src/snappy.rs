@@ -0,0 +1,130 @@
+//! Async adapters for the [Snappy framing format](https://github.com/google/snappy/blob/main/framing_format.txt),
+//! built on top of the synchronous frame codec in `snap`.
+use std::{
+    io::{self, Read, Write},
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use async_stream::stream;
+use bytes::Bytes;
+use futures::Stream;
+use snap::{read::FrameDecoder, write::FrameEncoder};
+use tokio::io::{AsyncBufRead, AsyncWrite};
+use tokio_util::io::SyncIoBridge;
+
+/// Maximum number of uncompressed bytes in a single frame chunk.
+const MAX_BLOCK_SIZE: usize = 1 << 16;
+
+/// Decodes a framed Snappy stream into a stream of decompressed chunks.
+///
+/// The frame decoder of `snap` only reads synchronously, so it runs on a blocking thread that hands the decompressed
+/// chunks back one at a time.
+pub(crate) fn decode_framed<T: AsyncBufRead + Send + 'static>(
+    reader: T,
+) -> impl Stream<Item = io::Result<Bytes>> + Send {
+    stream! {
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
+        let mut decoder = FrameDecoder::new(SyncIoBridge::new(Box::pin(reader)));
+        tokio::task::spawn_blocking(move || loop {
+            let mut chunk = vec![0u8; MAX_BLOCK_SIZE];
+            let chunk = match decoder.read(&mut chunk) {
+                Ok(0) => return,
+                Ok(len) => {
+                    chunk.truncate(len);
+                    Ok(Bytes::from(chunk))
+                }
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => Err(err),
+            };
+            let failed = chunk.is_err();
+            // Stop decoding once the stream has been dropped, or has failed.
+            if sender.blocking_send(chunk).is_err() || failed {
+                return;
+            }
+        });
+        while let Some(chunk) = receiver.recv().await {
+            yield chunk;
+        }
+    }
+}
+
+/// [`AsyncWrite`] adapter that compresses everything written to it into a framed Snappy stream.
+///
+/// The frame encoder of `snap` writes its chunks into an in-memory buffer, which is then drained into the inner writer.
+pub(crate) struct SnappyFrameEncoder<W> {
+    inner: Pin<Box<W>>,
+    encoder: FrameEncoder<Vec<u8>>,
+    output: Vec<u8>,
+    output_pos: usize,
+}
+
+impl<W: AsyncWrite> SnappyFrameEncoder<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner: Box::pin(inner),
+            encoder: FrameEncoder::new(vec![]),
+            output: vec![],
+            output_pos: 0,
+        }
+    }
+
+    /// Moves the chunks written by the frame encoder into the output buffer.
+    fn take_encoded(&mut self) {
+        let encoded = self.encoder.get_mut();
+        if self.output.is_empty() {
+            std::mem::swap(&mut self.output, encoded);
+        } else {
+            self.output.append(encoded);
+        }
+    }
+
+    fn poll_drain_output(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.output_pos < self.output.len() {
+            let written = ready!(self
+                .inner
+                .as_mut()
+                .poll_write(cx, &self.output[self.output_pos..]))?;
+            if written == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.output_pos += written;
+        }
+        self.output.clear();
+        self.output_pos = 0;
+        Poll::Ready(Ok(()))
+    }
+
+    /// Flushes the frame encoder's buffered input as a chunk and writes out all encoded chunks.
+    fn poll_finish_chunk(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.encoder.flush()?;
+        self.take_encoded();
+        self.poll_drain_output(cx)
+    }
+}
+
+impl<W: AsyncWrite> AsyncWrite for SnappyFrameEncoder<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain_output(cx))?;
+        let len = this.encoder.write(&buf[..buf.len().min(MAX_BLOCK_SIZE)])?;
+        this.take_encoded();
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_finish_chunk(cx))?;
+        this.inner.as_mut().poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_finish_chunk(cx))?;
+        this.inner.as_mut().poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{ops::from_arrow::FromArrow, DataArray, StructArray},
+    datatypes::*,
+};
+use common_error::{DaftError, DaftResult};
+use sketches_ddsketch::{Config, DDSketch};
+
+use super::{as_arrow::AsArrow, DaftApproxSketchAggable, GroupIndices};
+
+/// Serializes sketches into a StructArray of the sketch dtype, so that they can be shuffled between partitions.
+pub(crate) fn sketches_to_struct_array(
+    name: &str,
+    sketches: Vec<Option<DDSketch>>,
+) -> DaftResult<StructArray> {
+    let arrow_array = daft_sketch::into_arrow2(sketches);
+    StructArray::from_arrow(
+        Arc::new(Field::new(name, DDSKETCH_DTYPE.clone())),
+        arrow_array,
+    )
+}
+
+/// Deserializes the sketches in a StructArray of the sketch dtype.
+pub(crate) fn struct_array_to_sketches(array: &StructArray) -> DaftResult<Vec<Option<DDSketch>>> {
+    daft_sketch::from_arrow2(array.to_arrow()).map_err(|e| {
+        DaftError::ComputeError(format!(
+            "Failed to deserialize sketches from column \"{}\": {e}",
+            array.name()
+        ))
+    })
+}
+
+/// Adds all valid values to a new sketch, returning None if there are no valid values.
+fn sketch_values(values: impl Iterator<Item = Option<f64>>) -> Option<DDSketch> {
+    values.fold(None, |sketch, value| match (sketch, value) {
+        (sketch, None) => sketch,
+        (None, Some(value)) => {
+            let mut sketch = DDSketch::new(Config::defaults());
+            sketch.add(value);
+            Some(sketch)
+        }
+        (Some(mut sketch), Some(value)) => {
+            sketch.add(value);
+            Some(sketch)
+        }
+    })
+}
+
+impl DaftApproxSketchAggable for &DataArray<Float64Type> {
+    type Output = DaftResult<StructArray>;
+
+    fn approx_sketch(&self) -> Self::Output {
+        let sketch = sketch_values(self.as_arrow().iter().map(|v| v.copied()));
+        sketches_to_struct_array(self.name(), vec![sketch])
+    }
+
+    fn grouped_approx_sketch(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let sketches = groups
+            .iter()
+            .map(|g| sketch_values(g.iter().map(|i| arrow_array.get(*i as usize))))
+            .collect();
+        sketches_to_struct_array(self.name(), sketches)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{datatypes::BinaryArray, utils::hyperloglog::HyperLogLog};
+use common_error::DaftResult;
+
+use super::{
+    as_arrow::AsArrow, hll_sketch::sketches_to_binary_array, DaftHllMergeAggable, GroupIndices,
+};
+
+/// Merges all non-null sketches into a new sketch.
+fn merge_sketches<'a>(sketches: impl Iterator<Item = Option<&'a [u8]>>) -> DaftResult<HyperLogLog> {
+    let mut merged = HyperLogLog::new();
+    for bytes in sketches.flatten() {
+        merged.merge(&HyperLogLog::from_bytes(bytes)?);
+    }
+    Ok(merged)
+}
+
+impl DaftHllMergeAggable for &BinaryArray {
+    type Output = DaftResult<BinaryArray>;
+
+    fn hll_merge(&self) -> Self::Output {
+        let merged = merge_sketches(self.as_arrow().iter())?;
+        Ok(sketches_to_binary_array(self.name(), &[merged]))
+    }
+
+    fn grouped_hll_merge(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let merged = groups
+            .iter()
+            .map(|g| merge_sketches(g.iter().map(|i| arrow_array.get(*i as usize))))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(sketches_to_binary_array(self.name(), &merged))
+    }
+}
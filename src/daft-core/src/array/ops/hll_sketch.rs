@@ -0,0 +1,57 @@
+use crate::{
+    array::DataArray,
+    datatypes::{BinaryArray, UInt64Array},
+    utils::hyperloglog::HyperLogLog,
+};
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftHllSketchAggable, GroupIndices};
+
+/// Serializes sketches into a BinaryArray, so that they can be shuffled between partitions.
+pub(crate) fn sketches_to_binary_array(name: &str, sketches: &[HyperLogLog]) -> BinaryArray {
+    BinaryArray::from_iter(name, sketches.iter().map(|s| Some(s.as_bytes())))
+}
+
+/// Adds all valid hashes to a new sketch.
+fn sketch_hashes(hashes: impl Iterator<Item = Option<u64>>) -> HyperLogLog {
+    let mut sketch = HyperLogLog::new();
+    hashes.flatten().for_each(|hash| sketch.add_hash(hash));
+    sketch
+}
+
+impl DaftHllSketchAggable for &UInt64Array {
+    type Output = DaftResult<BinaryArray>;
+
+    fn hll_sketch(&self) -> Self::Output {
+        let sketch = sketch_hashes(self.as_arrow().iter().map(|v| v.copied()));
+        Ok(sketches_to_binary_array(self.name(), &[sketch]))
+    }
+
+    fn grouped_hll_sketch(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let sketches = groups
+            .iter()
+            .map(|g| sketch_hashes(g.iter().map(|i| arrow_array.get(*i as usize))))
+            .collect::<Vec<_>>();
+        Ok(sketches_to_binary_array(self.name(), &sketches))
+    }
+}
+
+impl BinaryArray {
+    /// Estimates the number of distinct values in each HyperLogLog sketch.
+    pub fn hll_cardinality(&self) -> DaftResult<UInt64Array> {
+        let counts = self
+            .as_arrow()
+            .iter()
+            .map(|bytes| {
+                bytes
+                    .map(|bytes| HyperLogLog::from_bytes(bytes).map(|s| s.count()))
+                    .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(DataArray::from((
+            self.name(),
+            Box::new(arrow2::array::PrimitiveArray::<u64>::from(counts)),
+        )))
+    }
+}
@@ -0,0 +1,43 @@
+use crate::array::StructArray;
+use common_error::{DaftError, DaftResult};
+use sketches_ddsketch::DDSketch;
+
+use super::{
+    approx_sketch::{sketches_to_struct_array, struct_array_to_sketches},
+    DaftMergeSketchAggable, GroupIndices,
+};
+
+/// Merges all non-null sketches into a new sketch, returning None if there are none.
+fn merge_sketches<'a>(
+    sketches: impl Iterator<Item = &'a Option<DDSketch>>,
+) -> DaftResult<Option<DDSketch>> {
+    let mut merged: Option<DDSketch> = None;
+    for sketch in sketches.flatten() {
+        match merged.as_mut() {
+            Some(merged) => merged
+                .merge(sketch)
+                .map_err(|e| DaftError::ComputeError(format!("Failed to merge sketches: {e}")))?,
+            None => merged = Some(sketch.clone()),
+        }
+    }
+    Ok(merged)
+}
+
+impl DaftMergeSketchAggable for &StructArray {
+    type Output = DaftResult<StructArray>;
+
+    fn merge_sketch(&self) -> Self::Output {
+        let sketches = struct_array_to_sketches(self)?;
+        let merged = merge_sketches(sketches.iter())?;
+        sketches_to_struct_array(self.name(), vec![merged])
+    }
+
+    fn grouped_merge_sketch(&self, groups: &GroupIndices) -> Self::Output {
+        let sketches = struct_array_to_sketches(self)?;
+        let merged = groups
+            .iter()
+            .map(|g| merge_sketches(g.iter().map(|i| &sketches[*i as usize])))
+            .collect::<DaftResult<Vec<_>>>()?;
+        sketches_to_struct_array(self.name(), merged)
+    }
+}
@@ -1,5 +1,6 @@
 mod abs;
 mod apply;
+mod approx_sketch;
 mod arange;
 mod arithmetic;
 pub mod arrow2;
@@ -22,6 +23,8 @@ pub mod full;
 mod get;
 pub(crate) mod groups;
 mod hash;
+mod hll_merge;
+mod hll_sketch;
 mod if_else;
 pub(crate) mod image;
 mod is_in;
@@ -30,12 +33,15 @@ mod len;
 mod list;
 mod list_agg;
 mod mean;
+mod merge_sketch;
+mod moments;
 mod null;
 mod pairwise;
 mod repr;
 mod round;
 mod search_sorted;
 mod sign;
+mod sketch_percentile;
 mod sort;
 mod struct_;
 mod sum;
@@ -140,6 +146,42 @@ pub trait DaftCompareAggable {
     fn grouped_max(&self, groups: &GroupIndices) -> Self::Output;
 }
 
+pub trait DaftApproxSketchAggable {
+    type Output;
+    fn approx_sketch(&self) -> Self::Output;
+    fn grouped_approx_sketch(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftMergeSketchAggable {
+    type Output;
+    fn merge_sketch(&self) -> Self::Output;
+    fn grouped_merge_sketch(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftHllSketchAggable {
+    type Output;
+    fn hll_sketch(&self) -> Self::Output;
+    fn grouped_hll_sketch(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftHllMergeAggable {
+    type Output;
+    fn hll_merge(&self) -> Self::Output;
+    fn grouped_hll_merge(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftMomentsAggable {
+    type Output;
+    fn moments(&self) -> Self::Output;
+    fn grouped_moments(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftMergeMomentsAggable {
+    type Output;
+    fn merge_moments(&self) -> Self::Output;
+    fn grouped_merge_moments(&self, groups: &GroupIndices) -> Self::Output;
+}
+
 pub trait DaftListAggable {
     type Output;
     fn list(&self) -> Self::Output;
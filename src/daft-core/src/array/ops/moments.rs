@@ -0,0 +1,283 @@
+use crate::{
+    array::StructArray,
+    datatypes::{DataType, Field, Float64Array, UInt64Array, CO_MOMENTS_DTYPE, MOMENTS_DTYPE},
+    series::IntoSeries,
+    utils::moments::{CoMoments, Moments},
+};
+use common_error::{DaftError, DaftResult};
+
+use super::{as_arrow::AsArrow, DaftMergeMomentsAggable, DaftMomentsAggable, GroupIndices};
+
+/// Mergeable moments that are serialized into StructArrays, so that they can be shuffled between partitions.
+trait MomentsState: Default + Copy {
+    fn dtype() -> &'static DataType;
+    fn merge(&mut self, other: &Self);
+    fn into_struct_array(name: &str, states: &[Self]) -> StructArray;
+    fn from_struct_array(array: &StructArray) -> DaftResult<Vec<Self>>;
+}
+
+fn u64_child(name: &str, values: Vec<u64>) -> crate::series::Series {
+    UInt64Array::from((name, values)).into_series()
+}
+
+fn f64_child(name: &str, values: Vec<f64>) -> crate::series::Series {
+    Float64Array::from((name, values)).into_series()
+}
+
+/// Reads a child of a moments StructArray, treating null states as empty.
+fn u64_values(array: &StructArray, name: &str) -> DaftResult<Vec<u64>> {
+    let child = array.get(name)?;
+    Ok(child
+        .u64()?
+        .as_arrow()
+        .iter()
+        .map(|v| v.copied().unwrap_or_default())
+        .collect())
+}
+
+fn f64_values(array: &StructArray, name: &str) -> DaftResult<Vec<f64>> {
+    let child = array.get(name)?;
+    Ok(child
+        .f64()?
+        .as_arrow()
+        .iter()
+        .map(|v| v.copied().unwrap_or_default())
+        .collect())
+}
+
+impl MomentsState for Moments {
+    fn dtype() -> &'static DataType {
+        &MOMENTS_DTYPE
+    }
+
+    fn merge(&mut self, other: &Self) {
+        Moments::merge(self, other)
+    }
+
+    fn into_struct_array(name: &str, states: &[Self]) -> StructArray {
+        StructArray::new(
+            Field::new(name, MOMENTS_DTYPE.clone()),
+            vec![
+                u64_child("count", states.iter().map(|s| s.count).collect()),
+                f64_child("mean", states.iter().map(|s| s.mean).collect()),
+                f64_child("m2", states.iter().map(|s| s.m2).collect()),
+                f64_child("m3", states.iter().map(|s| s.m3).collect()),
+            ],
+            None,
+        )
+    }
+
+    fn from_struct_array(array: &StructArray) -> DaftResult<Vec<Self>> {
+        let count = u64_values(array, "count")?;
+        let mean = f64_values(array, "mean")?;
+        let m2 = f64_values(array, "m2")?;
+        let m3 = f64_values(array, "m3")?;
+        Ok((0..array.len())
+            .map(|i| Moments {
+                count: count[i],
+                mean: mean[i],
+                m2: m2[i],
+                m3: m3[i],
+            })
+            .collect())
+    }
+}
+
+impl MomentsState for CoMoments {
+    fn dtype() -> &'static DataType {
+        &CO_MOMENTS_DTYPE
+    }
+
+    fn merge(&mut self, other: &Self) {
+        CoMoments::merge(self, other)
+    }
+
+    fn into_struct_array(name: &str, states: &[Self]) -> StructArray {
+        StructArray::new(
+            Field::new(name, CO_MOMENTS_DTYPE.clone()),
+            vec![
+                u64_child("count", states.iter().map(|s| s.count).collect()),
+                f64_child("mean_x", states.iter().map(|s| s.mean_x).collect()),
+                f64_child("mean_y", states.iter().map(|s| s.mean_y).collect()),
+                f64_child("m2_x", states.iter().map(|s| s.m2_x).collect()),
+                f64_child("m2_y", states.iter().map(|s| s.m2_y).collect()),
+                f64_child("c_xy", states.iter().map(|s| s.c_xy).collect()),
+            ],
+            None,
+        )
+    }
+
+    fn from_struct_array(array: &StructArray) -> DaftResult<Vec<Self>> {
+        let count = u64_values(array, "count")?;
+        let mean_x = f64_values(array, "mean_x")?;
+        let mean_y = f64_values(array, "mean_y")?;
+        let m2_x = f64_values(array, "m2_x")?;
+        let m2_y = f64_values(array, "m2_y")?;
+        let c_xy = f64_values(array, "c_xy")?;
+        Ok((0..array.len())
+            .map(|i| CoMoments {
+                count: count[i],
+                mean_x: mean_x[i],
+                mean_y: mean_y[i],
+                m2_x: m2_x[i],
+                m2_y: m2_y[i],
+                c_xy: c_xy[i],
+            })
+            .collect())
+    }
+}
+
+fn moments_of(values: impl Iterator<Item = Option<f64>>) -> Moments {
+    let mut moments = Moments::default();
+    values.flatten().for_each(|v| moments.add(v));
+    moments
+}
+
+fn merge_states<S: MomentsState>(states: impl Iterator<Item = S>) -> S {
+    states.fold(S::default(), |mut merged, state| {
+        merged.merge(&state);
+        merged
+    })
+}
+
+fn merge_struct_array<S: MomentsState>(
+    array: &StructArray,
+    groups: Option<&GroupIndices>,
+) -> DaftResult<StructArray> {
+    let states = S::from_struct_array(array)?;
+    let merged = match groups {
+        Some(groups) => groups
+            .iter()
+            .map(|g| merge_states(g.iter().map(|i| states[*i as usize])))
+            .collect(),
+        None => vec![merge_states(states.into_iter())],
+    };
+    Ok(S::into_struct_array(array.name(), &merged))
+}
+
+impl DaftMomentsAggable for &Float64Array {
+    type Output = DaftResult<StructArray>;
+
+    fn moments(&self) -> Self::Output {
+        let moments = moments_of(self.as_arrow().iter().map(|v| v.copied()));
+        Ok(Moments::into_struct_array(self.name(), &[moments]))
+    }
+
+    fn grouped_moments(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let moments = groups
+            .iter()
+            .map(|g| moments_of(g.iter().map(|i| arrow_array.get(*i as usize))))
+            .collect::<Vec<_>>();
+        Ok(Moments::into_struct_array(self.name(), &moments))
+    }
+}
+
+impl DaftMergeMomentsAggable for &StructArray {
+    type Output = DaftResult<StructArray>;
+
+    fn merge_moments(&self) -> Self::Output {
+        self.grouped_or_global_merge_moments(None)
+    }
+
+    fn grouped_merge_moments(&self, groups: &GroupIndices) -> Self::Output {
+        self.grouped_or_global_merge_moments(Some(groups))
+    }
+}
+
+impl StructArray {
+    fn grouped_or_global_merge_moments(
+        &self,
+        groups: Option<&GroupIndices>,
+    ) -> DaftResult<StructArray> {
+        match self.data_type() {
+            dtype if dtype == Moments::dtype() => merge_struct_array::<Moments>(self, groups),
+            dtype if dtype == CoMoments::dtype() => merge_struct_array::<CoMoments>(self, groups),
+            other => Err(DaftError::TypeError(format!(
+                "Merge moments is only valid for moments, got {other}"
+            ))),
+        }
+    }
+
+    /// Computes the variance with `ddof` delta degrees of freedom from each state of moments.
+    pub fn moments_variance(&self, ddof: u64) -> DaftResult<Float64Array> {
+        self.map_moments(|m: &Moments| m.variance(ddof))
+    }
+
+    /// Computes the standard deviation with `ddof` delta degrees of freedom from each state of moments.
+    pub fn moments_stddev(&self, ddof: u64) -> DaftResult<Float64Array> {
+        self.map_moments(|m: &Moments| m.variance(ddof).map(f64::sqrt))
+    }
+
+    /// Computes the population skewness from each state of moments.
+    pub fn moments_skew(&self) -> DaftResult<Float64Array> {
+        self.map_moments(Moments::skew)
+    }
+
+    /// Computes the covariance with `ddof` delta degrees of freedom from each state of co-moments.
+    pub fn co_moments_covar(&self, ddof: u64) -> DaftResult<Float64Array> {
+        self.map_moments(|m: &CoMoments| m.covar(ddof))
+    }
+
+    /// Computes the Pearson correlation coefficient from each state of co-moments.
+    pub fn co_moments_corr(&self) -> DaftResult<Float64Array> {
+        self.map_moments(CoMoments::corr)
+    }
+
+    fn map_moments<S: MomentsState>(
+        &self,
+        f: impl Fn(&S) -> Option<f64>,
+    ) -> DaftResult<Float64Array> {
+        if self.data_type() != S::dtype() {
+            return Err(DaftError::TypeError(format!(
+                "Expected moments of type {}, got {}",
+                S::dtype(),
+                self.data_type()
+            )));
+        }
+        let values = S::from_struct_array(self)?
+            .iter()
+            .map(f)
+            .collect::<Vec<_>>();
+        Ok(Float64Array::from((
+            self.name(),
+            Box::new(arrow2::array::PrimitiveArray::from(values)),
+        )))
+    }
+}
+
+impl Float64Array {
+    /// Computes the co-moments of the pairs of values in this array and `other` where both are valid, over all
+    /// values or per group.
+    pub fn co_moments(
+        &self,
+        other: &Float64Array,
+        groups: Option<&GroupIndices>,
+    ) -> DaftResult<StructArray> {
+        if self.len() != other.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected arrays of the same length for co-moments, got {} and {}",
+                self.len(),
+                other.len()
+            )));
+        }
+        let (xs, ys) = (self.as_arrow(), other.as_arrow());
+        let co_moments_of = |indices: &mut dyn Iterator<Item = usize>| {
+            let mut co_moments = CoMoments::default();
+            for i in indices {
+                if let (Some(x), Some(y)) = (xs.get(i), ys.get(i)) {
+                    co_moments.add(x, y);
+                }
+            }
+            co_moments
+        };
+        let states = match groups {
+            Some(groups) => groups
+                .iter()
+                .map(|g| co_moments_of(&mut g.iter().map(|i| *i as usize)))
+                .collect(),
+            None => vec![co_moments_of(&mut (0..self.len()))],
+        };
+        Ok(CoMoments::into_struct_array(self.name(), &states))
+    }
+}
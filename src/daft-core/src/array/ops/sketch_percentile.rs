@@ -0,0 +1,159 @@
+use crate::{
+    array::{FixedSizeListArray, StructArray},
+    datatypes::{DataType, Field, Float64Array},
+    series::{IntoSeries, Series},
+};
+use common_error::{DaftError, DaftResult};
+
+use super::approx_sketch::struct_array_to_sketches;
+
+impl StructArray {
+    /// Computes the given percentiles from each sketch in this array.
+    ///
+    /// Returns a Float64 series if a single percentile is requested and `force_list_output` is false, and a
+    /// FixedSizeList series with one value per percentile otherwise. Null sketches produce null outputs.
+    pub fn sketch_percentile(
+        &self,
+        percentiles: &[f64],
+        force_list_output: bool,
+    ) -> DaftResult<Series> {
+        let sketches = struct_array_to_sketches(self)?;
+        let quantiles = sketches
+            .iter()
+            .map(|sketch| {
+                sketch
+                    .as_ref()
+                    .map(|sketch| {
+                        percentiles
+                            .iter()
+                            .map(|p| {
+                                sketch.quantile(*p).map_err(|e| {
+                                    DaftError::ComputeError(format!(
+                                        "Failed to compute percentile {p}: {e}"
+                                    ))
+                                })
+                            })
+                            .collect::<DaftResult<Vec<_>>>()
+                    })
+                    .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        match percentiles {
+            [_] if !force_list_output => {
+                let values = quantiles
+                    .into_iter()
+                    .map(|quantiles| quantiles.and_then(|q| q[0]));
+                let arrow_array = arrow2::array::PrimitiveArray::from_trusted_len_iter(values);
+                Ok(Float64Array::from((self.name(), Box::new(arrow_array))).into_series())
+            }
+            _ => {
+                let flat_values = quantiles
+                    .iter()
+                    .flat_map(|quantiles| match quantiles {
+                        Some(quantiles) => quantiles.clone(),
+                        None => vec![None; percentiles.len()],
+                    })
+                    .collect::<Vec<_>>();
+                let flat_child = Float64Array::from((
+                    self.name(),
+                    Box::new(arrow2::array::PrimitiveArray::from(flat_values)),
+                ));
+                let validity =
+                    arrow2::bitmap::Bitmap::from_iter(quantiles.iter().map(Option::is_some));
+                Ok(FixedSizeListArray::new(
+                    Field::new(
+                        self.name(),
+                        DataType::FixedSizeList(Box::new(DataType::Float64), percentiles.len()),
+                    ),
+                    flat_child.into_series(),
+                    Some(validity),
+                )
+                .into_series())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::{DaftApproxSketchAggable, DaftMergeSketchAggable},
+        datatypes::Float64Array,
+        series::{IntoSeries, Series},
+    };
+
+    // DDSketch's default config guarantees a relative error of at most 1%.
+    const RELATIVE_ACCURACY: f64 = 0.01;
+
+    fn exact_percentile(sorted: &[f64], percentile: f64) -> f64 {
+        sorted[(percentile * (sorted.len() - 1) as f64) as usize]
+    }
+
+    #[test]
+    fn test_sketch_percentile_of_merged_sketches() -> DaftResult<()> {
+        // Spread 1..=10000 across 4 "partitions" in a scrambled order.
+        let values = (0..10000u64)
+            .map(|i| ((i * 7919) % 10000 + 1) as f64)
+            .collect::<Vec<_>>();
+        let sketches = values
+            .chunks(2500)
+            .map(|chunk| {
+                let array = Float64Array::from(("values", chunk.to_vec()));
+                Ok((&array).approx_sketch()?.into_series())
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let sketches = Series::concat(&sketches.iter().collect::<Vec<_>>())?;
+        let merged = sketches.struct_()?.merge_sketch()?;
+
+        let percentiles = [0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99];
+        let result = merged.sketch_percentile(&percentiles, false)?;
+        let result = result.fixed_size_list()?;
+        assert_eq!(result.len(), 1);
+        let estimates = result.flat_child.f64()?;
+
+        let mut sorted = values.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (i, percentile) in percentiles.iter().enumerate() {
+            let exact = exact_percentile(&sorted, *percentile);
+            let estimate = estimates.get(i).unwrap();
+            assert!(
+                (estimate - exact).abs() <= exact * RELATIVE_ACCURACY,
+                "Estimate {estimate} of percentile {percentile} is not within {RELATIVE_ACCURACY} of {exact}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_grouped_sketch_percentile_with_nulls() -> DaftResult<()> {
+        let array = Float64Array::from((
+            "values",
+            Box::new(arrow2::array::Float64Array::from(vec![
+                Some(1.0),
+                None,
+                Some(100.0),
+                None,
+                Some(10.0),
+            ])),
+        ));
+        // Groups: [1.0, 10.0], [None, None], [100.0]
+        let sketches = (&array).grouped_approx_sketch(&vec![vec![0, 4], vec![1, 3], vec![2]])?;
+
+        let single = sketches.sketch_percentile(&[1.0], false)?;
+        let single = single.f64()?;
+        assert_eq!(single.get(0), Some(10.0));
+        assert_eq!(single.get(1), None);
+        assert_eq!(single.get(2), Some(100.0));
+
+        let forced_list = sketches.sketch_percentile(&[0.0], true)?;
+        let forced_list = forced_list.fixed_size_list()?;
+        assert_eq!(forced_list.len(), 3);
+        assert!(forced_list.is_valid(0));
+        assert!(!forced_list.is_valid(1));
+        assert_eq!(forced_list.flat_child.f64()?.get(0), Some(1.0));
+        Ok(())
+    }
+}
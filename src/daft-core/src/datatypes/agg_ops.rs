@@ -1,6 +1,30 @@
 use common_error::{DaftError, DaftResult};
+use lazy_static::lazy_static;
 
-use super::DataType;
+use super::{DataType, Field};
+
+lazy_static! {
+    /// The data type of the DDSketches built by approximate sketch aggregations.
+    pub static ref DDSKETCH_DTYPE: DataType = DataType::from(&*daft_sketch::ARROW2_DDSKETCH_DTYPE);
+
+    /// The data type of the central moments built by variance, standard deviation and skew aggregations.
+    pub static ref MOMENTS_DTYPE: DataType = DataType::Struct(vec![
+        Field::new("count", DataType::UInt64),
+        Field::new("mean", DataType::Float64),
+        Field::new("m2", DataType::Float64),
+        Field::new("m3", DataType::Float64),
+    ]);
+
+    /// The data type of the co-moments built by covariance and correlation aggregations.
+    pub static ref CO_MOMENTS_DTYPE: DataType = DataType::Struct(vec![
+        Field::new("count", DataType::UInt64),
+        Field::new("mean_x", DataType::Float64),
+        Field::new("mean_y", DataType::Float64),
+        Field::new("m2_x", DataType::Float64),
+        Field::new("m2_y", DataType::Float64),
+        Field::new("c_xy", DataType::Float64),
+    ]);
+}
 
 /// Get the data type that the sum of a column of the given data type should be casted to.
 pub fn try_sum_supertype(dtype: &DataType) -> DaftResult<DataType> {
@@ -29,3 +53,43 @@ pub fn try_mean_supertype(dtype: &DataType) -> DaftResult<DataType> {
         )))
     }
 }
+
+/// Get the data type that the variance, standard deviation or skew of a column of the given data type should be
+/// casted to.
+pub fn try_variance_supertype(dtype: &DataType) -> DaftResult<DataType> {
+    use DataType::*;
+    if dtype.is_numeric() {
+        Ok(Float64)
+    } else {
+        Err(DaftError::TypeError(format!(
+            "Invalid argument to variance supertype: {}",
+            dtype
+        )))
+    }
+}
+
+/// Get the data type that the covariance or correlation of two columns of the given data types should be
+/// casted to.
+pub fn try_covariance_supertype(left: &DataType, right: &DataType) -> DaftResult<DataType> {
+    use DataType::*;
+    if left.is_numeric() && right.is_numeric() {
+        Ok(Float64)
+    } else {
+        Err(DaftError::TypeError(format!(
+            "Invalid arguments to covariance supertype: {} and {}",
+            left, right
+        )))
+    }
+}
+
+/// Get the data type that the approximate sketch of a column of the given data type should be casted to.
+pub fn try_approx_sketch_supertype(dtype: &DataType) -> DaftResult<DataType> {
+    if dtype.is_numeric() {
+        Ok(DDSKETCH_DTYPE.clone())
+    } else {
+        Err(DaftError::TypeError(format!(
+            "Invalid argument to approx sketch supertype: {}",
+            dtype
+        )))
+    }
+}
@@ -9,7 +9,10 @@ mod time_unit;
 
 pub use crate::array::{DataArray, FixedSizeListArray};
 use crate::array::{ListArray, StructArray};
-pub use agg_ops::{try_mean_supertype, try_sum_supertype};
+pub use agg_ops::{
+    try_approx_sketch_supertype, try_covariance_supertype, try_mean_supertype, try_sum_supertype,
+    try_variance_supertype, CO_MOMENTS_DTYPE, DDSKETCH_DTYPE, MOMENTS_DTYPE,
+};
 use arrow2::{
     compute::comparison::Simd8,
     types::{simd::Simd, NativeType},
@@ -90,6 +90,132 @@ impl Series {
         }
     }
 
+    pub fn approx_sketch(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftApproxSketchAggable;
+        use crate::datatypes::DataType::*;
+
+        // Upcast all numeric types to float64 and use f64 approx sketch kernel.
+        match self.data_type() {
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64 => {
+                let casted = self.cast(&Float64)?;
+                match groups {
+                    Some(groups) => Ok(DaftApproxSketchAggable::grouped_approx_sketch(
+                        &casted.f64()?,
+                        groups,
+                    )?
+                    .into_series()),
+                    None => {
+                        Ok(DaftApproxSketchAggable::approx_sketch(&casted.f64()?)?.into_series())
+                    }
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "Approx sketch is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn merge_sketch(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftMergeSketchAggable;
+
+        match self.data_type() {
+            dtype if dtype == &*DDSKETCH_DTYPE => {
+                let downcasted = self.struct_()?;
+                match groups {
+                    Some(groups) => Ok(DaftMergeSketchAggable::grouped_merge_sketch(
+                        &downcasted,
+                        groups,
+                    )?
+                    .into_series()),
+                    None => Ok(DaftMergeSketchAggable::merge_sketch(&downcasted)?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "Merge sketch is only valid for sketches, got {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn moments(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftMomentsAggable;
+
+        let casted = self.cast(&try_variance_supertype(self.data_type())?)?;
+        match groups {
+            Some(groups) => {
+                Ok(DaftMomentsAggable::grouped_moments(&casted.f64()?, groups)?.into_series())
+            }
+            None => Ok(DaftMomentsAggable::moments(&casted.f64()?)?.into_series()),
+        }
+    }
+
+    pub fn co_moments(&self, other: &Series, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        let supertype = try_covariance_supertype(self.data_type(), other.data_type())?;
+        let x = self.cast(&supertype)?;
+        let y = other.cast(&supertype)?;
+        Ok(x.f64()?.co_moments(y.f64()?, groups)?.into_series())
+    }
+
+    pub fn merge_moments(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftMergeMomentsAggable;
+
+        match self.data_type() {
+            dtype if dtype == &*MOMENTS_DTYPE || dtype == &*CO_MOMENTS_DTYPE => {
+                let downcasted = self.struct_()?;
+                match groups {
+                    Some(groups) => Ok(DaftMergeMomentsAggable::grouped_merge_moments(
+                        &downcasted,
+                        groups,
+                    )?
+                    .into_series()),
+                    None => Ok(DaftMergeMomentsAggable::merge_moments(&downcasted)?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "Merge moments is only valid for moments, got {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn hll_sketch(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::{full::FullNull, DaftHllSketchAggable};
+
+        // Sketch the hashes of the valid values, so that nulls are not counted as distinct values.
+        let hashes = match self.data_type() {
+            DataType::Null => UInt64Array::full_null(self.name(), &DataType::UInt64, self.len()),
+            _ => self.hash(None)?.with_validity(self.validity().cloned())?,
+        };
+        match groups {
+            Some(groups) => {
+                Ok(DaftHllSketchAggable::grouped_hll_sketch(&&hashes, groups)?.into_series())
+            }
+            None => Ok(DaftHllSketchAggable::hll_sketch(&&hashes)?.into_series()),
+        }
+    }
+
+    pub fn hll_merge(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftHllMergeAggable;
+
+        match self.data_type() {
+            DataType::Binary => {
+                let downcasted = self.binary()?;
+                match groups {
+                    Some(groups) => {
+                        Ok(DaftHllMergeAggable::grouped_hll_merge(&downcasted, groups)?
+                            .into_series())
+                    }
+                    None => Ok(DaftHllMergeAggable::hll_merge(&downcasted)?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "HyperLogLog merge is only valid for sketches, got {}",
+                other
+            ))),
+        }
+    }
+
     pub fn min(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         self.inner.min(groups)
     }
@@ -1,9 +1,9 @@
 use crate::{
-    datatypes::{Int32Array, UInt64Array},
+    datatypes::{DataType, Int32Array, UInt64Array},
     series::Series,
     with_match_comparable_daft_types,
 };
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 impl Series {
     pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
@@ -14,6 +14,16 @@ impl Series {
         })
     }
 
+    /// Estimates the number of distinct values in each HyperLogLog sketch of a Binary series.
+    pub fn hll_cardinality(&self) -> DaftResult<UInt64Array> {
+        match self.data_type() {
+            DataType::Binary => self.binary()?.hll_cardinality(),
+            other => Err(DaftError::TypeError(format!(
+                "HyperLogLog cardinality is only valid for sketches, got {other}"
+            ))),
+        }
+    }
+
     pub fn murmur3_32(&self) -> DaftResult<Int32Array> {
         use crate::DataType::*;
         match self.data_type() {
@@ -1,4 +1,5 @@
-use crate::datatypes::DataType;
+use crate::datatypes::{DataType, CO_MOMENTS_DTYPE, DDSKETCH_DTYPE, MOMENTS_DTYPE};
+use crate::series::IntoSeries;
 use crate::series::Series;
 use common_error::DaftError;
 use common_error::DaftResult;
@@ -13,4 +14,60 @@ impl Series {
             ))),
         }
     }
+
+    pub fn sketch_percentile(
+        &self,
+        percentiles: &[f64],
+        force_list_output: bool,
+    ) -> DaftResult<Series> {
+        match self.data_type() {
+            dtype if dtype == &*DDSKETCH_DTYPE => self
+                .struct_()?
+                .sketch_percentile(percentiles, force_list_output),
+            dt => Err(DaftError::TypeError(format!(
+                "sketch_percentile not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn moments_variance(&self, ddof: u64) -> DaftResult<Series> {
+        Ok(self.as_moments()?.moments_variance(ddof)?.into_series())
+    }
+
+    pub fn moments_stddev(&self, ddof: u64) -> DaftResult<Series> {
+        Ok(self.as_moments()?.moments_stddev(ddof)?.into_series())
+    }
+
+    pub fn moments_skew(&self) -> DaftResult<Series> {
+        Ok(self.as_moments()?.moments_skew()?.into_series())
+    }
+
+    pub fn co_moments_covar(&self, ddof: u64) -> DaftResult<Series> {
+        Ok(self.as_co_moments()?.co_moments_covar(ddof)?.into_series())
+    }
+
+    pub fn co_moments_corr(&self) -> DaftResult<Series> {
+        Ok(self.as_co_moments()?.co_moments_corr()?.into_series())
+    }
+
+    fn as_moments(&self) -> DaftResult<&crate::array::StructArray> {
+        match self.data_type() {
+            dtype if dtype == &*MOMENTS_DTYPE => self.struct_(),
+            dt => Err(DaftError::TypeError(format!(
+                "Expected moments, got {}",
+                dt
+            ))),
+        }
+    }
+
+    fn as_co_moments(&self) -> DaftResult<&crate::array::StructArray> {
+        match self.data_type() {
+            dtype if dtype == &*CO_MOMENTS_DTYPE => self.struct_(),
+            dt => Err(DaftError::TypeError(format!(
+                "Expected co-moments, got {}",
+                dt
+            ))),
+        }
+    }
 }
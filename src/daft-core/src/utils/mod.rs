@@ -1,6 +1,8 @@
 pub mod arrow;
 pub mod display_table;
 pub mod hashable_float_wrapper;
+pub mod hyperloglog;
+pub mod moments;
 pub mod supertype;
 
 #[macro_export]
@@ -0,0 +1,191 @@
+/// Central moments of a stream of values, up to the third moment.
+///
+/// Values are added with Welford's online algorithm and partial states are combined with the
+/// pairwise update formulas of Chan et al. and Pébay, which avoid the catastrophic cancellation
+/// of naive sum-of-squares approaches.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Moments {
+    pub count: u64,
+    pub mean: f64,
+    /// Sum of squared differences from the mean.
+    pub m2: f64,
+    /// Sum of cubed differences from the mean.
+    pub m3: f64,
+}
+
+impl Moments {
+    pub fn add(&mut self, value: f64) {
+        let prev_count = self.count as f64;
+        self.count += 1;
+        let count = self.count as f64;
+        let delta = value - self.mean;
+        let delta_n = delta / count;
+        let term = delta * delta_n * prev_count;
+        self.mean += delta_n;
+        self.m3 += term * delta_n * (count - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term;
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let (count_a, count_b) = (self.count as f64, other.count as f64);
+        let count = count_a + count_b;
+        let delta = other.mean - self.mean;
+        self.mean += delta * count_b / count;
+        self.m3 += other.m3
+            + delta.powi(3) * count_a * count_b * (count_a - count_b) / (count * count)
+            + 3.0 * delta * (count_a * other.m2 - count_b * self.m2) / count;
+        self.m2 += other.m2 + delta * delta * count_a * count_b / count;
+        self.count += other.count;
+    }
+
+    /// The variance with `ddof` delta degrees of freedom, or None if there are not more than `ddof` values.
+    pub fn variance(&self, ddof: u64) -> Option<f64> {
+        if self.count > ddof {
+            Some(self.m2 / (self.count - ddof) as f64)
+        } else {
+            None
+        }
+    }
+
+    /// The population skewness, or None if there are no values. Constant values have a NaN skewness.
+    pub fn skew(&self) -> Option<f64> {
+        (self.count > 0).then(|| (self.count as f64).sqrt() * self.m3 / self.m2.powf(1.5))
+    }
+}
+
+/// Co-moments of a stream of pairs of values, used for covariance and correlation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CoMoments {
+    pub count: u64,
+    pub mean_x: f64,
+    pub mean_y: f64,
+    pub m2_x: f64,
+    pub m2_y: f64,
+    /// Sum of products of the differences from the means.
+    pub c_xy: f64,
+}
+
+impl CoMoments {
+    pub fn add(&mut self, x: f64, y: f64) {
+        self.count += 1;
+        let count = self.count as f64;
+        let delta_x = x - self.mean_x;
+        let delta_y = y - self.mean_y;
+        self.mean_x += delta_x / count;
+        self.mean_y += delta_y / count;
+        self.m2_x += delta_x * (x - self.mean_x);
+        self.m2_y += delta_y * (y - self.mean_y);
+        self.c_xy += delta_x * (y - self.mean_y);
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let (count_a, count_b) = (self.count as f64, other.count as f64);
+        let count = count_a + count_b;
+        let delta_x = other.mean_x - self.mean_x;
+        let delta_y = other.mean_y - self.mean_y;
+        let weight = count_a * count_b / count;
+        self.mean_x += delta_x * count_b / count;
+        self.mean_y += delta_y * count_b / count;
+        self.m2_x += other.m2_x + delta_x * delta_x * weight;
+        self.m2_y += other.m2_y + delta_y * delta_y * weight;
+        self.c_xy += other.c_xy + delta_x * delta_y * weight;
+        self.count += other.count;
+    }
+
+    /// The covariance with `ddof` delta degrees of freedom, or None if there are not more than `ddof` pairs.
+    pub fn covar(&self, ddof: u64) -> Option<f64> {
+        if self.count > ddof {
+            Some(self.c_xy / (self.count - ddof) as f64)
+        } else {
+            None
+        }
+    }
+
+    /// The Pearson correlation coefficient, or None if there are no pairs. Constant values have a NaN correlation.
+    pub fn corr(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.c_xy / (self.m2_x * self.m2_y).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CoMoments, Moments};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= 1e-6 * expected.abs().max(1.0),
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn test_moments_are_numerically_stable_and_mergeable() {
+        // A large offset makes naive sum-of-squares variance lose all precision.
+        let values = (0..1000)
+            .map(|i| 1e9 + ((i % 10) as f64).powi(2) + (i % 7) as f64 * 0.5)
+            .collect::<Vec<_>>();
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let m2 = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+        let m3 = values.iter().map(|v| (v - mean).powi(3)).sum::<f64>();
+
+        let mut moments = Moments::default();
+        values.iter().for_each(|v| moments.add(*v));
+        // Merging partial states over uneven chunks should give the same result.
+        let mut merged = Moments::default();
+        for chunk in [&values[..1], &values[1..400], &values[400..]] {
+            let mut partial = Moments::default();
+            chunk.iter().for_each(|v| partial.add(*v));
+            merged.merge(&partial);
+        }
+
+        for m in [moments, merged] {
+            assert_eq!(m.count, 1000);
+            assert_close(m.variance(0).unwrap(), m2 / n);
+            assert_close(m.variance(1).unwrap(), m2 / (n - 1.0));
+            assert_close(m.skew().unwrap(), n.sqrt() * m3 / m2.powf(1.5));
+        }
+        assert_eq!(Moments::default().variance(0), None);
+        assert_eq!(Moments::default().skew(), None);
+    }
+
+    #[test]
+    fn test_co_moments_are_mergeable() {
+        let xs = (0..500).map(|i| i as f64).collect::<Vec<_>>();
+        let ys = xs.iter().map(|x| 3.0 - 2.0 * x).collect::<Vec<_>>();
+        let n = xs.len() as f64;
+        let (mean_x, mean_y) = (xs.iter().sum::<f64>() / n, ys.iter().sum::<f64>() / n);
+        let c_xy = xs
+            .iter()
+            .zip(ys.iter())
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum::<f64>();
+
+        let mut merged = CoMoments::default();
+        for (xs, ys) in [(&xs[..123], &ys[..123]), (&xs[123..], &ys[123..])] {
+            let mut partial = CoMoments::default();
+            xs.iter()
+                .zip(ys.iter())
+                .for_each(|(x, y)| partial.add(*x, *y));
+            merged.merge(&partial);
+        }
+
+        assert_close(merged.covar(1).unwrap(), c_xy / (n - 1.0));
+        assert_close(merged.corr().unwrap(), -1.0);
+        assert_eq!(CoMoments::default().covar(1), None);
+    }
+}
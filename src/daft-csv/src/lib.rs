@@ -7,28 +7,38 @@ use snafu::Snafu;
 
 pub mod metadata;
 pub mod options;
+mod preprocess;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod read;
 mod schema;
+pub mod write;
 
 pub use metadata::read_csv_schema_bulk;
-pub use options::{char_to_byte, CsvConvertOptions, CsvParseOptions, CsvReadOptions};
+pub use options::{
+    char_to_byte, CsvConvertOptions, CsvParseOptions, CsvReadOptions, RaggedRows,
+    REJECTED_ROW_COLUMN_NAME,
+};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
-pub use read::{read_csv, read_csv_bulk};
+pub use read::{read_csv, read_csv_bulk, stream_csv};
+pub use write::write_csv;
 
 #[derive(Debug, Snafu)]
 pub enum Error {
     #[snafu(display("{source}"))]
     IOError { source: daft_io::Error },
     #[snafu(display("{source}"))]
+    StdIOError { source: std::io::Error },
+    #[snafu(display("{source}"))]
     CSVError { source: csv_async::Error },
     #[snafu(display("Invalid char: {}", val))]
     WrongChar {
         source: std::char::TryFromCharError,
         val: char,
     },
+    #[snafu(display("Invalid delimiter: {:?}, delimiters must not be empty", val))]
+    InvalidDelimiter { val: String },
     #[snafu(display("{source}"))]
     ArrowError { source: arrow2::error::Error },
     #[snafu(display("Error joining spawned task: {}", source))]
@@ -72,7 +82,9 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_class::<CsvConvertOptions>()?;
     parent.add_class::<CsvParseOptions>()?;
     parent.add_class::<CsvReadOptions>()?;
+    parent.add_class::<RaggedRows>()?;
     parent.add_wrapped(wrap_pyfunction!(python::pylib::read_csv))?;
     parent.add_wrapped(wrap_pyfunction!(python::pylib::read_csv_schema))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::write_csv))?;
     Ok(())
 }
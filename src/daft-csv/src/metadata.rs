@@ -14,9 +14,12 @@ use tokio::{
 };
 use tokio_util::io::StreamReader;
 
-use crate::{schema::merge_schema, CsvParseOptions};
+use crate::{
+    preprocess::preprocess_reader,
+    schema::{infer_field_type, merge_schema},
+    CsvParseOptions, RaggedRows, REJECTED_ROW_COLUMN_NAME,
+};
 use daft_compression::CompressionCodec;
-use daft_decoding::inference::infer;
 
 const DEFAULT_COLUMN_PREFIX: &str = "column_";
 
@@ -189,6 +192,7 @@ async fn read_csv_arrow_schema_from_uncompressed_reader<R>(
 where
     R: AsyncRead + Unpin + Send,
 {
+    let reader = preprocess_reader(reader, &parse_options).await?;
     let mut reader = AsyncReaderBuilder::new()
         .has_headers(parse_options.has_header)
         .delimiter(parse_options.delimiter)
@@ -196,10 +200,18 @@ where
         .quote(parse_options.quote)
         .escape(parse_options.escape_char)
         .comment(parse_options.comment)
+        .flexible(parse_options.ragged_rows != RaggedRows::Error)
         .buffer_capacity(max_bytes.unwrap_or(1 << 20).min(1 << 20))
         .create_reader(reader.compat());
-    let (fields, read_stats) =
-        infer_schema(&mut reader, None, max_bytes, parse_options.has_header).await?;
+    let (mut fields, read_stats) =
+        infer_schema(&mut reader, None, max_bytes, &parse_options).await?;
+    if parse_options.ragged_rows == RaggedRows::Reject {
+        fields.push(arrow2::datatypes::Field::new(
+            REJECTED_ROW_COLUMN_NAME,
+            arrow2::datatypes::DataType::Utf8,
+            true,
+        ));
+    }
     Ok((fields.into(), read_stats))
 }
 
@@ -207,7 +219,7 @@ async fn infer_schema<R>(
     reader: &mut AsyncReader<R>,
     max_rows: Option<usize>,
     max_bytes: Option<usize>,
-    has_header: bool,
+    parse_options: &CsvParseOptions,
 ) -> arrow2::error::Result<(Vec<arrow2::datatypes::Field>, CsvReadStats)>
 where
     R: futures::AsyncRead + Unpin + Send,
@@ -215,7 +227,7 @@ where
     let mut record = ByteRecord::new();
     // get or create header names
     // when has_header is false, creates default column names with column_ prefix
-    let (headers, did_read_record): (Vec<String>, bool) = if has_header {
+    let (headers, did_read_record): (Vec<String>, bool) = if parse_options.has_header {
         (
             reader
                 .headers()
@@ -255,7 +267,7 @@ where
         m2 += delta * delta2;
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
-                column.insert(infer(string));
+                column.insert(infer_field_type(string, parse_options));
             }
         }
     }
@@ -266,15 +278,20 @@ where
             break;
         }
         records_count += 1;
+        let is_rejected =
+            parse_options.ragged_rows == RaggedRows::Reject && record.len() != column_types.len();
         let record_size = record.as_slice().len();
         total_bytes += record_size;
         let delta = (record_size as f64) - mean;
         mean += delta / (records_count as f64);
         let delta2 = (record_size as f64) - mean;
         m2 += delta * delta2;
+        if is_rejected {
+            continue;
+        }
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
-                column.insert(infer(string));
+                column.insert(infer_field_type(string, parse_options));
             }
         }
     }
@@ -1,4 +1,11 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use common_error::{DaftError, DaftResult};
 use daft_core::{impl_bincode_py_state_serialization, schema::SchemaRef};
+use daft_decoding::parse_mode::{ParseMode, DEFAULT_CORRUPT_RECORD_COLUMN_NAME};
 use daft_dsl::ExprRef;
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "python")]
@@ -20,6 +27,10 @@ pub struct CsvConvertOptions {
     pub column_names: Option<Vec<String>>,
     pub schema: Option<SchemaRef>,
     pub predicate: Option<ExprRef>,
+    /// How to handle malformed rows.
+    pub mode: ParseMode,
+    /// The name of the column holding the malformed rows captured with [`ParseMode::Permissive`].
+    pub corrupt_record_column: String,
 }
 
 impl CsvConvertOptions {
@@ -36,46 +47,41 @@ impl CsvConvertOptions {
             column_names,
             schema,
             predicate,
+            mode: ParseMode::FailFast,
+            corrupt_record_column: DEFAULT_CORRUPT_RECORD_COLUMN_NAME.to_string(),
         }
     }
 
     pub fn with_limit(self, limit: Option<usize>) -> Self {
-        Self {
-            limit,
-            include_columns: self.include_columns,
-            column_names: self.column_names,
-            schema: self.schema,
-            predicate: self.predicate,
-        }
+        Self { limit, ..self }
     }
 
     pub fn with_include_columns(self, include_columns: Option<Vec<String>>) -> Self {
         Self {
-            limit: self.limit,
             include_columns,
-            column_names: self.column_names,
-            schema: self.schema,
-            predicate: self.predicate,
+            ..self
         }
     }
 
     pub fn with_column_names(self, column_names: Option<Vec<String>>) -> Self {
         Self {
-            limit: self.limit,
-            include_columns: self.include_columns,
             column_names,
-            schema: self.schema,
-            predicate: self.predicate,
+            ..self
         }
     }
 
     pub fn with_schema(self, schema: Option<SchemaRef>) -> Self {
+        Self { schema, ..self }
+    }
+
+    pub fn with_mode(self, mode: ParseMode) -> Self {
+        Self { mode, ..self }
+    }
+
+    pub fn with_corrupt_record_column(self, corrupt_record_column: String) -> Self {
         Self {
-            limit: self.limit,
-            include_columns: self.include_columns,
-            column_names: self.column_names,
-            schema,
-            predicate: self.predicate,
+            corrupt_record_column,
+            ..self
         }
     }
 }
@@ -98,22 +104,42 @@ impl CsvConvertOptions {
     /// * `column_names` - The names for the CSV columns.
     /// * `schema` - The names and dtypes for the CSV columns.
     /// * `predicate` - Expression to filter rows applied before the limit
+    /// * `mode` - How to handle malformed rows, one of "fail_fast", "drop_malformed" or "permissive".
+    /// * `corrupt_record_column` - The name of the column holding the malformed rows in permissive mode.
+    #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (limit=None, include_columns=None, column_names=None, schema=None, predicate=None))]
+    #[pyo3(signature = (
+        limit=None,
+        include_columns=None,
+        column_names=None,
+        schema=None,
+        predicate=None,
+        mode=None,
+        corrupt_record_column=None
+    ))]
     pub fn new(
         limit: Option<usize>,
         include_columns: Option<Vec<String>>,
         column_names: Option<Vec<String>>,
         schema: Option<PySchema>,
         predicate: Option<PyExpr>,
-    ) -> Self {
-        Self::new_internal(
+        mode: Option<&str>,
+        corrupt_record_column: Option<String>,
+    ) -> PyResult<Self> {
+        let options = Self::new_internal(
             limit,
             include_columns,
             column_names,
             schema.map(|s| s.into()),
             predicate.map(|p| p.expr.into()),
         )
+        .with_mode(mode.map(str::parse).transpose()?.unwrap_or_default());
+        Ok(match corrupt_record_column {
+            Some(corrupt_record_column) => {
+                options.with_corrupt_record_column(corrupt_record_column)
+            }
+            None => options,
+        })
     }
 
     #[getter]
@@ -136,6 +162,16 @@ impl CsvConvertOptions {
         Ok(self.schema.as_ref().map(|s| s.clone().into()))
     }
 
+    #[getter]
+    pub fn get_mode(&self) -> PyResult<ParseMode> {
+        Ok(self.mode)
+    }
+
+    #[getter]
+    pub fn get_corrupt_record_column(&self) -> PyResult<String> {
+        Ok(self.corrupt_record_column.clone())
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
         match op {
             CompareOp::Eq => self == other,
@@ -151,6 +187,12 @@ impl CsvConvertOptions {
 
 impl_bincode_py_state_serialization!(CsvConvertOptions);
 
+/// The byte that a [`CsvParseOptions::multi_byte_delimiter`] is translated to before tokenizing.
+pub const MULTI_BYTE_DELIMITER_PLACEHOLDER: u8 = 0x1F;
+
+/// The name of the column holding the raw rows rejected with [`RaggedRows::Reject`].
+pub const REJECTED_ROW_COLUMN_NAME: &str = "_rejected_row";
+
 /// Options for parsing CSV files.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
@@ -161,6 +203,18 @@ pub struct CsvParseOptions {
     pub quote: u8,
     pub escape_char: Option<u8>,
     pub comment: Option<u8>,
+    /// A delimiter spanning multiple bytes, which is translated to `delimiter` before tokenizing.
+    pub multi_byte_delimiter: Option<String>,
+    /// Values, in addition to empty fields, that are parsed as null.
+    pub null_values: Option<Vec<String>>,
+    /// Values, in addition to a case-insensitive "true", that are parsed as true.
+    pub true_values: Option<Vec<String>>,
+    /// Values, in addition to a case-insensitive "false", that are parsed as false.
+    pub false_values: Option<Vec<String>>,
+    /// Number of lines to skip at the start of the file, before the header.
+    pub skip_rows: usize,
+    /// How to handle rows with fewer or more fields than the schema.
+    pub ragged_rows: RaggedRows,
 }
 
 impl CsvParseOptions {
@@ -179,25 +233,45 @@ impl CsvParseOptions {
             quote,
             escape_char,
             comment,
+            multi_byte_delimiter: None,
+            null_values: None,
+            true_values: None,
+            false_values: None,
+            skip_rows: 0,
+            ragged_rows: RaggedRows::Error,
         }
     }
 
+    /// Creates parsing options, where a `delimiter` of more than one byte is stored as
+    /// [`CsvParseOptions::multi_byte_delimiter`].
     pub fn new_with_defaults(
         has_header: bool,
-        delimiter: Option<char>,
+        delimiter: Option<&str>,
         double_quote: bool,
         quote: Option<char>,
         escape_char: Option<char>,
         comment: Option<char>,
     ) -> super::Result<Self> {
-        Ok(Self::new_internal(
+        let options = Self::new_internal(
             has_header,
-            char_to_byte(delimiter)?.unwrap_or(b','),
+            b',',
             double_quote,
             char_to_byte(quote)?.unwrap_or(b'"'),
             char_to_byte(escape_char)?,
             char_to_byte(comment)?,
-        ))
+        );
+        Ok(match delimiter {
+            None => options,
+            Some(delimiter) => match delimiter.as_bytes() {
+                [] => {
+                    return Err(super::Error::InvalidDelimiter {
+                        val: delimiter.to_string(),
+                    })
+                }
+                [byte] => options.with_delimiter(*byte),
+                _ => options.with_multi_byte_delimiter(Some(delimiter.to_string())),
+            },
+        })
     }
 
     pub fn with_has_header(self, has_header: bool) -> Self {
@@ -205,7 +279,26 @@ impl CsvParseOptions {
     }
 
     pub fn with_delimiter(self, delimiter: u8) -> Self {
-        Self { delimiter, ..self }
+        Self {
+            delimiter,
+            multi_byte_delimiter: None,
+            ..self
+        }
+    }
+
+    pub fn with_multi_byte_delimiter(self, multi_byte_delimiter: Option<String>) -> Self {
+        match multi_byte_delimiter {
+            Some(multi_byte_delimiter) => Self {
+                delimiter: MULTI_BYTE_DELIMITER_PLACEHOLDER,
+                multi_byte_delimiter: Some(multi_byte_delimiter),
+                ..self
+            },
+            None => Self {
+                delimiter: b',',
+                multi_byte_delimiter: None,
+                ..self
+            },
+        }
     }
 
     pub fn with_double_quote(self, double_quote: bool) -> Self {
@@ -229,6 +322,65 @@ impl CsvParseOptions {
     pub fn with_comment(self, comment: Option<u8>) -> Self {
         Self { comment, ..self }
     }
+
+    pub fn with_null_values(self, null_values: Option<Vec<String>>) -> Self {
+        Self {
+            null_values,
+            ..self
+        }
+    }
+
+    pub fn with_true_values(self, true_values: Option<Vec<String>>) -> Self {
+        Self {
+            true_values,
+            ..self
+        }
+    }
+
+    pub fn with_false_values(self, false_values: Option<Vec<String>>) -> Self {
+        Self {
+            false_values,
+            ..self
+        }
+    }
+
+    pub fn with_skip_rows(self, skip_rows: usize) -> Self {
+        Self { skip_rows, ..self }
+    }
+
+    pub fn with_ragged_rows(self, ragged_rows: RaggedRows) -> Self {
+        Self {
+            ragged_rows,
+            ..self
+        }
+    }
+
+    /// Whether `bytes` is one of the user-provided null values.
+    pub(crate) fn is_null_value(&self, bytes: &[u8]) -> bool {
+        contains_value(&self.null_values, bytes)
+    }
+
+    /// Parses `bytes` as one of the user-provided true or false values.
+    pub(crate) fn parse_bool_value(&self, bytes: &[u8]) -> Option<bool> {
+        if contains_value(&self.true_values, bytes) {
+            Some(true)
+        } else if contains_value(&self.false_values, bytes) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Whether values need to be checked against the user-provided null, true or false values.
+    pub(crate) fn has_custom_values(&self) -> bool {
+        self.null_values.is_some() || self.true_values.is_some() || self.false_values.is_some()
+    }
+}
+
+fn contains_value(values: &Option<Vec<String>>, bytes: &[u8]) -> bool {
+    values
+        .as_ref()
+        .map_or(false, |values| values.iter().any(|v| v.as_bytes() == bytes))
 }
 
 impl Default for CsvParseOptions {
@@ -251,15 +403,39 @@ impl CsvParseOptions {
     /// * `escape_char` - The character to use as an escape character.
     /// * `comment` - The character at the start of a line that indicates that the rest of the line is a comment,
     ///   which should be ignored while parsing.
+    /// * `null_values` - Values, in addition to empty fields, that should be parsed as null.
+    /// * `true_values` - Values, in addition to "true", that should be parsed as true.
+    /// * `false_values` - Values, in addition to "false", that should be parsed as false.
+    /// * `skip_rows` - Number of lines to skip at the start of the file, before the header.
+    /// * `ragged_rows` - How to handle rows with fewer or more fields than the schema, one of "error", "fill"
+    ///   or "reject".
+    #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (has_header=true, delimiter=None, double_quote=false, quote=None, escape_char=None, comment=None))]
+    #[pyo3(signature = (
+        has_header=true,
+        delimiter=None,
+        double_quote=false,
+        quote=None,
+        escape_char=None,
+        comment=None,
+        null_values=None,
+        true_values=None,
+        false_values=None,
+        skip_rows=0,
+        ragged_rows=None
+    ))]
     pub fn new(
         has_header: bool,
-        delimiter: Option<char>,
+        delimiter: Option<&str>,
         double_quote: bool,
         quote: Option<char>,
         escape_char: Option<char>,
         comment: Option<char>,
+        null_values: Option<Vec<String>>,
+        true_values: Option<Vec<String>>,
+        false_values: Option<Vec<String>>,
+        skip_rows: usize,
+        ragged_rows: Option<&str>,
     ) -> PyResult<Self> {
         Ok(Self::new_with_defaults(
             has_header,
@@ -268,7 +444,12 @@ impl CsvParseOptions {
             quote,
             escape_char,
             comment,
-        )?)
+        )?
+        .with_null_values(null_values)
+        .with_true_values(true_values)
+        .with_false_values(false_values)
+        .with_skip_rows(skip_rows)
+        .with_ragged_rows(ragged_rows.map(str::parse).transpose()?.unwrap_or_default()))
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
@@ -284,6 +465,55 @@ impl CsvParseOptions {
     }
 }
 
+/// How to handle rows with fewer or more fields than the schema.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub enum RaggedRows {
+    /// Fail the read.
+    #[default]
+    Error,
+    /// Fill missing fields with nulls and drop extra fields.
+    Fill,
+    /// Null out all fields of the row, and keep the raw row in the [`REJECTED_ROW_COLUMN_NAME`]
+    /// column.
+    Reject,
+}
+
+impl RaggedRows {
+    pub fn iterator() -> std::slice::Iter<'static, RaggedRows> {
+        use RaggedRows::*;
+
+        static RAGGED_ROWS: [RaggedRows; 3] = [Error, Fill, Reject];
+        RAGGED_ROWS.iter()
+    }
+}
+
+impl FromStr for RaggedRows {
+    type Err = DaftError;
+
+    fn from_str(ragged_rows: &str) -> DaftResult<Self> {
+        match ragged_rows {
+            "error" => Ok(Self::Error),
+            "fill" => Ok(Self::Fill),
+            "reject" => Ok(Self::Reject),
+            _ => Err(DaftError::ValueError(format!(
+                "Ragged rows mode {} is not supported; only the following modes are supported: {:?}",
+                ragged_rows,
+                RaggedRows::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
+impl Display for RaggedRows {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        // Leverage Debug trait implementation, which will already return the enum variant as a string.
+        write!(f, "{:?}", self)
+    }
+}
+
+impl_bincode_py_state_serialization!(RaggedRows);
+
 pub fn char_to_byte(c: Option<char>) -> Result<Option<u8>, super::Error> {
     match c.map(u8::try_from).transpose() {
         Ok(b) => Ok(b),
@@ -0,0 +1,207 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader, ReadBuf};
+
+use crate::CsvParseOptions;
+
+const TRANSLATION_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Applies the parsing options that operate on the raw bytes of a CSV file, before they reach the
+/// CSV tokenizer: skipping leading lines, and translating a multi-byte delimiter to a single byte.
+pub(crate) async fn preprocess_reader<'a, R>(
+    reader: R,
+    parse_options: &CsvParseOptions,
+) -> io::Result<Box<dyn AsyncRead + Unpin + Send + 'a>>
+where
+    R: AsyncRead + Unpin + Send + 'a,
+{
+    let reader: Box<dyn AsyncRead + Unpin + Send + 'a> = if parse_options.skip_rows > 0 {
+        Box::new(skip_lines(reader, parse_options.skip_rows).await?)
+    } else {
+        Box::new(reader)
+    };
+    Ok(match &parse_options.multi_byte_delimiter {
+        Some(delimiter) => Box::new(DelimiterTranslatingReader::new(
+            reader,
+            DelimiterTranslator::new(
+                delimiter.as_bytes().to_vec(),
+                parse_options.delimiter,
+                parse_options.quote,
+                parse_options.escape_char,
+            ),
+        )),
+        None => reader,
+    })
+}
+
+async fn skip_lines<R>(reader: R, num_lines: usize) -> io::Result<BufReader<R>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut reader = BufReader::new(reader);
+    let mut line = vec![];
+    for _ in 0..num_lines {
+        line.clear();
+        if reader.read_until(b'\n', &mut line).await? == 0 {
+            break;
+        }
+    }
+    Ok(reader)
+}
+
+/// Replaces every occurrence of a multi-byte delimiter outside of quoted fields with a single byte.
+struct DelimiterTranslator {
+    delimiter: Vec<u8>,
+    replacement: u8,
+    quote: u8,
+    escape_char: Option<u8>,
+    in_quotes: bool,
+    escaped: bool,
+    /// Bytes matching a prefix of the delimiter, which may be split across reads.
+    pending: Vec<u8>,
+}
+
+impl DelimiterTranslator {
+    fn new(delimiter: Vec<u8>, replacement: u8, quote: u8, escape_char: Option<u8>) -> Self {
+        Self {
+            delimiter,
+            replacement,
+            quote,
+            escape_char,
+            in_quotes: false,
+            escaped: false,
+            pending: vec![],
+        }
+    }
+
+    fn translate(&mut self, input: &[u8], output: &mut Vec<u8>) {
+        for byte in input {
+            self.push(*byte, output);
+        }
+    }
+
+    /// Flushes a trailing partial match of the delimiter.
+    fn finish(&mut self, output: &mut Vec<u8>) {
+        for byte in std::mem::take(&mut self.pending) {
+            self.emit(byte, output);
+        }
+    }
+
+    fn push(&mut self, byte: u8, output: &mut Vec<u8>) {
+        if self.in_quotes {
+            if self.escaped {
+                self.escaped = false;
+            } else if Some(byte) == self.escape_char {
+                self.escaped = true;
+            } else if byte == self.quote {
+                self.in_quotes = false;
+            }
+            output.push(byte);
+            return;
+        }
+        self.pending.push(byte);
+        if self.delimiter.starts_with(&self.pending) {
+            if self.pending.len() == self.delimiter.len() {
+                self.pending.clear();
+                output.push(self.replacement);
+            }
+            return;
+        }
+        // The pending bytes no longer match the delimiter, so emit the first one as-is and look for
+        // a match starting at the next one.
+        let pending = std::mem::take(&mut self.pending);
+        self.emit(pending[0], output);
+        for byte in &pending[1..] {
+            self.push(*byte, output);
+        }
+    }
+
+    fn emit(&mut self, byte: u8, output: &mut Vec<u8>) {
+        if byte == self.quote {
+            self.in_quotes = true;
+        }
+        output.push(byte);
+    }
+}
+
+struct DelimiterTranslatingReader<R> {
+    inner: R,
+    translator: DelimiterTranslator,
+    input: Box<[u8]>,
+    output: Vec<u8>,
+    position: usize,
+    eof: bool,
+}
+
+impl<R> DelimiterTranslatingReader<R> {
+    fn new(inner: R, translator: DelimiterTranslator) -> Self {
+        Self {
+            inner,
+            translator,
+            input: vec![0; TRANSLATION_BUFFER_SIZE].into_boxed_slice(),
+            output: Vec::with_capacity(TRANSLATION_BUFFER_SIZE),
+            position: 0,
+            eof: false,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for DelimiterTranslatingReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if this.position < this.output.len() {
+                let len = buf.remaining().min(this.output.len() - this.position);
+                buf.put_slice(&this.output[this.position..this.position + len]);
+                this.position += len;
+                return Poll::Ready(Ok(()));
+            }
+            if this.eof {
+                return Poll::Ready(Ok(()));
+            }
+            this.output.clear();
+            this.position = 0;
+            let mut input = ReadBuf::new(&mut this.input);
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut input))?;
+            if input.filled().is_empty() {
+                this.eof = true;
+                this.translator.finish(&mut this.output);
+            } else {
+                this.translator.translate(input.filled(), &mut this.output);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DelimiterTranslator;
+
+    #[test]
+    fn test_translate_multi_byte_delimiter() {
+        let translate = |chunks: &[&str]| {
+            let mut translator = DelimiterTranslator::new(b"||".to_vec(), b'\t', b'"', None);
+            let mut output = vec![];
+            for chunk in chunks {
+                translator.translate(chunk.as_bytes(), &mut output);
+            }
+            translator.finish(&mut output);
+            String::from_utf8(output).unwrap()
+        };
+        assert_eq!(translate(&["a||b||c"]), "a\tb\tc");
+        // Delimiters split across reads.
+        assert_eq!(translate(&["a|", "|b|", "|c"]), "a\tb\tc");
+        // Partial and overlapping matches.
+        assert_eq!(translate(&["a|b|||c|"]), "a|b\t|c|");
+        // Delimiters within quotes are kept.
+        assert_eq!(translate(&["\"a||\"\"b\"||c"]), "\"a||\"\"b\"\tc");
+    }
+}
@@ -65,4 +65,31 @@ pub mod pylib {
             Ok(Arc::new(schema).into())
         })
     }
+
+    #[pyfunction]
+    pub fn write_csv(
+        py: Python,
+        table: &PyTable,
+        uri: &str,
+        parse_options: Option<CsvParseOptions>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<()> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("write_csv: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            Ok(crate::write::write_csv(
+                &table.table,
+                uri,
+                parse_options,
+                io_client,
+                Some(io_stats),
+                multithreaded_io.unwrap_or(true),
+            )?)
+        })
+    }
 }
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, num::NonZeroUsize, sync::Arc};
+use std::{collections::HashMap, io::SeekFrom, num::NonZeroUsize, ops::Range, sync::Arc};
 
 use arrow2::{
     datatypes::Field,
@@ -7,13 +7,18 @@ use arrow2::{
 use async_compat::{Compat, CompatExt};
 use common_error::{DaftError, DaftResult};
 use csv_async::AsyncReader;
-use daft_core::{schema::Schema, utils::arrow::cast_array_for_daft_if_needed, Series};
+use daft_core::{
+    datatypes::BooleanArray,
+    schema::{Schema, SchemaRef},
+    utils::arrow::cast_array_for_daft_if_needed,
+    IntoSeries, Series,
+};
 use daft_dsl::optimization::get_required_columns;
 use daft_io::{get_runtime, GetResult, IOClient, IOStatsRef};
 use daft_table::Table;
-use futures::{Stream, StreamExt, TryStreamExt};
+use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
 use rayon::{
-    iter::{IndexedParallelIterator, IntoParallelIterator},
+    iter::IntoParallelIterator,
     prelude::{IntoParallelRefIterator, ParallelIterator},
 };
 use snafu::{
@@ -22,15 +27,21 @@ use snafu::{
 };
 use tokio::{
     fs::File,
-    io::{AsyncBufRead, AsyncRead, BufReader},
+    io::{AsyncBufRead, AsyncRead, AsyncReadExt, AsyncSeekExt, BufReader},
     task::JoinHandle,
 };
 use tokio_util::io::StreamReader;
 
-use crate::ArrowSnafu;
-use crate::{metadata::read_csv_schema_single, CsvConvertOptions, CsvParseOptions, CsvReadOptions};
+use crate::{
+    metadata::read_csv_schema_single, preprocess::preprocess_reader, CsvConvertOptions,
+    CsvParseOptions, CsvReadOptions, RaggedRows, REJECTED_ROW_COLUMN_NAME,
+};
+use crate::{ArrowSnafu, CSVSnafu};
 use daft_compression::CompressionCodec;
-use daft_decoding::deserialize::deserialize_column;
+use daft_decoding::{
+    deserialize::{deserialize_column, ByteRecordGeneric},
+    parse_mode::{corrupt_record_array, corrupt_record_field, ParseMode},
+};
 
 trait ByteRecordChunkStream = Stream<Item = super::Result<Vec<ByteRecord>>>;
 trait ColumnArrayChunkStream = Stream<
@@ -66,6 +77,7 @@ pub fn read_csv(
             convert_options,
             parse_options,
             read_options,
+            None,
             io_client,
             io_stats,
             max_chunks_in_flight,
@@ -74,12 +86,51 @@ pub fn read_csv(
     })
 }
 
+/// Streams a CSV file as [`Table`]s of a chunk of records each, rather than reading it into a
+/// single [`Table`].
+///
+/// The file is only read as the stream is polled, with at most `max_chunks_in_flight` chunks read
+/// ahead of the consumer, so files larger than memory can be processed with a bounded footprint.
+/// The size of each chunk is set by [`CsvReadOptions::chunk_size`]. The stream must be polled from
+/// within a Tokio runtime.
+#[allow(clippy::too_many_arguments)]
+pub fn stream_csv(
+    uri: String,
+    convert_options: Option<CsvConvertOptions>,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
+    byte_range: Option<Range<usize>>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    max_chunks_in_flight: Option<usize>,
+) -> BoxStream<'static, DaftResult<Table>> {
+    async_stream::try_stream! {
+        let (tables, _) = read_csv_single_into_table_stream(
+            uri.as_str(),
+            convert_options,
+            parse_options,
+            read_options,
+            byte_range,
+            io_client,
+            io_stats,
+            max_chunks_in_flight,
+        )
+        .await?;
+        futures::pin_mut!(tables);
+        while let Some(table) = tables.try_next().await? {
+            yield table;
+        }
+    }
+    .boxed()
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn read_csv_bulk(
     uris: &[&str],
     convert_options: Option<CsvConvertOptions>,
     parse_options: Option<CsvParseOptions>,
     read_options: Option<CsvReadOptions>,
+    byte_ranges: Option<Vec<Option<Range<usize>>>>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
     multithreaded_io: bool,
@@ -90,12 +141,21 @@ pub fn read_csv_bulk(
     let _rt_guard = runtime_handle.enter();
     let tables = runtime_handle.block_on(async move {
         // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
-        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
-            let (uri, convert_options, parse_options, read_options, io_client, io_stats) = (
+        let task_stream = futures::stream::iter(uris.iter().enumerate().map(|(i, uri)| {
+            let (
+                uri,
+                convert_options,
+                parse_options,
+                read_options,
+                byte_range,
+                io_client,
+                io_stats,
+            ) = (
                 uri.to_string(),
                 convert_options.clone(),
                 parse_options.clone(),
                 read_options.clone(),
+                byte_ranges.as_ref().and_then(|ranges| ranges[i].clone()),
                 io_client.clone(),
                 io_stats.clone(),
             );
@@ -105,6 +165,7 @@ pub fn read_csv_bulk(
                     convert_options,
                     parse_options,
                     read_options,
+                    byte_range,
                     io_client,
                     io_stats,
                     max_chunks_in_flight,
@@ -185,15 +246,17 @@ fn tables_concat(mut tables: Vec<Table>) -> DaftResult<Table> {
     Table::new(first_table.schema.clone(), new_series)
 }
 
-async fn read_csv_single_into_table(
+#[allow(clippy::too_many_arguments)]
+async fn read_csv_single_into_table_stream(
     uri: &str,
     convert_options: Option<CsvConvertOptions>,
     parse_options: Option<CsvParseOptions>,
     read_options: Option<CsvReadOptions>,
+    byte_range: Option<Range<usize>>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
     max_chunks_in_flight: Option<usize>,
-) -> DaftResult<Table> {
+) -> DaftResult<(impl Stream<Item = DaftResult<Table>> + Send, SchemaRef)> {
     let predicate = convert_options
         .as_ref()
         .and_then(|opts| opts.predicate.clone());
@@ -221,12 +284,19 @@ async fn read_csv_single_into_table(
             Some(co)
         }
     };
+    // Malformed rows are dropped after they're read, so the limit can't be applied to the stream.
+    let convert_options_with_predicate_columns =
+        convert_options_with_predicate_columns.map(|co| match co.mode {
+            ParseMode::DropMalformed => co.with_limit(None),
+            _ => co,
+        });
 
     let (chunk_stream, fields) = read_csv_single_into_stream(
         uri,
         convert_options_with_predicate_columns.unwrap_or_default(),
         parse_options.unwrap_or_default(),
         read_options,
+        byte_range,
         io_client,
         io_stats,
     )
@@ -275,39 +345,68 @@ async fn read_csv_single_into_table(
             table
         }
     }));
-    let mut remaining_rows = limit.map(|limit| limit as i64);
-    let collected_tables = filtered_tables
-        .try_take_while(|result| {
-            match (result, remaining_rows) {
-                // Limit has been met, early-terminate.
-                (_, Some(rows_left)) if rows_left <= 0 => futures::future::ready(Ok(false)),
-                // Limit has not yet been met, update remaining limit slack and continue.
-                (Ok(table), Some(rows_left)) => {
-                    remaining_rows = Some(rows_left - table.len() as i64);
-                    futures::future::ready(Ok(true))
-                }
-                // (1) No limit, never early-terminate.
-                // (2) Encountered error, propagate error to try_collect to allow it to short-circuit.
-                (_, None) | (Err(_), _) => futures::future::ready(Ok(true)),
-            }
-        })
-        .try_collect::<Vec<_>>()
-        .await?
-        .into_iter()
-        .collect::<DaftResult<Vec<_>>>()?;
+    let tables =
+        filtered_tables.map(|result| result.map_err(DaftError::from).and_then(|table| table));
+    Ok((limit_table_stream(tables, limit), schema))
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn read_csv_single_into_table(
+    uri: &str,
+    convert_options: Option<CsvConvertOptions>,
+    parse_options: Option<CsvParseOptions>,
+    read_options: Option<CsvReadOptions>,
+    byte_range: Option<Range<usize>>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    max_chunks_in_flight: Option<usize>,
+) -> DaftResult<Table> {
+    let (tables, schema) = read_csv_single_into_table_stream(
+        uri,
+        convert_options,
+        parse_options,
+        read_options,
+        byte_range,
+        io_client,
+        io_stats,
+        max_chunks_in_flight,
+    )
+    .await?;
+    let collected_tables = tables.try_collect::<Vec<_>>().await?;
     // Handle empty table case.
     if collected_tables.is_empty() {
         return Table::empty(Some(schema));
     }
 
     // // TODO(Clark): Don't concatenate all chunks from a file into a single table, since MicroPartition is natively chunked.
-    let concated_table = tables_concat(collected_tables)?;
-    if let Some(limit) = limit && concated_table.len() > limit {
-        // apply head incase that last chunk went over limit
-        concated_table.head(limit)
-    } else {
-        Ok(concated_table)
-    }
+    tables_concat(collected_tables)
+}
+
+/// Stops a stream of tables once `limit` rows have been yielded, truncating the last table if it
+/// goes over the limit.
+fn limit_table_stream(
+    tables: impl Stream<Item = DaftResult<Table>> + Send,
+    limit: Option<usize>,
+) -> impl Stream<Item = DaftResult<Table>> + Send {
+    tables.scan(limit, |remaining_rows, result| {
+        let result = match (result, *remaining_rows) {
+            // Limit has been met, early-terminate.
+            (_, Some(0)) => None,
+            // Limit has not yet been met, update remaining limit slack and continue.
+            (Ok(table), Some(rows_left)) => {
+                *remaining_rows = Some(rows_left.saturating_sub(table.len()));
+                if table.len() > rows_left {
+                    Some(table.head(rows_left))
+                } else {
+                    Some(Ok(table))
+                }
+            }
+            // (1) No limit, never early-terminate.
+            // (2) Encountered error, propagate error to allow the consumer to short-circuit.
+            (result, None) | (result @ Err(_), _) => Some(result),
+        };
+        futures::future::ready(result)
+    })
 }
 
 async fn read_csv_single_into_stream(
@@ -315,6 +414,7 @@ async fn read_csv_single_into_stream(
     convert_options: CsvConvertOptions,
     parse_options: CsvParseOptions,
     read_options: Option<CsvReadOptions>,
+    byte_range: Option<Range<usize>>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
 ) -> DaftResult<(impl TableStream + Send, Vec<Field>)> {
@@ -322,9 +422,16 @@ async fn read_csv_single_into_stream(
     {
         Some(schema) => (schema.to_arrow()?, None, None),
         None => {
+            // Malformed rows are handled while reading, so they shouldn't fail schema inference.
+            let inference_parse_options = match (convert_options.mode, parse_options.ragged_rows) {
+                (ParseMode::DropMalformed | ParseMode::Permissive, RaggedRows::Error) => {
+                    parse_options.clone().with_ragged_rows(RaggedRows::Fill)
+                }
+                _ => parse_options.clone(),
+            };
             let (schema, read_stats) = read_csv_schema_single(
                 uri,
-                parse_options.clone(),
+                inference_parse_options,
                 // Read at most 1 MiB when doing schema inference.
                 Some(1024 * 1024),
                 io_client.clone(),
@@ -338,6 +445,19 @@ async fn read_csv_single_into_stream(
             )
         }
     };
+    // The columns of rejected and corrupt rows aren't read from the CSV data, so they are added back
+    // after renaming.
+    if parse_options.ragged_rows == RaggedRows::Reject {
+        schema
+            .fields
+            .retain(|field| field.name != REJECTED_ROW_COLUMN_NAME);
+    }
+    if convert_options.mode == ParseMode::Permissive {
+        schema
+            .fields
+            .retain(|field| field.name != convert_options.corrupt_record_column);
+    }
+    let num_csv_fields = schema.fields.len();
     // Rename fields, if necessary.
     if let Some(column_names) = convert_options.column_names {
         schema = schema
@@ -350,53 +470,102 @@ async fn read_csv_single_into_stream(
             .collect::<Vec<_>>()
             .into();
     }
-    let (reader, buffer_size, chunk_size): (Box<dyn AsyncBufRead + Unpin + Send>, usize, usize) =
-        match io_client
-            .single_url_get(uri.to_string(), None, io_stats)
+    if parse_options.ragged_rows == RaggedRows::Reject {
+        schema.fields.push(Field::new(
+            REJECTED_ROW_COLUMN_NAME,
+            arrow2::datatypes::DataType::Utf8,
+            true,
+        ));
+    }
+    let corrupt_record_index = (convert_options.mode == ParseMode::Permissive).then(|| {
+        schema
+            .fields
+            .push(corrupt_record_field(&convert_options.corrupt_record_column));
+        schema.fields.len() - 1
+    });
+    // A byte range is read from the first record that starts within it up to the first record that
+    // starts after it, so only the first range of a file contains the header.
+    let (byte_range, has_header) = match byte_range {
+        Some(range) => {
+            let size = io_client
+                .single_url_get_size(uri.to_string(), io_stats.clone())
+                .await?;
+            let start = find_csv_record_start(
+                uri,
+                range.start,
+                size,
+                &parse_options,
+                num_csv_fields,
+                io_client.clone(),
+                io_stats.clone(),
+            )
+            .await?;
+            let end = find_csv_record_start(
+                uri,
+                range.end.min(size),
+                size,
+                &parse_options,
+                num_csv_fields,
+                io_client.clone(),
+                io_stats.clone(),
+            )
+            .await?;
+            (
+                Some(start..end.max(start)),
+                parse_options.has_header && start == 0,
+            )
+        }
+        None => (None, parse_options.has_header),
+    };
+    // Use user-provided buffer size, falling back to 8 * the user-provided chunk size if that exists, otherwise falling back to 512 KiB as the default.
+    let buffer_size = read_options
+        .as_ref()
+        .and_then(|opt| opt.buffer_size.or_else(|| opt.chunk_size.map(|cs| 8 * cs)))
+        .unwrap_or(512 * 1024);
+    let chunk_size = read_options
+        .as_ref()
+        .and_then(|opt| opt.chunk_size.or_else(|| opt.buffer_size.map(|bs| bs / 8)))
+        .unwrap_or(64 * 1024);
+    let reader: Box<dyn AsyncBufRead + Unpin + Send> = match byte_range {
+        Some(range) if range.is_empty() => Box::new(tokio::io::empty()),
+        byte_range => match io_client
+            .single_url_get(uri.to_string(), byte_range, io_stats)
             .await?
         {
             GetResult::File(file) => {
-                (
-                    Box::new(BufReader::new(File::open(file.path).await?)),
-                    // Use user-provided buffer size, falling back to 8 * the user-provided chunk size if that exists, otherwise falling back to 512 KiB as the default.
-                    read_options
-                        .as_ref()
-                        .and_then(|opt| opt.buffer_size.or_else(|| opt.chunk_size.map(|cs| 8 * cs)))
-                        .unwrap_or(512 * 1024),
-                    read_options
-                        .as_ref()
-                        .and_then(|opt| opt.chunk_size.or_else(|| opt.buffer_size.map(|bs| bs / 8)))
-                        .unwrap_or(64 * 1024),
-                )
+                let mut reader = File::open(file.path).await?;
+                match file.range {
+                    Some(range) => {
+                        reader.seek(SeekFrom::Start(range.start as u64)).await?;
+                        Box::new(BufReader::new(reader.take(range.len() as u64)))
+                    }
+                    None => Box::new(BufReader::new(reader)),
+                }
             }
-            GetResult::Stream(stream, _, _) => (
-                Box::new(StreamReader::new(stream)),
-                read_options
-                    .as_ref()
-                    .and_then(|opt| opt.buffer_size.or_else(|| opt.chunk_size.map(|cs| 8 * cs)))
-                    .unwrap_or(512 * 1024),
-                read_options
-                    .as_ref()
-                    .and_then(|opt| opt.chunk_size.or_else(|| opt.buffer_size.map(|bs| bs / 8)))
-                    .unwrap_or(64 * 1024),
-            ),
-        };
+            GetResult::Stream(stream, _, _) => Box::new(StreamReader::new(stream)),
+        },
+    };
     let reader: Box<dyn AsyncRead + Unpin + Send> = match CompressionCodec::from_uri(uri) {
         Some(compression) => Box::new(compression.to_decoder(reader)),
         None => reader,
     };
+    let reader = preprocess_reader(reader, &parse_options).await?;
     let reader = AsyncReaderBuilder::new()
-        .has_headers(parse_options.has_header)
+        .has_headers(has_header)
         .delimiter(parse_options.delimiter)
         .double_quote(parse_options.double_quote)
         .quote(parse_options.quote)
         .escape(parse_options.escape_char)
         .comment(parse_options.comment)
+        .flexible(
+            parse_options.ragged_rows != RaggedRows::Error
+                || convert_options.mode != ParseMode::FailFast,
+        )
         .buffer_capacity(buffer_size)
         .create_reader(reader.compat());
     let read_stream = read_into_byterecord_chunk_stream(
         reader,
-        schema.fields.len(),
+        num_csv_fields,
         convert_options.limit,
         chunk_size,
         estimated_mean_row_size,
@@ -410,11 +579,116 @@ async fn read_csv_single_into_stream(
         read_stream,
         Arc::new(fields.clone()),
         projection_indices,
+        Arc::new(parse_options),
+        num_csv_fields,
+        convert_options.mode,
+        corrupt_record_index,
     )?;
 
     Ok((stream, fields))
 }
 
+/// Finds the offset of the first CSV record that starts at or after `offset`, i.e. right after a
+/// line break that isn't inside a quoted field, or `size` if there is none.
+///
+/// Whether a line break is inside a quoted field can't be known without reading the file from the
+/// start, so each line break after `offset` is tried in turn, and the first one that's followed by
+/// records with the expected number of fields is taken as the record boundary. If there is no such
+/// line break, the first one is used.
+async fn find_csv_record_start(
+    uri: &str,
+    offset: usize,
+    size: usize,
+    parse_options: &CsvParseOptions,
+    num_fields: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<usize> {
+    // Give up on finding a line break followed by well-formed records after this many bytes.
+    const MAX_WINDOW_SIZE: usize = 16 * 1024 * 1024;
+    if offset == 0 || offset >= size {
+        return Ok(offset.min(size));
+    }
+    // Start looking at the byte before the offset, in case the offset is at the start of a record.
+    let window_start = offset - 1;
+    let mut window_size = 64 * 1024;
+    loop {
+        let window_end = (window_start + window_size).min(size);
+        let window = io_client
+            .single_url_get(
+                uri.to_string(),
+                Some(window_start..window_end),
+                io_stats.clone(),
+            )
+            .await?
+            .bytes()
+            .await?;
+        let at_eof = window_end == size;
+        // Collected up front, since an iterator with borrowing closures can't be held across an
+        // await in a future that must be `Send`.
+        let candidates = window
+            .iter()
+            .enumerate()
+            .filter(|(_, byte)| **byte == b'\n')
+            .map(|(i, _)| i + 1)
+            .collect::<Vec<_>>();
+        let first_candidate = candidates.first().copied();
+        for candidate in candidates {
+            match is_csv_record_boundary(&window[candidate..], at_eof, parse_options, num_fields)
+                .await?
+            {
+                Some(true) => return Ok(window_start + candidate),
+                Some(false) => continue,
+                // Not enough data in the window to tell.
+                None => break,
+            }
+        }
+        if at_eof || window_size >= MAX_WINDOW_SIZE {
+            return Ok(first_candidate.map_or(size, |candidate| window_start + candidate));
+        }
+        window_size *= 2;
+    }
+}
+
+/// Whether `data`, which starts after a line break, starts with records that have `num_fields`
+/// fields, or `None` if it doesn't contain a complete record.
+async fn is_csv_record_boundary(
+    data: &[u8],
+    at_eof: bool,
+    parse_options: &CsvParseOptions,
+    num_fields: usize,
+) -> DaftResult<Option<bool>> {
+    // Number of records to check after a line break.
+    const NUM_RECORDS_TO_CHECK: usize = 8;
+    let mut reader = AsyncReaderBuilder::new()
+        .has_headers(false)
+        .delimiter(parse_options.delimiter)
+        .double_quote(parse_options.double_quote)
+        .quote(parse_options.quote)
+        .escape(parse_options.escape_char)
+        .comment(parse_options.comment)
+        .flexible(true)
+        .create_reader(data);
+    let mut record = ByteRecord::new();
+    let mut num_records = 0;
+    while num_records < NUM_RECORDS_TO_CHECK
+        && reader
+            .read_byte_record(&mut record)
+            .await
+            .context(CSVSnafu {})?
+    {
+        // The last record in the data may be cut off, unless the data runs to the end of the file.
+        if !at_eof && reader.position().byte() as usize >= data.len() {
+            break;
+        }
+        if record.len() != num_fields {
+            return Ok(Some(false));
+        }
+        num_records += 1;
+    }
+    Ok((num_records > 0 || at_eof).then_some(true))
+}
+
 fn read_into_byterecord_chunk_stream<R>(
     mut reader: AsyncReader<Compat<R>>,
     num_fields: usize,
@@ -457,6 +731,10 @@ where
             let byte_pos_before = reader.position().byte();
             rows_read = read_rows(&mut reader, 0, chunk_buffer.as_mut_slice()).await.context(ArrowSnafu {})?;
             let bytes_read = reader.position().byte() - byte_pos_before;
+            // Nothing left to read, e.g. for an empty byte range.
+            if rows_read == 0 {
+                break;
+            }
 
             // Update stats.
             total_rows_read += rows_read;
@@ -468,17 +746,20 @@ where
             estimated_std_row_size = (m2 / ((total_rows_read - 1) as f64)).sqrt();
 
             chunk_buffer.truncate(rows_read);
-            if rows_read > 0 {
-                yield chunk_buffer
-            }
+            yield chunk_buffer
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn parse_into_column_array_chunk_stream(
     stream: impl ByteRecordChunkStream + Send,
     fields: Arc<Vec<arrow2::datatypes::Field>>,
     projection_indices: Arc<Vec<usize>>,
+    parse_options: Arc<CsvParseOptions>,
+    num_csv_fields: usize,
+    mode: ParseMode,
+    corrupt_record_index: Option<usize>,
 ) -> DaftResult<impl TableStream + Send> {
     // Parsing stream: we spawn background tokio + rayon tasks so we can pipeline chunk parsing with chunk reading, and
     // we further parse each chunk column in parallel on the rayon threadpool.
@@ -500,27 +781,103 @@ fn parse_into_column_array_chunk_stream(
         let (fields, projection_indices) = (fields.clone(), projection_indices.clone());
         let read_schema = read_schema.clone();
         let read_daft_fields = read_daft_fields.clone();
+        let parse_options = parse_options.clone();
         tokio::spawn(async move {
             let (send, recv) = tokio::sync::oneshot::channel();
             rayon::spawn(move || {
                 let result = (move || {
-                    let chunk = projection_indices
+                    let rejected: Vec<bool> = match parse_options.ragged_rows {
+                        RaggedRows::Reject => record
+                            .iter()
+                            .map(|record| record.len() != num_csv_fields)
+                            .collect(),
+                        _ => vec![],
+                    };
+                    // Errors of malformed rows, which only need to be tracked if they are dropped or
+                    // captured instead of failing the read.
+                    let mut malformed: Vec<Option<String>> = match mode {
+                        ParseMode::FailFast => vec![],
+                        _ => record
+                            .iter()
+                            .map(|record| {
+                                (parse_options.ragged_rows == RaggedRows::Error
+                                    && record.len() != num_csv_fields)
+                                    .then(|| {
+                                        format!(
+                                            "Expected {} fields, but found {}",
+                                            num_csv_fields,
+                                            record.len()
+                                        )
+                                    })
+                            })
+                            .collect(),
+                    };
+                    let columns = projection_indices
                         .par_iter()
-                        .enumerate()
-                        .map(|(i, proj_idx)| {
-                            let deserialized_col = deserialize_column(
-                                record.as_slice(),
-                                *proj_idx,
-                                fields[*proj_idx].data_type().clone(),
-                                0,
-                            );
+                        .map(|proj_idx| {
+                            if Some(*proj_idx) == corrupt_record_index {
+                                // The corrupt record column is built once all other columns are parsed.
+                                Ok((None, vec![]))
+                            } else if *proj_idx == num_csv_fields {
+                                let column = deserialize_rejected_rows(
+                                    record.as_slice(),
+                                    &rejected,
+                                    &parse_options,
+                                );
+                                Ok((Some(column), vec![]))
+                            } else {
+                                deserialize_csv_column(
+                                    record.as_slice(),
+                                    *proj_idx,
+                                    fields[*proj_idx].data_type().clone(),
+                                    &parse_options,
+                                    &rejected,
+                                    &malformed,
+                                )
+                                .map(|(column, unparseable)| (Some(column), unparseable))
+                            }
+                        })
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    for ((_, unparseable), proj_idx) in
+                        columns.iter().zip(projection_indices.iter())
+                    {
+                        for row in unparseable {
+                            malformed[*row].get_or_insert_with(|| {
+                                format!(
+                                    "Could not parse field {:?} as {:?}",
+                                    fields[*proj_idx].name,
+                                    fields[*proj_idx].data_type()
+                                )
+                            });
+                        }
+                    }
+                    let chunk = columns
+                        .into_iter()
+                        .zip(read_daft_fields.iter())
+                        .map(|((column, _), field)| {
+                            let column = column.unwrap_or_else(|| {
+                                corrupt_record_array(&corrupt_records(
+                                    record.as_slice(),
+                                    &malformed,
+                                    &parse_options,
+                                ))
+                            });
                             Series::try_from_field_and_arrow_array(
-                                read_daft_fields[i].clone(),
-                                cast_array_for_daft_if_needed(deserialized_col?),
+                                field.clone(),
+                                cast_array_for_daft_if_needed(column),
                             )
                         })
                         .collect::<DaftResult<Vec<Series>>>()?;
-                    Ok(Table::new_unchecked(read_schema, chunk))
+                    let table = Table::new_unchecked(read_schema, chunk);
+                    match mode {
+                        ParseMode::DropMalformed => {
+                            let mask = malformed.iter().map(Option::is_none).collect::<Vec<_>>();
+                            table.mask_filter(
+                                &BooleanArray::from(("mask", mask.as_slice())).into_series(),
+                            )
+                        }
+                        _ => Ok(table),
+                    }
                 })();
                 let _ = send.send(result);
             });
@@ -530,6 +887,118 @@ fn parse_into_column_array_chunk_stream(
     }))
 }
 
+/// Deserializes a column of a chunk. If malformed rows are tracked, also returns the rows with
+/// values that couldn't be parsed as the column's type.
+fn deserialize_csv_column(
+    records: &[ByteRecord],
+    column: usize,
+    data_type: arrow2::datatypes::DataType,
+    parse_options: &CsvParseOptions,
+    rejected: &[bool],
+    malformed: &[Option<String>],
+) -> DaftResult<(Box<dyn arrow2::array::Array>, Vec<usize>)> {
+    if !parse_options.has_custom_values() && rejected.is_empty() && malformed.is_empty() {
+        return Ok((deserialize_column(records, column, data_type, 0)?, vec![]));
+    }
+    let is_boolean = data_type == arrow2::datatypes::DataType::Boolean;
+    // Null columns can't hold any values.
+    let is_null = data_type == arrow2::datatypes::DataType::Null;
+    let views = records
+        .iter()
+        .enumerate()
+        .map(|(row, record)| RecordView {
+            record,
+            parse_options,
+            is_boolean,
+            is_rejected: rejected.get(row).copied().unwrap_or(false)
+                || malformed.get(row).map_or(false, Option::is_some),
+        })
+        .collect::<Vec<_>>();
+    let array = deserialize_column(views.as_slice(), column, data_type, 0)?;
+    if malformed.is_empty() || is_null {
+        return Ok((array, vec![]));
+    }
+    // Values that are present but were deserialized as nulls couldn't be parsed.
+    let unparseable = views
+        .iter()
+        .enumerate()
+        .filter(|(row, view)| {
+            array.is_null(*row) && view.get(column).map_or(false, |v| !v.is_empty())
+        })
+        .map(|(row, _)| row)
+        .collect();
+    Ok((array, unparseable))
+}
+
+/// A view of a CSV record that applies the user-provided null, true and false values to its
+/// fields, and nulls out all of its fields if it was rejected or malformed.
+struct RecordView<'a> {
+    record: &'a ByteRecord,
+    parse_options: &'a CsvParseOptions,
+    is_boolean: bool,
+    is_rejected: bool,
+}
+
+impl ByteRecordGeneric for RecordView<'_> {
+    fn get(&self, index: usize) -> Option<&[u8]> {
+        if self.is_rejected {
+            return None;
+        }
+        let bytes = self.record.get(index)?;
+        if self.parse_options.is_null_value(bytes) {
+            return None;
+        }
+        if self.is_boolean {
+            match self.parse_options.parse_bool_value(bytes) {
+                Some(true) => return Some(b"true".as_slice()),
+                Some(false) => return Some(b"false".as_slice()),
+                None => {}
+            }
+        }
+        Some(bytes)
+    }
+}
+
+/// Deserializes the rejected rows of a chunk into a string column.
+fn deserialize_rejected_rows(
+    records: &[ByteRecord],
+    rejected: &[bool],
+    parse_options: &CsvParseOptions,
+) -> Box<dyn arrow2::array::Array> {
+    let rows = records
+        .iter()
+        .zip(rejected)
+        .map(|(record, is_rejected)| is_rejected.then(|| raw_row(record, parse_options)));
+    Box::new(arrow2::array::Utf8Array::<i64>::from_iter(rows))
+}
+
+/// Pairs the raw row of each malformed row of a chunk with its error.
+fn corrupt_records(
+    records: &[ByteRecord],
+    malformed: &[Option<String>],
+    parse_options: &CsvParseOptions,
+) -> Vec<Option<(String, String)>> {
+    records
+        .iter()
+        .zip(malformed)
+        .map(|(record, error)| {
+            error
+                .as_ref()
+                .map(|error| (raw_row(record, parse_options), error.clone()))
+        })
+        .collect()
+}
+
+/// Rebuilds a raw row by joining its fields with the delimiter.
+fn raw_row(record: &ByteRecord, parse_options: &CsvParseOptions) -> String {
+    let delimiter = match &parse_options.multi_byte_delimiter {
+        Some(delimiter) => delimiter.as_bytes().to_vec(),
+        None => vec![parse_options.delimiter],
+    };
+    String::from_utf8_lossy(&record.iter().collect::<Vec<_>>().join(delimiter.as_slice()))
+        .into_owned()
+}
+
 fn fields_to_projection_indices(
     fields: &Vec<arrow2::datatypes::Field>,
     include_columns: &Option<Vec<String>>,
@@ -570,11 +1039,16 @@ mod tests {
     };
     use daft_io::{IOClient, IOConfig};
     use daft_table::Table;
+    use futures::{StreamExt, TryStreamExt};
     use rstest::rstest;
 
-    use crate::{char_to_byte, CsvConvertOptions, CsvParseOptions, CsvReadOptions};
+    use crate::{
+        char_to_byte, CsvConvertOptions, CsvParseOptions, CsvReadOptions, RaggedRows,
+        REJECTED_ROW_COLUMN_NAME,
+    };
+    use daft_decoding::parse_mode::{ParseMode, DEFAULT_CORRUPT_RECORD_COLUMN_NAME};
 
-    use super::read_csv;
+    use super::{read_csv, read_csv_bulk, stream_csv};
 
     fn check_equal_local_arrow2(
         path: &str,
@@ -1257,6 +1731,132 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    fn test_csv_stream_local(#[values(None, Some(10))] limit: Option<usize>) -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let convert_options = CsvConvertOptions::default().with_limit(limit);
+        let read_options = CsvReadOptions::default().with_chunk_size(Some(2));
+
+        let tables = daft_io::get_runtime(true)?.block_on(
+            stream_csv(
+                file.clone(),
+                Some(convert_options),
+                None,
+                Some(read_options),
+                None,
+                io_client,
+                None,
+                Some(1),
+            )
+            .try_collect::<Vec<_>>(),
+        )?;
+        // Chunks hold at least 8 rows, regardless of the chunk size in bytes.
+        assert_eq!(
+            tables.iter().map(Table::len).collect::<Vec<_>>(),
+            match limit {
+                None => vec![8, 8, 4],
+                Some(_) => vec![8, 2],
+            }
+        );
+        check_equal_local_arrow2(
+            file.as_ref(),
+            &Table::concat(&tables)?,
+            true,
+            None,
+            true,
+            None,
+            None,
+            None,
+            None,
+            None,
+            limit,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_stream_local_drop_early() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let read_options = CsvReadOptions::default().with_chunk_size(Some(2));
+
+        daft_io::get_runtime(true)?.block_on(async {
+            let mut tables = stream_csv(
+                file,
+                None,
+                None,
+                Some(read_options),
+                None,
+                io_client,
+                None,
+                Some(1),
+            );
+            assert_eq!(tables.try_next().await?.map(|table| table.len()), Some(8));
+            // Dropping the stream stops reading the file.
+            drop(tables);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_csv_stream_local_errors() -> DaftResult<()> {
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let runtime_handle = daft_io::get_runtime(true)?;
+
+        let mut tables = stream_csv(
+            format!("{}/test/missing.csv", env!("CARGO_MANIFEST_DIR")),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            Some(1),
+        );
+        assert!(matches!(
+            runtime_handle.block_on(tables.next()),
+            Some(Err(DaftError::IoError(err))) if err.kind() == std::io::ErrorKind::NotFound
+        ));
+        assert!(runtime_handle.block_on(tables.next()).is_none());
+
+        // A malformed row fails the stream after the chunks before it.
+        let file = tempfile::NamedTempFile::new()?;
+        let mut data = "a,b\n".to_string();
+        for i in 0..8 {
+            data.push_str(&format!("{i},{i}\n"));
+        }
+        data.push_str("8,8,8\n");
+        std::fs::write(file.path(), data)?;
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ])?;
+        let mut tables = stream_csv(
+            file.path().to_string_lossy().to_string(),
+            Some(CsvConvertOptions::default().with_schema(Some(schema.into()))),
+            None,
+            Some(CsvReadOptions::default().with_chunk_size(Some(2))),
+            None,
+            io_client,
+            None,
+            Some(1),
+        );
+        assert_eq!(
+            runtime_handle
+                .block_on(tables.try_next())?
+                .map(|table| table.len()),
+            Some(8)
+        );
+        assert!(runtime_handle.block_on(tables.try_next()).is_err());
+        assert!(runtime_handle.block_on(tables.next()).is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_csv_read_local_throttled_streaming() -> DaftResult<()> {
         let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
@@ -1581,6 +2181,238 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_csv_read_local_custom_values() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("custom_values.csv");
+        std::fs::write(
+            &file,
+            "exported by vendor\na::b::c\n1::Y::NA\nNA::N::x\n3::Y::\n",
+        )?;
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let parse_options =
+            CsvParseOptions::new_with_defaults(true, Some("::"), true, None, None, None)?
+                .with_skip_rows(1)
+                .with_null_values(Some(vec!["NA".to_string()]))
+                .with_true_values(Some(vec!["Y".to_string()]))
+                .with_false_values(Some(vec!["N".to_string()]));
+        let table = read_csv(
+            file.to_str().unwrap(),
+            None,
+            Some(parse_options),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Boolean),
+                Field::new("c", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        let a = table.get_column("a")?.i64()?;
+        assert_eq!(
+            (0..3).map(|i| a.get(i)).collect::<Vec<_>>(),
+            vec![Some(1), None, Some(3)]
+        );
+        let b = table.get_column("b")?.bool()?;
+        assert_eq!(
+            (0..3).map(|i| b.get(i)).collect::<Vec<_>>(),
+            vec![Some(true), Some(false), Some(true)]
+        );
+        let c = table.get_column("c")?.utf8()?;
+        assert_eq!(
+            (0..3).map(|i| c.get(i)).collect::<Vec<_>>(),
+            vec![None, Some("x"), Some("")]
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_csv_read_local_ragged_rows(
+        #[values(RaggedRows::Fill, RaggedRows::Reject)] ragged_rows: RaggedRows,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("ragged.csv");
+        std::fs::write(&file, "a,b\n1,2\n3\n4,5,6\n7,8\n")?;
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let table = read_csv(
+            file.to_str().unwrap(),
+            None,
+            Some(CsvParseOptions::default().with_ragged_rows(ragged_rows)),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        let column = |name: &str| -> DaftResult<Vec<Option<i64>>> {
+            let column = table.get_column(name)?.i64()?;
+            Ok((0..column.len()).map(|i| column.get(i)).collect())
+        };
+        match ragged_rows {
+            RaggedRows::Fill => {
+                assert_eq!(table.num_columns(), 2);
+                assert_eq!(column("a")?, vec![Some(1), Some(3), Some(4), Some(7)]);
+                assert_eq!(column("b")?, vec![Some(2), None, Some(5), Some(8)]);
+            }
+            _ => {
+                assert_eq!(table.num_columns(), 3);
+                assert_eq!(column("a")?, vec![Some(1), None, None, Some(7)]);
+                assert_eq!(column("b")?, vec![Some(2), None, None, Some(8)]);
+                let rejected = table.get_column(REJECTED_ROW_COLUMN_NAME)?.utf8()?;
+                assert_eq!(
+                    (0..rejected.len())
+                        .map(|i| rejected.get(i))
+                        .collect::<Vec<_>>(),
+                    vec![None, Some("3"), Some("4,5,6"), None]
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::one_line_break("a,b\n1,x\n2,\"multi\nline\"\n3,y\n4,\"z\"\n", ["x", "multi\nline", "y", "z"])]
+    #[case::delimiters_in_quotes(
+        "a,b\n1,\"first\nsecond, line\nthird\"\n2,\"x\"\n3,\"one,\ntwo\"\n4,y\n",
+        ["first\nsecond, line\nthird", "x", "one,\ntwo", "y"]
+    )]
+    fn test_csv_read_local_byte_ranges(
+        #[case] data: &str,
+        #[case] expected_b: [&str; 4],
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("multiline.csv");
+        std::fs::write(&file, data)?;
+        let uri = file.to_str().unwrap();
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        // Every record should be read exactly once, wherever the file is split, including within
+        // the quoted fields that span several lines.
+        for split in 0..=data.len() {
+            let tables = read_csv_bulk(
+                &[uri, uri],
+                None,
+                None,
+                None,
+                Some(vec![Some(0..split), Some(split..data.len())]),
+                io_client.clone(),
+                None,
+                true,
+                None,
+                2,
+            )?;
+            let mut a = vec![];
+            let mut b = vec![];
+            for table in tables {
+                let a_column = table.get_column("a")?.i64()?;
+                a.extend((0..a_column.len()).map(|i| a_column.get(i)));
+                let b_column = table.get_column("b")?.utf8()?;
+                b.extend((0..b_column.len()).map(|i| b_column.get(i).map(str::to_string)));
+            }
+            assert_eq!(
+                a,
+                vec![Some(1), Some(2), Some(3), Some(4)],
+                "split at {}",
+                split
+            );
+            assert_eq!(
+                b,
+                expected_b.map(|b| Some(b.to_string())).to_vec(),
+                "split at {}",
+                split
+            );
+        }
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_csv_read_local_parse_modes(
+        #[values(ParseMode::DropMalformed, ParseMode::Permissive)] mode: ParseMode,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("malformed.csv");
+        std::fs::write(&file, "a,b\n1,2\nx,3\n4\n5,6\n")?;
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ])?;
+        let table = read_csv(
+            file.to_str().unwrap(),
+            Some(
+                CsvConvertOptions::default()
+                    .with_schema(Some(schema.into()))
+                    .with_mode(mode),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        let column = |name: &str| -> DaftResult<Vec<Option<i64>>> {
+            let column = table.get_column(name)?.i64()?;
+            Ok((0..column.len()).map(|i| column.get(i)).collect())
+        };
+        match mode {
+            ParseMode::DropMalformed => {
+                assert_eq!(table.num_columns(), 2);
+                assert_eq!(column("a")?, vec![Some(1), Some(5)]);
+                assert_eq!(column("b")?, vec![Some(2), Some(6)]);
+            }
+            _ => {
+                assert_eq!(table.num_columns(), 3);
+                assert_eq!(column("a")?, vec![Some(1), None, None, Some(5)]);
+                assert_eq!(column("b")?, vec![Some(2), Some(3), None, Some(6)]);
+                let corrupt = table
+                    .get_column(DEFAULT_CORRUPT_RECORD_COLUMN_NAME)?
+                    .to_arrow();
+                let corrupt = corrupt
+                    .as_any()
+                    .downcast_ref::<arrow2::array::StructArray>()
+                    .unwrap();
+                let child = |i: usize| {
+                    corrupt.values()[i]
+                        .as_any()
+                        .downcast_ref::<arrow2::array::Utf8Array<i64>>()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.map(str::to_string))
+                        .collect::<Vec<_>>()
+                };
+                assert_eq!(
+                    child(0),
+                    vec![None, Some("x,3".to_string()), Some("4".to_string()), None]
+                );
+                assert_eq!(
+                    child(1),
+                    vec![
+                        None,
+                        Some("Could not parse field \"a\" as Int64".to_string()),
+                        Some("Expected 2 fields, but found 1".to_string()),
+                        None
+                    ]
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     #[rstest]
     fn test_csv_read_s3_compression(
         #[values(
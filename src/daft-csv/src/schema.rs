@@ -1,5 +1,24 @@
 use std::collections::HashSet;
 
+use daft_decoding::inference::infer;
+
+use crate::CsvParseOptions;
+
+/// Infers the type of a single CSV field, taking the user-provided null, true and false values into
+/// account before falling back to [`infer`].
+pub(crate) fn infer_field_type(
+    bytes: &[u8],
+    parse_options: &CsvParseOptions,
+) -> arrow2::datatypes::DataType {
+    if parse_options.is_null_value(bytes) {
+        arrow2::datatypes::DataType::Null
+    } else if parse_options.parse_bool_value(bytes).is_some() {
+        arrow2::datatypes::DataType::Boolean
+    } else {
+        infer(bytes)
+    }
+}
+
 /// Merges two Arrow2 schemas
 pub fn merge_schema(
     headers: &[String],
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use async_compat::CompatExt;
+use common_error::{DaftError, DaftResult};
+use csv_async::AsyncWriterBuilder;
+use daft_compression::CompressionCodec;
+use daft_core::array::ops::as_arrow::AsArrow;
+use daft_core::DataType;
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_table::Table;
+use snafu::ResultExt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{CSVSnafu, CsvParseOptions, StdIOSnafu};
+
+/// Writes `table` as a single CSV file to `uri`, compressing it if `uri` has a known compression
+/// extension (e.g. `.csv.gz`).
+pub fn write_csv(
+    table: &Table,
+    uri: &str,
+    parse_options: Option<CsvParseOptions>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> DaftResult<()> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        write_csv_single(
+            table,
+            uri,
+            parse_options.unwrap_or_default(),
+            io_client,
+            io_stats,
+        )
+        .await
+    })
+}
+
+async fn write_csv_single(
+    table: &Table,
+    uri: &str,
+    parse_options: CsvParseOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<()> {
+    let codec = CompressionCodec::from_uri(uri);
+    io_client
+        .single_url_put_from_writer(uri.to_string(), io_stats, |sink| async move {
+            match codec {
+                Some(codec) => serialize_csv(table, &parse_options, codec.to_encoder(sink)).await,
+                None => serialize_csv(table, &parse_options, sink).await,
+            }
+        })
+        .await
+}
+
+async fn serialize_csv<W: AsyncWrite + Unpin>(
+    table: &Table,
+    parse_options: &CsvParseOptions,
+    sink: W,
+) -> DaftResult<()> {
+    if let Some(delimiter) = &parse_options.multi_byte_delimiter {
+        return Err(DaftError::ValueError(format!(
+            "Writing CSV files with the multi-byte delimiter {delimiter:?} is not supported"
+        )));
+    }
+    let mut builder = AsyncWriterBuilder::new();
+    builder
+        .delimiter(parse_options.delimiter)
+        .quote(parse_options.quote)
+        .double_quote(parse_options.double_quote);
+    if let Some(escape_char) = parse_options.escape_char {
+        builder.escape(escape_char);
+    }
+    let mut writer = builder.create_writer(sink.compat());
+
+    if parse_options.has_header {
+        writer
+            .write_record(table.schema.fields.keys())
+            .await
+            .context(CSVSnafu)?;
+    }
+
+    let columns = (0..table.num_columns())
+        .map(|i| table.get_column_by_index(i)?.cast(&DataType::Utf8))
+        .collect::<DaftResult<Vec<_>>>()?;
+    let columns = columns
+        .iter()
+        .map(|s| Ok(s.utf8()?.as_arrow()))
+        .collect::<DaftResult<Vec<_>>>()?;
+    for row in 0..table.len() {
+        writer
+            .write_record(columns.iter().map(|c| c.get(row).unwrap_or("")))
+            .await
+            .context(CSVSnafu)?;
+    }
+
+    let mut sink = writer.into_inner().await.context(StdIOSnafu)?.into_inner();
+    sink.shutdown().await.context(StdIOSnafu)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{Int64Array, Utf8Array},
+        IntoSeries,
+    };
+    use daft_io::{IOClient, IOConfig};
+    use daft_table::Table;
+    use rstest::rstest;
+
+    use super::write_csv;
+    use crate::{read::read_csv, CsvParseOptions};
+
+    #[rstest]
+    fn test_csv_write_round_trip(
+        #[values("out.csv", "out.csv.gz", "out.csv.zst")] file_name: &str,
+        #[values(b',', b'|')] delimiter: u8,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let uri = dir.path().join(file_name).to_string_lossy().to_string();
+        let table = Table::from_columns(vec![
+            Int64Array::from(("a", vec![1, 2, 3])).into_series(),
+            Utf8Array::from(("b", ["x", "has,comma|pipe", "has \"quote\""].as_slice()))
+                .into_series(),
+        ])?;
+        let parse_options = CsvParseOptions::default().with_delimiter(delimiter);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        write_csv(
+            &table,
+            &uri,
+            Some(parse_options.clone()),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        let read_back = read_csv(
+            &uri,
+            None,
+            Some(parse_options),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(read_back.len(), 3);
+        for name in ["a", "b"] {
+            assert_eq!(
+                read_back.get_column(name)?.to_arrow(),
+                table.get_column(name)?.to_arrow()
+            );
+        }
+        Ok(())
+    }
+}
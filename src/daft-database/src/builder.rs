@@ -0,0 +1,112 @@
+use arrow2::array::{
+    Array, MutableArray, MutableBinaryArray, MutableBooleanArray, MutablePrimitiveArray,
+    MutableUtf8Array, NullArray,
+};
+use common_error::DaftResult;
+use daft_core::{datatypes::Field, schema::Schema, DataType, Series};
+use daft_table::Table;
+
+use crate::{Error, Result};
+
+/// Accumulates the values of a single result column into an Arrow array.
+pub(crate) enum ColumnBuilder {
+    Null(usize),
+    Boolean(MutableBooleanArray),
+    Int16(MutablePrimitiveArray<i16>),
+    Int32(MutablePrimitiveArray<i32>),
+    Int64(MutablePrimitiveArray<i64>),
+    Float32(MutablePrimitiveArray<f32>),
+    Float64(MutablePrimitiveArray<f64>),
+    /// Values multiplied by 10^scale, and the precision and scale.
+    Decimal128(MutablePrimitiveArray<i128>, usize, usize),
+    Utf8(MutableUtf8Array<i64>),
+    Binary(MutableBinaryArray<i64>),
+    /// Days since the UNIX epoch.
+    Date(MutablePrimitiveArray<i32>),
+    /// Microseconds since midnight.
+    Time(MutablePrimitiveArray<i64>),
+    /// Microseconds since the UNIX epoch.
+    Timestamp(MutablePrimitiveArray<i64>),
+}
+
+impl ColumnBuilder {
+    pub fn try_new(field: &Field, capacity: usize) -> Result<Self> {
+        use daft_core::datatypes::TimeUnit;
+
+        Ok(match &field.dtype {
+            DataType::Null => Self::Null(0),
+            DataType::Boolean => Self::Boolean(MutableBooleanArray::with_capacity(capacity)),
+            DataType::Int16 => Self::Int16(MutablePrimitiveArray::with_capacity(capacity)),
+            DataType::Int32 => Self::Int32(MutablePrimitiveArray::with_capacity(capacity)),
+            DataType::Int64 => Self::Int64(MutablePrimitiveArray::with_capacity(capacity)),
+            DataType::Float32 => Self::Float32(MutablePrimitiveArray::with_capacity(capacity)),
+            DataType::Float64 => Self::Float64(MutablePrimitiveArray::with_capacity(capacity)),
+            DataType::Decimal128(precision, scale) => Self::Decimal128(
+                MutablePrimitiveArray::with_capacity(capacity),
+                *precision,
+                *scale,
+            ),
+            DataType::Utf8 => Self::Utf8(MutableUtf8Array::with_capacity(capacity)),
+            DataType::Binary => Self::Binary(MutableBinaryArray::with_capacity(capacity)),
+            DataType::Date => Self::Date(MutablePrimitiveArray::with_capacity(capacity)),
+            DataType::Time(TimeUnit::Microseconds) => {
+                Self::Time(MutablePrimitiveArray::with_capacity(capacity))
+            }
+            DataType::Timestamp(TimeUnit::Microseconds, _) => {
+                Self::Timestamp(MutablePrimitiveArray::with_capacity(capacity))
+            }
+            other => {
+                return Err(Error::UnsupportedType {
+                    column: field.name.clone(),
+                    type_name: other.to_string(),
+                })
+            }
+        })
+    }
+
+    pub fn push_null(&mut self) {
+        match self {
+            Self::Null(len) => *len += 1,
+            Self::Boolean(b) => b.push_null(),
+            Self::Int16(b) => b.push_null(),
+            Self::Int32(b) => b.push_null(),
+            Self::Int64(b) | Self::Time(b) | Self::Timestamp(b) => b.push_null(),
+            Self::Float32(b) => b.push_null(),
+            Self::Float64(b) => b.push_null(),
+            Self::Decimal128(b, ..) => b.push_null(),
+            Self::Utf8(b) => b.push_null(),
+            Self::Binary(b) => b.push_null(),
+            Self::Date(b) => b.push_null(),
+        }
+    }
+
+    fn finish(self, field: &Field) -> DaftResult<Box<dyn Array>> {
+        let arrow_dtype = field.dtype.to_arrow()?;
+        Ok(match self {
+            Self::Null(len) => Box::new(NullArray::new(arrow_dtype, len)),
+            Self::Boolean(mut b) => b.as_box(),
+            Self::Int16(b) => b.to(arrow_dtype).as_box(),
+            Self::Int32(b) | Self::Date(b) => b.to(arrow_dtype).as_box(),
+            Self::Int64(b) | Self::Time(b) | Self::Timestamp(b) => b.to(arrow_dtype).as_box(),
+            Self::Float32(b) => b.to(arrow_dtype).as_box(),
+            Self::Float64(b) => b.to(arrow_dtype).as_box(),
+            Self::Decimal128(b, ..) => b.to(arrow_dtype).as_box(),
+            Self::Utf8(mut b) => b.as_box(),
+            Self::Binary(mut b) => b.as_box(),
+        })
+    }
+}
+
+/// Builds a [`Table`] with the given schema out of one builder per field.
+pub(crate) fn finish_table(schema: Schema, builders: Vec<ColumnBuilder>) -> DaftResult<Table> {
+    let columns = schema
+        .fields
+        .values()
+        .zip(builders)
+        .map(|(field, builder)| {
+            let array = builder.finish(field)?;
+            Series::try_from((field.name.as_str(), array))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new(schema, columns)
+}
@@ -0,0 +1,50 @@
+use common_error::DaftError;
+use snafu::Snafu;
+
+mod builder;
+mod connection;
+mod postgres;
+pub mod read;
+mod sqlite;
+
+pub use connection::DatabaseUrl;
+pub use read::{read_sql, read_sql_count, read_sql_min_max, read_sql_schema};
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Invalid database URL \"{}\": {}", url, message))]
+    InvalidUrl { url: String, message: String },
+    #[snafu(display(
+        "Database \"{}\" is not supported by the native reader, only SQLite and PostgreSQL are",
+        scheme
+    ))]
+    UnsupportedDatabase { scheme: String },
+    #[snafu(display("Failed to execute sql: {} with SQLite: {}", sql, source))]
+    SqliteError {
+        sql: String,
+        source: rusqlite::Error,
+    },
+    #[snafu(display("Failed to execute sql: {} with PostgreSQL: {}", sql, source))]
+    PostgresError {
+        sql: String,
+        source: tokio_postgres::Error,
+    },
+    #[snafu(display("Unable to set up TLS for PostgreSQL: {}", source))]
+    TlsError { source: native_tls::Error },
+    #[snafu(display(
+        "Column \"{}\" has type {} which is not supported by the native reader, consider casting it in the query",
+        column,
+        type_name
+    ))]
+    UnsupportedType { column: String, type_name: String },
+    #[snafu(display("Unable to convert value of column \"{}\": {}", column, message))]
+    ConversionError { column: String, message: String },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> DaftError {
+        DaftError::External(err.into())
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
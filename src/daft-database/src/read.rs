@@ -0,0 +1,230 @@
+use std::future::Future;
+
+use common_error::DaftResult;
+use daft_core::schema::Schema;
+use daft_dsl::ExprRef;
+use daft_io::get_runtime;
+use daft_table::Table;
+
+use crate::{
+    postgres::{postgres_schema, query_postgres},
+    sqlite::{query_sqlite, sqlite_schema},
+    DatabaseUrl,
+};
+
+/// Reads the results of the `sql` query against the database at `url` into a [`Table`].
+///
+/// The projection, predicate and limit are pushed into the query by wrapping it in a subquery. If
+/// the predicate can't be translated to the database's SQL dialect, none of them are pushed down
+/// and they are applied to the results instead.
+pub fn read_sql(
+    sql: &str,
+    url: &str,
+    columns: Option<&[&str]>,
+    predicate: Option<ExprRef>,
+    limit: Option<usize>,
+    multithreaded_io: bool,
+) -> DaftResult<Table> {
+    let database = DatabaseUrl::parse(url)?;
+    let predicate_sql = predicate
+        .as_ref()
+        .map(|predicate| predicate.to_sql(database.dialect()));
+    match (predicate, predicate_sql) {
+        (Some(predicate), Some(None)) => {
+            let table = execute(
+                &database,
+                &build_query(sql, None, None, None),
+                multithreaded_io,
+            )?;
+            let table = table.filter(&[predicate])?;
+            let table = match limit {
+                Some(limit) => table.head(limit)?,
+                None => table,
+            };
+            match columns {
+                Some(columns) => table.get_columns(columns),
+                None => Ok(table),
+            }
+        }
+        (_, predicate_sql) => execute(
+            &database,
+            &build_query(sql, columns, predicate_sql.flatten().as_deref(), limit),
+            multithreaded_io,
+        ),
+    }
+}
+
+/// Infers the schema of the results of the `sql` query from the types of its columns, see
+/// [`postgres_schema`] and [`sqlite_schema`].
+pub fn read_sql_schema(sql: &str, url: &str, multithreaded_io: bool) -> DaftResult<Schema> {
+    match DatabaseUrl::parse(url)? {
+        DatabaseUrl::Sqlite { path } => sqlite_schema(&path, sql),
+        DatabaseUrl::Postgres { url } => block_on(postgres_schema(&url, sql), multithreaded_io),
+    }
+}
+
+/// Counts the rows returned by the `sql` query.
+pub fn read_sql_count(sql: &str, url: &str, multithreaded_io: bool) -> DaftResult<usize> {
+    let database = DatabaseUrl::parse(url)?;
+    let table = execute(
+        &database,
+        &format!("SELECT COUNT(*) AS count FROM ({sql}) AS subquery"),
+        multithreaded_io,
+    )?;
+    let count = table
+        .get_column_by_index(0)?
+        .cast(&daft_core::DataType::Int64)?;
+    Ok(count.i64()?.get(0).unwrap_or(0) as usize)
+}
+
+/// Reads the smallest and largest non-null values of `column` in the results of the `sql` query,
+/// as a table with a single column and two rows, or no rows if the column only holds nulls.
+///
+/// The values are read by sorting rather than with `MIN`/`MAX` so that they keep the column's type,
+/// which SQLite doesn't preserve through aggregations.
+pub fn read_sql_min_max(
+    sql: &str,
+    url: &str,
+    column: &str,
+    multithreaded_io: bool,
+) -> DaftResult<Table> {
+    let database = DatabaseUrl::parse(url)?;
+    let column = quote_identifier(column);
+    let bound = |order: &str| {
+        execute(
+            &database,
+            &format!(
+                "SELECT {column} FROM ({sql}) AS subquery WHERE {column} IS NOT NULL ORDER BY {column} {order} LIMIT 1"
+            ),
+            multithreaded_io,
+        )
+    };
+    Table::concat(&[bound("ASC")?, bound("DESC")?])
+}
+
+fn execute(database: &DatabaseUrl, sql: &str, multithreaded_io: bool) -> DaftResult<Table> {
+    match database {
+        DatabaseUrl::Sqlite { path } => query_sqlite(path, sql),
+        DatabaseUrl::Postgres { url } => block_on(query_postgres(url, sql), multithreaded_io),
+    }
+}
+
+fn block_on<T>(
+    future: impl Future<Output = DaftResult<T>>,
+    multithreaded_io: bool,
+) -> DaftResult<T> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(future)
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn build_query(
+    sql: &str,
+    columns: Option<&[&str]>,
+    predicate: Option<&str>,
+    limit: Option<usize>,
+) -> String {
+    let mut query = match columns {
+        Some(columns) => format!(
+            "SELECT {}",
+            columns
+                .iter()
+                .map(|c| quote_identifier(c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        None => "SELECT *".to_string(),
+    };
+    query.push_str(&format!("\nFROM ({sql}) AS subquery"));
+    if let Some(predicate) = predicate {
+        query.push_str(&format!("\nWHERE {predicate}"));
+    }
+    if let Some(limit) = limit {
+        query.push_str(&format!("\nLIMIT {limit}"));
+    }
+    query
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::DataType;
+    use daft_dsl::{col, lit};
+
+    use super::{build_query, read_sql, read_sql_count, read_sql_min_max, read_sql_schema};
+
+    #[test]
+    fn test_build_query() {
+        assert_eq!(
+            build_query("SELECT * FROM t", None, None, None),
+            "SELECT *\nFROM (SELECT * FROM t) AS subquery"
+        );
+        assert_eq!(
+            build_query(
+                "SELECT * FROM t",
+                Some(&["a", "b\"c"]),
+                Some("a > 1"),
+                Some(10)
+            ),
+            "SELECT \"a\", \"b\"\"c\"\nFROM (SELECT * FROM t) AS subquery\nWHERE a > 1\nLIMIT 10"
+        );
+    }
+
+    #[test]
+    fn test_read_sql_sqlite_pushdowns() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("test.db");
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE t (id INTEGER, name TEXT);
+             INSERT INTO t VALUES (3, 'c'), (1, 'a'), (2, NULL), (4, 'd');",
+        )
+        .unwrap();
+        let url = format!("sqlite://{}", path.to_str().unwrap());
+        let sql = "SELECT * FROM t";
+
+        assert_eq!(read_sql_count(sql, &url, false)?, 4);
+
+        // The type of a computed column is inferred from more than its first value, which is null.
+        let schema = read_sql_schema(
+            "SELECT *, CASE WHEN id > 1 THEN id END AS later FROM t ORDER BY id",
+            &url,
+            false,
+        )?;
+        assert_eq!(schema.get_field("name")?.dtype, DataType::Utf8);
+        assert_eq!(schema.get_field("later")?.dtype, DataType::Int64);
+
+        let bounds = read_sql_min_max(sql, &url, "id", false)?;
+        assert_eq!(bounds.get_column("id")?.i64()?.get(0), Some(1));
+        assert_eq!(bounds.get_column("id")?.i64()?.get(1), Some(4));
+
+        // Pushed down into the query.
+        let table = read_sql(
+            sql,
+            &url,
+            Some(&["name"]),
+            Some(col("id").gt(&lit(1)).into()),
+            Some(2),
+            false,
+        )?;
+        assert_eq!(table.num_columns(), 1);
+        assert_eq!(table.len(), 2);
+
+        // Not translatable to SQL, so applied to the results.
+        let table = read_sql(
+            sql,
+            &url,
+            Some(&["id"]),
+            Some(col("id").cast(&DataType::Utf8).eq(&lit("2")).into()),
+            None,
+            false,
+        )?;
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.get_column("id")?.i64()?.get(0), Some(2));
+        Ok(())
+    }
+}
@@ -0,0 +1,259 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use common_error::DaftResult;
+use daft_core::{datatypes::Field, schema::Schema, DataType};
+use daft_table::Table;
+use rusqlite::{types::Value, Connection, OpenFlags};
+use snafu::ResultExt;
+
+use crate::{
+    builder::{finish_table, ColumnBuilder},
+    Error, Result, SqliteSnafu,
+};
+
+/// Number of rows read to infer the types of the columns without a declared type.
+const SCHEMA_INFERENCE_ROWS: usize = 1024;
+
+/// Runs `sql` against the SQLite database at `path` and collects the results into a [`Table`].
+///
+/// SQLite columns are dynamically typed, so the type of each column is derived from its declared
+/// type (following SQLite's type affinity rules), or from the values it holds if it has none, e.g.
+/// because it is computed by the query.
+pub(crate) fn query_sqlite(path: &str, sql: &str) -> DaftResult<Table> {
+    let (columns, values) = read_values(path, sql, None)?;
+    let fields = result_fields(columns, &values);
+    let builders = fields
+        .iter()
+        .zip(values)
+        .map(|(field, values)| {
+            let mut builder = ColumnBuilder::try_new(field, values.len())?;
+            for value in values {
+                push_value(&mut builder, value).map_err(|message| Error::ConversionError {
+                    column: field.name.clone(),
+                    message,
+                })?;
+            }
+            Ok(builder)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    finish_table(Schema::new(fields)?, builders)
+}
+
+/// Infers the schema of the results of `sql` from the declared types of its columns. Only the
+/// columns without one need the query to run, and their types are inferred from the first
+/// [`SCHEMA_INFERENCE_ROWS`] rows.
+pub(crate) fn sqlite_schema(path: &str, sql: &str) -> DaftResult<Schema> {
+    let (columns, values) = read_values(path, sql, Some(SCHEMA_INFERENCE_ROWS))?;
+    Schema::new(result_fields(columns, &values))
+}
+
+/// Names and declared types of the result columns of a query.
+type ResultColumns = Vec<(String, Option<DataType>)>;
+
+/// Runs `sql` and reads the values of its result columns, or of at most `limit` rows if every
+/// column doesn't have a declared type.
+fn read_values(
+    path: &str,
+    sql: &str,
+    limit: Option<usize>,
+) -> Result<(ResultColumns, Vec<Vec<Value>>)> {
+    let conn = Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY
+            | OpenFlags::SQLITE_OPEN_URI
+            | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .context(SqliteSnafu { sql })?;
+    let mut stmt = conn.prepare(sql).context(SqliteSnafu { sql })?;
+    let columns = stmt
+        .columns()
+        .iter()
+        .map(|c| (c.name().to_string(), c.decl_type().map(declared_dtype)))
+        .collect::<Vec<_>>();
+    let limit = match limit {
+        Some(_) if columns.iter().all(|(_, dtype)| dtype.is_some()) => Some(0),
+        limit => limit,
+    };
+
+    let mut values = vec![vec![]; columns.len()];
+    let mut rows = stmt.query([]).context(SqliteSnafu { sql })?;
+    let mut num_rows = 0;
+    while limit.map_or(true, |limit| num_rows < limit) {
+        let Some(row) = rows.next().context(SqliteSnafu { sql })? else {
+            break;
+        };
+        for (i, column) in values.iter_mut().enumerate() {
+            column.push(Value::from(row.get_ref(i).context(SqliteSnafu { sql })?));
+        }
+        num_rows += 1;
+    }
+    Ok((columns, values))
+}
+
+fn result_fields(columns: ResultColumns, values: &[Vec<Value>]) -> Vec<Field> {
+    columns
+        .into_iter()
+        .zip(values.iter())
+        .map(|((name, dtype), values)| {
+            Field::new(name, dtype.unwrap_or_else(|| inferred_dtype(values)))
+        })
+        .collect()
+}
+
+/// Maps a declared column type to a Daft type, see https://www.sqlite.org/datatype3.html.
+fn declared_dtype(decl_type: &str) -> DataType {
+    use daft_core::datatypes::TimeUnit;
+
+    let decl_type = decl_type.to_uppercase();
+    if decl_type.contains("DATETIME") || decl_type.contains("TIMESTAMP") {
+        DataType::Timestamp(TimeUnit::Microseconds, None)
+    } else if decl_type == "DATE" {
+        DataType::Date
+    } else if decl_type == "TIME" {
+        DataType::Time(TimeUnit::Microseconds)
+    } else if decl_type.contains("BOOL") {
+        DataType::Boolean
+    } else if decl_type.contains("INT") {
+        DataType::Int64
+    } else if decl_type.contains("CHAR") || decl_type.contains("CLOB") || decl_type.contains("TEXT")
+    {
+        DataType::Utf8
+    } else if decl_type.contains("BLOB") || decl_type.is_empty() {
+        DataType::Binary
+    } else {
+        // REAL and NUMERIC affinity.
+        DataType::Float64
+    }
+}
+
+/// Picks the narrowest type that can hold all the `values` of a column without a declared type.
+fn inferred_dtype(values: &[Value]) -> DataType {
+    values
+        .iter()
+        .fold(DataType::Null, |dtype, value| match (dtype, value) {
+            (DataType::Binary, _) | (_, Value::Blob(_)) => DataType::Binary,
+            (DataType::Utf8, _) | (_, Value::Text(_)) => DataType::Utf8,
+            (DataType::Float64, _) | (_, Value::Real(_)) => DataType::Float64,
+            (DataType::Int64, _) | (_, Value::Integer(_)) => DataType::Int64,
+            (dtype, Value::Null) => dtype,
+        })
+}
+
+fn push_value(builder: &mut ColumnBuilder, value: Value) -> Result<(), String> {
+    let mismatch = |value: &Value| format!("unexpected SQLite value {value:?}");
+    match (builder, value) {
+        (builder, Value::Null) => builder.push_null(),
+        (ColumnBuilder::Boolean(b), Value::Integer(v)) => b.push(Some(v != 0)),
+        (ColumnBuilder::Int64(b), Value::Integer(v)) => b.push(Some(v)),
+        (ColumnBuilder::Int64(b), Value::Real(v)) if v.fract() == 0.0 => b.push(Some(v as i64)),
+        (ColumnBuilder::Int64(b), Value::Text(v)) => b.push(Some(
+            v.trim().parse().map_err(|_| mismatch(&Value::Text(v)))?,
+        )),
+        (ColumnBuilder::Float64(b), Value::Integer(v)) => b.push(Some(v as f64)),
+        (ColumnBuilder::Float64(b), Value::Real(v)) => b.push(Some(v)),
+        (ColumnBuilder::Float64(b), Value::Text(v)) => b.push(Some(
+            v.trim().parse().map_err(|_| mismatch(&Value::Text(v)))?,
+        )),
+        (ColumnBuilder::Utf8(b), Value::Text(v)) => b.push(Some(v)),
+        (ColumnBuilder::Utf8(b), Value::Integer(v)) => b.push(Some(v.to_string())),
+        (ColumnBuilder::Utf8(b), Value::Real(v)) => b.push(Some(v.to_string())),
+        (ColumnBuilder::Utf8(b), Value::Blob(v)) => {
+            b.push(Some(String::from_utf8(v).map_err(|e| e.to_string())?))
+        }
+        (ColumnBuilder::Binary(b), Value::Blob(v)) => b.push(Some(v)),
+        (ColumnBuilder::Binary(b), Value::Text(v)) => b.push(Some(v.into_bytes())),
+        (ColumnBuilder::Binary(b), Value::Integer(v)) => b.push(Some(v.to_string().into_bytes())),
+        (ColumnBuilder::Binary(b), Value::Real(v)) => b.push(Some(v.to_string().into_bytes())),
+        (ColumnBuilder::Date(b), Value::Text(v)) => {
+            let date = parse_date(&v).ok_or_else(|| mismatch(&Value::Text(v)))?;
+            b.push(Some(
+                (date - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32,
+            ))
+        }
+        (ColumnBuilder::Time(b), Value::Text(v)) => {
+            let time = NaiveTime::parse_from_str(v.trim(), "%H:%M:%S%.f")
+                .map_err(|_| mismatch(&Value::Text(v)))?;
+            b.push(Some(
+                (time - NaiveTime::MIN)
+                    .num_microseconds()
+                    .expect("time of day fits in i64 microseconds"),
+            ))
+        }
+        (ColumnBuilder::Timestamp(b), Value::Text(v)) => {
+            let timestamp = parse_timestamp(&v).ok_or_else(|| mismatch(&Value::Text(v)))?;
+            b.push(Some(timestamp.timestamp_micros()))
+        }
+        // SQLite's `unixepoch()` convention: seconds since the UNIX epoch.
+        (ColumnBuilder::Timestamp(b), Value::Integer(v)) => b.push(Some(v * 1_000_000)),
+        (_, value) => return Err(mismatch(&value)),
+    };
+    Ok(())
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .or_else(|| parse_timestamp(value).map(|ts| ts.date()))
+}
+
+fn parse_timestamp(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    .or_else(|| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .map(|date| date.and_time(NaiveTime::MIN))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::DataType;
+
+    use super::query_sqlite;
+
+    #[test]
+    fn test_query_sqlite() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("test.db");
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE t (id INTEGER, name VARCHAR(10), score REAL, flag BOOLEAN, day DATE);
+             INSERT INTO t VALUES (1, 'a', 0.5, 1, '2024-01-02'), (2, NULL, 1, 0, NULL);",
+        )
+        .unwrap();
+
+        let table = query_sqlite(
+            path.to_str().unwrap(),
+            "SELECT *, id * 2 AS doubled FROM t ORDER BY id",
+        )?;
+        let schema = table.schema.clone();
+        assert_eq!(
+            schema
+                .fields
+                .values()
+                .map(|f| f.dtype.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                DataType::Int64,
+                DataType::Utf8,
+                DataType::Float64,
+                DataType::Boolean,
+                DataType::Date,
+                DataType::Int64,
+            ]
+        );
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get_column("name")?.utf8()?.get(1), None);
+        assert_eq!(table.get_column("score")?.f64()?.get(1), Some(1.0));
+        assert_eq!(table.get_column("doubled")?.i64()?.get(1), Some(4));
+        Ok(())
+    }
+}
@@ -1,3 +1,13 @@
 //! Utilities for decoding data from various sources into both array data and metadata (e.g. schema inference)
 pub mod deserialize;
 pub mod inference;
+pub mod parse_mode;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+#[cfg(feature = "python")]
+pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
+    parent.add_class::<parse_mode::ParseMode>()?;
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use arrow2::{
+    array::{Array, StructArray, Utf8Array},
+    bitmap::Bitmap,
+    datatypes::{DataType, Field},
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::impl_bincode_py_state_serialization;
+#[cfg(feature = "python")]
+use pyo3::{
+    pyclass, pymethods, types::PyBytes, PyObject, PyResult, PyTypeInfo, Python, ToPyObject,
+};
+use serde::{Deserialize, Serialize};
+
+/// The default name of the column holding the records captured with [`ParseMode::Permissive`].
+pub const DEFAULT_CORRUPT_RECORD_COLUMN_NAME: &str = "_corrupt_record";
+
+/// How to handle malformed records, e.g. JSON lines that aren't valid JSON, or CSV rows with the
+/// wrong number of fields.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub enum ParseMode {
+    /// Fail the read on the first malformed record. Values that can't be parsed as the type of
+    /// their column are read as nulls.
+    #[default]
+    FailFast,
+    /// Drop malformed records, as well as records with values that can't be parsed as the type of
+    /// their column.
+    DropMalformed,
+    /// Keep malformed records with all of their fields set to null, and null out values that can't
+    /// be parsed as the type of their column. The raw record and the error are kept in a corrupt
+    /// record column.
+    Permissive,
+}
+
+impl ParseMode {
+    pub fn iterator() -> std::slice::Iter<'static, ParseMode> {
+        use ParseMode::*;
+
+        static PARSE_MODES: [ParseMode; 3] = [FailFast, DropMalformed, Permissive];
+        PARSE_MODES.iter()
+    }
+}
+
+impl FromStr for ParseMode {
+    type Err = DaftError;
+
+    fn from_str(mode: &str) -> DaftResult<Self> {
+        match mode {
+            "fail_fast" => Ok(Self::FailFast),
+            "drop_malformed" => Ok(Self::DropMalformed),
+            "permissive" => Ok(Self::Permissive),
+            _ => Err(DaftError::ValueError(format!(
+                "Parse mode {} is not supported; only the following modes are supported: {:?}",
+                mode,
+                ParseMode::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
+impl Display for ParseMode {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        // Leverage Debug trait implementation, which will already return the enum variant as a string.
+        write!(f, "{:?}", self)
+    }
+}
+
+impl_bincode_py_state_serialization!(ParseMode);
+
+/// The field of the corrupt record column, a struct of the raw record and the error encountered
+/// while parsing it.
+pub fn corrupt_record_field(name: &str) -> Field {
+    Field::new(
+        name,
+        DataType::Struct(vec![
+            Field::new("record", DataType::LargeUtf8, true),
+            Field::new("error", DataType::LargeUtf8, true),
+        ]),
+        true,
+    )
+}
+
+/// Builds the corrupt record column from the raw record and error of each corrupt row, where rows
+/// that were parsed successfully are null.
+pub fn corrupt_record_array(corrupt_records: &[Option<(String, String)>]) -> Box<dyn Array> {
+    let records = Utf8Array::<i64>::from_iter(
+        corrupt_records
+            .iter()
+            .map(|corrupt| corrupt.as_ref().map(|(record, _)| record.as_str())),
+    );
+    let errors = Utf8Array::<i64>::from_iter(
+        corrupt_records
+            .iter()
+            .map(|corrupt| corrupt.as_ref().map(|(_, error)| error.as_str())),
+    );
+    let validity = Bitmap::from_iter(corrupt_records.iter().map(Option::is_some));
+    Box::new(StructArray::new(
+        corrupt_record_field("").data_type,
+        vec![records.boxed(), errors.boxed()],
+        Some(validity),
+    ))
+}
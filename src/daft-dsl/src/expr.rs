@@ -1,12 +1,19 @@
 use daft_core::{
     count_mode::CountMode,
-    datatypes::{try_mean_supertype, try_sum_supertype, DataType, Field, FieldID},
+    datatypes::{
+        try_approx_sketch_supertype, try_covariance_supertype, try_mean_supertype,
+        try_sum_supertype, try_variance_supertype, DataType, Field, FieldID, CO_MOMENTS_DTYPE,
+        DDSKETCH_DTYPE, MOMENTS_DTYPE,
+    },
     schema::Schema,
-    utils::supertype::try_get_supertype,
+    utils::{hashable_float_wrapper::FloatWrapper, supertype::try_get_supertype},
 };
 
 use crate::{
-    functions::{function_display, function_semantic_id, struct_::StructExpr, FunctionEvaluator},
+    functions::{
+        function_display, function_semantic_id, sketch::percentiles_dtype, struct_::StructExpr,
+        FunctionEvaluator,
+    },
     lit,
     optimization::{get_required_columns, requires_computation},
 };
@@ -50,6 +57,7 @@ pub enum Expr {
         if_false: ExprRef,
         predicate: ExprRef,
     },
+    Window(WindowExpr),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -62,12 +70,103 @@ pub enum AggExpr {
     AnyValue(ExprRef, bool),
     List(ExprRef),
     Concat(ExprRef),
+    CountDistinct(ExprRef),
+    SumDistinct(ExprRef),
+    ListDistinct(ExprRef),
+    ApproxSketch(ExprRef),
+    MergeSketch(ExprRef),
+    ApproxPercentile(ApproxPercentileParams),
+    HllSketch(ExprRef),
+    HllMerge(ExprRef),
+    ApproxCountDistinct(ExprRef),
+    Variance(ExprRef, u64),
+    Stddev(ExprRef, u64),
+    Skew(ExprRef),
+    Covar(ExprRef, ExprRef, u64),
+    Corr(ExprRef, ExprRef),
+    Moments(ExprRef),
+    CoMoments(ExprRef, ExprRef),
+    MergeMoments(ExprRef),
     MapGroups {
         func: FunctionExpr,
         inputs: Vec<Expr>,
     },
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ApproxPercentileParams {
+    pub child: ExprRef,
+    pub percentiles: Vec<FloatWrapper<f64>>,
+    pub force_list_output: bool,
+}
+
+/// Functions that are computed over the ordered rows of a window partition, by the `Window` operator.
+///
+/// Aggregations over a window frame (running sums, moving averages, etc.) reuse [`AggExpr`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowExpr {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag {
+        input: ExprRef,
+        offset: u64,
+        default: Option<ExprRef>,
+    },
+    Lead {
+        input: ExprRef,
+        offset: u64,
+        default: Option<ExprRef>,
+    },
+}
+
+/// Rows that a window aggregation is computed over, as offsets relative to the current row.
+///
+/// `None` is unbounded in that direction, e.g. `start: None, end: Some(0)` is a running aggregation
+/// and `start: Some(-1), end: Some(1)` is a 3-row moving window.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WindowFrame {
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+}
+
+impl WindowFrame {
+    pub fn new(start: Option<i64>, end: Option<i64>) -> DaftResult<Self> {
+        if let (Some(start), Some(end)) = (start, end) && start > end {
+            return Err(DaftError::ValueError(format!(
+                "Window frame start must not be after its end, got rows between {start} and {end}"
+            )));
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Frame used when none is given: the whole partition, or all rows up to and including the
+    /// current row if the window is ordered.
+    pub fn default_for(is_ordered: bool) -> Self {
+        Self {
+            start: None,
+            end: if is_ordered { Some(0) } else { None },
+        }
+    }
+}
+
+impl Display for WindowFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let fmt_bound = |bound: Option<i64>, unbounded: &str| match bound {
+            None => unbounded.to_string(),
+            Some(0) => "current row".to_string(),
+            Some(offset) if offset < 0 => format!("{} preceding", -offset),
+            Some(offset) => format!("{offset} following"),
+        };
+        write!(
+            f,
+            "rows between {} and {}",
+            fmt_bound(self.start, "unbounded preceding"),
+            fmt_bound(self.end, "unbounded following")
+        )
+    }
+}
+
 pub fn col<S: Into<Arc<str>>>(name: S) -> Expr {
     Expr::Column(name.into())
 }
@@ -91,7 +190,24 @@ impl AggExpr {
             | Max(expr)
             | AnyValue(expr, _)
             | List(expr)
-            | Concat(expr) => expr.name(),
+            | Concat(expr)
+            | CountDistinct(expr)
+            | SumDistinct(expr)
+            | ListDistinct(expr)
+            | ApproxSketch(expr)
+            | MergeSketch(expr)
+            | ApproxPercentile(ApproxPercentileParams { child: expr, .. })
+            | HllSketch(expr)
+            | HllMerge(expr)
+            | ApproxCountDistinct(expr)
+            | Variance(expr, _)
+            | Stddev(expr, _)
+            | Skew(expr)
+            | Covar(expr, ..)
+            | Corr(expr, _)
+            | Moments(expr)
+            | CoMoments(expr, _)
+            | MergeMoments(expr) => expr.name(),
             MapGroups { func: _, inputs } => inputs.first().unwrap().name(),
         }
     }
@@ -133,6 +249,81 @@ impl AggExpr {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_concat()"))
             }
+            CountDistinct(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_count_distinct()"))
+            }
+            SumDistinct(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_sum_distinct()"))
+            }
+            ListDistinct(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_list_distinct()"))
+            }
+            ApproxSketch(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_approx_sketch()"))
+            }
+            MergeSketch(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_merge_sketch()"))
+            }
+            ApproxPercentile(ApproxPercentileParams {
+                child,
+                percentiles,
+                force_list_output,
+            }) => {
+                let child_id = child.semantic_id(schema);
+                FieldID::new(format!(
+                    "{child_id}.local_approx_percentiles(percentiles={:?},force_list_output={force_list_output})",
+                    percentiles.iter().map(|p| p.0).collect::<Vec<_>>(),
+                ))
+            }
+            HllSketch(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_hll_sketch()"))
+            }
+            HllMerge(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_hll_merge()"))
+            }
+            ApproxCountDistinct(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_approx_count_distinct()"))
+            }
+            Variance(expr, ddof) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_variance(ddof={ddof})"))
+            }
+            Stddev(expr, ddof) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_stddev(ddof={ddof})"))
+            }
+            Skew(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_skew()"))
+            }
+            Covar(x, y, ddof) => {
+                let (x_id, y_id) = (x.semantic_id(schema), y.semantic_id(schema));
+                FieldID::new(format!("{x_id}.local_covar({y_id}, ddof={ddof})"))
+            }
+            Corr(x, y) => {
+                let (x_id, y_id) = (x.semantic_id(schema), y.semantic_id(schema));
+                FieldID::new(format!("{x_id}.local_corr({y_id})"))
+            }
+            Moments(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_moments()"))
+            }
+            CoMoments(x, y) => {
+                let (x_id, y_id) = (x.semantic_id(schema), y.semantic_id(schema));
+                FieldID::new(format!("{x_id}.local_co_moments({y_id})"))
+            }
+            MergeMoments(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_merge_moments()"))
+            }
             MapGroups { func, inputs } => function_semantic_id(func, inputs, schema),
         }
     }
@@ -147,7 +338,22 @@ impl AggExpr {
             | Max(expr)
             | AnyValue(expr, _)
             | List(expr)
-            | Concat(expr) => vec![expr.clone()],
+            | Concat(expr)
+            | CountDistinct(expr)
+            | SumDistinct(expr)
+            | ListDistinct(expr)
+            | ApproxSketch(expr)
+            | MergeSketch(expr)
+            | ApproxPercentile(ApproxPercentileParams { child: expr, .. })
+            | HllSketch(expr)
+            | HllMerge(expr)
+            | ApproxCountDistinct(expr)
+            | Variance(expr, _)
+            | Stddev(expr, _)
+            | Skew(expr)
+            | Moments(expr)
+            | MergeMoments(expr) => vec![expr.clone()],
+            Covar(x, y, _) | Corr(x, y) | CoMoments(x, y) => vec![x.clone(), y.clone()],
             MapGroups { func: _, inputs } => inputs.iter().map(|e| e.clone().into()).collect(),
         }
     }
@@ -155,11 +361,11 @@ impl AggExpr {
     pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
         use AggExpr::*;
         match self {
-            Count(expr, ..) => {
+            Count(expr, ..) | CountDistinct(expr) => {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(field.name.as_str(), DataType::UInt64))
             }
-            Sum(expr) => {
+            Sum(expr) | SumDistinct(expr) => {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(
                     field.name.as_str(),
@@ -177,7 +383,7 @@ impl AggExpr {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(field.name.as_str(), field.dtype))
             }
-            List(expr) => expr.to_field(schema)?.to_list_field(),
+            List(expr) | ListDistinct(expr) => expr.to_field(schema)?.to_list_field(),
             Concat(expr) => {
                 let field = expr.to_field(schema)?;
                 match field.dtype {
@@ -190,6 +396,103 @@ impl AggExpr {
                     ))),
                 }
             }
+            ApproxSketch(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    field.name.as_str(),
+                    try_approx_sketch_supertype(&field.dtype)?,
+                ))
+            }
+            MergeSketch(expr) => {
+                let field = expr.to_field(schema)?;
+                if field.dtype != *DDSKETCH_DTYPE {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to merge_sketch to be a sketch, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    )));
+                }
+                Ok(field)
+            }
+            ApproxPercentile(ApproxPercentileParams {
+                child,
+                percentiles,
+                force_list_output,
+            }) => {
+                let field = child.to_field(schema)?;
+                if !field.dtype.is_numeric() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to approx_percentiles to be numeric, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    )));
+                }
+                if percentiles.is_empty() {
+                    return Err(DaftError::ValueError(
+                        "Expected at least one percentile for approx_percentiles".to_string(),
+                    ));
+                }
+                if let Some(p) = percentiles.iter().find(|p| !(0.0..=1.0).contains(&p.0)) {
+                    return Err(DaftError::ValueError(format!(
+                        "Expected percentiles to be between 0.0 and 1.0, got {}",
+                        p.0
+                    )));
+                }
+                Ok(Field::new(
+                    field.name.as_str(),
+                    percentiles_dtype(percentiles, *force_list_output),
+                ))
+            }
+            HllSketch(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(field.name.as_str(), DataType::Binary))
+            }
+            HllMerge(expr) => {
+                let field = expr.to_field(schema)?;
+                if field.dtype != DataType::Binary {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to hll_merge to be a HyperLogLog sketch, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    )));
+                }
+                Ok(field)
+            }
+            ApproxCountDistinct(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(field.name.as_str(), DataType::UInt64))
+            }
+            Variance(expr, _) | Stddev(expr, _) | Skew(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    field.name.as_str(),
+                    try_variance_supertype(&field.dtype)?,
+                ))
+            }
+            Covar(x, y, _) | Corr(x, y) => {
+                let (x_field, y_field) = (x.to_field(schema)?, y.to_field(schema)?);
+                Ok(Field::new(
+                    x_field.name.as_str(),
+                    try_covariance_supertype(&x_field.dtype, &y_field.dtype)?,
+                ))
+            }
+            Moments(expr) => {
+                let field = expr.to_field(schema)?;
+                try_variance_supertype(&field.dtype)?;
+                Ok(Field::new(field.name.as_str(), MOMENTS_DTYPE.clone()))
+            }
+            CoMoments(x, y) => {
+                let (x_field, y_field) = (x.to_field(schema)?, y.to_field(schema)?);
+                try_covariance_supertype(&x_field.dtype, &y_field.dtype)?;
+                Ok(Field::new(x_field.name.as_str(), CO_MOMENTS_DTYPE.clone()))
+            }
+            MergeMoments(expr) => {
+                let field = expr.to_field(schema)?;
+                if field.dtype != *MOMENTS_DTYPE && field.dtype != *CO_MOMENTS_DTYPE {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to merge_moments to be moments, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    )));
+                }
+                Ok(field)
+            }
             MapGroups { func, inputs } => func.to_field(inputs.as_slice(), schema, func),
         }
     }
@@ -211,6 +514,82 @@ impl AggExpr {
     }
 }
 
+impl WindowExpr {
+    pub fn name(&self) -> DaftResult<&str> {
+        use WindowExpr::*;
+        match self {
+            RowNumber => Ok("row_number"),
+            Rank => Ok("rank"),
+            DenseRank => Ok("dense_rank"),
+            Lag { input, .. } | Lead { input, .. } => input.name(),
+        }
+    }
+
+    pub fn semantic_id(&self, schema: &Schema) -> FieldID {
+        use WindowExpr::*;
+        match self {
+            RowNumber => FieldID::new("row_number()"),
+            Rank => FieldID::new("rank()"),
+            DenseRank => FieldID::new("dense_rank()"),
+            Lag {
+                input,
+                offset,
+                default,
+            }
+            | Lead {
+                input,
+                offset,
+                default,
+            } => {
+                let fn_name = if matches!(self, Lag { .. }) {
+                    "lag"
+                } else {
+                    "lead"
+                };
+                let child_id = input.semantic_id(schema);
+                let default_id = match default {
+                    Some(default) => default.semantic_id(schema).id.to_string(),
+                    None => "None".to_string(),
+                };
+                FieldID::new(format!("{child_id}.{fn_name}({offset}, {default_id})"))
+            }
+        }
+    }
+
+    pub fn children(&self) -> Vec<ExprRef> {
+        use WindowExpr::*;
+        match self {
+            RowNumber | Rank | DenseRank => vec![],
+            Lag { input, default, .. } | Lead { input, default, .. } => {
+                std::iter::once(input.clone())
+                    .chain(default.iter().cloned())
+                    .collect()
+            }
+        }
+    }
+
+    pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
+        use WindowExpr::*;
+        match self {
+            RowNumber | Rank | DenseRank => Ok(Field::new(self.name()?, DataType::UInt64)),
+            Lag { input, default, .. } | Lead { input, default, .. } => {
+                let field = input.to_field(schema)?;
+                if let Some(default) = default {
+                    let default_field = default.to_field(schema)?;
+                    if try_get_supertype(&field.dtype, &default_field.dtype)? != field.dtype {
+                        return Err(DaftError::TypeError(format!(
+                            "Expected default value for {} to be castable to {}, but received {default_field}",
+                            self.name()?,
+                            field.dtype,
+                        )));
+                    }
+                }
+                Ok(field)
+            }
+        }
+    }
+}
+
 impl AsRef<Expr> for Expr {
     fn as_ref(&self) -> &Expr {
         self
@@ -266,6 +645,101 @@ impl Expr {
         Expr::Agg(AggExpr::Concat(self.clone().into()))
     }
 
+    pub fn count_distinct(&self) -> Self {
+        Expr::Agg(AggExpr::CountDistinct(self.clone().into()))
+    }
+
+    pub fn sum_distinct(&self) -> Self {
+        Expr::Agg(AggExpr::SumDistinct(self.clone().into()))
+    }
+
+    pub fn agg_list_distinct(&self) -> Self {
+        Expr::Agg(AggExpr::ListDistinct(self.clone().into()))
+    }
+
+    pub fn approx_sketch(&self) -> Self {
+        Expr::Agg(AggExpr::ApproxSketch(self.clone().into()))
+    }
+
+    pub fn merge_sketch(&self) -> Self {
+        Expr::Agg(AggExpr::MergeSketch(self.clone().into()))
+    }
+
+    pub fn approx_percentiles(&self, percentiles: &[f64], force_list_output: bool) -> Self {
+        Expr::Agg(AggExpr::ApproxPercentile(ApproxPercentileParams {
+            child: self.clone().into(),
+            percentiles: percentiles.iter().map(|p| FloatWrapper(*p)).collect(),
+            force_list_output,
+        }))
+    }
+
+    pub fn hll_sketch(&self) -> Self {
+        Expr::Agg(AggExpr::HllSketch(self.clone().into()))
+    }
+
+    pub fn hll_merge(&self) -> Self {
+        Expr::Agg(AggExpr::HllMerge(self.clone().into()))
+    }
+
+    pub fn approx_count_distinct(&self) -> Self {
+        Expr::Agg(AggExpr::ApproxCountDistinct(self.clone().into()))
+    }
+
+    pub fn variance(&self, ddof: u64) -> Self {
+        Expr::Agg(AggExpr::Variance(self.clone().into(), ddof))
+    }
+
+    pub fn stddev(&self, ddof: u64) -> Self {
+        Expr::Agg(AggExpr::Stddev(self.clone().into(), ddof))
+    }
+
+    pub fn skew(&self) -> Self {
+        Expr::Agg(AggExpr::Skew(self.clone().into()))
+    }
+
+    pub fn covar(&self, other: &Self, ddof: u64) -> Self {
+        Expr::Agg(AggExpr::Covar(
+            self.clone().into(),
+            other.clone().into(),
+            ddof,
+        ))
+    }
+
+    pub fn corr(&self, other: &Self) -> Self {
+        Expr::Agg(AggExpr::Corr(self.clone().into(), other.clone().into()))
+    }
+
+    pub fn moments(&self) -> Self {
+        Expr::Agg(AggExpr::Moments(self.clone().into()))
+    }
+
+    pub fn co_moments(&self, other: &Self) -> Self {
+        Expr::Agg(AggExpr::CoMoments(
+            self.clone().into(),
+            other.clone().into(),
+        ))
+    }
+
+    pub fn merge_moments(&self) -> Self {
+        Expr::Agg(AggExpr::MergeMoments(self.clone().into()))
+    }
+
+    pub fn lag(&self, offset: u64, default: Option<&Self>) -> Self {
+        Expr::Window(WindowExpr::Lag {
+            input: self.clone().into(),
+            offset,
+            default: default.map(|d| d.clone().into()),
+        })
+    }
+
+    pub fn lead(&self, offset: u64, default: Option<&Self>) -> Self {
+        Expr::Window(WindowExpr::Lead {
+            input: self.clone().into(),
+            offset,
+            default: default.map(|d| d.clone().into()),
+        })
+    }
+
     pub fn not(&self) -> Self {
         Expr::Not(self.clone().into())
     }
@@ -379,6 +853,9 @@ impl Expr {
 
             // Agg: Separate path.
             Agg(agg_expr) => agg_expr.semantic_id(schema),
+
+            // Window: Separate path.
+            Window(window_expr) => window_expr.semantic_id(schema),
         }
     }
 
@@ -394,6 +871,7 @@ impl Expr {
                 vec![expr.clone()]
             }
             Agg(agg_expr) => agg_expr.children(),
+            Window(window_expr) => window_expr.children(),
 
             // Multiple children.
             Function { inputs, .. } => inputs.iter().map(|e| e.clone().into()).collect(),
@@ -417,6 +895,7 @@ impl Expr {
         match self {
             Alias(expr, name) => Ok(Field::new(name.as_ref(), expr.get_type(schema)?)),
             Agg(agg_expr) => agg_expr.to_field(schema),
+            Window(window_expr) => window_expr.to_field(schema),
             Cast(expr, dtype) => Ok(Field::new(expr.name()?, dtype.clone())),
             Column(name) => Ok(schema.get_field(name).cloned()?),
             Not(expr) => {
@@ -524,6 +1003,7 @@ impl Expr {
         match self {
             Alias(.., name) => Ok(name.as_ref()),
             Agg(agg_expr) => agg_expr.name(),
+            Window(window_expr) => window_expr.name(),
             Cast(expr, ..) => expr.name(),
             Column(name) => Ok(name.as_ref()),
             Not(expr) => expr.name(),
@@ -619,6 +1099,7 @@ impl Expr {
                 }
                 // TODO: Implement SQL translations for these expressions if possible
                 Expr::Agg(..)
+                | Expr::Window(..)
                 | Expr::Cast(..)
                 | Expr::IsIn(..)
                 | Expr::Function { .. }
@@ -643,6 +1124,7 @@ impl Display for Expr {
         match self {
             Alias(expr, name) => write!(f, "{expr} AS {name}"),
             Agg(agg_expr) => write!(f, "{agg_expr}"),
+            Window(window_expr) => write!(f, "{window_expr}"),
             BinaryOp { op, left, right } => {
                 let write_out_expr = |f: &mut Formatter, input: &Expr| match input {
                     Alias(e, _) => write!(f, "{e}"),
@@ -689,11 +1171,59 @@ impl Display for AggExpr {
             }
             List(expr) => write!(f, "list({expr})"),
             Concat(expr) => write!(f, "list({expr})"),
+            CountDistinct(expr) => write!(f, "count_distinct({expr})"),
+            SumDistinct(expr) => write!(f, "sum_distinct({expr})"),
+            ListDistinct(expr) => write!(f, "list_distinct({expr})"),
+            ApproxSketch(expr) => write!(f, "approx_sketch({expr})"),
+            MergeSketch(expr) => write!(f, "merge_sketch({expr})"),
+            ApproxPercentile(ApproxPercentileParams {
+                child,
+                percentiles,
+                force_list_output,
+            }) => write!(
+                f,
+                "approx_percentiles({child}, percentiles={:?}, force_list_output={force_list_output})",
+                percentiles.iter().map(|p| p.0).collect::<Vec<_>>(),
+            ),
+            HllSketch(expr) => write!(f, "hll_sketch({expr})"),
+            HllMerge(expr) => write!(f, "hll_merge({expr})"),
+            ApproxCountDistinct(expr) => write!(f, "approx_count_distinct({expr})"),
+            Variance(expr, ddof) => write!(f, "variance({expr}, ddof={ddof})"),
+            Stddev(expr, ddof) => write!(f, "stddev({expr}, ddof={ddof})"),
+            Skew(expr) => write!(f, "skew({expr})"),
+            Covar(x, y, ddof) => write!(f, "covar({x}, {y}, ddof={ddof})"),
+            Corr(x, y) => write!(f, "corr({x}, {y})"),
+            Moments(expr) => write!(f, "moments({expr})"),
+            CoMoments(x, y) => write!(f, "co_moments({x}, {y})"),
+            MergeMoments(expr) => write!(f, "merge_moments({expr})"),
             MapGroups { func, inputs } => function_display(f, func, inputs),
         }
     }
 }
 
+impl Display for WindowExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        use WindowExpr::*;
+        match self {
+            RowNumber => write!(f, "row_number()"),
+            Rank => write!(f, "rank()"),
+            DenseRank => write!(f, "dense_rank()"),
+            Lag {
+                input,
+                offset,
+                default: Some(default),
+            } => write!(f, "lag({input}, {offset}, {default})"),
+            Lag { input, offset, .. } => write!(f, "lag({input}, {offset})"),
+            Lead {
+                input,
+                offset,
+                default: Some(default),
+            } => write!(f, "lead({input}, {offset}, {default})"),
+            Lead { input, offset, .. } => write!(f, "lead({input}, {offset})"),
+        }
+    }
+}
+
 /// Based on Polars first class operators: https://github.com/pola-rs/polars/blob/master/polars/polars-lazy/polars-plan/src/dsl/expr.rs
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
 pub enum Operator {
@@ -2,8 +2,10 @@ pub mod float;
 pub mod image;
 pub mod json;
 pub mod list;
+pub mod moments;
 pub mod numeric;
 pub mod partitioning;
+pub mod sketch;
 pub mod struct_;
 pub mod temporal;
 pub mod uri;
@@ -14,8 +16,10 @@ use std::fmt::{Display, Formatter, Result};
 use self::image::ImageExpr;
 use self::json::JsonExpr;
 use self::list::ListExpr;
+use self::moments::MomentsExpr;
 use self::numeric::NumericExpr;
 use self::partitioning::PartitioningExpr;
+use self::sketch::SketchExpr;
 use self::struct_::StructExpr;
 use self::temporal::TemporalExpr;
 use self::utf8::Utf8Expr;
@@ -46,6 +50,8 @@ pub enum FunctionExpr {
     Python(PythonUDF),
     Partitioning(PartitioningExpr),
     Uri(UriExpr),
+    Sketch(SketchExpr),
+    Moments(MomentsExpr),
 }
 
 pub trait FunctionEvaluator {
@@ -71,6 +77,8 @@ impl FunctionExpr {
             #[cfg(feature = "python")]
             Python(expr) => expr,
             Partitioning(expr) => expr.get_evaluator(),
+            Sketch(expr) => expr.get_evaluator(),
+            Moments(expr) => expr.get_evaluator(),
         }
     }
 }
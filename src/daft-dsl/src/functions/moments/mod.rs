@@ -0,0 +1,95 @@
+use daft_core::{
+    datatypes::{DataType, Field, CO_MOMENTS_DTYPE, MOMENTS_DTYPE},
+    schema::Schema,
+    series::Series,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+use common_error::{DaftError, DaftResult};
+
+use super::{FunctionEvaluator, FunctionExpr};
+
+/// Statistics that are computed from the mergeable moments built by statistical aggregations.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum MomentsExpr {
+    Variance { ddof: u64 },
+    Stddev { ddof: u64 },
+    Skew,
+    Covar { ddof: u64 },
+    Corr,
+}
+
+impl MomentsExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        &MomentsEvaluator {}
+    }
+
+    /// The data type of the moments this statistic is computed from.
+    fn input_dtype(&self) -> &'static DataType {
+        use MomentsExpr::*;
+        match self {
+            Variance { .. } | Stddev { .. } | Skew => &MOMENTS_DTYPE,
+            Covar { .. } | Corr => &CO_MOMENTS_DTYPE,
+        }
+    }
+}
+
+struct MomentsEvaluator {}
+
+impl FunctionEvaluator for MomentsEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "moments_stat"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, expr: &FunctionExpr) -> DaftResult<Field> {
+        let moments_expr = match expr {
+            FunctionExpr::Moments(moments_expr) => moments_expr,
+            _ => panic!("Expected Moments Expr, got {expr}"),
+        };
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                if &input_field.dtype != moments_expr.input_dtype() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to {moments_expr:?} to be {}, received: {}",
+                        moments_expr.input_dtype(),
+                        input_field.dtype
+                    )));
+                }
+                Ok(Field::new(input_field.name, DataType::Float64))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        use MomentsExpr::*;
+        match inputs {
+            [input] => match expr {
+                FunctionExpr::Moments(Variance { ddof }) => input.moments_variance(*ddof),
+                FunctionExpr::Moments(Stddev { ddof }) => input.moments_stddev(*ddof),
+                FunctionExpr::Moments(Skew) => input.moments_skew(),
+                FunctionExpr::Moments(Covar { ddof }) => input.co_moments_covar(*ddof),
+                FunctionExpr::Moments(Corr) => input.co_moments_corr(),
+                _ => panic!("Expected Moments Expr, got {expr}"),
+            },
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Computes a statistic from a column of moments, as built by the `Moments` and `CoMoments` aggregations.
+pub fn moments_stat(input: &Expr, stat: MomentsExpr) -> Expr {
+    Expr::Function {
+        func: FunctionExpr::Moments(stat),
+        inputs: vec![input.clone()],
+    }
+}
@@ -0,0 +1,48 @@
+use crate::Expr;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::{IntoSeries, Series},
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct HllCardinalityEvaluator {}
+
+impl FunctionEvaluator for HllCardinalityEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "hll_cardinality"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                if input_field.dtype != DataType::Binary {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to hll_cardinality to be a HyperLogLog sketch, received: {}",
+                        input_field.dtype
+                    )));
+                }
+                Ok(Field::new(input_field.name, DataType::UInt64))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => Ok(input.hll_cardinality()?.into_series()),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+mod cardinality;
+mod percentile;
+
+use cardinality::HllCardinalityEvaluator;
+use daft_core::{datatypes::DataType, utils::hashable_float_wrapper::FloatWrapper};
+use percentile::PercentileEvaluator;
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SketchExpr {
+    Percentile {
+        percentiles: Vec<FloatWrapper<f64>>,
+        force_list_output: bool,
+    },
+    HllCardinality,
+}
+
+impl SketchExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use SketchExpr::*;
+        match self {
+            Percentile { .. } => &PercentileEvaluator {},
+            HllCardinality => &HllCardinalityEvaluator {},
+        }
+    }
+}
+
+/// Get the data type of the percentiles computed from a sketch: a single Float64 if one percentile is requested
+/// without forcing list output, and a FixedSizeList of Float64s otherwise.
+pub fn percentiles_dtype(percentiles: &[FloatWrapper<f64>], force_list_output: bool) -> DataType {
+    match percentiles {
+        [_] if !force_list_output => DataType::Float64,
+        _ => DataType::FixedSizeList(Box::new(DataType::Float64), percentiles.len()),
+    }
+}
+
+pub fn sketch_percentile(input: &Expr, percentiles: &[f64], force_list_output: bool) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Sketch(SketchExpr::Percentile {
+            percentiles: percentiles.iter().map(|p| FloatWrapper(*p)).collect(),
+            force_list_output,
+        }),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn hll_cardinality(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Sketch(SketchExpr::HllCardinality),
+        inputs: vec![input.clone()],
+    }
+}
@@ -0,0 +1,66 @@
+use crate::Expr;
+use daft_core::{
+    datatypes::{Field, DDSKETCH_DTYPE},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, percentiles_dtype, SketchExpr};
+
+pub(super) struct PercentileEvaluator {}
+
+impl FunctionEvaluator for PercentileEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "sketch_percentile"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, expr: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                if input_field.dtype != *DDSKETCH_DTYPE {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to sketch_percentile to be a sketch, received: {}",
+                        input_field.dtype
+                    )));
+                }
+                match expr {
+                    FunctionExpr::Sketch(SketchExpr::Percentile {
+                        percentiles,
+                        force_list_output,
+                    }) => Ok(Field::new(
+                        input_field.name,
+                        percentiles_dtype(percentiles, *force_list_output),
+                    )),
+                    _ => panic!("Expected Sketch Percentile Expr, got {expr}"),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => match expr {
+                FunctionExpr::Sketch(SketchExpr::Percentile {
+                    percentiles,
+                    force_list_output,
+                }) => {
+                    let percentiles = percentiles.iter().map(|p| p.0).collect::<Vec<_>>();
+                    input.sketch_percentile(&percentiles, *force_list_output)
+                }
+                _ => panic!("Expected Sketch Percentile Expr, got {expr}"),
+            },
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
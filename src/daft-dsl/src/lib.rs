@@ -12,7 +12,7 @@ mod treenode;
 pub use common_treenode;
 pub use expr::binary_op;
 pub use expr::col;
-pub use expr::{AggExpr, Expr, ExprRef, Operator};
+pub use expr::{AggExpr, ApproxPercentileParams, Expr, ExprRef, Operator, WindowExpr, WindowFrame};
 pub use lit::{lit, null_lit, Literal, LiteralValue};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
@@ -29,6 +29,9 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_wrapped(wrap_pyfunction!(python::decimal_lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::series_lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::udf))?;
+    parent.add_wrapped(wrap_pyfunction!(python::row_number))?;
+    parent.add_wrapped(wrap_pyfunction!(python::rank))?;
+    parent.add_wrapped(wrap_pyfunction!(python::dense_rank))?;
     parent.add_wrapped(wrap_pyfunction!(python::eq))?;
 
     Ok(())
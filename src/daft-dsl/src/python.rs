@@ -7,7 +7,7 @@ use daft_core::python::datatype::PyTimeUnit;
 use daft_core::python::PySeries;
 use serde::{Deserialize, Serialize};
 
-use crate::{functions, optimization, Expr, LiteralValue};
+use crate::{functions, optimization, Expr, LiteralValue, WindowExpr};
 use daft_core::{
     count_mode::CountMode,
     datatypes::ImageFormat,
@@ -163,6 +163,21 @@ pub fn udf(
     })
 }
 
+#[pyfunction]
+pub fn row_number() -> PyResult<PyExpr> {
+    Ok(Expr::Window(WindowExpr::RowNumber).into())
+}
+
+#[pyfunction]
+pub fn rank() -> PyResult<PyExpr> {
+    Ok(Expr::Window(WindowExpr::Rank).into())
+}
+
+#[pyfunction]
+pub fn dense_rank() -> PyResult<PyExpr> {
+    Ok(Expr::Window(WindowExpr::DenseRank).into())
+}
+
 #[pyclass(module = "daft.daft")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PyExpr {
@@ -311,6 +326,78 @@ impl PyExpr {
         Ok(self.expr.agg_concat().into())
     }
 
+    pub fn count_distinct(&self) -> PyResult<Self> {
+        Ok(self.expr.count_distinct().into())
+    }
+
+    pub fn sum_distinct(&self) -> PyResult<Self> {
+        Ok(self.expr.sum_distinct().into())
+    }
+
+    pub fn agg_list_distinct(&self) -> PyResult<Self> {
+        Ok(self.expr.agg_list_distinct().into())
+    }
+
+    pub fn variance(&self, ddof: u64) -> PyResult<Self> {
+        Ok(self.expr.variance(ddof).into())
+    }
+
+    pub fn stddev(&self, ddof: u64) -> PyResult<Self> {
+        Ok(self.expr.stddev(ddof).into())
+    }
+
+    pub fn skew(&self) -> PyResult<Self> {
+        Ok(self.expr.skew().into())
+    }
+
+    pub fn covar(&self, other: &Self, ddof: u64) -> PyResult<Self> {
+        Ok(self.expr.covar(&other.expr, ddof).into())
+    }
+
+    pub fn corr(&self, other: &Self) -> PyResult<Self> {
+        Ok(self.expr.corr(&other.expr).into())
+    }
+
+    pub fn approx_sketch(&self) -> PyResult<Self> {
+        Ok(self.expr.approx_sketch().into())
+    }
+
+    pub fn merge_sketch(&self) -> PyResult<Self> {
+        Ok(self.expr.merge_sketch().into())
+    }
+
+    pub fn approx_percentiles(
+        &self,
+        percentiles: Vec<f64>,
+        force_list_output: bool,
+    ) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .approx_percentiles(percentiles.as_slice(), force_list_output)
+            .into())
+    }
+
+    pub fn sketch_percentile(
+        &self,
+        percentiles: Vec<f64>,
+        force_list_output: bool,
+    ) -> PyResult<Self> {
+        use crate::functions::sketch::sketch_percentile;
+        Ok(sketch_percentile(&self.expr, percentiles.as_slice(), force_list_output).into())
+    }
+
+    pub fn approx_count_distinct(&self) -> PyResult<Self> {
+        Ok(self.expr.approx_count_distinct().into())
+    }
+
+    pub fn lag(&self, offset: u64, default: Option<&Self>) -> PyResult<Self> {
+        Ok(self.expr.lag(offset, default.map(|d| &d.expr)).into())
+    }
+
+    pub fn lead(&self, offset: u64, default: Option<&Self>) -> PyResult<Self> {
+        Ok(self.expr.lead(offset, default.map(|d| &d.expr)).into())
+    }
+
     pub fn explode(&self) -> PyResult<Self> {
         use functions::list::explode;
         Ok(explode(&self.expr).into())
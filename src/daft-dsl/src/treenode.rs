@@ -1,7 +1,7 @@
 use common_error::DaftResult;
 use common_treenode::{TreeNode, VisitRecursion};
 
-use crate::Expr;
+use crate::{ApproxPercentileParams, Expr};
 
 impl TreeNode for Expr {
     fn apply_children<F>(&self, op: &mut F) -> DaftResult<common_treenode::VisitRecursion>
@@ -23,10 +23,38 @@ impl TreeNode for Expr {
                     | Max(expr)
                     | AnyValue(expr, _)
                     | List(expr)
-                    | Concat(expr) => vec![expr.as_ref()],
+                    | Concat(expr)
+                    | CountDistinct(expr)
+                    | SumDistinct(expr)
+                    | ListDistinct(expr)
+                    | ApproxSketch(expr)
+                    | MergeSketch(expr)
+                    | ApproxPercentile(ApproxPercentileParams { child: expr, .. })
+                    | HllSketch(expr)
+                    | HllMerge(expr)
+                    | ApproxCountDistinct(expr)
+                    | Variance(expr, _)
+                    | Stddev(expr, _)
+                    | Skew(expr)
+                    | Moments(expr)
+                    | MergeMoments(expr) => {
+                        vec![expr.as_ref()]
+                    }
+                    Covar(x, y, _) | Corr(x, y) | CoMoments(x, y) => vec![x.as_ref(), y.as_ref()],
                     MapGroups { func: _, inputs } => inputs.iter().collect::<Vec<_>>(),
                 }
             }
+            Window(window_expr) => {
+                use crate::WindowExpr::*;
+                match window_expr {
+                    RowNumber | Rank | DenseRank => vec![],
+                    Lag { input, default, .. } | Lead { input, default, .. } => {
+                        std::iter::once(input.as_ref())
+                            .chain(default.as_deref())
+                            .collect()
+                    }
+                }
+            }
             BinaryOp { op: _, left, right } => vec![left.as_ref(), right.as_ref()],
             IsIn(expr, items) => vec![expr.as_ref(), items.as_ref()],
             FillNull(expr, fill_value) => vec![expr.as_ref(), fill_value.as_ref()],
@@ -72,6 +100,39 @@ impl TreeNode for Expr {
                     }
                     List(expr) => transform(expr.as_ref().clone())?.agg_list(),
                     Concat(expr) => transform(expr.as_ref().clone())?.agg_concat(),
+                    CountDistinct(expr) => transform(expr.as_ref().clone())?.count_distinct(),
+                    SumDistinct(expr) => transform(expr.as_ref().clone())?.sum_distinct(),
+                    ListDistinct(expr) => transform(expr.as_ref().clone())?.agg_list_distinct(),
+                    ApproxSketch(expr) => transform(expr.as_ref().clone())?.approx_sketch(),
+                    MergeSketch(expr) => transform(expr.as_ref().clone())?.merge_sketch(),
+                    ApproxPercentile(ApproxPercentileParams {
+                        child,
+                        percentiles,
+                        force_list_output,
+                    }) => Expr::Agg(ApproxPercentile(ApproxPercentileParams {
+                        child: transform(child.as_ref().clone())?.into(),
+                        percentiles,
+                        force_list_output,
+                    })),
+                    HllSketch(expr) => transform(expr.as_ref().clone())?.hll_sketch(),
+                    HllMerge(expr) => transform(expr.as_ref().clone())?.hll_merge(),
+                    ApproxCountDistinct(expr) => {
+                        transform(expr.as_ref().clone())?.approx_count_distinct()
+                    }
+                    Variance(expr, ddof) => transform(expr.as_ref().clone())?.variance(ddof),
+                    Stddev(expr, ddof) => transform(expr.as_ref().clone())?.stddev(ddof),
+                    Skew(expr) => transform(expr.as_ref().clone())?.skew(),
+                    Covar(x, y, ddof) => {
+                        transform(x.as_ref().clone())?.covar(&transform(y.as_ref().clone())?, ddof)
+                    }
+                    Corr(x, y) => {
+                        transform(x.as_ref().clone())?.corr(&transform(y.as_ref().clone())?)
+                    }
+                    Moments(expr) => transform(expr.as_ref().clone())?.moments(),
+                    CoMoments(x, y) => {
+                        transform(x.as_ref().clone())?.co_moments(&transform(y.as_ref().clone())?)
+                    }
+                    MergeMoments(expr) => transform(expr.as_ref().clone())?.merge_moments(),
                     MapGroups { func, inputs } => Expr::Agg(MapGroups {
                         func,
                         inputs: inputs
@@ -81,6 +142,34 @@ impl TreeNode for Expr {
                     }),
                 }
             }
+            Window(window_expr) => {
+                use crate::WindowExpr::*;
+                match window_expr {
+                    RowNumber | Rank | DenseRank => Window(window_expr),
+                    Lag {
+                        input,
+                        offset,
+                        default,
+                    } => Window(Lag {
+                        input: transform(input.as_ref().clone())?.into(),
+                        offset,
+                        default: default
+                            .map(|d| transform(d.as_ref().clone()).map(Into::into))
+                            .transpose()?,
+                    }),
+                    Lead {
+                        input,
+                        offset,
+                        default,
+                    } => Window(Lead {
+                        input: transform(input.as_ref().clone())?.into(),
+                        offset,
+                        default: default
+                            .map(|d| transform(d.as_ref().clone()).map(Into::into))
+                            .transpose()?,
+                    }),
+                }
+            }
             Not(expr) => Not(transform(expr.as_ref().clone())?.into()),
             IsNull(expr) => IsNull(transform(expr.as_ref().clone())?.into()),
             NotNull(expr) => NotNull(transform(expr.as_ref().clone())?.into()),
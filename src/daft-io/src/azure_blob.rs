@@ -4,6 +4,7 @@ use azure_storage_blobs::{
     container::{operations::BlobItem, Container},
     prelude::*,
 };
+use bytes::Bytes;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use snafu::{IntoError, ResultExt, Snafu};
 use std::{ops::Range, sync::Arc};
@@ -18,6 +19,7 @@ use common_io_config::AzureConfig;
 
 const AZURE_DELIMITER: &str = "/";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+const MULTIPART_BLOCK_SIZE: usize = 8 * 1024 * 1024;
 
 #[derive(Debug, Snafu)]
 enum Error {
@@ -57,6 +59,18 @@ enum Error {
         source: azure_storage::Error,
     },
 
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToWriteToFile {
+        path: String,
+        source: azure_storage::Error,
+    },
+
+    #[snafu(display("Unable to delete {}: {}", path, source))]
+    UnableToDeleteFile {
+        path: String,
+        source: azure_storage::Error,
+    },
+
     #[snafu(display("Unable to read metadata about {}: {}", path, source))]
     RequestFailedForPath {
         path: String,
@@ -96,6 +110,22 @@ impl From<Error> for super::Error {
                     },
                 }
             }
+            UnableToWriteToFile { path, source } => super::Error::UnableToWriteToFile {
+                path,
+                source: source.into(),
+            },
+            UnableToDeleteFile { path, source } => {
+                match source.as_http_error().map(|v| v.status().into()) {
+                    Some(404) | Some(410) => super::Error::NotFound {
+                        path,
+                        source: source.into(),
+                    },
+                    _ => super::Error::UnableToDeleteFile {
+                        path,
+                        source: source.into(),
+                    },
+                }
+            }
             NotFound { ref path } => super::Error::NotFound {
                 path: path.into(),
                 source: error.into(),
@@ -383,6 +413,27 @@ impl AzureBlobSource {
             .boxed()
     }
 
+    fn _blob_client_for_uri(&self, uri: &str) -> super::Result<BlobClient> {
+        let parsed = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let container = match parsed.host_str() {
+            Some(s) => Ok(s),
+            None => Err(Error::InvalidUrl {
+                path: uri.into(),
+                source: url::ParseError::EmptyHost,
+            }),
+        }?;
+        let key = parsed.path();
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        Ok(self
+            .blob_client
+            .container_client(container)
+            .blob_client(key))
+    }
+
     fn _container_to_file_metadata(&self, protocol: &str, container: &Container) -> FileMetadata {
         // NB: Cannot pass through to Azure client's .url() methods here
         // because they return URIs of a very different format (https://.../container/path).
@@ -494,6 +545,97 @@ impl ObjectSource for AzureBlobSource {
         Ok(metadata.blob.properties.content_length as usize)
     }
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let blob_client = self._blob_client_for_uri(uri)?;
+        let num_bytes = data.len();
+        blob_client
+            .put_block_blob(data)
+            .await
+            .context(UnableToWriteToFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn put_multipart(
+        &self,
+        uri: &str,
+        mut stream: BoxStream<'static, super::Result<Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let blob_client = self._blob_client_for_uri(uri)?;
+
+        // Blocks are staged as the stream is consumed and only become visible once the block list
+        // is committed, so a failure part-way through leaves the destination untouched.
+        let mut block_list = BlockList { blocks: vec![] };
+        let mut buffer = Vec::with_capacity(MULTIPART_BLOCK_SIZE);
+        loop {
+            let chunk = stream.next().await.transpose()?;
+            let end_of_stream = chunk.is_none();
+            if let Some(chunk) = chunk {
+                buffer.extend_from_slice(&chunk);
+            }
+            if buffer.len() >= MULTIPART_BLOCK_SIZE || (end_of_stream && !buffer.is_empty()) {
+                let block =
+                    std::mem::replace(&mut buffer, Vec::with_capacity(MULTIPART_BLOCK_SIZE));
+                let num_bytes = block.len();
+                // Block ids must all have the same length within a blob.
+                let block_id = BlockId::new(format!("{:08}", block_list.blocks.len()));
+                blob_client
+                    .put_block(block_id.clone(), Bytes::from(block))
+                    .await
+                    .context(UnableToWriteToFileSnafu::<String> { path: uri.into() })?;
+                block_list
+                    .blocks
+                    .push(BlobBlockType::new_uncommitted(block_id));
+                if let Some(is) = io_stats.as_ref() {
+                    is.mark_put_requests(1);
+                    is.mark_bytes_uploaded(num_bytes);
+                }
+            }
+            if end_of_stream {
+                break;
+            }
+        }
+
+        blob_client
+            .put_block_list(block_list)
+            .await
+            .context(UnableToWriteToFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let blob_client = self._blob_client_for_uri(uri)?;
+        blob_client
+            .delete()
+            .await
+            .context(UnableToDeleteFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let src_client = self._blob_client_for_uri(src)?;
+        let dst_client = self._blob_client_for_uri(dst)?;
+        let src_url = src_client.url().context(AzureGenericSnafu)?;
+        dst_client
+            .copy(src_url)
+            .await
+            .context(UnableToWriteToFileSnafu::<String> { path: dst.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1)
+        }
+        Ok(())
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -6,8 +6,13 @@ use futures::TryStreamExt;
 use google_cloud_storage::client::ClientConfig;
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use futures::StreamExt;
 use google_cloud_storage::client::Client;
+use google_cloud_storage::http::objects::copy::CopyObjectRequest;
+use google_cloud_storage::http::objects::delete::DeleteObjectRequest;
 use google_cloud_storage::http::objects::get::GetObjectRequest;
+use google_cloud_storage::http::objects::upload::{Media, UploadObjectRequest, UploadType};
 
 use google_cloud_storage::http::objects::list::ListObjectsRequest;
 use google_cloud_storage::http::Error as GError;
@@ -39,6 +44,12 @@ enum Error {
     #[snafu(display("Unable to read data from {}: {}", path, source))]
     UnableToReadBytes { path: String, source: GError },
 
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToWriteToFile { path: String, source: GError },
+
+    #[snafu(display("Unable to delete {}: {}", path, source))]
+    UnableToDeleteFile { path: String, source: GError },
+
     #[snafu(display("Unable to parse URL: \"{}\"", path))]
     InvalidUrl {
         path: String,
@@ -96,6 +107,14 @@ impl From<Error> for super::Error {
                     source: err,
                 },
             },
+            UnableToWriteToFile { path, source } => super::Error::UnableToWriteToFile {
+                path,
+                source: source.into(),
+            },
+            UnableToDeleteFile { path, source } => super::Error::UnableToDeleteFile {
+                path,
+                source: source.into(),
+            },
             NotFound { ref path } => super::Error::NotFound {
                 path: path.into(),
                 source: error.into(),
@@ -201,6 +220,135 @@ impl GCSClientWrapper {
         }
         Ok(response.size as usize)
     }
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let uri = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let (bucket, key) = parse_uri(&uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let client = &self.0;
+        let req = UploadObjectRequest {
+            bucket: bucket.into(),
+            ..Default::default()
+        };
+        let num_bytes = data.len();
+        client
+            .upload_object(&req, data, &UploadType::Simple(Media::new(key.to_string())))
+            .await
+            .context(UnableToWriteToFileSnafu {
+                path: uri.to_string(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn put_multipart(
+        &self,
+        uri: &str,
+        mut stream: BoxStream<'static, super::Result<Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let uri = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let (bucket, key) = parse_uri(&uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let client = &self.0;
+        let req = UploadObjectRequest {
+            bucket: bucket.into(),
+            ..Default::default()
+        };
+
+        // The GCS client requires a `Sync` body stream, so forward chunks through a channel.
+        let (tx, rx) = tokio::sync::mpsc::channel::<super::Result<Bytes>>(2);
+        let forward_task = tokio::spawn(async move {
+            let mut num_bytes = 0;
+            while let Some(chunk) = stream.next().await {
+                if let Ok(chunk) = chunk.as_ref() {
+                    num_bytes += chunk.len();
+                }
+                if tx.send(chunk).await.is_err() {
+                    break;
+                }
+            }
+            num_bytes
+        });
+        client
+            .upload_streamed_object(
+                &req,
+                tokio_stream::wrappers::ReceiverStream::new(rx),
+                &UploadType::Simple(Media::new(key.to_string())),
+            )
+            .await
+            .context(UnableToWriteToFileSnafu {
+                path: uri.to_string(),
+            })?;
+        let num_bytes = forward_task.await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let uri = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let (bucket, key) = parse_uri(&uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let client = &self.0;
+        let req = DeleteObjectRequest {
+            bucket: bucket.into(),
+            object: key.into(),
+            ..Default::default()
+        };
+        client
+            .delete_object(&req)
+            .await
+            .context(UnableToDeleteFileSnafu {
+                path: uri.to_string(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let src_uri = url::Url::parse(src).with_context(|_| InvalidUrlSnafu { path: src })?;
+        let (src_bucket, src_key) = parse_uri(&src_uri)?;
+        let dst_uri = url::Url::parse(dst).with_context(|_| InvalidUrlSnafu { path: dst })?;
+        let (dst_bucket, dst_key) = parse_uri(&dst_uri)?;
+        if src_key.is_empty() {
+            return Err(Error::NotAFile { path: src.into() }.into());
+        }
+        if dst_key.is_empty() {
+            return Err(Error::NotAFile { path: dst.into() }.into());
+        }
+        let client = &self.0;
+        let req = CopyObjectRequest {
+            source_bucket: src_bucket.into(),
+            source_object: src_key.into(),
+            destination_bucket: dst_bucket.into(),
+            destination_object: dst_key.into(),
+            ..Default::default()
+        };
+        client
+            .copy_object(&req)
+            .await
+            .context(UnableToWriteToFileSnafu {
+                path: dst.to_string(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1)
+        }
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn _ls_impl(
         &self,
@@ -375,6 +523,27 @@ impl ObjectSource for GCSSource {
         self.client.get_size(uri, io_stats).await
     }
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.put(uri, data, io_stats).await
+    }
+
+    async fn put_multipart(
+        &self,
+        uri: &str,
+        stream: BoxStream<'static, super::Result<Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.client.put_multipart(uri, stream, io_stats).await
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.delete(uri, io_stats).await
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.copy(src, dst, io_stats).await
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
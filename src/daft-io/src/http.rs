@@ -1,6 +1,7 @@
 use std::{num::ParseIntError, ops::Range, string::FromUtf8Error, sync::Arc};
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use futures::{stream::BoxStream, TryStreamExt};
 
 use lazy_static::lazy_static;
@@ -246,6 +247,25 @@ impl ObjectSource for HttpSource {
         }
     }
 
+    async fn put(
+        &self,
+        _uri: &str,
+        _data: Bytes,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        Err(super::Error::NotImplementedMethod {
+            store: super::SourceType::Http,
+            method: "put".into(),
+        })
+    }
+
+    async fn delete(&self, _uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        Err(super::Error::NotImplementedMethod {
+            store: super::SourceType::Http,
+            method: "delete".into(),
+        })
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
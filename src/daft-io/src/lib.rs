@@ -24,9 +24,10 @@ pub use python::register_modules;
 pub use stats::{IOStatsContext, IOStatsRef};
 use tokio::runtime::RuntimeFlavor;
 
-use std::{borrow::Cow, collections::HashMap, hash::Hash, ops::Range, sync::Arc};
+use std::{borrow::Cow, collections::HashMap, future::Future, hash::Hash, ops::Range, sync::Arc};
 
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use tokio_util::io::ReaderStream;
 
 use snafu::Snafu;
 use url::ParseError;
@@ -69,6 +70,12 @@ pub enum Error {
         source: url::ParseError,
     },
 
+    #[snafu(display("Unable to write data to file {}: {}", path, source))]
+    UnableToWriteToFile { path: String, source: DynError },
+
+    #[snafu(display("Unable to delete file {}: {}", path, source))]
+    UnableToDeleteFile { path: String, source: DynError },
+
     #[snafu(display("Not a File: \"{}\"", path))]
     NotAFile { path: String },
 
@@ -91,6 +98,9 @@ pub enum Error {
     #[snafu(display("Source not yet implemented: {}", store))]
     NotImplementedSource { store: String },
 
+    #[snafu(display("{} is not supported for store: {}", method, store))]
+    NotImplementedMethod { store: SourceType, method: String },
+
     #[snafu(display("Unhandled Error for path: {}\nDetails:\n{}", path, msg))]
     Unhandled { path: String, msg: String },
 
@@ -125,6 +135,10 @@ impl From<Error> for std::io::Error {
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
+/// Capacity of the in-memory pipe between a writer and the upload in
+/// [`IOClient::single_url_put_from_writer`].
+const UPLOAD_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
 #[derive(Default)]
 pub struct IOClient {
     source_type_to_store: tokio::sync::RwLock<HashMap<SourceType, Arc<dyn ObjectSource>>>,
@@ -209,6 +223,119 @@ impl IOClient {
         source.get_size(path.as_ref(), io_stats).await
     }
 
+    pub async fn single_url_put(
+        &self,
+        dest: String,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (scheme, path) = parse_url(&dest)?;
+        let source = self.get_source(&scheme).await?;
+        source.put(path.as_ref(), data, io_stats).await
+    }
+
+    pub async fn single_url_put_multipart(
+        &self,
+        dest: String,
+        stream: BoxStream<'static, Result<bytes::Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (scheme, path) = parse_url(&dest)?;
+        let source = self.get_source(&scheme).await?;
+        source.put_multipart(path.as_ref(), stream, io_stats).await
+    }
+
+    /// Uploads everything that `write` writes to the sink it is handed to `dest`, streaming it to the
+    /// object store as it is produced.
+    ///
+    /// If `write` fails, the upload is failed as well so that no truncated object is committed, and
+    /// the error from `write` is returned.
+    pub async fn single_url_put_from_writer<F, Fut>(
+        &self,
+        dest: String,
+        io_stats: Option<IOStatsRef>,
+        write: F,
+    ) -> DaftResult<()>
+    where
+        F: FnOnce(tokio::io::DuplexStream) -> Fut,
+        Fut: Future<Output = DaftResult<()>>,
+    {
+        let (write_half, read_half) = tokio::io::duplex(UPLOAD_BUFFER_SIZE);
+        let (status_tx, status_rx) =
+            tokio::sync::oneshot::channel::<std::result::Result<(), String>>();
+
+        let path = dest.clone();
+        let error_path = dest.clone();
+        let body = ReaderStream::new(read_half)
+            .map_err(move |err| Error::UnableToWriteToFile {
+                path: path.clone(),
+                source: err.into(),
+            })
+            // Once the writer is done, fail the upload if it errored.
+            .chain(futures::stream::once(status_rx).filter_map(move |status| {
+                let path = error_path.clone();
+                async move {
+                    let message = match status {
+                        Ok(Ok(())) => return None,
+                        Ok(Err(message)) => message,
+                        Err(_) => "Writer exited before finishing".to_string(),
+                    };
+                    Some(Err(Error::UnableToWriteToFile {
+                        path,
+                        source: message.into(),
+                    }))
+                }
+            }));
+
+        let write = async move {
+            let result = write(write_half).await;
+            let _ = status_tx.send(result.as_ref().map(|_| ()).map_err(|e| e.to_string()));
+            result
+        };
+        let upload = self.single_url_put_multipart(dest, body.boxed(), io_stats);
+
+        let (write_result, upload_result) = futures::join!(write, upload);
+        write_result?;
+        upload_result?;
+        Ok(())
+    }
+
+    pub async fn single_url_delete(
+        &self,
+        input: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (scheme, path) = parse_url(&input)?;
+        let source = self.get_source(&scheme).await?;
+        source.delete(path.as_ref(), io_stats).await
+    }
+
+    pub async fn single_url_copy(
+        &self,
+        src: String,
+        dest: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (src_scheme, src_path) = parse_url(&src)?;
+        let (dest_scheme, dest_path) = parse_url(&dest)?;
+        if src_scheme == dest_scheme {
+            let source = self.get_source(&src_scheme).await?;
+            source
+                .copy(src_path.as_ref(), dest_path.as_ref(), io_stats)
+                .await
+        } else {
+            // Cross-store copies have to stream the object through the client.
+            let src_source = self.get_source(&src_scheme).await?;
+            let dest_source = self.get_source(&dest_scheme).await?;
+            let data = src_source
+                .get(src_path.as_ref(), None, io_stats.clone())
+                .await?
+                .bytes()
+                .await?;
+            dest_source.put(dest_path.as_ref(), data, io_stats).await
+        }
+    }
+
     async fn single_url_download(
         &self,
         index: usize,
@@ -14,8 +14,9 @@ use futures::stream::BoxStream;
 use futures::StreamExt;
 use futures::TryStreamExt;
 use snafu::{ResultExt, Snafu};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 use url::ParseError;
 
 /// NOTE: We hardcode this even for Windows
@@ -39,6 +40,38 @@ enum Error {
         path: String,
         source: std::io::Error,
     },
+    #[snafu(display("Unable to write data to file {}: {}", path, source))]
+    UnableToWriteToFile {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to create directory {}: {}", path, source))]
+    UnableToCreateDirectory {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to delete file {}: {}", path, source))]
+    UnableToDeleteFile {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to copy file {} to {}: {}", src, dest, source))]
+    UnableToCopyFile {
+        src: String,
+        dest: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to rename file {} to {}: {}", src, dest, source))]
+    UnableToRenameFile {
+        src: String,
+        dest: String,
+        source: std::io::Error,
+    },
+
     #[snafu(display("Unable to seek in file {}: {}", path, source))]
     UnableToSeek {
         path: String,
@@ -101,6 +134,37 @@ impl From<Error> for super::Error {
                 }
             }
             UnableToReadBytes { path, source } => super::Error::UnableToReadBytes { path, source },
+            UnableToWriteToFile { path, source } | UnableToCreateDirectory { path, source } => {
+                super::Error::UnableToWriteToFile {
+                    path,
+                    source: source.into(),
+                }
+            }
+            UnableToRenameFile { dest, source, .. } => super::Error::UnableToWriteToFile {
+                path: dest,
+                source: source.into(),
+            },
+            UnableToDeleteFile { path, source } => {
+                use std::io::ErrorKind::*;
+                match source.kind() {
+                    NotFound => super::Error::NotFound {
+                        path,
+                        source: source.into(),
+                    },
+                    _ => super::Error::UnableToDeleteFile {
+                        path,
+                        source: source.into(),
+                    },
+                }
+            }
+            UnableToCopyFile { src, source, .. }
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                super::Error::NotFound {
+                    path: src,
+                    source: source.into(),
+                }
+            }
             InvalidUrl { url, source } => super::Error::InvalidUrl {
                 path: url.to_string_lossy().into_owned(),
                 source,
@@ -119,6 +183,45 @@ impl LocalSource {
     }
 }
 
+const LOCAL_PROTOCOL: &str = "file://";
+
+fn strip_local_protocol(uri: &str) -> super::Result<&str> {
+    uri.strip_prefix(LOCAL_PROTOCOL)
+        .ok_or_else(|| Error::InvalidFilePath { path: uri.into() }.into())
+}
+
+/// Opens `path` for writing, creating any missing parent directories first.
+async fn create_file_for_write(path: &str) -> super::Result<tokio::fs::File> {
+    if let Some(parent) = std::path::Path::new(path).parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .with_context(|_| UnableToCreateDirectorySnafu {
+                path: parent.to_string_lossy().to_string(),
+            })?;
+    }
+    Ok(tokio::fs::File::create(path)
+        .await
+        .with_context(|_| UnableToWriteToFileSnafu { path })?)
+}
+
+/// Returns a unique path in the same directory as `path` to stage writes to `path` in.
+fn temp_path_for_write(path: &str) -> String {
+    static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let path = std::path::Path::new(path);
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    let temp_name = format!(
+        ".{file_name}.{}.{}.tmp",
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+    path.with_file_name(temp_name)
+        .to_string_lossy()
+        .into_owned()
+}
+
 pub struct LocalFile {
     pub path: PathBuf,
     pub range: Option<Range<usize>>,
@@ -132,7 +235,6 @@ impl ObjectSource for LocalSource {
         range: Option<Range<usize>>,
         _io_stats: Option<IOStatsRef>,
     ) -> super::Result<GetResult> {
-        const LOCAL_PROTOCOL: &str = "file://";
         if let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) {
             Ok(GetResult::File(LocalFile {
                 path: uri.into(),
@@ -144,7 +246,6 @@ impl ObjectSource for LocalSource {
     }
 
     async fn get_size(&self, uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<usize> {
-        const LOCAL_PROTOCOL: &str = "file://";
         let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
             return Err(Error::InvalidFilePath { path: uri.into() }.into());
         };
@@ -163,6 +264,100 @@ impl ObjectSource for LocalSource {
         }
     }
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        // Stage the write in a temporary file like multipart puts, so that readers never observe a
+        // partially written file.
+        self.put_multipart(
+            uri,
+            futures::stream::once(async { Ok(data) }).boxed(),
+            io_stats,
+        )
+        .await
+    }
+
+    async fn put_multipart(
+        &self,
+        uri: &str,
+        mut stream: BoxStream<'static, super::Result<Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let path = strip_local_protocol(uri)?;
+        // Write to a temporary file next to the destination and rename it into place once the
+        // stream is exhausted, so that readers never observe a partially written file.
+        let temp_path = temp_path_for_write(path);
+        let mut file = create_file_for_write(&temp_path).await?;
+        let result: super::Result<usize> = async {
+            let mut bytes_written = 0;
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk?;
+                file.write_all(&chunk)
+                    .await
+                    .with_context(|_| UnableToWriteToFileSnafu { path })?;
+                bytes_written += chunk.len();
+            }
+            file.flush()
+                .await
+                .with_context(|_| UnableToWriteToFileSnafu { path })?;
+            drop(file);
+            tokio::fs::rename(&temp_path, path)
+                .await
+                .with_context(|_| UnableToRenameFileSnafu {
+                    src: temp_path.as_str(),
+                    dest: path,
+                })?;
+            Ok(bytes_written)
+        }
+        .await;
+        let bytes_written = match result {
+            Ok(bytes_written) => bytes_written,
+            Err(err) => {
+                if let Err(remove_err) = tokio::fs::remove_file(&temp_path).await {
+                    log::warn!("Failed to remove temporary file {temp_path}: {remove_err}");
+                }
+                return Err(err);
+            }
+        };
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(bytes_written);
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let path = strip_local_protocol(uri)?;
+        tokio::fs::remove_file(path)
+            .await
+            .with_context(|_| UnableToDeleteFileSnafu { path })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let src_path = strip_local_protocol(src)?;
+        let dst_path = strip_local_protocol(dst)?;
+        if let Some(parent) = std::path::Path::new(dst_path).parent() {
+            tokio::fs::create_dir_all(parent).await.with_context(|_| {
+                UnableToCreateDirectorySnafu {
+                    path: parent.to_string_lossy().to_string(),
+                }
+            })?;
+        }
+        let bytes_copied = tokio::fs::copy(src_path, dst_path)
+            .await
+            .with_context(|_| UnableToCopyFileSnafu {
+                src: src_path,
+                dest: dst_path,
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(bytes_copied as usize);
+        }
+        Ok(())
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -223,7 +418,6 @@ impl ObjectSource for LocalSource {
             unimplemented!("Prefix-listing is not implemented for local.");
         }
 
-        const LOCAL_PROTOCOL: &str = "file://";
         let uri = if uri.is_empty() {
             std::borrow::Cow::Owned(
                 std::env::current_dir()
@@ -345,6 +539,8 @@ pub(crate) async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
 mod tests {
     use std::io::Write;
 
+    use futures::StreamExt;
+
     use crate::object_io::{FileMetadata, FileType, ObjectSource};
     use crate::Result;
     use crate::{HttpSource, LocalSource};
@@ -467,4 +663,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_local_put_copy_delete() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = format!("file://{}", dir.path().to_string_lossy().replace('\\', "/"));
+        let client = LocalSource::get_client().await?;
+
+        let first_path = format!("{dir_path}/nested/first.bin");
+        let data = bytes::Bytes::from_static(b"hello world");
+        client.put(&first_path, data.clone(), None).await?;
+        let read_back = client.get(&first_path, None, None).await?.bytes().await?;
+        assert_eq!(read_back, data);
+
+        let second_path = format!("{dir_path}/second.bin");
+        let chunks = vec![
+            Ok(bytes::Bytes::from_static(b"hello ")),
+            Ok(bytes::Bytes::from_static(b"world")),
+        ];
+        client
+            .put_multipart(&second_path, futures::stream::iter(chunks).boxed(), None)
+            .await?;
+        let read_back = client.get(&second_path, None, None).await?.bytes().await?;
+        assert_eq!(read_back, data);
+
+        let third_path = format!("{dir_path}/copies/third.bin");
+        client.copy(&first_path, &third_path, None).await?;
+        let read_back = client.get(&third_path, None, None).await?.bytes().await?;
+        assert_eq!(read_back, data);
+
+        client.delete(&first_path, None).await?;
+        assert!(matches!(
+            client.get_size(&first_path, None).await,
+            Err(crate::Error::NotFound { .. })
+        ));
+        assert!(matches!(
+            client.delete(&first_path, None).await,
+            Err(crate::Error::NotFound { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_local_put_multipart_failure_leaves_no_file() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = format!("file://{}", dir.path().to_string_lossy().replace('\\', "/"));
+        let client = LocalSource::get_client().await?;
+
+        let path = format!("{dir_path}/failed.bin");
+        let chunks = vec![
+            Ok(bytes::Bytes::from_static(b"hello ")),
+            Err(crate::Error::NotAFile {
+                path: "upstream".to_string(),
+            }),
+        ];
+        assert!(client
+            .put_multipart(&path, futures::stream::iter(chunks).boxed(), None)
+            .await
+            .is_err());
+        // Neither the destination nor the temporary file the upload was staged in should exist.
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+
+        let chunks = vec![Ok(bytes::Bytes::from_static(b"hello world"))];
+        client
+            .put_multipart(&path, futures::stream::iter(chunks).boxed(), None)
+            .await?;
+        let entries = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect::<Vec<_>>();
+        assert_eq!(entries, vec!["failed.bin"]);
+
+        Ok(())
+    }
 }
@@ -111,6 +111,33 @@ pub(crate) trait ObjectSource: Sync + Send {
     }
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()>;
+
+    /// Uploads a stream of chunks to `uri`.
+    ///
+    /// Sources with native multipart uploads override this, the default buffers the whole stream
+    /// and issues a single `put`.
+    async fn put_multipart(
+        &self,
+        uri: &str,
+        stream: BoxStream<'static, super::Result<Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let data = collect_bytes(stream, None).await?;
+        self.put(uri, data, io_stats).await
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()>;
+
+    /// Copies the object at `src` to `dst`, where both live in this source.
+    ///
+    /// The default implementation round-trips the bytes through the client, sources that support
+    /// server-side copies should override it.
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let data = self.get(src, None, io_stats.clone()).await?.bytes().await?;
+        self.put(dst, data, io_stats).await
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -61,6 +61,78 @@ mod py {
         Ok(PyList::new(py, to_rtn))
     }
 
+    #[pyfunction]
+    fn io_put(
+        py: Python,
+        path: String,
+        data: &[u8],
+        multithreaded_io: Option<bool>,
+        io_config: Option<common_io_config::python::IOConfig>,
+    ) -> PyResult<()> {
+        let multithreaded_io = multithreaded_io.unwrap_or(true);
+        let io_stats = IOStatsContext::new(format!("io_put for {path}"));
+        let data = bytes::Bytes::copy_from_slice(data);
+        let result: DaftResult<()> = py.allow_threads(|| {
+            let io_client = get_io_client(
+                multithreaded_io,
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let runtime_handle = get_runtime(multithreaded_io)?;
+            let _rt_guard = runtime_handle.enter();
+            runtime_handle.block_on(async move {
+                Ok(io_client.single_url_put(path, data, Some(io_stats)).await?)
+            })
+        });
+        Ok(result?)
+    }
+
+    #[pyfunction]
+    fn io_delete(
+        py: Python,
+        path: String,
+        multithreaded_io: Option<bool>,
+        io_config: Option<common_io_config::python::IOConfig>,
+    ) -> PyResult<()> {
+        let multithreaded_io = multithreaded_io.unwrap_or(true);
+        let io_stats = IOStatsContext::new(format!("io_delete for {path}"));
+        let result: DaftResult<()> = py.allow_threads(|| {
+            let io_client = get_io_client(
+                multithreaded_io,
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let runtime_handle = get_runtime(multithreaded_io)?;
+            let _rt_guard = runtime_handle.enter();
+            runtime_handle.block_on(async move {
+                Ok(io_client.single_url_delete(path, Some(io_stats)).await?)
+            })
+        });
+        Ok(result?)
+    }
+
+    #[pyfunction]
+    fn io_copy(
+        py: Python,
+        src: String,
+        dest: String,
+        multithreaded_io: Option<bool>,
+        io_config: Option<common_io_config::python::IOConfig>,
+    ) -> PyResult<()> {
+        let multithreaded_io = multithreaded_io.unwrap_or(true);
+        let io_stats = IOStatsContext::new(format!("io_copy for {src} to {dest}"));
+        let result: DaftResult<()> = py.allow_threads(|| {
+            let io_client = get_io_client(
+                multithreaded_io,
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let runtime_handle = get_runtime(multithreaded_io)?;
+            let _rt_guard = runtime_handle.enter();
+            runtime_handle.block_on(async move {
+                Ok(io_client.single_url_copy(src, dest, Some(io_stats)).await?)
+            })
+        });
+        Ok(result?)
+    }
+
     #[pyfunction]
     fn set_io_pool_num_threads(num_threads: i64) -> PyResult<bool> {
         Ok(crate::set_io_pool_num_threads(num_threads as usize))
@@ -82,6 +154,9 @@ mod py {
     pub fn register_modules(py: Python, parent: &PyModule) -> PyResult<()> {
         common_io_config::python::register_modules(py, parent)?;
         parent.add_function(wrap_pyfunction!(io_glob, parent)?)?;
+        parent.add_function(wrap_pyfunction!(io_put, parent)?)?;
+        parent.add_function(wrap_pyfunction!(io_delete, parent)?)?;
+        parent.add_function(wrap_pyfunction!(io_copy, parent)?)?;
         parent.add_function(wrap_pyfunction!(set_io_pool_num_threads, parent)?)?;
         parent.add_function(wrap_pyfunction!(s3_config_from_env, parent)?)?;
         Ok(())
@@ -5,8 +5,14 @@ use aws_config::timeout::TimeoutConfig;
 use aws_smithy_async::rt::sleep::TokioSleep;
 use futures::stream::BoxStream;
 use reqwest::StatusCode;
+use s3::operation::complete_multipart_upload::CompleteMultipartUploadError;
+use s3::operation::copy_object::CopyObjectError;
+use s3::operation::create_multipart_upload::CreateMultipartUploadError;
+use s3::operation::delete_object::DeleteObjectError;
 use s3::operation::head_object::HeadObjectError;
 use s3::operation::list_objects_v2::ListObjectsV2Error;
+use s3::operation::put_object::PutObjectError;
+use s3::operation::upload_part::UploadPartError;
 use tokio::sync::{OwnedSemaphorePermit, SemaphorePermit};
 
 use crate::object_io::{FileMetadata, FileType, LSResult};
@@ -29,7 +35,8 @@ use url::{ParseError, Position};
 use super::object_io::{GetResult, ObjectSource};
 use async_recursion::async_recursion;
 use aws_sdk_s3 as s3;
-use aws_sdk_s3::primitives::ByteStreamError;
+use aws_sdk_s3::primitives::{ByteStream, ByteStreamError};
+use bytes::Bytes;
 
 use std::collections::HashMap;
 
@@ -41,6 +48,8 @@ use std::time::Duration;
 
 const S3_DELIMITER: &str = "/";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+// S3 requires every part of a multipart upload except the last to be at least 5MiB
+const MULTIPART_PART_SIZE: usize = 8 * 1024 * 1024;
 pub(crate) struct S3LikeSource {
     region_to_client_map: tokio::sync::RwLock<HashMap<Region, Arc<s3::Client>>>,
     connection_pool_sema: Arc<tokio::sync::Semaphore>,
@@ -69,6 +78,72 @@ enum Error {
         source: SdkError<ListObjectsV2Error, Response>,
     },
 
+    #[snafu(display("Unable to put {}: {}", path, s3::error::DisplayErrorContext(source)))]
+    UnableToPutFile {
+        path: String,
+        source: SdkError<PutObjectError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to create multipart upload for {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCreateMultipartUpload {
+        path: String,
+        source: SdkError<CreateMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to upload part {} of {}: {}",
+        part_number,
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToUploadPart {
+        path: String,
+        part_number: i32,
+        source: SdkError<UploadPartError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to complete multipart upload for {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCompleteMultipartUpload {
+        path: String,
+        source: SdkError<CompleteMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Missing upload id in response when creating multipart upload for {}",
+        path
+    ))]
+    MissingUploadId { path: String },
+
+    #[snafu(display(
+        "Unable to delete {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToDeleteFile {
+        path: String,
+        source: SdkError<DeleteObjectError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to copy {} to {}: {}",
+        src,
+        dest,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCopyFile {
+        src: String,
+        dest: String,
+        source: SdkError<CopyObjectError, Response>,
+    },
+
     #[snafu(display("Unable to query the region for {}: {}", path, source))]
     UnableToQueryRegion {
         path: String,
@@ -160,6 +235,30 @@ impl From<Error> for super::Error {
                     source: err.into(),
                 },
             },
+            UnableToPutFile { path, source } => super::Error::UnableToWriteToFile {
+                path,
+                source: source.into_service_error().into(),
+            },
+            UnableToCreateMultipartUpload { path, source } => super::Error::UnableToWriteToFile {
+                path,
+                source: source.into_service_error().into(),
+            },
+            UnableToUploadPart { path, source, .. } => super::Error::UnableToWriteToFile {
+                path,
+                source: source.into_service_error().into(),
+            },
+            UnableToCompleteMultipartUpload { path, source } => super::Error::UnableToWriteToFile {
+                path,
+                source: source.into_service_error().into(),
+            },
+            UnableToDeleteFile { path, source } => super::Error::UnableToDeleteFile {
+                path,
+                source: source.into_service_error().into(),
+            },
+            UnableToCopyFile { dest, source, .. } => super::Error::UnableToWriteToFile {
+                path: dest,
+                source: source.into_service_error().into(),
+            },
             InvalidUrl { path, source } => super::Error::InvalidUrl { path, source },
             UnableToReadBytes { path, source } => {
                 use std::error::Error;
@@ -842,6 +941,387 @@ impl S3LikeSource {
     }
 }
 
+fn parse_redirect_region(uri: &str, region_header: Option<&[u8]>) -> super::Result<Region> {
+    let new_region = region_header.ok_or(Error::MissingHeader {
+        path: uri.into(),
+        header: REGION_HEADER.into(),
+    })?;
+    let region_name = String::from_utf8(new_region.to_vec())
+        .with_context(|_| UnableToParseUtf8Snafu::<String> { path: uri.into() })?;
+    Ok(Region::new(region_name))
+}
+
+impl S3LikeSource {
+    #[async_recursion]
+    async fn _put_impl(
+        &self,
+        _permit: SemaphorePermit<'async_recursion>,
+        uri: &str,
+        data: Bytes,
+        region: &Region,
+    ) -> super::Result<()> {
+        log::debug!("S3 put at {uri} in region: {region}");
+        let (_scheme, bucket, key) = parse_url(uri)?;
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let request = self
+            .get_s3_client(region)
+            .await?
+            .put_object()
+            .bucket(bucket)
+            .key(key)
+            .body(ByteStream::from(data.clone()));
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        match request.send().await {
+            Ok(_) => Ok(()),
+            Err(SdkError::ServiceError(err))
+                if err.raw().http().status() == StatusCode::MOVED_PERMANENTLY =>
+            {
+                let headers = err.raw().http().headers();
+                let new_region =
+                    parse_redirect_region(uri, headers.get(REGION_HEADER).map(|v| v.as_bytes()))?;
+                log::debug!("S3 Region of {uri} different than client {:?} vs {:?} Attempting PUT in that region with new client", new_region, region);
+                self._put_impl(_permit, uri, data, &new_region).await
+            }
+            Err(err) => Err(UnableToPutFileSnafu { path: uri }.into_error(err).into()),
+        }
+    }
+
+    #[async_recursion]
+    async fn _delete_impl(
+        &self,
+        _permit: SemaphorePermit<'async_recursion>,
+        uri: &str,
+        region: &Region,
+    ) -> super::Result<()> {
+        log::debug!("S3 delete at {uri} in region: {region}");
+        let (_scheme, bucket, key) = parse_url(uri)?;
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let request = self
+            .get_s3_client(region)
+            .await?
+            .delete_object()
+            .bucket(bucket)
+            .key(key);
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        match request.send().await {
+            Ok(_) => Ok(()),
+            Err(SdkError::ServiceError(err))
+                if err.raw().http().status() == StatusCode::MOVED_PERMANENTLY =>
+            {
+                let headers = err.raw().http().headers();
+                let new_region =
+                    parse_redirect_region(uri, headers.get(REGION_HEADER).map(|v| v.as_bytes()))?;
+                log::debug!("S3 Region of {uri} different than client {:?} vs {:?} Attempting DELETE in that region with new client", new_region, region);
+                self._delete_impl(_permit, uri, &new_region).await
+            }
+            Err(err) => Err(UnableToDeleteFileSnafu { path: uri }.into_error(err).into()),
+        }
+    }
+
+    #[async_recursion]
+    async fn _copy_impl(
+        &self,
+        _permit: SemaphorePermit<'async_recursion>,
+        src: &str,
+        dst: &str,
+        region: &Region,
+    ) -> super::Result<()> {
+        log::debug!("S3 copy from {src} to {dst} in region: {region}");
+        let (_, src_bucket, src_key) = parse_url(src)?;
+        let (_, dst_bucket, dst_key) = parse_url(dst)?;
+        if src_key.is_empty() {
+            return Err(Error::NotAFile { path: src.into() }.into());
+        }
+        if dst_key.is_empty() {
+            return Err(Error::NotAFile { path: dst.into() }.into());
+        }
+        // The copy source is sent as a header and must be URL-encoded, which `Url::path` already is.
+        let src_url = url::Url::parse(src).with_context(|_| InvalidUrlSnafu { path: src })?;
+        let copy_source = format!("{src_bucket}{}", src_url.path());
+        let request = self
+            .get_s3_client(region)
+            .await?
+            .copy_object()
+            .copy_source(copy_source)
+            .bucket(dst_bucket)
+            .key(dst_key);
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        match request.send().await {
+            Ok(_) => Ok(()),
+            // The request is sent to the destination bucket, so a redirect points at its region.
+            Err(SdkError::ServiceError(err))
+                if err.raw().http().status() == StatusCode::MOVED_PERMANENTLY =>
+            {
+                let headers = err.raw().http().headers();
+                let new_region =
+                    parse_redirect_region(dst, headers.get(REGION_HEADER).map(|v| v.as_bytes()))?;
+                log::debug!("S3 Region of {dst} different than client {:?} vs {:?} Attempting COPY in that region with new client", new_region, region);
+                self._copy_impl(_permit, src, dst, &new_region).await
+            }
+            Err(err) => Err(UnableToCopyFileSnafu { src, dest: dst }
+                .into_error(err)
+                .into()),
+        }
+    }
+
+    /// Starts a multipart upload, following region redirects. Returns the region the upload lives
+    /// in along with its upload id.
+    #[async_recursion]
+    async fn _create_multipart_upload_impl(
+        &self,
+        uri: &str,
+        bucket: &str,
+        key: &str,
+        region: &Region,
+    ) -> super::Result<(Region, String)> {
+        log::debug!("S3 create_multipart_upload at {uri} in region: {region}");
+        let request = self
+            .get_s3_client(region)
+            .await?
+            .create_multipart_upload()
+            .bucket(bucket)
+            .key(key);
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        match request.send().await {
+            Ok(v) => match v.upload_id() {
+                Some(upload_id) => Ok((region.clone(), upload_id.to_string())),
+                None => Err(Error::MissingUploadId { path: uri.into() }.into()),
+            },
+            Err(SdkError::ServiceError(err))
+                if err.raw().http().status() == StatusCode::MOVED_PERMANENTLY =>
+            {
+                let headers = err.raw().http().headers();
+                let new_region =
+                    parse_redirect_region(uri, headers.get(REGION_HEADER).map(|v| v.as_bytes()))?;
+                log::debug!("S3 Region of {uri} different than client {:?} vs {:?} Attempting multipart upload in that region with new client", new_region, region);
+                self._create_multipart_upload_impl(uri, bucket, key, &new_region)
+                    .await
+            }
+            Err(err) => Err(UnableToCreateMultipartUploadSnafu { path: uri }
+                .into_error(err)
+                .into()),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn _upload_part_impl(
+        &self,
+        uri: &str,
+        bucket: &str,
+        key: &str,
+        region: &Region,
+        upload_id: &str,
+        part_number: i32,
+        data: Bytes,
+    ) -> super::Result<s3::types::CompletedPart> {
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        log::debug!("S3 upload_part {part_number} of {uri} in region: {region}");
+        let request = self
+            .get_s3_client(region)
+            .await?
+            .upload_part()
+            .bucket(bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .body(ByteStream::from(data));
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+        let response = request
+            .send()
+            .await
+            .with_context(|_| UnableToUploadPartSnafu {
+                path: uri,
+                part_number,
+            })?;
+        Ok(s3::types::CompletedPart::builder()
+            .set_e_tag(response.e_tag().map(String::from))
+            .part_number(part_number)
+            .build())
+    }
+
+    async fn _put_multipart_impl(
+        &self,
+        uri: &str,
+        mut stream: BoxStream<'static, super::Result<Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let (_scheme, bucket, key) = parse_url(uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        let mut buffer = Vec::with_capacity(MULTIPART_PART_SIZE);
+        let mut upload: Option<(Region, String)> = None;
+        let mut completed_parts = vec![];
+
+        let result: super::Result<()> = async {
+            loop {
+                let chunk = stream.next().await.transpose()?;
+                let end_of_stream = chunk.is_none();
+                if let Some(chunk) = chunk {
+                    buffer.extend_from_slice(&chunk);
+                }
+                if buffer.len() < MULTIPART_PART_SIZE && !end_of_stream {
+                    continue;
+                }
+                if end_of_stream && upload.is_none() {
+                    // Everything fit into a single part, so skip the multipart machinery.
+                    let permit = self
+                        .connection_pool_sema
+                        .acquire()
+                        .await
+                        .context(UnableToGrabSemaphoreSnafu)?;
+                    let num_bytes = buffer.len();
+                    self._put_impl(
+                        permit,
+                        uri,
+                        std::mem::take(&mut buffer).into(),
+                        &self.default_region,
+                    )
+                    .await?;
+                    if let Some(is) = io_stats.as_ref() {
+                        is.mark_put_requests(1);
+                        is.mark_bytes_uploaded(num_bytes);
+                    }
+                    return Ok(());
+                }
+                if upload.is_none() {
+                    let permit = self
+                        .connection_pool_sema
+                        .acquire()
+                        .await
+                        .context(UnableToGrabSemaphoreSnafu)?;
+                    let created = self
+                        ._create_multipart_upload_impl(uri, &bucket, &key, &self.default_region)
+                        .await?;
+                    drop(permit);
+                    if let Some(is) = io_stats.as_ref() {
+                        is.mark_put_requests(1)
+                    }
+                    upload = Some(created);
+                }
+                let (region, upload_id) = upload.as_ref().unwrap();
+                if !buffer.is_empty() {
+                    let part =
+                        std::mem::replace(&mut buffer, Vec::with_capacity(MULTIPART_PART_SIZE));
+                    let num_bytes = part.len();
+                    let part_number = completed_parts.len() as i32 + 1;
+                    let completed = self
+                        ._upload_part_impl(
+                            uri,
+                            &bucket,
+                            &key,
+                            region,
+                            upload_id,
+                            part_number,
+                            part.into(),
+                        )
+                        .await?;
+                    completed_parts.push(completed);
+                    if let Some(is) = io_stats.as_ref() {
+                        is.mark_put_requests(1);
+                        is.mark_bytes_uploaded(num_bytes);
+                    }
+                }
+                if end_of_stream {
+                    let _permit = self
+                        .connection_pool_sema
+                        .acquire()
+                        .await
+                        .context(UnableToGrabSemaphoreSnafu)?;
+                    let multipart_upload = s3::types::CompletedMultipartUpload::builder()
+                        .set_parts(Some(std::mem::take(&mut completed_parts)))
+                        .build();
+                    let request = self
+                        .get_s3_client(region)
+                        .await?
+                        .complete_multipart_upload()
+                        .bucket(&bucket)
+                        .key(&key)
+                        .upload_id(upload_id)
+                        .multipart_upload(multipart_upload);
+                    let request = if self.s3_config.requester_pays {
+                        request.request_payer(s3::types::RequestPayer::Requester)
+                    } else {
+                        request
+                    };
+                    request
+                        .send()
+                        .await
+                        .context(UnableToCompleteMultipartUploadSnafu { path: uri })?;
+                    if let Some(is) = io_stats.as_ref() {
+                        is.mark_put_requests(1)
+                    }
+                    return Ok(());
+                }
+            }
+        }
+        .await;
+
+        if result.is_err() && let Some((region, upload_id)) = upload.as_ref() {
+            // Best-effort cleanup so that failed uploads do not leave dangling parts behind.
+            let aborted = match self.get_s3_client(region).await {
+                Ok(client) => {
+                    let request = client
+                        .abort_multipart_upload()
+                        .bucket(&bucket)
+                        .key(&key)
+                        .upload_id(upload_id);
+                    let request = if self.s3_config.requester_pays {
+                        request.request_payer(s3::types::RequestPayer::Requester)
+                    } else {
+                        request
+                    };
+                    request
+                        .send()
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| DisplayErrorContext(e).to_string())
+                }
+                Err(e) => Err(e.to_string()),
+            };
+            if let Err(err) = aborted {
+                log::warn!("Failed to abort multipart upload {upload_id} for {uri}: {err}");
+            }
+        }
+        result
+    }
+}
+
 #[async_trait]
 impl ObjectSource for S3LikeSource {
     async fn get(
@@ -891,6 +1371,58 @@ impl ObjectSource for S3LikeSource {
         Ok(head_result)
     }
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let num_bytes = data.len();
+        self._put_impl(permit, uri, data, &self.default_region)
+            .await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn put_multipart(
+        &self,
+        uri: &str,
+        stream: BoxStream<'static, super::Result<Bytes>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self._put_multipart_impl(uri, stream, io_stats).await
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        self._delete_impl(permit, uri, &self.default_region).await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        self._copy_impl(permit, src, dst, &self.default_region)
+            .await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1)
+        }
+        Ok(())
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
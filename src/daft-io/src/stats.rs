@@ -14,7 +14,10 @@ pub struct IOStatsContext {
     num_get_requests: atomic::AtomicUsize,
     num_head_requests: atomic::AtomicUsize,
     num_list_requests: atomic::AtomicUsize,
+    num_put_requests: atomic::AtomicUsize,
+    num_delete_requests: atomic::AtomicUsize,
     bytes_read: atomic::AtomicUsize,
+    bytes_uploaded: atomic::AtomicUsize,
 }
 
 impl Drop for IOStatsContext {
@@ -23,13 +26,16 @@ impl Drop for IOStatsContext {
         let num_gets = self.load_get_requests();
         let mean_size = (bytes_read as f64) / (num_gets as f64);
         log::info!(
-            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, BytesRead: {}, AvgGetSize: {}",
+            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, Puts: {}, Deletes: {}, BytesRead: {}, AvgGetSize: {}, BytesUploaded: {}",
             self.name,
             num_gets,
             self.load_head_requests(),
             self.load_list_requests(),
+            self.load_put_requests(),
+            self.load_delete_requests(),
             bytes_read,
-            mean_size as i64
+            mean_size as i64,
+            self.load_bytes_uploaded(),
         );
     }
 }
@@ -47,7 +53,10 @@ impl IOStatsContext {
             num_get_requests: atomic::AtomicUsize::new(0),
             num_head_requests: atomic::AtomicUsize::new(0),
             num_list_requests: atomic::AtomicUsize::new(0),
+            num_put_requests: atomic::AtomicUsize::new(0),
+            num_delete_requests: atomic::AtomicUsize::new(0),
             bytes_read: atomic::AtomicUsize::new(0),
+            bytes_uploaded: atomic::AtomicUsize::new(0),
         })
     }
 
@@ -69,6 +78,18 @@ impl IOStatsContext {
             .fetch_add(num_requests, atomic::Ordering::Relaxed);
     }
 
+    #[inline]
+    pub(crate) fn mark_put_requests(&self, num_requests: usize) {
+        self.num_put_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn mark_delete_requests(&self, num_requests: usize) {
+        self.num_delete_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
     #[inline]
     pub fn load_get_requests(&self) -> usize {
         self.num_get_requests.load(atomic::Ordering::Acquire)
@@ -84,6 +105,16 @@ impl IOStatsContext {
         self.num_list_requests.load(atomic::Ordering::Acquire)
     }
 
+    #[inline]
+    pub fn load_put_requests(&self) -> usize {
+        self.num_put_requests.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_delete_requests(&self) -> usize {
+        self.num_delete_requests.load(atomic::Ordering::Acquire)
+    }
+
     #[inline]
     pub(crate) fn mark_bytes_read(&self, bytes_read: usize) {
         self.bytes_read
@@ -94,6 +125,17 @@ impl IOStatsContext {
     pub fn load_bytes_read(&self) -> usize {
         self.bytes_read.load(atomic::Ordering::Acquire)
     }
+
+    #[inline]
+    pub(crate) fn mark_bytes_uploaded(&self, bytes_uploaded: usize) {
+        self.bytes_uploaded
+            .fetch_add(bytes_uploaded, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn load_bytes_uploaded(&self) -> usize {
+        self.bytes_uploaded.load(atomic::Ordering::Acquire)
+    }
 }
 
 impl IOStatsByteStreamContextHandle {
@@ -0,0 +1,54 @@
+#![feature(let_chains)]
+use common_error::DaftError;
+use snafu::Snafu;
+
+mod metadata;
+#[cfg(feature = "python")]
+pub mod python;
+mod range_file;
+pub mod read;
+pub mod schema;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+pub use read::{read_ipc, read_ipc_bulk};
+pub use schema::read_ipc_schema;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+    #[snafu(display("Unable to parse Arrow IPC data from \"{}\": {}", path, source))]
+    ArrowError {
+        path: String,
+        source: arrow2::error::Error,
+    },
+    #[snafu(display("File: {} is not a valid Arrow IPC file: {}", path, message))]
+    InvalidIpcFile { path: String, message: String },
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> DaftError {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => DaftError::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Error::IOError { source: err }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(feature = "python")]
+pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_ipc))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_ipc_schema))?;
+    Ok(())
+}
@@ -0,0 +1,252 @@
+use std::{
+    io::{Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
+
+use arrow2::io::ipc::{
+    read::{read_file_metadata, read_stream_metadata, FileMetadata, StreamMetadata},
+    IpcField,
+};
+use arrow_format::ipc::{planus::ReadAsRoot, MessageHeaderRef, MessageRef};
+use daft_io::{IOClient, IOStatsRef};
+use snafu::ResultExt;
+
+use crate::{range_file::RangeFile, ArrowSnafu};
+
+const ARROW_MAGIC: &[u8; 6] = b"ARROW1";
+/// Files up to this size are fetched with a single request.
+const SMALL_FILE_SIZE: usize = 1024 * 1024;
+/// Size of the speculative reads of the head and tail of larger files.
+const HEAD_READ_SIZE: usize = 64 * 1024;
+const FOOTER_READ_SIZE: usize = 64 * 1024;
+/// Size of the speculative read of a message's length prefix and flatbuffer header.
+const MESSAGE_HEADER_READ_SIZE: usize = 4 * 1024;
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+/// What the header of an IPC message says about it, read without fetching the message body.
+pub(crate) struct MessageInfo {
+    /// Offset of the first byte after the message body.
+    pub end: usize,
+    /// Number of rows of a record batch message, `None` for other messages.
+    pub num_rows: Option<usize>,
+}
+
+/// An Arrow IPC file or stream being read with range requests.
+///
+/// Bytes are fetched lazily from the object source and accumulated in a [`RangeFile`], which the
+/// arrow2 decoders then read from.
+pub(crate) struct IpcSource {
+    pub uri: String,
+    pub file: RangeFile,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+}
+
+impl IpcSource {
+    pub async fn open(
+        uri: &str,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Self> {
+        let size = io_client
+            .single_url_get_size(uri.to_string(), io_stats.clone())
+            .await?;
+        let mut source = Self {
+            uri: uri.to_string(),
+            file: RangeFile::new(size),
+            io_client,
+            io_stats,
+        };
+        if size <= SMALL_FILE_SIZE {
+            source.fetch(0..size).await?;
+        } else {
+            source.fetch(0..HEAD_READ_SIZE).await?;
+            if source.is_file_format() {
+                source.fetch(size - FOOTER_READ_SIZE..size).await?;
+            }
+        }
+        Ok(source)
+    }
+
+    /// Whether this is the random-access IPC file format (as opposed to the IPC stream format).
+    pub fn is_file_format(&self) -> bool {
+        self.file
+            .get(0..ARROW_MAGIC.len())
+            .is_some_and(|head| head == ARROW_MAGIC)
+    }
+
+    /// Fetches the parts of `range` that haven't been fetched yet.
+    pub async fn fetch(&mut self, range: Range<usize>) -> super::Result<()> {
+        self.fetch_ranges(vec![range]).await
+    }
+
+    /// Concurrently fetches the parts of `ranges` that haven't been fetched yet.
+    pub async fn fetch_ranges(&mut self, mut ranges: Vec<Range<usize>>) -> super::Result<()> {
+        let size = self.file.size();
+        ranges.sort_unstable_by_key(|range| range.start);
+        // Merge overlapping ranges so that no byte is fetched twice.
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            let range = range.start.min(size)..range.end.min(size);
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        let missing = merged
+            .into_iter()
+            .flat_map(|range| self.file.missing(range))
+            .collect::<Vec<_>>();
+        let fetches = missing.into_iter().map(|range| {
+            let (uri, io_client, io_stats) = (
+                self.uri.clone(),
+                self.io_client.clone(),
+                self.io_stats.clone(),
+            );
+            async move {
+                let bytes = io_client
+                    .single_url_get(uri, Some(range.clone()), io_stats)
+                    .await?
+                    .bytes()
+                    .await?;
+                Ok::<_, super::Error>((range.start, bytes))
+            }
+        });
+        for (start, bytes) in futures::future::try_join_all(fetches).await? {
+            self.file.insert(start, bytes);
+        }
+        Ok(())
+    }
+
+    /// Reads the footer of an IPC file.
+    pub async fn file_metadata(&mut self) -> super::Result<FileMetadata> {
+        let size = self.file.size();
+        let trailer = size
+            .checked_sub(10)
+            .and_then(|start| self.file.get(start..size))
+            .filter(|trailer| &trailer[4..] == ARROW_MAGIC)
+            .ok_or_else(|| super::Error::InvalidIpcFile {
+                path: self.uri.clone(),
+                message: "missing footer magic bytes".to_string(),
+            })?;
+        let footer_len = i32::from_le_bytes(trailer[..4].try_into().unwrap());
+        let footer_start = usize::try_from(footer_len)
+            .ok()
+            .and_then(|footer_len| (size - 10).checked_sub(footer_len))
+            .ok_or_else(|| super::Error::InvalidIpcFile {
+                path: self.uri.clone(),
+                message: format!("invalid footer length {footer_len}"),
+            })?;
+        self.fetch(footer_start..size).await?;
+        self.file.seek(SeekFrom::Start(0)).unwrap();
+        read_file_metadata(&mut self.file).context(ArrowSnafu {
+            path: self.uri.clone(),
+        })
+    }
+
+    /// Reads the schema message at the start of an IPC stream.
+    pub async fn stream_metadata(&mut self) -> super::Result<StreamMetadata> {
+        self.file.seek(SeekFrom::Start(0)).unwrap();
+        match read_stream_metadata(&mut self.file) {
+            Ok(metadata) => Ok(metadata),
+            // The schema message didn't fit in the speculative head read.
+            Err(_) if !self.file.missing(0..self.file.size()).is_empty() => {
+                self.fetch_all().await?;
+                self.file.seek(SeekFrom::Start(0)).unwrap();
+                read_stream_metadata(&mut self.file).context(ArrowSnafu {
+                    path: self.uri.clone(),
+                })
+            }
+            Err(err) => Err(err).context(ArrowSnafu {
+                path: self.uri.clone(),
+            }),
+        }
+    }
+
+    pub async fn fetch_all(&mut self) -> super::Result<()> {
+        self.fetch(0..self.file.size()).await
+    }
+
+    /// Reads the header of the message starting at `offset`, fetching only its length prefix and
+    /// flatbuffer metadata. Returns `None` at the end of a stream.
+    pub async fn message_info(&mut self, offset: usize) -> super::Result<Option<MessageInfo>> {
+        self.fetch(offset..offset.saturating_add(MESSAGE_HEADER_READ_SIZE))
+            .await?;
+        let Some(meta) = self.message_metadata_range(offset)? else {
+            return Ok(None);
+        };
+        self.fetch(meta.clone()).await?;
+        self.parse_message_metadata(offset, meta).map(Some)
+    }
+
+    /// Returns the byte range of the flatbuffer metadata of the message starting at `offset`.
+    fn message_metadata_range(&self, offset: usize) -> super::Result<Option<Range<usize>>> {
+        let read_prefix = |start: usize| self.file.get(start..start + 4);
+        // Like arrow2's stream reader, treat a stream without the end-of-stream marker as ended.
+        let Some(mut prefix) = read_prefix(offset) else {
+            return Ok(None);
+        };
+        let mut meta_start = offset + 4;
+        if prefix == CONTINUATION_MARKER {
+            let Some(length) = read_prefix(meta_start) else {
+                return Ok(None);
+            };
+            prefix = length;
+            meta_start += 4;
+        }
+        match i32::from_le_bytes(prefix.try_into().unwrap()) {
+            0 => Ok(None),
+            meta_len => usize::try_from(meta_len)
+                .map(|meta_len| Some(meta_start..meta_start + meta_len))
+                .map_err(|_| self.invalid_message(offset, format!("invalid length {meta_len}"))),
+        }
+    }
+
+    fn parse_message_metadata(
+        &self,
+        offset: usize,
+        meta: Range<usize>,
+    ) -> super::Result<MessageInfo> {
+        let invalid = |message: String| self.invalid_message(offset, message);
+        let meta_end = meta.end;
+        let meta = self
+            .file
+            .get(meta)
+            .ok_or_else(|| invalid("truncated header".to_string()))?;
+        let message = MessageRef::read_as_root(&meta).map_err(|err| invalid(err.to_string()))?;
+        let body_len = message
+            .body_length()
+            .map_err(|err| invalid(err.to_string()))?;
+        let body_len = usize::try_from(body_len)
+            .map_err(|_| invalid(format!("invalid body length {body_len}")))?;
+        let num_rows = match message.header().map_err(|err| invalid(err.to_string()))? {
+            Some(MessageHeaderRef::RecordBatch(batch)) => {
+                let length = batch.length().map_err(|err| invalid(err.to_string()))?;
+                Some(
+                    usize::try_from(length)
+                        .map_err(|_| invalid(format!("invalid row count {length}")))?,
+                )
+            }
+            _ => None,
+        };
+        Ok(MessageInfo {
+            end: meta_end + body_len,
+            num_rows,
+        })
+    }
+
+    fn invalid_message(&self, offset: usize, message: String) -> super::Error {
+        super::Error::InvalidIpcFile {
+            path: self.uri.clone(),
+            message: format!("{message} in message at offset {offset}"),
+        }
+    }
+}
+
+/// Whether any of the fields (or their children) are dictionary-encoded.
+pub(crate) fn has_dictionaries(fields: &[IpcField]) -> bool {
+    fields
+        .iter()
+        .any(|field| field.dictionary_id.is_some() || has_dictionaries(&field.fields))
+}
@@ -0,0 +1,56 @@
+pub mod pylib {
+    use std::sync::Arc;
+
+    use daft_core::python::schema::PySchema;
+    use daft_io::{get_io_client, python::IOConfig, IOStatsContext};
+    use daft_table::python::PyTable;
+    use pyo3::{pyfunction, PyResult, Python};
+
+    #[pyfunction]
+    pub fn read_ipc(
+        py: Python,
+        uri: &str,
+        columns: Option<Vec<&str>>,
+        num_rows: Option<usize>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<PyTable> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_ipc: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            Ok(crate::read::read_ipc(
+                uri,
+                columns.as_deref(),
+                num_rows,
+                None,
+                io_client,
+                Some(io_stats),
+                multithreaded_io.unwrap_or(true),
+            )?
+            .into())
+        })
+    }
+
+    #[pyfunction]
+    pub fn read_ipc_schema(
+        py: Python,
+        uri: &str,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<PySchema> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_ipc_schema: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let schema = crate::schema::read_ipc_schema(uri, io_client, Some(io_stats))?;
+            Ok(Arc::new(schema).into())
+        })
+    }
+}
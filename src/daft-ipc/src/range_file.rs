@@ -0,0 +1,156 @@
+use std::{
+    io::{Read, Seek, SeekFrom},
+    ops::Range,
+};
+
+use bytes::Bytes;
+
+/// A `Read + Seek` view over a file of which only some byte ranges have been fetched.
+///
+/// This lets the synchronous arrow2 IPC decoders run over the handful of ranges (header, footer,
+/// record batch blocks) that we actually pulled from the object store. Reading a byte that was
+/// never fetched is an `UnexpectedEof` error rather than a silent zero.
+pub(crate) struct RangeFile {
+    size: usize,
+    // Sorted by start offset, non-overlapping.
+    ranges: Vec<(usize, Bytes)>,
+    position: usize,
+}
+
+impl RangeFile {
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            ranges: vec![],
+            position: 0,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the sub-ranges of `range` which have not been fetched yet.
+    pub fn missing(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let mut missing = vec![];
+        let mut start = range.start;
+        for (offset, bytes) in self.ranges.iter() {
+            if start >= range.end {
+                break;
+            }
+            let end = offset + bytes.len();
+            if end <= start {
+                continue;
+            }
+            if *offset > start {
+                missing.push(start..(*offset).min(range.end));
+            }
+            start = start.max(end);
+        }
+        if start < range.end {
+            missing.push(start..range.end);
+        }
+        missing
+    }
+
+    /// Adds the bytes fetched for `start..start + bytes.len()`, which must not overlap any range
+    /// that was already inserted.
+    pub fn insert(&mut self, start: usize, bytes: Bytes) {
+        if bytes.is_empty() {
+            return;
+        }
+        let idx = self.ranges.partition_point(|(offset, _)| *offset < start);
+        self.ranges.insert(idx, (start, bytes));
+    }
+
+    /// Returns the fetched bytes of `range`, if all of them were fetched.
+    pub fn get(&self, range: Range<usize>) -> Option<Vec<u8>> {
+        let mut out = Vec::with_capacity(range.len());
+        let mut position = range.start;
+        while position < range.end {
+            let (offset, bytes) = self.range_at(position)?;
+            let end = (offset + bytes.len()).min(range.end);
+            out.extend_from_slice(&bytes[position - offset..end - offset]);
+            position = end;
+        }
+        Some(out)
+    }
+
+    fn range_at(&self, position: usize) -> Option<(usize, &Bytes)> {
+        let idx = self
+            .ranges
+            .partition_point(|(offset, _)| *offset <= position);
+        let (offset, bytes) = self.ranges.get(idx.checked_sub(1)?)?;
+        (position < offset + bytes.len()).then_some((*offset, bytes))
+    }
+}
+
+impl Read for RangeFile {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.position >= self.size {
+            return Ok(0);
+        }
+        let (offset, bytes) = self.range_at(self.position).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!("byte {} of the file was not fetched", self.position),
+            )
+        })?;
+        let start = self.position - offset;
+        let len = buf.len().min(bytes.len() - start);
+        buf[..len].copy_from_slice(&bytes[start..start + len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+impl Seek for RangeFile {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset as i64),
+            SeekFrom::End(offset) => (self.size as i64).checked_add(offset),
+            SeekFrom::Current(offset) => (self.position as i64).checked_add(offset),
+        };
+        match position {
+            Some(position) if position >= 0 => {
+                self.position = position as usize;
+                Ok(position as u64)
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Seek, SeekFrom};
+
+    use bytes::Bytes;
+
+    use super::RangeFile;
+
+    #[test]
+    fn test_range_file_reads_only_fetched_ranges() {
+        let mut file = RangeFile::new(100);
+        file.insert(90, Bytes::from_static(&[9; 10]));
+        file.insert(0, Bytes::from_static(&[1; 10]));
+        assert_eq!(file.missing(0..100), vec![10..90]);
+        assert_eq!(file.missing(5..95), vec![10..90]);
+        assert!(file.missing(92..100).is_empty());
+
+        file.insert(10, Bytes::from_static(&[2; 5]));
+        assert_eq!(file.get(8..12), Some(vec![1, 1, 2, 2]));
+        assert_eq!(file.get(8..16), None);
+
+        let mut buf = [0; 4];
+        file.seek(SeekFrom::End(-2)).unwrap();
+        assert_eq!(file.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], &[9, 9]);
+
+        file.seek(SeekFrom::Start(13)).unwrap();
+        assert!(file.read_exact(&mut buf).is_err());
+    }
+}
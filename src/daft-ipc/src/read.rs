@@ -0,0 +1,535 @@
+use std::{
+    io::{Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
+
+use arrow2::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Schema as ArrowSchema,
+    io::ipc::read::{
+        read_batch, read_file_dictionaries, read_stream_metadata, StreamReader, StreamState,
+    },
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::{schema::Schema, utils::arrow::cast_array_for_daft_if_needed, Series};
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{StreamExt, TryStreamExt};
+use snafu::{futures::TryFutureExt, ResultExt};
+
+use crate::{
+    metadata::{has_dictionaries, IpcSource},
+    ArrowSnafu,
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_ipc(
+    uri: &str,
+    columns: Option<&[&str]>,
+    num_rows: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> DaftResult<Table> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        read_ipc_single(
+            uri,
+            columns.map(|cols| cols.iter().map(|col| col.to_string()).collect()),
+            num_rows,
+            predicate,
+            io_client,
+            io_stats,
+        )
+        .await
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_ipc_bulk(
+    uris: &[&str],
+    columns: Option<&[&str]>,
+    num_rows: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Table>> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    let owned_columns: Option<Vec<String>> =
+        columns.map(|cols| cols.iter().map(|col| col.to_string()).collect());
+    let tables = runtime_handle.block_on(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
+            let (uri, columns, predicate, io_client, io_stats) = (
+                uri.to_string(),
+                owned_columns.clone(),
+                predicate.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_ipc_single(&uri, columns, num_rows, predicate, io_client, io_stats).await
+            })
+            .context(crate::JoinSnafu)
+        }));
+        task_stream
+            .buffered(num_parallel_tasks)
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+async fn read_ipc_single(
+    uri: &str,
+    columns: Option<Vec<String>>,
+    num_rows: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Table> {
+    let original_columns = columns.clone();
+    let original_num_rows = num_rows;
+    let mut columns = columns;
+    let mut num_rows = num_rows;
+    if let Some(ref pred) = predicate {
+        // The limit can only be applied after filtering, which may need extra columns.
+        num_rows = None;
+        if let Some(req_columns) = columns.as_mut() {
+            for c in get_required_columns(pred) {
+                if !req_columns.contains(&c) {
+                    req_columns.push(c);
+                }
+            }
+        }
+    }
+
+    let mut source = IpcSource::open(uri, io_client, io_stats).await?;
+    let mut table = if source.is_file_format() {
+        read_file_format(&mut source, columns.as_deref(), num_rows).await?
+    } else {
+        read_stream_format(&mut source, columns.as_deref(), num_rows).await?
+    };
+
+    if let Some(predicate) = predicate {
+        table = table.filter(&[predicate])?;
+        if let Some(oc) = original_columns {
+            table = table.get_columns(oc.as_slice())?;
+        }
+        if let Some(nr) = original_num_rows {
+            table = table.head(nr)?;
+        }
+    }
+    Ok(table)
+}
+
+/// Reads the random-access IPC file format, fetching only the record batch blocks that are needed
+/// to satisfy the limit.
+async fn read_file_format(
+    source: &mut IpcSource,
+    columns: Option<&[String]>,
+    num_rows: Option<usize>,
+) -> DaftResult<Table> {
+    let metadata = source.file_metadata().await?;
+    let projection = projection_indices(&source.uri, &metadata.schema, columns)?;
+    if projection.as_ref().is_some_and(|p| p.is_empty()) {
+        // No column needs to be decoded, but the row count is in the record batch headers.
+        let offsets = metadata
+            .blocks
+            .iter()
+            .map(|block| block_range(&source.uri, block.offset, block.meta_data_length, 0))
+            .collect::<crate::Result<Vec<_>>>()?;
+        source.fetch_ranges(offsets.clone()).await?;
+        let mut total_rows = 0;
+        for offset in offsets {
+            if num_rows.is_some_and(|num_rows| total_rows >= num_rows) {
+                break;
+            }
+            total_rows += source
+                .message_info(offset.start)
+                .await?
+                .and_then(|info| info.num_rows)
+                .unwrap_or(0);
+        }
+        return Table::new_with_size(
+            Schema::empty(),
+            vec![],
+            total_rows.min(num_rows.unwrap_or(usize::MAX)),
+        );
+    }
+
+    let mut message_scratch = vec![];
+    let mut data_scratch = vec![];
+    let dictionaries = if has_dictionaries(&metadata.ipc_schema.fields) {
+        // arrow2 doesn't expose where the dictionary batches live, so read the whole file.
+        source.fetch_all().await?;
+        read_file_dictionaries(&mut source.file, &metadata, &mut data_scratch).context(
+            ArrowSnafu {
+                path: source.uri.clone(),
+            },
+        )?
+    } else {
+        Default::default()
+    };
+
+    let block_ranges = metadata
+        .blocks
+        .iter()
+        .map(|block| {
+            block_range(
+                &source.uri,
+                block.offset,
+                block.meta_data_length,
+                block.body_length,
+            )
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
+    if num_rows.is_none()
+        && let Some(start) = block_ranges.iter().map(|r| r.start).min()
+        && let Some(end) = block_ranges.iter().map(|r| r.end).max()
+    {
+        // Without a limit every block is needed, so fetch them all with a single request.
+        source.fetch(start..end).await?;
+    }
+
+    let mut remaining = num_rows;
+    let mut chunks = Vec::with_capacity(block_ranges.len());
+    for (index, range) in block_ranges.into_iter().enumerate() {
+        if remaining == Some(0) {
+            break;
+        }
+        source.fetch(range).await?;
+        let chunk = read_batch(
+            &mut source.file,
+            &dictionaries,
+            &metadata,
+            projection.as_deref(),
+            remaining,
+            index,
+            &mut message_scratch,
+            &mut data_scratch,
+        )
+        .context(ArrowSnafu {
+            path: source.uri.clone(),
+        })?;
+        if let Some(remaining) = remaining.as_mut() {
+            *remaining = remaining.saturating_sub(chunk.len());
+        }
+        chunks.push(chunk);
+    }
+    chunks_to_table(&metadata.schema, projection.as_deref(), chunks, columns)
+}
+
+/// Reads the IPC stream format. Stream messages aren't indexed, so with a limit the message headers
+/// are walked first to find how much of the stream is needed, which is then decoded in order.
+async fn read_stream_format(
+    source: &mut IpcSource,
+    columns: Option<&[String]>,
+    num_rows: Option<usize>,
+) -> DaftResult<Table> {
+    let metadata = source.stream_metadata().await?;
+    let schema = metadata.schema.clone();
+    let projection = projection_indices(&source.uri, &schema, columns)?;
+    let is_empty_projection = projection.as_ref().is_some_and(|p| p.is_empty());
+    if is_empty_projection || num_rows.is_some() {
+        let (total_rows, end) = walk_stream_messages(source, num_rows).await?;
+        if is_empty_projection {
+            return Table::new_with_size(
+                Schema::empty(),
+                vec![],
+                total_rows.min(num_rows.unwrap_or(usize::MAX)),
+            );
+        }
+        source.fetch(0..end).await?;
+    } else {
+        source.fetch_all().await?;
+    }
+    source.file.seek(SeekFrom::Start(0)).unwrap();
+    let path = source.uri.clone();
+    // Re-read the schema message so that the reader is positioned at the first batch.
+    let metadata = read_stream_metadata(&mut source.file).context(ArrowSnafu { path: &path })?;
+    let reader = StreamReader::new(&mut source.file, metadata, projection.clone());
+
+    let mut remaining = num_rows.unwrap_or(usize::MAX);
+    let mut chunks = vec![];
+    for state in reader {
+        match state.context(ArrowSnafu { path: &path })? {
+            StreamState::Some(chunk) => {
+                remaining = remaining.saturating_sub(chunk.len());
+                chunks.push(chunk);
+                if remaining == 0 {
+                    break;
+                }
+            }
+            StreamState::Waiting => break,
+        }
+    }
+    let table = chunks_to_table(&schema, projection.as_deref(), chunks, columns)?;
+    match num_rows {
+        Some(num_rows) => table.head(num_rows),
+        None => Ok(table),
+    }
+}
+
+/// Walks the message headers of an IPC stream, without fetching the message bodies, until the
+/// stream ends or `num_rows` rows were seen. Returns the number of rows seen and the offset of the
+/// end of the last message walked.
+async fn walk_stream_messages(
+    source: &mut IpcSource,
+    num_rows: Option<usize>,
+) -> DaftResult<(usize, usize)> {
+    let mut total_rows = 0;
+    let mut offset = 0;
+    // The first message is the schema.
+    while let Some(info) = source.message_info(offset).await? {
+        offset = info.end;
+        total_rows += info.num_rows.unwrap_or(0);
+        if num_rows.is_some_and(|num_rows| total_rows >= num_rows) {
+            break;
+        }
+    }
+    Ok((total_rows, offset))
+}
+
+/// Resolves the requested column names to the sorted field indices expected by the arrow2 readers.
+fn projection_indices(
+    uri: &str,
+    schema: &ArrowSchema,
+    columns: Option<&[String]>,
+) -> DaftResult<Option<Vec<usize>>> {
+    columns
+        .map(|columns| {
+            let mut indices = columns
+                .iter()
+                .map(|name| {
+                    schema
+                        .fields
+                        .iter()
+                        .position(|field| &field.name == name)
+                        .ok_or_else(|| {
+                            DaftError::FieldNotFound(format!(
+                                "Column {name} not found in Arrow IPC file {uri}"
+                            ))
+                        })
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            indices.sort_unstable();
+            indices.dedup();
+            Ok(indices)
+        })
+        .transpose()
+}
+
+/// Byte range of a record batch block, i.e. its message header followed by its body.
+fn block_range(
+    uri: &str,
+    offset: i64,
+    meta_data_length: i32,
+    body_length: i64,
+) -> crate::Result<Range<usize>> {
+    let start = usize::try_from(offset).ok();
+    let len = usize::try_from(meta_data_length)
+        .ok()
+        .zip(usize::try_from(body_length).ok())
+        .map(|(meta, body)| meta + body);
+    match (start, len) {
+        (Some(start), Some(len)) => Ok(start..start + len),
+        _ => Err(crate::Error::InvalidIpcFile {
+            path: uri.to_string(),
+            message: format!("invalid record batch block at offset {}", offset),
+        }),
+    }
+}
+
+/// Converts the decoded record batches to a [`Table`] with the columns in the requested order.
+fn chunks_to_table(
+    schema: &ArrowSchema,
+    projection: Option<&[usize]>,
+    chunks: Vec<Chunk<Box<dyn Array>>>,
+    columns: Option<&[String]>,
+) -> DaftResult<Table> {
+    let fields = match projection {
+        Some(projection) => projection
+            .iter()
+            .map(|i| schema.fields[*i].clone())
+            .collect(),
+        None => schema.fields.clone(),
+    };
+    let daft_schema = Arc::new(Schema::try_from(&ArrowSchema::from(fields))?);
+    let tables = chunks
+        .into_iter()
+        .map(|chunk| {
+            let all_series = chunk
+                .into_arrays()
+                .into_iter()
+                .zip(daft_schema.fields.values())
+                .map(|(array, field)| {
+                    Series::try_from_field_and_arrow_array(
+                        Arc::new(field.clone()),
+                        cast_array_for_daft_if_needed(array),
+                    )
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Table::new(daft_schema.clone(), all_series)
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    let table = if tables.is_empty() {
+        Table::empty(Some(daft_schema))?
+    } else {
+        Table::concat(&tables)?
+    };
+    match columns {
+        Some(columns) => table.get_columns(columns),
+        None => Ok(table),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow2::{
+        array::{Int64Array, Utf8Array},
+        chunk::Chunk,
+        datatypes::{DataType, Field, Schema},
+        io::ipc::write::{FileWriter, StreamWriter, WriteOptions},
+    };
+    use common_error::DaftResult;
+    use daft_io::{get_runtime, IOClient, IOConfig};
+
+    use super::{read_ipc, read_stream_format};
+    use crate::{metadata::IpcSource, read_ipc_schema};
+
+    fn write_ipc(path: &std::path::Path, stream: bool) {
+        let schema = Schema::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let chunks = (0..3).map(|batch| {
+            let a = Int64Array::from_iter((0..10).map(|i| Some(batch * 10 + i)));
+            let b = Utf8Array::<i32>::from_iter((0..10).map(|i| Some(format!("{batch}-{i}"))));
+            Chunk::new(vec![a.boxed(), b.boxed()])
+        });
+        let file = std::fs::File::create(path).unwrap();
+        let options = WriteOptions { compression: None };
+        if stream {
+            let mut writer = StreamWriter::new(file, options);
+            writer.start(&schema, None).unwrap();
+            chunks.for_each(|chunk| writer.write(&chunk, None).unwrap());
+            writer.finish().unwrap();
+        } else {
+            let mut writer = FileWriter::try_new(file, schema, None, options).unwrap();
+            chunks.for_each(|chunk| writer.write(&chunk, None).unwrap());
+            writer.finish().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_ipc_read_local() -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        for (name, stream) in [("data.arrow", false), ("data.arrows", true)] {
+            let path = dir.path().join(name);
+            write_ipc(&path, stream);
+            let uri = path.to_str().unwrap();
+
+            let schema = read_ipc_schema(uri, io_client.clone(), None)?;
+            assert_eq!(schema.names(), vec!["a", "b"]);
+
+            let table = read_ipc(uri, None, None, None, io_client.clone(), None, true)?;
+            assert_eq!(table.len(), 30);
+            assert_eq!(table.schema.as_ref(), &schema);
+
+            let table = read_ipc(
+                uri,
+                Some(&["b", "a"]),
+                Some(15),
+                None,
+                io_client.clone(),
+                None,
+                true,
+            )?;
+            assert_eq!(table.len(), 15);
+            assert_eq!(table.column_names(), vec!["b", "a"]);
+            let a = table.get_column("a")?.to_arrow();
+            assert_eq!(
+                a.as_any().downcast_ref::<Int64Array>().unwrap(),
+                &Int64Array::from_iter((0..15).map(Some))
+            );
+
+            assert!(
+                read_ipc(uri, Some(&["c"]), None, None, io_client.clone(), None, true).is_err()
+            );
+
+            let table = read_ipc(uri, Some(&[]), None, None, io_client.clone(), None, true)?;
+            assert_eq!(table.num_columns(), 0);
+            assert_eq!(table.len(), 30);
+            let table = read_ipc(
+                uri,
+                Some(&[]),
+                Some(15),
+                None,
+                io_client.clone(),
+                None,
+                true,
+            )?;
+            assert_eq!(table.len(), 15);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ipc_read_stream_with_limit_stops_early() -> DaftResult<()> {
+        // Large enough that the stream isn't fetched with a single request.
+        const BATCH_ROWS: i64 = 100_000;
+        let schema = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.arrows");
+        let mut writer = StreamWriter::new(
+            std::fs::File::create(&path).unwrap(),
+            WriteOptions { compression: None },
+        );
+        writer.start(&schema, None).unwrap();
+        for batch in 0..3 {
+            let a = Int64Array::from_iter((0..BATCH_ROWS).map(|i| Some(batch * BATCH_ROWS + i)));
+            writer.write(&Chunk::new(vec![a.boxed()]), None).unwrap();
+        }
+        writer.finish().unwrap();
+        let uri = path.to_str().unwrap();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        get_runtime(true)?.block_on(async {
+            let mut source = IpcSource::open(uri, io_client.clone(), None).await?;
+            let table = read_stream_format(&mut source, None, Some(10)).await?;
+            assert_eq!(table.len(), 10);
+            // Only the first record batch is fetched.
+            let size = source.file.size();
+            assert_eq!(source.file.missing(0..size).len(), 1);
+            assert!(source.file.missing(0..size)[0].len() > 2 * BATCH_ROWS as usize * 8);
+
+            let mut source = IpcSource::open(uri, io_client.clone(), None).await?;
+            let table = read_stream_format(&mut source, Some(&[]), Some(150_000)).await?;
+            assert_eq!(table.len(), 150_000);
+            // Counting rows only needs the message headers.
+            assert!(source.file.missing(0..size).len() > 1);
+            DaftResult::Ok(())
+        })?;
+
+        let table = read_ipc(uri, None, Some(150_000), None, io_client, None, true)?;
+        assert_eq!(table.len(), 150_000);
+        let a = table.get_column("a")?.to_arrow();
+        assert_eq!(
+            a.as_any().downcast_ref::<Int64Array>().unwrap(),
+            &Int64Array::from_iter((0..150_000).map(Some))
+        );
+        Ok(())
+    }
+}
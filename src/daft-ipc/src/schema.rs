@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::schema::Schema;
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+
+use crate::metadata::IpcSource;
+
+pub fn read_ipc_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let runtime_handle = get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async { read_ipc_schema_single(uri, io_client, io_stats).await })
+}
+
+pub(crate) async fn read_ipc_schema_single(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let mut source = IpcSource::open(uri, io_client, io_stats).await?;
+    let arrow_schema = if source.is_file_format() {
+        source.file_metadata().await?.schema
+    } else {
+        source.stream_metadata().await?.schema
+    };
+    Schema::try_from(&arrow_schema)
+}
@@ -20,7 +20,7 @@ use json_deserializer::{Number, Value};
 
 /// Deserialize chunk of JSON records into a chunk of Arrow2 arrays.
 pub(crate) fn deserialize_records(
-    records: Vec<Value>,
+    records: &[Value],
     schema: &Schema,
     schema_is_projection: bool,
 ) -> Result<Vec<Box<dyn Array>>> {
@@ -31,7 +31,7 @@ pub(crate) fn deserialize_records(
         .map(|f| (&f.name, allocate_array(f, records.len())))
         .collect::<IndexMap<_, _>>();
 
-    for record in records {
+    for (row, record) in records.iter().enumerate() {
         match record {
             Value::Object(record) => {
                 for (key, value) in record.iter() {
@@ -45,6 +45,12 @@ pub(crate) fn deserialize_records(
                         return Err(Error::ExternalFormat(format!("unexpected key: '{key}'")));
                     }
                 }
+                // Keys missing from this record are null.
+                for arr in results.values_mut() {
+                    if arr.len() == row {
+                        arr.push_null();
+                    }
+                }
             }
             _ => {
                 return Err(Error::ExternalFormat(format!(
@@ -0,0 +1,319 @@
+use std::io::{self, Cursor};
+
+use futures::{stream::BoxStream, StreamExt};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+
+/// How the records of a JSON file are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JsonLayout {
+    /// Newline-delimited JSON, with a record per line.
+    Lines,
+    /// A single top-level JSON array, with a record per element.
+    Array,
+    /// Concatenated JSON documents, e.g. pretty-printed objects spanning multiple lines, with a
+    /// record per document.
+    Documents,
+}
+
+impl JsonLayout {
+    /// Detects the layout of a JSON file from its first non-blank line, or a prefix of it: a file
+    /// starting with an array is a JSON array, and a file whose first value doesn't end on its first
+    /// line holds multi-line documents. A first line cut short is read as documents, which also
+    /// splits well-formed newline-delimited JSON.
+    pub(crate) fn detect(first_line: &[u8]) -> Self {
+        let first_line = match first_line.iter().position(|b| !b.is_ascii_whitespace()) {
+            Some(start) => &first_line[start..],
+            None => return Self::Lines,
+        };
+        if first_line[0] == b'[' {
+            return Self::Array;
+        }
+        let mut splitter = JsonValueSplitter::new(Self::Documents);
+        let mut values = vec![];
+        // Anything but an unterminated value on the first line is left to the NDJSON reader, which
+        // can skip or keep malformed lines.
+        match splitter.push(first_line, &mut values) {
+            Ok(()) if splitter.depth > 0 || splitter.in_string => Self::Documents,
+            _ => Self::Lines,
+        }
+    }
+}
+
+/// The most bytes read ahead of the records to detect the layout of a JSON file, so that a minified
+/// file on a single line isn't buffered whole.
+const LAYOUT_DETECTION_BYTES: usize = 64 * 1024;
+
+/// Streams the raw text of the records of a JSON file, in any of the [`JsonLayout`]s, without
+/// reading the whole file into memory.
+pub(crate) async fn read_json_records<R>(
+    mut reader: R,
+) -> io::Result<BoxStream<'static, io::Result<String>>>
+where
+    R: AsyncBufRead + Unpin + Send + 'static,
+{
+    // Read up to the end of the first non-blank line, or up to `LAYOUT_DETECTION_BYTES`, to detect
+    // the layout, and replay it in front of the rest of the file.
+    let mut prefix = vec![];
+    let mut first_line_start = 0;
+    while prefix.len() < LAYOUT_DETECTION_BYTES {
+        let buf = reader.fill_buf().await?;
+        if buf.is_empty() {
+            break;
+        }
+        let buf = &buf[..buf.len().min(LAYOUT_DETECTION_BYTES - prefix.len())];
+        let (num_bytes, end_of_line) = match buf.iter().position(|b| *b == b'\n') {
+            Some(newline) => (newline + 1, true),
+            None => (buf.len(), false),
+        };
+        prefix.extend_from_slice(&buf[..num_bytes]);
+        reader.consume(num_bytes);
+        if end_of_line {
+            if !prefix[first_line_start..]
+                .iter()
+                .all(u8::is_ascii_whitespace)
+            {
+                break;
+            }
+            first_line_start = prefix.len();
+        }
+    }
+    let layout = JsonLayout::detect(&prefix[first_line_start..]);
+    let reader = Cursor::new(prefix).chain(reader);
+    Ok(match layout {
+        JsonLayout::Lines => tokio_stream::wrappers::LinesStream::new(reader.lines()).boxed(),
+        layout => split_json_values(reader, layout).boxed(),
+    })
+}
+
+fn split_json_values<R>(
+    mut reader: R,
+    layout: JsonLayout,
+) -> impl futures::Stream<Item = io::Result<String>> + Send
+where
+    R: AsyncBufRead + Unpin + Send + 'static,
+{
+    async_stream::try_stream! {
+        let mut splitter = JsonValueSplitter::new(layout);
+        let mut values = vec![];
+        loop {
+            let buf = reader.fill_buf().await?;
+            let num_bytes = buf.len();
+            if num_bytes == 0 {
+                splitter.finish(&mut values)?;
+            } else {
+                splitter.push(buf, &mut values)?;
+                reader.consume(num_bytes);
+            }
+            for value in values.drain(..) {
+                yield value;
+            }
+            if num_bytes == 0 {
+                break;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SplitterState {
+    BeforeArray,
+    BetweenValues,
+    InValue,
+    AfterArray,
+}
+
+/// Splits the bytes of a top-level JSON array or of concatenated JSON documents into the raw text of
+/// each element or document, tracking just enough of the JSON grammar to find where values end.
+struct JsonValueSplitter {
+    in_array: bool,
+    state: SplitterState,
+    /// Nesting depth of objects and arrays within the current value.
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    value: Vec<u8>,
+}
+
+impl JsonValueSplitter {
+    fn new(layout: JsonLayout) -> Self {
+        let in_array = layout == JsonLayout::Array;
+        Self {
+            in_array,
+            state: if in_array {
+                SplitterState::BeforeArray
+            } else {
+                SplitterState::BetweenValues
+            },
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            value: vec![],
+        }
+    }
+
+    fn push(&mut self, bytes: &[u8], values: &mut Vec<String>) -> io::Result<()> {
+        for &byte in bytes {
+            match self.state {
+                SplitterState::BeforeArray => match byte {
+                    b'[' => self.state = SplitterState::BetweenValues,
+                    byte if byte.is_ascii_whitespace() => {}
+                    _ => return Err(invalid_data("Expected a JSON array")),
+                },
+                SplitterState::AfterArray => {
+                    if !byte.is_ascii_whitespace() {
+                        return Err(invalid_data(
+                            "Unexpected data after the end of the JSON array",
+                        ));
+                    }
+                }
+                SplitterState::BetweenValues => match byte {
+                    byte if byte.is_ascii_whitespace() => {}
+                    b',' if self.in_array => {}
+                    b']' if self.in_array => self.state = SplitterState::AfterArray,
+                    _ => {
+                        self.state = SplitterState::InValue;
+                        self.push_value_byte(byte, values)?;
+                    }
+                },
+                SplitterState::InValue => self.push_value_byte(byte, values)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn push_value_byte(&mut self, byte: u8, values: &mut Vec<String>) -> io::Result<()> {
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+            }
+            self.value.push(byte);
+            return Ok(());
+        }
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                self.value.push(byte);
+            }
+            b'{' | b'[' => {
+                self.depth += 1;
+                self.value.push(byte);
+            }
+            b'}' | b']' if self.depth > 0 => {
+                self.depth -= 1;
+                self.value.push(byte);
+                if self.depth == 0 {
+                    self.emit(values)?;
+                }
+            }
+            // The end of a scalar at the top level.
+            b']' if self.in_array => {
+                self.emit(values)?;
+                self.state = SplitterState::AfterArray;
+            }
+            b',' if self.depth == 0 && self.in_array => self.emit(values)?,
+            byte if self.depth == 0 && byte.is_ascii_whitespace() => self.emit(values)?,
+            _ => self.value.push(byte),
+        }
+        Ok(())
+    }
+
+    fn emit(&mut self, values: &mut Vec<String>) -> io::Result<()> {
+        let value = String::from_utf8(std::mem::take(&mut self.value))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        values.push(value);
+        self.state = SplitterState::BetweenValues;
+        Ok(())
+    }
+
+    fn finish(&mut self, values: &mut Vec<String>) -> io::Result<()> {
+        if self.in_string || self.depth > 0 {
+            return Err(invalid_data("Unexpected end of JSON data"));
+        }
+        if self.state == SplitterState::InValue {
+            self.emit(values)?;
+        }
+        if self.in_array && self.state != SplitterState::AfterArray {
+            return Err(invalid_data(
+                "Unexpected end of JSON data, the JSON array isn't closed",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use tokio::io::AsyncReadExt;
+
+    use super::{read_json_records, JsonLayout, LAYOUT_DETECTION_BYTES};
+
+    async fn records(data: &'static str) -> std::io::Result<Vec<String>> {
+        read_json_records(data.as_bytes())
+            .await?
+            .try_collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_json_layouts() -> std::io::Result<()> {
+        assert_eq!(JsonLayout::detect(b"{\"a\": 1}\n"), JsonLayout::Lines);
+        assert_eq!(
+            records("{\"a\": 1}\n{\"a\": 2}\n").await?,
+            vec!["{\"a\": 1}".to_string(), "{\"a\": 2}".to_string()]
+        );
+        assert_eq!(JsonLayout::detect(b" [{\"a\": 1},\n"), JsonLayout::Array);
+        assert_eq!(
+            records("\n [{\"a\": \"]\"},\n {\"a\": [2]}, 3]\n").await?,
+            vec![
+                "{\"a\": \"]\"}".to_string(),
+                "{\"a\": [2]}".to_string(),
+                "3".to_string()
+            ]
+        );
+        assert_eq!(JsonLayout::detect(b"{\n"), JsonLayout::Documents);
+        assert_eq!(
+            records("{\n  \"a\": \"}\\\"\"\n}\n{\n  \"a\": 2\n}{\"a\": 3}").await?,
+            vec![
+                "{\n  \"a\": \"}\\\"\"\n}".to_string(),
+                "{\n  \"a\": 2\n}".to_string(),
+                "{\"a\": 3}".to_string()
+            ]
+        );
+        assert!(records("[{\"a\": 1}").await.is_err());
+        assert!(records("[{\"a\": 1}] {}").await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_layout_detection_is_bounded() -> std::io::Result<()> {
+        // A JSON array that never ends on its first line is still streamed element by element.
+        let reader = tokio::io::BufReader::new(b"[{\"a\": 1},".chain(tokio::io::repeat(b' ')));
+        let mut records = read_json_records(reader).await?;
+        assert_eq!(records.try_next().await?, Some("{\"a\": 1}".to_string()));
+
+        // Newline-delimited JSON with a first line longer than the detection prefix.
+        let long = "x".repeat(LAYOUT_DETECTION_BYTES);
+        let data = format!("{{\"a\": \"{long}\"}}\n{{\"a\": \"b\"}}\n");
+        let records: Vec<String> = read_json_records(std::io::Cursor::new(data.into_bytes()))
+            .await?
+            .try_collect()
+            .await?;
+        assert_eq!(
+            records,
+            vec![
+                format!("{{\"a\": \"{long}\"}}"),
+                "{\"a\": \"b\"}".to_string()
+            ]
+        );
+        Ok(())
+    }
+}
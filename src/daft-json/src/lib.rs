@@ -8,17 +8,20 @@ use snafu::Snafu;
 
 mod decoding;
 mod inference;
+mod layout;
 pub mod options;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod read;
 pub mod schema;
+pub mod write;
 
 // pub use metadata::read_json_schema_bulk;
 pub use options::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
-pub use read::{read_json, read_json_bulk};
+pub use read::{read_json, read_json_bulk, stream_json};
+pub use write::write_json;
 
 #[derive(Debug, Snafu)]
 pub enum Error {
@@ -69,5 +72,6 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_class::<JsonReadOptions>()?;
     parent.add_wrapped(wrap_pyfunction!(python::pylib::read_json))?;
     parent.add_wrapped(wrap_pyfunction!(python::pylib::read_json_schema))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::write_json))?;
     Ok(())
 }
@@ -1,4 +1,5 @@
 use daft_core::{impl_bincode_py_state_serialization, schema::SchemaRef};
+use daft_decoding::parse_mode::{ParseMode, DEFAULT_CORRUPT_RECORD_COLUMN_NAME};
 use daft_dsl::ExprRef;
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "python")]
@@ -19,6 +20,10 @@ pub struct JsonConvertOptions {
     pub include_columns: Option<Vec<String>>,
     pub schema: Option<SchemaRef>,
     pub predicate: Option<ExprRef>,
+    /// How to handle malformed lines.
+    pub mode: ParseMode,
+    /// The name of the column holding the malformed lines captured with [`ParseMode::Permissive`].
+    pub corrupt_record_column: String,
 }
 
 impl JsonConvertOptions {
@@ -33,6 +38,8 @@ impl JsonConvertOptions {
             include_columns,
             schema,
             predicate,
+            mode: ParseMode::FailFast,
+            corrupt_record_column: DEFAULT_CORRUPT_RECORD_COLUMN_NAME.to_string(),
         }
     }
 
@@ -54,6 +61,17 @@ impl JsonConvertOptions {
     pub fn with_predicate(self, predicate: Option<ExprRef>) -> Self {
         Self { predicate, ..self }
     }
+
+    pub fn with_mode(self, mode: ParseMode) -> Self {
+        Self { mode, ..self }
+    }
+
+    pub fn with_corrupt_record_column(self, corrupt_record_column: String) -> Self {
+        Self {
+            corrupt_record_column,
+            ..self
+        }
+    }
 }
 
 impl Default for JsonConvertOptions {
@@ -73,21 +91,39 @@ impl JsonConvertOptions {
     /// * `include_columns` - The names of the columns that should be kept, e.g. via a projection.
     /// * `schema` - The names and dtypes for the JSON columns.
     /// * `predicate` - Expression to filter rows applied before limit.
+    /// * `mode` - How to handle malformed lines, one of "fail_fast", "drop_malformed" or "permissive".
+    /// * `corrupt_record_column` - The name of the column holding the malformed lines in permissive mode.
 
     #[new]
-    #[pyo3(signature = (limit=None, include_columns=None, schema=None, predicate=None))]
+    #[pyo3(signature = (
+        limit=None,
+        include_columns=None,
+        schema=None,
+        predicate=None,
+        mode=None,
+        corrupt_record_column=None
+    ))]
     pub fn new(
         limit: Option<usize>,
         include_columns: Option<Vec<String>>,
         schema: Option<PySchema>,
         predicate: Option<PyExpr>,
-    ) -> Self {
-        Self::new_internal(
+        mode: Option<&str>,
+        corrupt_record_column: Option<String>,
+    ) -> PyResult<Self> {
+        let options = Self::new_internal(
             limit,
             include_columns,
             schema.map(|s| s.into()),
             predicate.map(|p| p.expr.into()),
         )
+        .with_mode(mode.map(str::parse).transpose()?.unwrap_or_default());
+        Ok(match corrupt_record_column {
+            Some(corrupt_record_column) => {
+                options.with_corrupt_record_column(corrupt_record_column)
+            }
+            None => options,
+        })
     }
 
     #[getter]
@@ -105,6 +141,16 @@ impl JsonConvertOptions {
         Ok(self.schema.as_ref().map(|s| s.clone().into()))
     }
 
+    #[getter]
+    pub fn get_mode(&self) -> PyResult<ParseMode> {
+        Ok(self.mode)
+    }
+
+    #[getter]
+    pub fn get_corrupt_record_column(&self) -> PyResult<String> {
+        Ok(self.corrupt_record_column.clone())
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
         match op {
             CompareOp::Eq => self == other,
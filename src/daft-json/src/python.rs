@@ -61,10 +61,38 @@ pub mod pylib {
                 uri,
                 parse_options,
                 max_bytes,
+                false,
                 io_client,
                 Some(io_stats),
             )?;
             Ok(Arc::new(schema).into())
         })
     }
+
+    #[pyfunction]
+    pub fn write_json(
+        py: Python,
+        table: &PyTable,
+        uri: &str,
+        line_delimited: Option<bool>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<()> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("write_json: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            Ok(crate::write::write_json(
+                &table.table,
+                uri,
+                line_delimited.unwrap_or(true),
+                io_client,
+                Some(io_stats),
+                multithreaded_io.unwrap_or(true),
+            )?)
+        })
+    }
 }
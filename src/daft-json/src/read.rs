@@ -1,11 +1,17 @@
-use std::{collections::HashMap, num::NonZeroUsize, sync::Arc};
+use std::{collections::HashMap, io::SeekFrom, num::NonZeroUsize, ops::Range, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
-use daft_core::{schema::Schema, utils::arrow::cast_array_for_daft_if_needed, Series};
+use daft_core::{
+    datatypes::BooleanArray,
+    schema::{Schema, SchemaRef},
+    utils::arrow::cast_array_for_daft_if_needed,
+    IntoSeries, Series,
+};
+use daft_decoding::parse_mode::{corrupt_record_array, corrupt_record_field, ParseMode};
 use daft_dsl::optimization::get_required_columns;
 use daft_io::{get_runtime, GetResult, IOClient, IOStatsRef};
 use daft_table::Table;
-use futures::{Stream, StreamExt, TryStreamExt};
+use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 use snafu::{
     futures::{try_future::Context, TryFutureExt, TryStreamExt as _},
@@ -13,16 +19,21 @@ use snafu::{
 };
 use tokio::{
     fs::File,
-    io::{AsyncBufRead, AsyncBufReadExt, BufReader},
+    io::{AsyncBufRead, AsyncReadExt, AsyncSeekExt, BufReader},
     task::JoinHandle,
 };
 use tokio_util::io::StreamReader;
 
-use crate::{decoding::deserialize_records, ArrowSnafu, ChunkSnafu};
+use crate::{
+    decoding::deserialize_records,
+    layout::{read_json_records, JsonLayout},
+    ArrowSnafu, ChunkSnafu,
+};
 use crate::{
     schema::read_json_schema_single, JsonConvertOptions, JsonParseOptions, JsonReadOptions,
 };
 use daft_compression::CompressionCodec;
+use json_deserializer::Value;
 
 trait LineChunkStream = Stream<Item = super::Result<Vec<String>>>;
 trait ColumnArrayChunkStream = Stream<
@@ -58,6 +69,7 @@ pub fn read_json(
             convert_options,
             parse_options,
             read_options,
+            None,
             io_client,
             io_stats,
             max_chunks_in_flight,
@@ -66,12 +78,51 @@ pub fn read_json(
     })
 }
 
+/// Streams a JSON file as [`Table`]s of a chunk of records each, rather than reading it into a
+/// single [`Table`].
+///
+/// The file is only read as the stream is polled, with at most `max_chunks_in_flight` chunks read
+/// ahead of the consumer, so files larger than memory can be processed with a bounded footprint.
+/// The size of each chunk is set by [`JsonReadOptions::chunk_size`]. The stream must be polled from
+/// within a Tokio runtime.
+#[allow(clippy::too_many_arguments)]
+pub fn stream_json(
+    uri: String,
+    convert_options: Option<JsonConvertOptions>,
+    parse_options: Option<JsonParseOptions>,
+    read_options: Option<JsonReadOptions>,
+    byte_range: Option<Range<usize>>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    max_chunks_in_flight: Option<usize>,
+) -> BoxStream<'static, DaftResult<Table>> {
+    async_stream::try_stream! {
+        let (tables, _) = read_json_single_into_table_stream(
+            uri.as_str(),
+            convert_options,
+            parse_options,
+            read_options,
+            byte_range,
+            io_client,
+            io_stats,
+            max_chunks_in_flight,
+        )
+        .await?;
+        futures::pin_mut!(tables);
+        while let Some(table) = tables.try_next().await? {
+            yield table;
+        }
+    }
+    .boxed()
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn read_json_bulk(
     uris: &[&str],
     convert_options: Option<JsonConvertOptions>,
     parse_options: Option<JsonParseOptions>,
     read_options: Option<JsonReadOptions>,
+    byte_ranges: Option<Vec<Option<Range<usize>>>>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
     multithreaded_io: bool,
@@ -82,12 +133,21 @@ pub fn read_json_bulk(
     let _rt_guard = runtime_handle.enter();
     let tables = runtime_handle.block_on(async move {
         // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
-        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
-            let (uri, convert_options, parse_options, read_options, io_client, io_stats) = (
+        let task_stream = futures::stream::iter(uris.iter().enumerate().map(|(i, uri)| {
+            let (
+                uri,
+                convert_options,
+                parse_options,
+                read_options,
+                byte_range,
+                io_client,
+                io_stats,
+            ) = (
                 uri.to_string(),
                 convert_options.clone(),
                 parse_options.clone(),
                 read_options.clone(),
+                byte_ranges.as_ref().and_then(|ranges| ranges[i].clone()),
                 io_client.clone(),
                 io_stats.clone(),
             );
@@ -97,6 +157,7 @@ pub fn read_json_bulk(
                     convert_options,
                     parse_options,
                     read_options,
+                    byte_range,
                     io_client,
                     io_stats,
                     max_chunks_in_flight,
@@ -177,15 +238,17 @@ fn assert_stream_send<'u, R>(
     s
 }
 
-async fn read_json_single_into_table(
+#[allow(clippy::too_many_arguments)]
+async fn read_json_single_into_table_stream(
     uri: &str,
     convert_options: Option<JsonConvertOptions>,
     parse_options: Option<JsonParseOptions>,
     read_options: Option<JsonReadOptions>,
+    byte_range: Option<Range<usize>>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
     max_chunks_in_flight: Option<usize>,
-) -> DaftResult<Table> {
+) -> DaftResult<(impl Stream<Item = DaftResult<Table>> + Send, SchemaRef)> {
     let predicate = convert_options.as_ref().and_then(|p| p.predicate.clone());
 
     let limit = convert_options.as_ref().and_then(|opts| opts.limit);
@@ -211,12 +274,19 @@ async fn read_json_single_into_table(
             Some(co)
         }
     };
+    // Malformed lines are dropped after they're read, so the limit can't be applied to the stream.
+    let convert_options_with_predicate_columns =
+        convert_options_with_predicate_columns.map(|co| match co.mode {
+            ParseMode::DropMalformed => co.with_limit(None),
+            _ => co,
+        });
 
     let (table_stream, schema) = read_json_single_into_stream(
         uri,
         convert_options_with_predicate_columns.unwrap_or_default(),
         parse_options.unwrap_or_default(),
         read_options,
+        byte_range,
         io_client,
         io_stats,
     )
@@ -247,39 +317,68 @@ async fn read_json_single_into_table(
             table
         }
     }));
-    let mut remaining_rows = limit.map(|limit| limit as i64);
-    let collected_tables = filtered_tables
-        .try_take_while(|result| {
-            match (result, remaining_rows) {
-                // Limit has been met, early-terminate.
-                (_, Some(rows_left)) if rows_left <= 0 => futures::future::ready(Ok(false)),
-                // Limit has not yet been met, update remaining limit slack and continue.
-                (Ok(table), Some(rows_left)) => {
-                    remaining_rows = Some(rows_left - table.len() as i64);
-                    futures::future::ready(Ok(true))
-                }
-                // (1) No limit, never early-terminate.
-                // (2) Encountered error, propagate error to try_collect to allow it to short-circuit.
-                (_, None) | (Err(_), _) => futures::future::ready(Ok(true)),
-            }
-        })
-        .try_collect::<Vec<_>>()
-        .await?
-        .into_iter()
-        .collect::<DaftResult<Vec<_>>>()?;
+    let tables =
+        filtered_tables.map(|result| result.map_err(DaftError::from).and_then(|table| table));
+    let daft_schema = Arc::new(Schema::try_from(&schema)?);
+    Ok((limit_table_stream(tables, limit), daft_schema))
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn read_json_single_into_table(
+    uri: &str,
+    convert_options: Option<JsonConvertOptions>,
+    parse_options: Option<JsonParseOptions>,
+    read_options: Option<JsonReadOptions>,
+    byte_range: Option<Range<usize>>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    max_chunks_in_flight: Option<usize>,
+) -> DaftResult<Table> {
+    let (tables, schema) = read_json_single_into_table_stream(
+        uri,
+        convert_options,
+        parse_options,
+        read_options,
+        byte_range,
+        io_client,
+        io_stats,
+        max_chunks_in_flight,
+    )
+    .await?;
+    let collected_tables = tables.try_collect::<Vec<_>>().await?;
     // Handle empty table case.
     if collected_tables.is_empty() {
-        let daft_schema = Arc::new(Schema::try_from(&schema)?);
-        return Table::empty(Some(daft_schema));
+        return Table::empty(Some(schema));
     }
     // // TODO(Clark): Don't concatenate all chunks from a file into a single table, since MicroPartition is natively chunked.
-    let concated_table = tables_concat(collected_tables)?;
-    if let Some(limit) = limit && concated_table.len() > limit {
-        // apply head incase that last chunk went over limit
-        concated_table.head(limit)
-    } else {
-        Ok(concated_table)
-    }
+    tables_concat(collected_tables)
+}
+
+/// Stops a stream of tables once `limit` rows have been yielded, truncating the last table if it
+/// goes over the limit.
+fn limit_table_stream(
+    tables: impl Stream<Item = DaftResult<Table>> + Send,
+    limit: Option<usize>,
+) -> impl Stream<Item = DaftResult<Table>> + Send {
+    tables.scan(limit, |remaining_rows, result| {
+        let result = match (result, *remaining_rows) {
+            // Limit has been met, early-terminate.
+            (_, Some(0)) => None,
+            // Limit has not yet been met, update remaining limit slack and continue.
+            (Ok(table), Some(rows_left)) => {
+                *remaining_rows = Some(rows_left.saturating_sub(table.len()));
+                if table.len() > rows_left {
+                    Some(table.head(rows_left))
+                } else {
+                    Some(Ok(table))
+                }
+            }
+            // (1) No limit, never early-terminate.
+            // (2) Encountered error, propagate error to allow the consumer to short-circuit.
+            (result, None) | (result @ Err(_), _) => Some(result),
+        };
+        futures::future::ready(result)
+    })
 }
 
 async fn read_json_single_into_stream(
@@ -287,28 +386,79 @@ async fn read_json_single_into_stream(
     convert_options: JsonConvertOptions,
     parse_options: JsonParseOptions,
     read_options: Option<JsonReadOptions>,
+    byte_range: Option<Range<usize>>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
 ) -> DaftResult<(impl TableChunkStream + Send, arrow2::datatypes::Schema)> {
-    let schema = match convert_options.schema {
+    let mut schema = match convert_options.schema {
         Some(schema) => schema.to_arrow()?,
         None => read_json_schema_single(
             uri,
             parse_options.clone(),
             // Read at most 1 MiB when doing schema inference.
             Some(1024 * 1024),
+            // Malformed lines are handled while reading, so they shouldn't fail schema inference.
+            convert_options.mode != ParseMode::FailFast,
             io_client.clone(),
             io_stats.clone(),
         )
         .await?
         .to_arrow()?,
     };
+    // The corrupt record column isn't read from the JSON data, so it is added back after projection.
+    let corrupt_record_column = match convert_options.mode {
+        ParseMode::Permissive => Some(convert_options.corrupt_record_column),
+        _ => None,
+    };
+    if let Some(corrupt_record_column) = &corrupt_record_column {
+        schema
+            .fields
+            .retain(|field| &field.name != corrupt_record_column);
+    }
 
+    // A byte range of newline-delimited JSON is read from the first line that starts within it up to
+    // the first line that starts after it. Files in other layouts can't be split, so they are read
+    // whole by the range at the start of the file.
+    let byte_range = match byte_range {
+        Some(range) => {
+            let size = io_client
+                .single_url_get_size(uri.to_string(), io_stats.clone())
+                .await?;
+            if detect_json_layout(uri, size, io_client.clone(), io_stats.clone()).await?
+                == JsonLayout::Lines
+            {
+                let start =
+                    find_line_start(uri, range.start, size, io_client.clone(), io_stats.clone())
+                        .await?;
+                let end = find_line_start(
+                    uri,
+                    range.end.min(size),
+                    size,
+                    io_client.clone(),
+                    io_stats.clone(),
+                )
+                .await?;
+                Some(start..end.max(start))
+            } else if range.start == 0 {
+                None
+            } else {
+                Some(size..size)
+            }
+        }
+        None => None,
+    };
+    let get_result = match byte_range {
+        Some(range) if range.is_empty() => {
+            GetResult::Stream(futures::stream::empty().boxed(), Some(0), None)
+        }
+        byte_range => {
+            io_client
+                .single_url_get(uri.to_string(), byte_range, io_stats)
+                .await?
+        }
+    };
     let (reader, buffer_size, chunk_size): (Box<dyn AsyncBufRead + Unpin + Send>, usize, usize) =
-        match io_client
-            .single_url_get(uri.to_string(), None, io_stats)
-            .await?
-        {
+        match get_result {
             GetResult::File(file) => {
                 // Use user-provided buffer size, falling back to 8 * the user-provided chunk size if that exists, otherwise falling back to 512 KiB as the default.
                 let buffer_size = read_options
@@ -318,11 +468,18 @@ async fn read_json_single_into_stream(
                             .or_else(|| opt.chunk_size.map(|cs| (64 * cs).min(256 * 1024 * 1024)))
                     })
                     .unwrap_or(256 * 1024);
+                let mut reader = File::open(file.path).await?;
                 (
-                    Box::new(BufReader::with_capacity(
-                        buffer_size,
-                        File::open(file.path).await?,
-                    )),
+                    match file.range {
+                        Some(range) => {
+                            reader.seek(SeekFrom::Start(range.start as u64)).await?;
+                            Box::new(BufReader::with_capacity(
+                                buffer_size,
+                                reader.take(range.len() as u64),
+                            ))
+                        }
+                        None => Box::new(BufReader::with_capacity(buffer_size, reader)),
+                    },
                     buffer_size,
                     read_options
                         .as_ref()
@@ -360,9 +517,16 @@ async fn read_json_single_into_stream(
         )),
         None => reader,
     };
-    let read_stream = read_into_line_chunk_stream(reader, convert_options.limit, chunk_size);
-    let (projected_schema, schema_is_projection) = match convert_options.include_columns {
-        Some(projection) => {
+    let records = read_json_records(reader).await?;
+    let read_stream = read_into_line_chunk_stream(records, convert_options.limit, chunk_size);
+    let (projected_schema, schema_is_projection, include_corrupt_records) = match convert_options
+        .include_columns
+    {
+        Some(mut projection) => {
+            let include_corrupt_records = corrupt_record_column
+                .as_ref()
+                .map_or(false, |name| projection.contains(name));
+            projection.retain(|col| Some(col) != corrupt_record_column.as_ref());
             let mut field_map = schema
                 .fields
                 .into_iter()
@@ -372,32 +536,97 @@ async fn read_json_single_into_stream(
             (
                 arrow2::datatypes::Schema::from(projected_fields).with_metadata(schema.metadata),
                 true,
+                include_corrupt_records,
             )
         }
-        None => (schema, false),
+        None => (schema, false, corrupt_record_column.is_some()),
     };
+    let mut output_schema = projected_schema.clone();
+    if let Some(corrupt_record_column) = &corrupt_record_column && include_corrupt_records {
+        output_schema
+            .fields
+            .push(corrupt_record_field(corrupt_record_column));
+    }
     Ok((
         parse_into_column_array_chunk_stream(
             read_stream,
-            projected_schema.clone().into(),
+            projected_schema.into(),
             schema_is_projection,
+            convert_options.mode,
+            include_corrupt_records,
+            output_schema.clone().into(),
         )?,
-        projected_schema,
+        output_schema,
     ))
 }
 
-fn read_into_line_chunk_stream<R>(
-    reader: R,
+/// Detects the layout of a JSON file from its first line, which is taken to be multi-line documents if
+/// it doesn't fit in the bytes that are read.
+async fn detect_json_layout(
+    uri: &str,
+    size: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<JsonLayout> {
+    // Read at most 1 MiB to find the first line.
+    const MAX_HEAD_SIZE: usize = 1024 * 1024;
+    if size == 0 {
+        return Ok(JsonLayout::Lines);
+    }
+    let head = io_client
+        .single_url_get(uri.to_string(), Some(0..size.min(MAX_HEAD_SIZE)), io_stats)
+        .await?
+        .bytes()
+        .await?;
+    let first_line = head
+        .split(|byte| *byte == b'\n')
+        .find(|line| !line.iter().all(u8::is_ascii_whitespace))
+        .unwrap_or_default();
+    Ok(JsonLayout::detect(first_line))
+}
+
+/// Finds the offset of the first line that starts at or after `offset`, or `size` if there is none.
+async fn find_line_start(
+    uri: &str,
+    offset: usize,
+    size: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<usize> {
+    if offset == 0 || offset >= size {
+        return Ok(offset.min(size));
+    }
+    // Start looking at the byte before the offset, in case the offset is at the start of a line.
+    let mut window_start = offset - 1;
+    let mut window_size = 64 * 1024;
+    while window_start < size {
+        let window_end = (window_start + window_size).min(size);
+        let window = io_client
+            .single_url_get(
+                uri.to_string(),
+                Some(window_start..window_end),
+                io_stats.clone(),
+            )
+            .await?
+            .bytes()
+            .await?;
+        if let Some(i) = window.iter().position(|byte| *byte == b'\n') {
+            return Ok(window_start + i + 1);
+        }
+        window_start = window_end;
+        window_size *= 2;
+    }
+    Ok(size)
+}
+
+fn read_into_line_chunk_stream(
+    records: impl Stream<Item = std::io::Result<String>> + Send,
     num_rows: Option<usize>,
     chunk_size: usize,
-) -> impl LineChunkStream + Send
-where
-    R: AsyncBufRead + Unpin + Send + 'static,
-{
+) -> impl LineChunkStream + Send {
     let num_rows = num_rows.unwrap_or(usize::MAX);
     // Stream of unparsed json string record chunks.
-    let line_stream = tokio_stream::wrappers::LinesStream::new(reader.lines());
-    line_stream
+    records
         .take(num_rows)
         .try_chunks(chunk_size)
         .context(ChunkSnafu)
@@ -407,8 +636,11 @@ fn parse_into_column_array_chunk_stream(
     stream: impl LineChunkStream + Send,
     schema: Arc<arrow2::datatypes::Schema>,
     schema_is_projection: bool,
+    mode: ParseMode,
+    include_corrupt_records: bool,
+    output_schema: Arc<arrow2::datatypes::Schema>,
 ) -> DaftResult<impl TableChunkStream + Send> {
-    let daft_schema = Arc::new(daft_core::schema::Schema::try_from(schema.as_ref())?);
+    let daft_schema = Arc::new(daft_core::schema::Schema::try_from(output_schema.as_ref())?);
     let daft_fields = Arc::new(
         daft_schema
             .fields
@@ -427,18 +659,42 @@ fn parse_into_column_array_chunk_stream(
             rayon::spawn(move || {
                 let result = (move || {
                     // TODO(Clark): Switch to streaming parse + array construction?
-                    let parsed = records
-                        .iter()
-                        .map(|unparsed_record| {
-                            json_deserializer::parse(unparsed_record.as_bytes()).map_err(|e| {
-                                super::Error::JsonDeserializationError {
-                                    string: e.to_string(),
-                                }
+                    let (parsed, mut malformed) = match mode {
+                        ParseMode::FailFast => (
+                            records
+                                .iter()
+                                .map(|unparsed_record| {
+                                    json_deserializer::parse(unparsed_record.as_bytes()).map_err(
+                                        |e| super::Error::JsonDeserializationError {
+                                            string: e.to_string(),
+                                        },
+                                    )
+                                })
+                                .collect::<super::Result<Vec<_>>>()?,
+                            vec![],
+                        ),
+                        _ => parse_records_tracking_malformed(
+                            &records,
+                            schema.as_ref(),
+                            schema_is_projection,
+                        ),
+                    };
+                    let mut chunk =
+                        deserialize_records(&parsed, schema.as_ref(), schema_is_projection)
+                            .context(ArrowSnafu)?;
+                    if !malformed.is_empty() {
+                        track_unparseable_values(&parsed, &chunk, schema.as_ref(), &mut malformed);
+                    }
+                    if include_corrupt_records {
+                        let corrupt_records = records
+                            .iter()
+                            .zip(malformed.iter())
+                            .map(|(record, error)| {
+                                error.as_ref().map(|error| (record.clone(), error.clone()))
                             })
-                        })
-                        .collect::<super::Result<Vec<_>>>()?;
-                    let chunk = deserialize_records(parsed, schema.as_ref(), schema_is_projection)
-                        .context(ArrowSnafu)?;
+                            .collect::<Vec<_>>();
+                        chunk.push(corrupt_record_array(&corrupt_records));
+                    }
                     let all_series = chunk
                         .into_iter()
                         .zip(daft_fields.iter())
@@ -449,7 +705,16 @@ fn parse_into_column_array_chunk_stream(
                             )
                         })
                         .collect::<DaftResult<Vec<_>>>()?;
-                    Ok(Table::new_unchecked(daft_schema.clone(), all_series))
+                    let table = Table::new_unchecked(daft_schema.clone(), all_series);
+                    match mode {
+                        ParseMode::DropMalformed => {
+                            let mask = malformed.iter().map(Option::is_none).collect::<Vec<_>>();
+                            table.mask_filter(
+                                &BooleanArray::from(("mask", mask.as_slice())).into_series(),
+                            )
+                        }
+                        _ => Ok(table),
+                    }
                 })();
                 let _ = send.send(result);
             });
@@ -459,11 +724,81 @@ fn parse_into_column_array_chunk_stream(
     }))
 }
 
+/// Parses each line of a chunk, replacing malformed lines with empty objects so that all of their
+/// fields are null. Also returns the error of each malformed line.
+fn parse_records_tracking_malformed<'a>(
+    records: &'a [String],
+    schema: &arrow2::datatypes::Schema,
+    schema_is_projection: bool,
+) -> (Vec<Value<'a>>, Vec<Option<String>>) {
+    records
+        .iter()
+        .map(
+            |unparsed_record| match json_deserializer::parse(unparsed_record.as_bytes()) {
+                Ok(Value::Object(record)) => {
+                    let unexpected_key = (!schema_is_projection)
+                        .then(|| {
+                            record
+                                .keys()
+                                .find(|key| schema.fields.iter().all(|f| &f.name != *key))
+                                .cloned()
+                        })
+                        .flatten();
+                    match unexpected_key {
+                        Some(key) => (
+                            Value::Object(Default::default()),
+                            Some(format!("unexpected key: '{key}'")),
+                        ),
+                        None => (Value::Object(record), None),
+                    }
+                }
+                Ok(_) => (
+                    Value::Object(Default::default()),
+                    Some(
+                        "Each line in a newline-delimited JSON file must be a JSON object"
+                            .to_string(),
+                    ),
+                ),
+                Err(e) => (Value::Object(Default::default()), Some(e.to_string())),
+            },
+        )
+        .unzip()
+}
+
+/// Marks records with values that are present but were deserialized as nulls, i.e. that couldn't
+/// be parsed as the type of their column, as malformed.
+fn track_unparseable_values(
+    records: &[Value],
+    columns: &[Box<dyn arrow2::array::Array>],
+    schema: &arrow2::datatypes::Schema,
+    malformed: &mut [Option<String>],
+) {
+    for (field, column) in schema.fields.iter().zip(columns) {
+        // Null columns can't hold any values.
+        if field.data_type == arrow2::datatypes::DataType::Null {
+            continue;
+        }
+        for (row, record) in records.iter().enumerate() {
+            if malformed[row].is_some() || !column.is_null(row) {
+                continue;
+            }
+            if let Value::Object(record) = record
+                && record.get(&field.name).map_or(false, |value| !matches!(value, Value::Null))
+            {
+                malformed[row] = Some(format!(
+                    "Could not parse field {:?} as {:?}",
+                    field.name, field.data_type
+                ));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{collections::HashSet, io::BufRead, sync::Arc};
 
-    use common_error::DaftResult;
+    use common_error::{DaftError, DaftResult};
 
     use daft_core::{
         datatypes::{Field, TimeUnit},
@@ -473,6 +808,7 @@ mod tests {
     };
     use daft_io::{IOClient, IOConfig};
     use daft_table::Table;
+    use futures::{StreamExt, TryStreamExt};
     use indexmap::IndexMap;
     use rstest::rstest;
 
@@ -481,8 +817,9 @@ mod tests {
         inference::{column_types_map_to_fields, infer_records_schema},
     };
     use crate::{JsonConvertOptions, JsonReadOptions};
+    use daft_decoding::parse_mode::{ParseMode, DEFAULT_CORRUPT_RECORD_COLUMN_NAME};
 
-    use super::read_json;
+    use super::{read_json, read_json_bulk, stream_json};
 
     fn check_equal_local_arrow2(
         path: &str,
@@ -535,7 +872,7 @@ mod tests {
             None => (field_map.into_values().collect::<Vec<_>>().into(), false),
         };
         // Deserialize JSON records into Arrow2 column arrays.
-        let columns = deserialize_records(parsed, &schema, is_projection).unwrap();
+        let columns = deserialize_records(&parsed, &schema, is_projection).unwrap();
         // Roundtrip columns with Daft for casting.
         let columns = columns
             .into_iter()
@@ -823,6 +1160,111 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    fn test_json_stream_local(#[values(None, Some(10))] limit: Option<usize>) -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.jsonl", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let convert_options = JsonConvertOptions::default().with_limit(limit);
+        let read_options = JsonReadOptions::default().with_chunk_size(Some(8));
+
+        let tables = daft_io::get_runtime(true)?.block_on(
+            stream_json(
+                file.clone(),
+                Some(convert_options),
+                None,
+                Some(read_options),
+                None,
+                io_client,
+                None,
+                Some(1),
+            )
+            .try_collect::<Vec<_>>(),
+        )?;
+        assert_eq!(
+            tables.iter().map(Table::len).collect::<Vec<_>>(),
+            match limit {
+                None => vec![8, 8, 4],
+                Some(_) => vec![8, 2],
+            }
+        );
+        check_equal_local_arrow2(file.as_ref(), &Table::concat(&tables)?, limit, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_stream_local_drop_early() -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.jsonl", env!("CARGO_MANIFEST_DIR"),);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let read_options = JsonReadOptions::default().with_chunk_size(Some(2));
+
+        daft_io::get_runtime(true)?.block_on(async {
+            let mut tables = stream_json(
+                file,
+                None,
+                None,
+                Some(read_options),
+                None,
+                io_client,
+                None,
+                Some(1),
+            );
+            assert_eq!(tables.try_next().await?.map(|table| table.len()), Some(2));
+            // Dropping the stream stops reading the file.
+            drop(tables);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_json_stream_local_errors() -> DaftResult<()> {
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let runtime_handle = daft_io::get_runtime(true)?;
+
+        let mut tables = stream_json(
+            format!("{}/test/missing.jsonl", env!("CARGO_MANIFEST_DIR")),
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            Some(1),
+        );
+        assert!(matches!(
+            runtime_handle.block_on(tables.next()),
+            Some(Err(DaftError::IoError(err))) if err.kind() == std::io::ErrorKind::NotFound
+        ));
+        assert!(runtime_handle.block_on(tables.next()).is_none());
+
+        // A malformed record fails the stream after the chunks before it.
+        let file = tempfile::NamedTempFile::new()?;
+        std::fs::write(file.path(), "{\"a\": 1}\n{\"a\": 2}\n{\"a\": 3}\n{\"a\":\n")?;
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64)])?;
+        let mut tables = stream_json(
+            file.path().to_string_lossy().to_string(),
+            Some(JsonConvertOptions::default().with_schema(Some(schema.into()))),
+            None,
+            Some(JsonReadOptions::default().with_chunk_size(Some(2))),
+            None,
+            io_client,
+            None,
+            Some(1),
+        );
+        assert_eq!(
+            runtime_handle
+                .block_on(tables.try_next())?
+                .map(|table| table.len()),
+            Some(2)
+        );
+        assert!(runtime_handle.block_on(tables.try_next()).is_err());
+        assert!(runtime_handle.block_on(tables.next()).is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_json_read_local_throttled_streaming() -> DaftResult<()> {
         let file = format!("{}/test/iris_tiny.jsonl", env!("CARGO_MANIFEST_DIR"),);
@@ -1072,6 +1514,113 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_json_read_local_byte_ranges() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("lines.jsonl");
+        let data = "{\"a\": 1}\n{\"a\": 2}\n{\"a\": 3}\n";
+        std::fs::write(&file, data)?;
+        let uri = file.to_str().unwrap();
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        // Every line should be read exactly once, wherever the file is split.
+        for split in 0..=data.len() {
+            let tables = read_json_bulk(
+                &[uri, uri],
+                None,
+                None,
+                None,
+                Some(vec![Some(0..split), Some(split..data.len())]),
+                io_client.clone(),
+                None,
+                true,
+                None,
+                2,
+            )?;
+            let mut a = vec![];
+            for table in tables {
+                let column = table.get_column("a")?.i64()?;
+                a.extend((0..column.len()).map(|i| column.get(i)));
+            }
+            assert_eq!(a, vec![Some(1), Some(2), Some(3)], "split at {}", split);
+        }
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_json_read_local_parse_modes(
+        #[values(ParseMode::DropMalformed, ParseMode::Permissive)] mode: ParseMode,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("malformed.jsonl");
+        std::fs::write(
+            &file,
+            "{\"a\": 1, \"b\": \"x\"}\n{\"a\": \"oops\", \"b\": \"y\"}\nnot json\n[1, 2]\n{\"a\": 3}\n",
+        )?;
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])?;
+        let table = read_json(
+            file.to_str().unwrap(),
+            Some(
+                JsonConvertOptions::default()
+                    .with_schema(Some(schema.into()))
+                    .with_mode(mode),
+            ),
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        let a = table.get_column("a")?.i64()?;
+        let a = (0..a.len()).map(|i| a.get(i)).collect::<Vec<_>>();
+        let b = table.get_column("b")?.utf8()?;
+        let b = (0..b.len()).map(|i| b.get(i)).collect::<Vec<_>>();
+        match mode {
+            ParseMode::DropMalformed => {
+                assert_eq!(table.num_columns(), 2);
+                assert_eq!(a, vec![Some(1), Some(3)]);
+                assert_eq!(b, vec![Some("x"), None]);
+            }
+            _ => {
+                assert_eq!(table.num_columns(), 3);
+                assert_eq!(a, vec![Some(1), None, None, None, Some(3)]);
+                assert_eq!(b, vec![Some("x"), Some("y"), None, None, None]);
+                let corrupt = table
+                    .get_column(DEFAULT_CORRUPT_RECORD_COLUMN_NAME)?
+                    .to_arrow();
+                let corrupt = corrupt
+                    .as_any()
+                    .downcast_ref::<arrow2::array::StructArray>()
+                    .unwrap();
+                let records = corrupt.values()[0]
+                    .as_any()
+                    .downcast_ref::<arrow2::array::Utf8Array<i64>>()
+                    .unwrap()
+                    .iter()
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    records,
+                    vec![
+                        None,
+                        Some("{\"a\": \"oops\", \"b\": \"y\"}"),
+                        Some("not json"),
+                        Some("[1, 2]"),
+                        None
+                    ]
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     #[rstest]
     fn test_json_read_s3(
         #[values(
@@ -9,12 +9,13 @@ use json_deserializer::parse;
 use snafu::ResultExt;
 use tokio::{
     fs::File,
-    io::{AsyncBufRead, AsyncBufReadExt, BufReader},
+    io::{AsyncBufRead, BufReader},
 };
 use tokio_util::io::StreamReader;
 
 use crate::{
     inference::{column_types_map_to_fields, infer_records_schema},
+    layout::read_json_records,
     ArrowSnafu, JsonParseOptions, StdIOSnafu,
 };
 use daft_compression::CompressionCodec;
@@ -49,10 +50,13 @@ impl Default for JsonReadStats {
     }
 }
 
+/// Infers the schema of a JSON file. If `skip_malformed` is set, lines that aren't valid JSON
+/// objects are skipped instead of failing the inference.
 pub fn read_json_schema(
     uri: &str,
     parse_options: Option<JsonParseOptions>,
     max_bytes: Option<usize>,
+    skip_malformed: bool,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
 ) -> DaftResult<Schema> {
@@ -64,6 +68,7 @@ pub fn read_json_schema(
             parse_options.unwrap_or_default(),
             // Default to 1 MiB.
             max_bytes.or(Some(1024 * 1024)),
+            skip_malformed,
             io_client,
             io_stats,
         )
@@ -93,6 +98,7 @@ pub async fn read_json_schema_bulk(
                         &owned_string,
                         owned_parse_options.unwrap_or_default(),
                         max_bytes,
+                        false,
                         owned_client,
                         owned_io_stats,
                     )
@@ -112,6 +118,7 @@ pub(crate) async fn read_json_schema_single(
     uri: &str,
     _: JsonParseOptions,
     max_bytes: Option<usize>,
+    skip_malformed: bool,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
 ) -> DaftResult<Schema> {
@@ -134,7 +141,7 @@ pub(crate) async fn read_json_schema_single(
         Some(compression) => Box::new(tokio::io::BufReader::new(compression.to_decoder(reader))),
         None => reader,
     };
-    let arrow_schema = infer_schema(reader, None, max_bytes).await?;
+    let arrow_schema = infer_schema(reader, None, max_bytes, skip_malformed).await?;
     let schema = Schema::try_from(&arrow_schema)?;
     Ok(schema)
 }
@@ -143,16 +150,17 @@ async fn infer_schema<R>(
     reader: R,
     max_rows: Option<usize>,
     max_bytes: Option<usize>,
+    skip_malformed: bool,
 ) -> DaftResult<arrow2::datatypes::Schema>
 where
-    R: tokio::io::AsyncBufRead + Unpin + Send,
+    R: tokio::io::AsyncBufRead + Unpin + Send + 'static,
 {
     let max_records = max_rows.unwrap_or(usize::MAX);
     let max_bytes = max_bytes.unwrap_or(usize::MAX);
     let mut total_bytes = 0;
     // Stream of unparsed JSON string records.
-    let line_stream = tokio_stream::wrappers::LinesStream::new(reader.lines());
-    let mut schema_stream = line_stream
+    let records = read_json_records(reader).await.context(StdIOSnafu)?;
+    let mut schema_stream = records
         .try_take_while(|record| {
             // Terminate scan if we've exceeded our max_bytes threshold with the last-read line.
             if total_bytes >= max_bytes {
@@ -167,11 +175,15 @@ where
             let record = record.context(StdIOSnafu)?;
 
             // Parse record into a JSON Value, then infer the schema.
-            let parsed_record =
-                parse(record.as_bytes()).map_err(|e| super::Error::JsonDeserializationError {
+            let schema = parse(record.as_bytes())
+                .map_err(|e| super::Error::JsonDeserializationError {
                     string: e.to_string(),
-                })?;
-            infer_records_schema(&parsed_record).context(ArrowSnafu)
+                })
+                .and_then(|parsed_record| infer_records_schema(&parsed_record).context(ArrowSnafu));
+            match schema {
+                Err(_) if skip_malformed => Ok(arrow2::datatypes::Schema::default()),
+                schema => schema,
+            }
         });
     // Collect all infered dtypes for each column.
     let mut column_types: IndexMap<String, HashSet<arrow2::datatypes::DataType>> = IndexMap::new();
@@ -245,7 +257,7 @@ mod tests {
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let schema = read_json_schema(file.as_ref(), None, None, io_client.clone(), None)?;
+        let schema = read_json_schema(file.as_ref(), None, None, false, io_client.clone(), None)?;
         assert_eq!(
             schema,
             Schema::new(vec![
@@ -269,7 +281,7 @@ mod tests {
 
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let schema = read_json_schema(file.as_ref(), None, None, io_client, None)?;
+        let schema = read_json_schema(file.as_ref(), None, None, false, io_client, None)?;
         assert_eq!(
             schema,
             Schema::new(vec![
@@ -331,7 +343,7 @@ mod tests {
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let schema = read_json_schema(file.as_ref(), None, None, io_client.clone(), None)?;
+        let schema = read_json_schema(file.as_ref(), None, None, false, io_client.clone(), None)?;
         assert_eq!(
             schema,
             Schema::new(vec![
@@ -357,7 +369,7 @@ mod tests {
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let schema = read_json_schema(file.as_ref(), None, None, io_client.clone(), None)?;
+        let schema = read_json_schema(file.as_ref(), None, None, false, io_client.clone(), None)?;
         assert_eq!(
             schema,
             Schema::new(vec![
@@ -382,7 +394,14 @@ mod tests {
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let schema = read_json_schema(file.as_ref(), None, Some(100), io_client.clone(), None)?;
+        let schema = read_json_schema(
+            file.as_ref(),
+            None,
+            Some(100),
+            false,
+            io_client.clone(),
+            None,
+        )?;
         assert_eq!(
             schema,
             Schema::new(vec![
@@ -431,7 +450,7 @@ mod tests {
         io_config.s3.anonymous = true;
         let io_client = Arc::new(IOClient::new(io_config.into())?);
 
-        let schema = read_json_schema(file.as_ref(), None, None, io_client.clone(), None)?;
+        let schema = read_json_schema(file.as_ref(), None, None, false, io_client.clone(), None)?;
         assert_eq!(
             schema,
             Schema::new(vec![
@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use arrow2::{
+    chunk::Chunk,
+    io::json::write::{FallibleStreamingIterator, RecordSerializer},
+};
+use common_error::DaftResult;
+use daft_compression::CompressionCodec;
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_table::Table;
+use snafu::ResultExt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{ArrowSnafu, StdIOSnafu};
+
+/// Number of serialized bytes to accumulate before handing them to the sink.
+const WRITE_BUFFER_SIZE: usize = 1024 * 1024;
+
+/// Writes `table` as a single JSON file to `uri`, compressing it if `uri` has a known compression
+/// extension (e.g. `.jsonl.gz`).
+///
+/// With `line_delimited`, every row is written as a JSON object on its own line (NDJSON); otherwise
+/// the rows are written as a single JSON array of objects.
+pub fn write_json(
+    table: &Table,
+    uri: &str,
+    line_delimited: bool,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> DaftResult<()> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        write_json_single(table, uri, line_delimited, io_client, io_stats).await
+    })
+}
+
+async fn write_json_single(
+    table: &Table,
+    uri: &str,
+    line_delimited: bool,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<()> {
+    let codec = CompressionCodec::from_uri(uri);
+    io_client
+        .single_url_put_from_writer(uri.to_string(), io_stats, |sink| async move {
+            match codec {
+                Some(codec) => serialize_json(table, line_delimited, codec.to_encoder(sink)).await,
+                None => serialize_json(table, line_delimited, sink).await,
+            }
+        })
+        .await
+}
+
+async fn serialize_json<W: AsyncWrite + Unpin>(
+    table: &Table,
+    line_delimited: bool,
+    mut sink: W,
+) -> DaftResult<()> {
+    let arrow_schema = table.schema.to_arrow()?;
+    let arrays = (0..table.num_columns())
+        .map(|i| Ok(table.get_column_by_index(i)?.to_arrow()))
+        .collect::<DaftResult<Vec<_>>>()?;
+    let chunk = Chunk::new(arrays);
+    let mut serializer = RecordSerializer::new(arrow_schema, &chunk, vec![]);
+
+    let mut buffer = Vec::with_capacity(WRITE_BUFFER_SIZE);
+    if !line_delimited {
+        buffer.push(b'[');
+    }
+    let mut is_first_row = true;
+    while let Some(row) = serializer.next().context(ArrowSnafu)? {
+        if line_delimited {
+            buffer.extend_from_slice(row);
+            buffer.push(b'\n');
+        } else {
+            if !is_first_row {
+                buffer.push(b',');
+            }
+            buffer.extend_from_slice(row);
+        }
+        is_first_row = false;
+
+        if buffer.len() >= WRITE_BUFFER_SIZE {
+            sink.write_all(&buffer).await.context(StdIOSnafu)?;
+            buffer.clear();
+        }
+    }
+    if !line_delimited {
+        buffer.push(b']');
+    }
+    sink.write_all(&buffer).await.context(StdIOSnafu)?;
+    sink.shutdown().await.context(StdIOSnafu)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{Int64Array, Utf8Array},
+        IntoSeries,
+    };
+    use daft_io::{IOClient, IOConfig};
+    use daft_table::Table;
+    use rstest::rstest;
+
+    use super::write_json;
+    use crate::read::read_json;
+
+    #[rstest]
+    fn test_ndjson_write_round_trip(
+        #[values("out.jsonl", "out.jsonl.gz", "out.jsonl.zst")] file_name: &str,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let uri = dir.path().join(file_name).to_string_lossy().to_string();
+        let table = Table::from_columns(vec![
+            Int64Array::from(("a", vec![1, 2, 3])).into_series(),
+            Utf8Array::from(("b", ["x", "has \"quote\"", "has\nnewline"].as_slice())).into_series(),
+        ])?;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        write_json(&table, &uri, true, io_client.clone(), None, true)?;
+        let read_back = read_json(&uri, None, None, None, io_client, None, true, None)?;
+        assert_eq!(read_back.len(), 3);
+        for name in ["a", "b"] {
+            assert_eq!(
+                read_back.get_column(name)?.to_arrow(),
+                table.get_column(name)?.to_arrow()
+            );
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_json_array_write_round_trip(
+        #[values("out.json", "out.json.gz")] file_name: &str,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let uri = dir.path().join(file_name).to_string_lossy().to_string();
+        let table = Table::from_columns(vec![
+            Int64Array::from(("a", vec![1, 2, 3])).into_series(),
+            Utf8Array::from(("b", ["x", "has \"quote\"", "has\nnewline"].as_slice())).into_series(),
+        ])?;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        write_json(&table, &uri, false, io_client.clone(), None, true)?;
+        if file_name == "out.json" {
+            let written = std::fs::read_to_string(&uri).unwrap();
+            assert_eq!(
+                written,
+                r#"[{"a":1,"b":"x"},{"a":2,"b":"has \"quote\""},{"a":3,"b":"has\nnewline"}]"#
+            );
+        }
+        let read_back = read_json(&uri, None, None, None, io_client, None, true, None)?;
+        assert_eq!(read_back.len(), 3);
+        for name in ["a", "b"] {
+            assert_eq!(
+                read_back.get_column(name)?.to_arrow(),
+                table.get_column(name)?.to_arrow()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_array_write_empty() -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let uri = dir.path().join("out.json").to_string_lossy().to_string();
+        let table = Table::from_columns(vec![Int64Array::from(("a", vec![])).into_series()])?;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        write_json(&table, &uri, false, io_client, None, true)?;
+        assert_eq!(std::fs::read_to_string(&uri).unwrap(), "[]");
+        Ok(())
+    }
+}
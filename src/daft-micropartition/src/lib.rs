@@ -6,6 +6,8 @@ use snafu::Snafu;
 mod micropartition;
 mod ops;
 
+pub use micropartition::stream_scan_task;
+
 #[cfg(feature = "python")]
 pub mod python;
 #[cfg(feature = "python")]
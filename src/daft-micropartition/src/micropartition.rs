@@ -1,7 +1,10 @@
 use std::collections::{BTreeMap, HashSet};
 use std::fmt::Display;
 use std::sync::Arc;
-use std::{ops::Deref, sync::Mutex};
+use std::{
+    ops::{Deref, Range},
+    sync::Mutex,
+};
 
 use arrow2::io::parquet::read::schema::infer_schema_with_options;
 use common_error::DaftResult;
@@ -15,10 +18,14 @@ use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 use daft_parquet::read::{
     read_parquet_bulk, read_parquet_metadata_bulk, ParquetSchemaInferenceOptions,
 };
-use daft_scan::file_format::{CsvSourceConfig, FileFormatConfig, ParquetSourceConfig};
+use daft_scan::file_format::{
+    CsvSourceConfig, DatabaseConnection, DatabaseSourceConfig, FileFormatConfig, JsonSourceConfig,
+    ParquetSourceConfig,
+};
 use daft_scan::storage_config::{NativeStorageConfig, StorageConfig};
 use daft_scan::{ChunkSpec, DataFileSource, Pushdowns, ScanTask};
 use daft_table::Table;
+use futures::{stream::BoxStream, StreamExt};
 
 use snafu::ResultExt;
 
@@ -152,46 +159,20 @@ fn materialize_scan_task(
                 // Native CSV Reads
                 // ****************
                 FileFormatConfig::Csv(cfg) => {
-                    let schema_of_file = scan_task.schema.clone();
-                    let col_names = if !cfg.has_headers {
-                        Some(
-                            schema_of_file
-                                .fields
-                                .values()
-                                .map(|f| f.name.as_str())
-                                .collect::<Vec<_>>(),
-                        )
-                    } else {
-                        None
-                    };
-                    let convert_options = CsvConvertOptions::new_internal(
+                    let (convert_options, parse_options, read_options) = csv_options_for_scan_task(
+                        &scan_task,
+                        cfg,
+                        file_column_names.as_deref(),
                         scan_task.pushdowns.limit,
-                        file_column_names
-                            .as_ref()
-                            .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
-                        col_names
-                            .as_ref()
-                            .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
-                        None,
-                        scan_task.pushdowns.filters.clone(),
-                    );
-                    let parse_options = CsvParseOptions::new_with_defaults(
-                        cfg.has_headers,
-                        cfg.delimiter,
-                        cfg.double_quote,
-                        cfg.quote,
-                        cfg.escape_char,
-                        cfg.comment,
-                    )
-                    .context(DaftCSVSnafu)?;
-                    let read_options =
-                        CsvReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
+                    )?;
                     let uris = urls.collect::<Vec<_>>();
+                    let byte_ranges = sources_to_byte_ranges(scan_task.sources.as_slice());
                     daft_csv::read_csv_bulk(
                         uris.as_slice(),
                         Some(convert_options),
                         Some(parse_options),
                         Some(read_options),
+                        byte_ranges,
                         io_client,
                         io_stats,
                         native_storage_config.multithreaded_io,
@@ -205,23 +186,20 @@ fn materialize_scan_task(
                 // Native JSON Reads
                 // ****************
                 FileFormatConfig::Json(cfg) => {
-                    let convert_options = JsonConvertOptions::new_internal(
+                    let (convert_options, parse_options, read_options) = json_options_for_scan_task(
+                        &scan_task,
+                        cfg,
+                        file_column_names.as_deref(),
                         scan_task.pushdowns.limit,
-                        file_column_names
-                            .as_ref()
-                            .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
-                        Some(scan_task.schema.clone()),
-                        scan_task.pushdowns.filters.clone(),
                     );
-                    let parse_options = JsonParseOptions::new_internal();
-                    let read_options =
-                        JsonReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
                     let uris = urls.collect::<Vec<_>>();
+                    let byte_ranges = sources_to_byte_ranges(scan_task.sources.as_slice());
                     daft_json::read_json_bulk(
                         uris.as_slice(),
                         Some(convert_options),
                         Some(parse_options),
                         Some(read_options),
+                        byte_ranges,
                         io_client,
                         io_stats,
                         native_storage_config.multithreaded_io,
@@ -230,10 +208,107 @@ fn materialize_scan_task(
                     )
                     .context(DaftCoreComputeSnafu)?
                 }
+
+                // *********************
+                // Native Arrow IPC Reads
+                // *********************
+                FileFormatConfig::ArrowIpc(_) => {
+                    let uris = urls.collect::<Vec<_>>();
+                    daft_ipc::read_ipc_bulk(
+                        uris.as_slice(),
+                        file_column_names.as_deref(),
+                        scan_task.pushdowns.limit,
+                        scan_task.pushdowns.filters.clone(),
+                        io_client,
+                        io_stats,
+                        native_storage_config.multithreaded_io,
+                        8,
+                    )
+                    .context(DaftCoreComputeSnafu)?
+                }
+
+                // *********************
+                // Native Avro Reads
+                // *********************
+                FileFormatConfig::Avro(_) => {
+                    let uris = urls.collect::<Vec<_>>();
+                    // Files are resolved against the scan schema, except for partition columns,
+                    // which aren't stored in the files and are filled in afterwards.
+                    let reader_schema = match scan_task.partition_spec() {
+                        Some(partition_spec) => {
+                            let partition_fillmap = partition_spec.to_fill_map();
+                            let fields = scan_task
+                                .schema
+                                .fields
+                                .values()
+                                .filter(|field| {
+                                    !partition_fillmap.contains_key(field.name.as_str())
+                                })
+                                .cloned()
+                                .collect();
+                            Arc::new(Schema::new(fields).context(DaftCoreComputeSnafu)?)
+                        }
+                        None => scan_task.schema.clone(),
+                    };
+                    daft_avro::read_avro_bulk(
+                        uris.as_slice(),
+                        file_column_names.as_deref(),
+                        scan_task.pushdowns.limit,
+                        scan_task.pushdowns.filters.clone(),
+                        Some(reader_schema),
+                        io_client,
+                        io_stats,
+                        native_storage_config.multithreaded_io,
+                        8,
+                    )
+                    .context(DaftCoreComputeSnafu)?
+                }
+
+                // ****************
+                // Native ORC Reads
+                // ****************
+                FileFormatConfig::Orc(_) => {
+                    let uris = urls.collect::<Vec<_>>();
+                    let stripes = orc_sources_to_stripes(scan_task.sources.as_slice());
+                    daft_orc::read_orc_bulk(
+                        uris.as_slice(),
+                        file_column_names.as_deref(),
+                        scan_task.pushdowns.limit,
+                        stripes,
+                        scan_task.pushdowns.filters.clone(),
+                        io_client,
+                        io_stats,
+                        native_storage_config.multithreaded_io,
+                        8,
+                    )
+                    .context(DaftCoreComputeSnafu)?
+                }
+
+                // *********************
+                // Native Database Reads
+                // *********************
+                FileFormatConfig::Database(DatabaseSourceConfig {
+                    sql,
+                    conn: DatabaseConnection::Url(url),
+                }) => {
+                    let table = daft_database::read_sql(
+                        sql,
+                        url,
+                        file_column_names.as_deref(),
+                        scan_task.pushdowns.filters.clone(),
+                        scan_task.pushdowns.limit,
+                        native_storage_config.multithreaded_io,
+                    )
+                    .context(DaftCoreComputeSnafu)?;
+                    vec![table]
+                }
                 #[cfg(feature = "python")]
-                FileFormatConfig::Database(_) => {
+                FileFormatConfig::Database(DatabaseSourceConfig {
+                    conn: DatabaseConnection::Python(_),
+                    ..
+                }) => {
                     return Err(common_error::DaftError::TypeError(
-                        "Native reads for Database file format not implemented".to_string(),
+                        "Native reads for Database file format with a Python connection not implemented".to_string(),
                     ))
                     .context(DaftCoreComputeSnafu);
                 }
@@ -277,7 +352,7 @@ fn materialize_scan_task(
                             py,
                             url,
                             *has_headers,
-                            *delimiter,
+                            delimiter.as_deref(),
                             *double_quote,
                             scan_task.schema.clone().into(),
                             scan_task.storage_config.clone().into(),
@@ -312,9 +387,37 @@ fn materialize_scan_task(
                     })
                     .collect::<crate::Result<Vec<_>>>()
                 })?,
-                FileFormatConfig::Database(daft_scan::file_format::DatabaseSourceConfig {
+                FileFormatConfig::ArrowIpc(_) => {
+                    return Err(common_error::DaftError::TypeError(
+                        "Python storage reads for Arrow IPC file format not implemented"
+                            .to_string(),
+                    ))
+                    .context(DaftCoreComputeSnafu);
+                }
+                FileFormatConfig::Avro(_) => {
+                    return Err(common_error::DaftError::TypeError(
+                        "Python storage reads for Avro file format not implemented".to_string(),
+                    ))
+                    .context(DaftCoreComputeSnafu);
+                }
+                FileFormatConfig::Orc(_) => {
+                    return Err(common_error::DaftError::TypeError(
+                        "Python storage reads for ORC file format not implemented".to_string(),
+                    ))
+                    .context(DaftCoreComputeSnafu);
+                }
+                FileFormatConfig::Database(DatabaseSourceConfig {
+                    conn: DatabaseConnection::Url(_),
+                    ..
+                }) => {
+                    return Err(common_error::DaftError::TypeError(
+                        "Python storage reads for Database file format with a URL connection not implemented".to_string(),
+                    ))
+                    .context(DaftCoreComputeSnafu);
+                }
+                FileFormatConfig::Database(DatabaseSourceConfig {
                     sql,
-                    conn,
+                    conn: DatabaseConnection::Python(conn),
                 }) => {
                     let predicate_expr = scan_task
                         .pushdowns
@@ -359,6 +462,243 @@ fn materialize_scan_task(
     Ok((table_values, cast_to_schema))
 }
 
+/// Streams the data of a [`ScanTask`] as [`Table`]s of bounded size, rather than materializing it
+/// into a `Vec<Table>` like [`materialize_scan_task`].
+///
+/// The sources of the ScanTask are read one after the other on the IO runtime, with at most
+/// `max_tables_in_flight` tables buffered ahead of the consumer: reads are paused while the consumer
+/// falls behind, and stopped once the stream is dropped.
+///
+/// All [`Table`] objects returned will have the same [`Schema`] as [`ScanTask::materialized_schema`].
+/// Only native Parquet, CSV and JSON reads can be streamed.
+///
+/// # Arguments
+///
+/// * `scan_task` - the ScanTask to stream as Tables
+/// * `io_stats` - an optional IOStats object to record the IO operations performed
+/// * `max_tables_in_flight` - the maximum number of Tables read ahead of the consumer
+pub fn stream_scan_task(
+    scan_task: Arc<ScanTask>,
+    io_stats: Option<IOStatsRef>,
+    max_tables_in_flight: usize,
+) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+    let native_storage_config = match scan_task.storage_config.as_ref() {
+        StorageConfig::Native(native_storage_config) => native_storage_config.clone(),
+        #[cfg(feature = "python")]
+        StorageConfig::Python(_) => {
+            return Err(common_error::DaftError::TypeError(
+                "Streaming reads with Python storage not implemented".to_string(),
+            ));
+        }
+    };
+    if scan_task.pushdowns.aggregation.is_some() {
+        return Err(common_error::DaftError::TypeError(
+            "Streaming reads with aggregation pushdowns not implemented".to_string(),
+        ));
+    }
+    match scan_task.file_format_config.as_ref() {
+        FileFormatConfig::Parquet(_) | FileFormatConfig::Csv(_) | FileFormatConfig::Json(_) => {}
+        file_format_config => {
+            return Err(common_error::DaftError::TypeError(format!(
+                "Streaming reads for {} file format not implemented",
+                file_format_config.var_name()
+            )));
+        }
+    }
+    let runtime_handle = daft_io::get_runtime(native_storage_config.multithreaded_io)?;
+    let io_config = Arc::new(
+        native_storage_config
+            .io_config
+            .as_ref()
+            .cloned()
+            .unwrap_or_default(),
+    );
+    let io_client = daft_io::get_io_client(native_storage_config.multithreaded_io, io_config)?;
+
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(max_tables_in_flight.max(1));
+    runtime_handle.spawn(async move {
+        let cast_to_schema = scan_task.materialized_schema();
+        let fill_map = scan_task.partition_spec().map(|pspec| pspec.to_fill_map());
+        let mut remaining_rows = scan_task.pushdowns.limit;
+        for source in scan_task.sources.iter() {
+            let mut tables = match stream_scan_task_source(
+                &scan_task,
+                source,
+                remaining_rows,
+                io_client.clone(),
+                io_stats.clone(),
+            ) {
+                Ok(tables) => tables,
+                Err(err) => {
+                    let _ = sender.send(Err(err)).await;
+                    return;
+                }
+            };
+            while let Some(table) = tables.next().await {
+                let table = table.and_then(|table| {
+                    remaining_rows =
+                        remaining_rows.map(|rows_left| rows_left.saturating_sub(table.len()));
+                    table.cast_to_schema_with_fill(cast_to_schema.as_ref(), fill_map.as_ref())
+                });
+                let failed = table.is_err();
+                // Stop reading once the stream has been dropped, or has failed.
+                if sender.send(table).await.is_err() || failed {
+                    return;
+                }
+            }
+            if remaining_rows == Some(0) {
+                return;
+            }
+        }
+    });
+    Ok(futures::stream::poll_fn(move |cx| receiver.poll_recv(cx)).boxed())
+}
+
+/// Streams a single source of a [`ScanTask`] as [`Table`]s, with the schema of the file.
+fn stream_scan_task_source(
+    scan_task: &ScanTask,
+    source: &DataFileSource,
+    limit: Option<usize>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+    let pushdown_columns = scan_task
+        .pushdowns
+        .columns
+        .as_ref()
+        .map(|v| v.iter().map(|s| s.as_str()).collect::<Vec<&str>>());
+    let file_column_names =
+        _get_file_column_names(pushdown_columns.as_deref(), scan_task.partition_spec());
+    let uri = source.get_path().to_string();
+
+    Ok(match scan_task.file_format_config.as_ref() {
+        FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit,
+            field_id_mapping,
+        }) => {
+            let row_groups = match source.get_chunk_spec() {
+                Some(ChunkSpec::Parquet(row_groups)) => Some(row_groups.clone()),
+                _ => None,
+            };
+            daft_parquet::read::stream_parquet(
+                uri,
+                file_column_names.map(|cols| cols.iter().map(|col| col.to_string()).collect()),
+                limit,
+                row_groups,
+                scan_task.pushdowns.filters.clone(),
+                PARQUET_STREAM_BATCH_SIZE,
+                io_client,
+                io_stats,
+                ParquetSchemaInferenceOptions::new(Some(*coerce_int96_timestamp_unit)),
+                field_id_mapping.clone(),
+            )
+        }
+        FileFormatConfig::Csv(cfg) => {
+            let (convert_options, parse_options, read_options) =
+                csv_options_for_scan_task(scan_task, cfg, file_column_names.as_deref(), limit)?;
+            daft_csv::stream_csv(
+                uri,
+                Some(convert_options),
+                Some(parse_options),
+                Some(read_options),
+                source_to_byte_range(source),
+                io_client,
+                io_stats,
+                None,
+            )
+        }
+        FileFormatConfig::Json(cfg) => {
+            let (convert_options, parse_options, read_options) =
+                json_options_for_scan_task(scan_task, cfg, file_column_names.as_deref(), limit);
+            daft_json::stream_json(
+                uri,
+                Some(convert_options),
+                Some(parse_options),
+                Some(read_options),
+                source_to_byte_range(source),
+                io_client,
+                io_stats,
+                None,
+            )
+        }
+        file_format_config => {
+            return Err(common_error::DaftError::TypeError(format!(
+                "Streaming reads for {} file format not implemented",
+                file_format_config.var_name()
+            )));
+        }
+    })
+}
+
+/// Number of rows in each [`Table`] streamed from a Parquet file.
+const PARQUET_STREAM_BATCH_SIZE: usize = 128 * 1024;
+
+fn csv_options_for_scan_task(
+    scan_task: &ScanTask,
+    cfg: &CsvSourceConfig,
+    file_column_names: Option<&[&str]>,
+    limit: Option<usize>,
+) -> crate::Result<(CsvConvertOptions, CsvParseOptions, CsvReadOptions)> {
+    let schema_of_file = scan_task.schema.clone();
+    let col_names = if !cfg.has_headers {
+        Some(
+            schema_of_file
+                .fields
+                .values()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>(),
+        )
+    } else {
+        None
+    };
+    let convert_options = CsvConvertOptions::new_internal(
+        limit,
+        file_column_names.map(|cols| cols.iter().map(|col| col.to_string()).collect()),
+        col_names
+            .as_ref()
+            .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
+        None,
+        scan_task.pushdowns.filters.clone(),
+    )
+    .with_mode(cfg.mode)
+    .with_corrupt_record_column(cfg.corrupt_record_column.clone());
+    let parse_options = CsvParseOptions::new_with_defaults(
+        cfg.has_headers,
+        cfg.delimiter.as_deref(),
+        cfg.double_quote,
+        cfg.quote,
+        cfg.escape_char,
+        cfg.comment,
+    )
+    .context(DaftCSVSnafu)?
+    .with_null_values(cfg.null_values.clone())
+    .with_true_values(cfg.true_values.clone())
+    .with_false_values(cfg.false_values.clone())
+    .with_skip_rows(cfg.skip_rows)
+    .with_ragged_rows(cfg.ragged_rows);
+    let read_options = CsvReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
+    Ok((convert_options, parse_options, read_options))
+}
+
+fn json_options_for_scan_task(
+    scan_task: &ScanTask,
+    cfg: &JsonSourceConfig,
+    file_column_names: Option<&[&str]>,
+    limit: Option<usize>,
+) -> (JsonConvertOptions, JsonParseOptions, JsonReadOptions) {
+    let convert_options = JsonConvertOptions::new_internal(
+        limit,
+        file_column_names.map(|cols| cols.iter().map(|col| col.to_string()).collect()),
+        Some(scan_task.schema.clone()),
+        scan_task.pushdowns.filters.clone(),
+    )
+    .with_mode(cfg.mode)
+    .with_corrupt_record_column(cfg.corrupt_record_column.clone());
+    let parse_options = JsonParseOptions::new_internal();
+    let read_options = JsonReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
+    (convert_options, parse_options, read_options)
+}
+
 impl MicroPartition {
     /// Create a new "unloaded" MicroPartition using an associated [`ScanTask`]
     ///
@@ -423,6 +763,20 @@ impl MicroPartition {
     }
 
     pub fn from_scan_task(scan_task: Arc<ScanTask>, io_stats: IOStatsRef) -> crate::Result<Self> {
+        // CASE: ScanTask computes an aggregation over its data.
+        // Read the data without the aggregation (which only reads metadata if possible), and then aggregate it.
+        if let Some(aggregation) = &scan_task.pushdowns.aggregation {
+            let data_scan_task = ScanTask::new(
+                scan_task.sources.clone(),
+                scan_task.file_format_config.clone(),
+                scan_task.schema.clone(),
+                scan_task.storage_config.clone(),
+                scan_task.pushdowns.with_aggregation(None),
+            );
+            return Self::from_scan_task(Arc::new(data_scan_task), io_stats)?
+                .agg_pushdown(aggregation, scan_task.partition_spec())
+                .context(DaftCoreComputeSnafu);
+        }
         let schema = scan_task.materialized_schema();
         match (
             &scan_task.metadata,
@@ -662,6 +1016,41 @@ fn parquet_sources_to_row_groups(sources: &[DataFileSource]) -> Option<Vec<Optio
     }
 }
 
+fn orc_sources_to_stripes(sources: &[DataFileSource]) -> Option<Vec<Option<Vec<i64>>>> {
+    let stripes = sources
+        .iter()
+        .map(|s| {
+            if let Some(ChunkSpec::Orc(stripes)) = s.get_chunk_spec() {
+                Some(stripes.clone())
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    if stripes.iter().any(|stripes| stripes.is_some()) {
+        Some(stripes)
+    } else {
+        None
+    }
+}
+
+fn source_to_byte_range(source: &DataFileSource) -> Option<Range<usize>> {
+    if let Some(ChunkSpec::Bytes { start, end }) = source.get_chunk_spec() {
+        Some(*start..*end)
+    } else {
+        None
+    }
+}
+
+fn sources_to_byte_ranges(sources: &[DataFileSource]) -> Option<Vec<Option<Range<usize>>>> {
+    let byte_ranges = sources.iter().map(source_to_byte_range).collect::<Vec<_>>();
+    if byte_ranges.iter().any(|range| range.is_some()) {
+        Some(byte_ranges)
+    } else {
+        None
+    }
+}
+
 pub(crate) fn read_csv_into_micropartition(
     uris: &[&str],
     convert_options: Option<CsvConvertOptions>,
@@ -682,6 +1071,7 @@ pub(crate) fn read_csv_into_micropartition(
                 convert_options,
                 parse_options,
                 read_options,
+                None,
                 io_client,
                 io_stats,
                 multithreaded_io,
@@ -731,6 +1121,7 @@ pub(crate) fn read_json_into_micropartition(
                 convert_options,
                 parse_options,
                 read_options,
+                None,
                 io_client,
                 io_stats,
                 multithreaded_io,
@@ -1003,6 +1394,7 @@ pub(crate) fn read_parquet_into_micropartition(
                 columns
                     .map(|cols| Arc::new(cols.iter().map(|v| v.to_string()).collect::<Vec<_>>())),
                 num_rows,
+                None,
             ),
         );
 
@@ -1064,4 +1456,139 @@ impl Display for MicroPartition {
 }
 
 #[cfg(test)]
-mod test {}
+mod test {
+    use std::sync::Arc;
+
+    use common_error::{DaftError, DaftResult};
+    use daft_core::{array::ops::as_arrow::AsArrow, datatypes::Field, schema::Schema, DataType};
+    use daft_scan::{
+        file_format::{FileFormatConfig, JsonSourceConfig},
+        storage_config::{NativeStorageConfig, StorageConfig},
+        DataFileSource, Pushdowns, ScanTask,
+    };
+    use daft_table::Table;
+    use futures::{StreamExt, TryStreamExt};
+
+    use super::stream_scan_task;
+
+    /// Writes a JSON file of 10 records, with an `a` column holding 0 to 9.
+    fn write_json(dir: &tempfile::TempDir, name: &str) -> std::io::Result<String> {
+        let path = dir.path().join(name);
+        let records = (0..10)
+            .map(|i| format!("{{\"a\": {i}}}\n"))
+            .collect::<String>();
+        std::fs::write(&path, records)?;
+        Ok(path.to_string_lossy().to_string())
+    }
+
+    /// A scan task over JSON files, which are read 4 records at a time.
+    fn json_scan_task(paths: &[String], limit: Option<usize>) -> DaftResult<Arc<ScanTask>> {
+        let sources = paths
+            .iter()
+            .map(|path| DataFileSource::AnonymousDataFile {
+                path: path.clone(),
+                chunk_spec: None,
+                size_bytes: None,
+                metadata: None,
+                partition_spec: None,
+                statistics: None,
+            })
+            .collect();
+        Ok(ScanTask::new(
+            sources,
+            FileFormatConfig::Json(JsonSourceConfig::new_internal(None, Some(4))).into(),
+            Schema::new(vec![Field::new("a", DataType::Int64)])?.into(),
+            StorageConfig::Native(NativeStorageConfig::new_internal(true, None).into()).into(),
+            Pushdowns::default().with_limit(limit),
+        )
+        .into())
+    }
+
+    fn collect(scan_task: Arc<ScanTask>) -> DaftResult<Vec<Table>> {
+        daft_io::get_runtime(true)?.block_on(stream_scan_task(scan_task, None, 1)?.try_collect())
+    }
+
+    fn values(tables: &[Table]) -> DaftResult<Vec<i64>> {
+        Ok(Table::concat(tables)?
+            .get_column("a")?
+            .i64()?
+            .as_arrow()
+            .values()
+            .to_vec())
+    }
+
+    #[test]
+    fn test_stream_scan_task_batches_and_limit() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let paths = vec![write_json(&dir, "0.jsonl")?, write_json(&dir, "1.jsonl")?];
+
+        // The sources are streamed one after the other, in batches of the chunk size.
+        let tables = collect(json_scan_task(&paths, None)?)?;
+        assert_eq!(
+            tables.iter().map(Table::len).collect::<Vec<_>>(),
+            vec![4, 4, 2, 4, 4, 2]
+        );
+        assert_eq!(
+            values(&tables)?,
+            [(0..10).collect::<Vec<_>>(), (0..10).collect()].concat()
+        );
+
+        // The limit spans the sources, and stops the stream once it's reached.
+        let tables = collect(json_scan_task(&paths, Some(12))?)?;
+        assert_eq!(
+            tables.iter().map(Table::len).collect::<Vec<_>>(),
+            vec![4, 4, 2, 2]
+        );
+        let tables = collect(json_scan_task(&paths, Some(0))?)?;
+        assert!(tables.iter().all(Table::is_empty));
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_scan_task_drop_early() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let paths = vec![write_json(&dir, "0.jsonl")?, write_json(&dir, "1.jsonl")?];
+
+        let mut tables = stream_scan_task(json_scan_task(&paths, None)?, None, 1)?;
+        let runtime_handle = daft_io::get_runtime(true)?;
+        assert_eq!(
+            runtime_handle
+                .block_on(tables.try_next())?
+                .map(|table| table.len()),
+            Some(4)
+        );
+        // Dropping the stream stops the reads of the scan task.
+        drop(tables);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_scan_task_errors() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let paths = vec![
+            write_json(&dir, "0.jsonl")?,
+            dir.path()
+                .join("missing.jsonl")
+                .to_string_lossy()
+                .to_string(),
+        ];
+
+        // The tables of the sources before the failing one are streamed, followed by its error.
+        let mut tables = stream_scan_task(json_scan_task(&paths, None)?, None, 1)?;
+        let runtime_handle = daft_io::get_runtime(true)?;
+        for len in [4, 4, 2] {
+            assert_eq!(
+                runtime_handle
+                    .block_on(tables.try_next())?
+                    .map(|table| table.len()),
+                Some(len)
+            );
+        }
+        assert!(matches!(
+            runtime_handle.block_on(tables.next()),
+            Some(Err(DaftError::IoError(_)))
+        ));
+        assert!(runtime_handle.block_on(tables.next()).is_none());
+        Ok(())
+    }
+}
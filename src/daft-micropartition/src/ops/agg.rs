@@ -1,9 +1,14 @@
+use std::ops::Deref;
+
 use common_error::DaftResult;
-use daft_dsl::Expr;
+use daft_core::{count_mode::CountMode, datatypes::UInt64Array, IntoSeries};
+use daft_dsl::{AggExpr, Expr};
 use daft_io::IOStatsContext;
+use daft_scan::AggregationPushdown;
+use daft_stats::{ColumnRangeStatistics, PartitionSpec};
 use daft_table::Table;
 
-use crate::micropartition::MicroPartition;
+use crate::micropartition::{MicroPartition, TableState};
 
 impl MicroPartition {
     pub fn agg(&self, to_agg: &[Expr], group_by: &[Expr]) -> DaftResult<Self> {
@@ -32,4 +37,92 @@ impl MicroPartition {
             _ => unreachable!(),
         }
     }
+
+    /// Compute an aggregation that was pushed down into the ScanTask that this MicroPartition was read from.
+    ///
+    /// If the MicroPartition hasn't been loaded, the aggregation is derived from its metadata and statistics when
+    /// possible, so that no data needs to be read; otherwise, the data is aggregated.
+    pub(crate) fn agg_pushdown(
+        &self,
+        aggregation: &AggregationPushdown,
+        partition_spec: Option<&PartitionSpec>,
+    ) -> DaftResult<Self> {
+        if let Some(agged) = self.agg_from_metadata(aggregation, partition_spec)? {
+            return Ok(MicroPartition::new_loaded(
+                agged.schema.clone(),
+                vec![agged].into(),
+                None,
+            ));
+        }
+        let to_agg = aggregation
+            .aggregations
+            .iter()
+            .map(|agg| Expr::Agg(agg.clone()))
+            .collect::<Vec<_>>();
+        let group_by = aggregation
+            .groupby
+            .iter()
+            .map(|e| e.as_ref().clone())
+            .collect::<Vec<_>>();
+        self.agg(&to_agg, &group_by)
+    }
+
+    /// Derive an aggregation from the row count, column statistics and partition values of an unloaded
+    /// MicroPartition, returning None if they don't determine the result.
+    fn agg_from_metadata(
+        &self,
+        aggregation: &AggregationPushdown,
+        partition_spec: Option<&PartitionSpec>,
+    ) -> DaftResult<Option<Table>> {
+        // Loaded data is aggregated directly, and empty data has no groups.
+        if matches!(self.state.lock().unwrap().deref(), TableState::Loaded(_)) || self.len() == 0 {
+            return Ok(None);
+        }
+        let mut columns = vec![];
+        if !aggregation.groupby.is_empty() {
+            // All rows of a ScanTask have the same partition values, which form the only group.
+            let Some(partition_spec) = partition_spec else {
+                return Ok(None);
+            };
+            let group_by = aggregation
+                .groupby
+                .iter()
+                .map(|e| e.as_ref().clone())
+                .collect::<Vec<_>>();
+            let groups = partition_spec.keys.eval_expression_list(&group_by)?;
+            for name in groups.column_names() {
+                columns.push(groups.get_column(&name)?.clone());
+            }
+        }
+        for agg in aggregation.aggregations.iter() {
+            let name = agg.name()?;
+            let column = match agg {
+                AggExpr::Count(_, CountMode::All) => {
+                    UInt64Array::from((name, vec![self.len() as u64])).into_series()
+                }
+                AggExpr::Min(_) | AggExpr::Max(_) => {
+                    let Some(column) = AggregationPushdown::min_max_column(agg) else {
+                        return Ok(None);
+                    };
+                    match self
+                        .statistics
+                        .as_ref()
+                        .and_then(|stats| stats.columns.get(column))
+                    {
+                        Some(ColumnRangeStatistics::Loaded(lower, _))
+                            if matches!(agg, AggExpr::Min(_)) =>
+                        {
+                            lower.rename(name)
+                        }
+                        Some(ColumnRangeStatistics::Loaded(_, upper)) => upper.rename(name),
+                        _ => return Ok(None),
+                    }
+                }
+                _ => return Ok(None),
+            };
+            columns.push(column);
+        }
+        let agged = Table::from_columns(columns)?;
+        Ok(Some(agged.cast_to_schema(&aggregation.output_schema)?))
+    }
 }
@@ -1,62 +1,104 @@
 use common_error::DaftResult;
-use daft_core::array::ops::DaftCompare;
+use daft_core::{array::ops::DaftCompare, JoinType};
 use daft_dsl::Expr;
-use daft_io::IOStatsContext;
-use daft_table::infer_join_schema;
+use daft_io::{IOStatsContext, IOStatsRef};
+use daft_table::{infer_join_schema, Table};
 
 use crate::micropartition::MicroPartition;
 
 use daft_stats::TruthValue;
 
 impl MicroPartition {
-    pub fn hash_join(&self, right: &Self, left_on: &[Expr], right_on: &[Expr]) -> DaftResult<Self> {
-        let io_stats = IOStatsContext::new("MicroPartition::hash_join");
-        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on)?;
+    fn join<F>(
+        &self,
+        right: &Self,
+        io_stats: IOStatsRef,
+        left_on: &[Expr],
+        right_on: &[Expr],
+        how: JoinType,
+        table_join: F,
+    ) -> DaftResult<Self>
+    where
+        F: FnOnce(&Table, &Table, &[Expr], &[Expr], JoinType) -> DaftResult<Table>,
+    {
+        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on, how)?;
 
-        if self.len() == 0 || right.len() == 0 {
+        // Whether the join output is empty when the given side of the join is empty.
+        let is_empty_if = |left_is_empty: bool, right_is_empty: bool| match how {
+            JoinType::Left | JoinType::Anti => left_is_empty,
+            JoinType::Right => right_is_empty,
+            JoinType::Outer => left_is_empty && right_is_empty,
+            JoinType::Inner | JoinType::Semi | JoinType::Cross => left_is_empty || right_is_empty,
+        };
+        if is_empty_if(self.len() == 0, right.len() == 0) {
             return Ok(Self::empty(Some(join_schema.into())));
         }
 
-        let tv = match (&self.statistics, &right.statistics) {
-            (_, None) => TruthValue::Maybe,
-            (None, _) => TruthValue::Maybe,
-            (Some(l), Some(r)) => {
-                let l_eval_stats = l.eval_expression_list(left_on, &self.schema)?;
-                let r_eval_stats = r.eval_expression_list(right_on, &right.schema)?;
-                let mut curr_tv = TruthValue::Maybe;
-                for (lc, rc) in l_eval_stats
-                    .columns
-                    .values()
-                    .zip(r_eval_stats.columns.values())
-                {
-                    if let TruthValue::False = lc.equal(rc)?.to_truth_value() {
-                        curr_tv = TruthValue::False;
-                        break;
+        // Disjoint join keys only mean an empty output if the join doesn't keep unmatched rows.
+        if matches!(how, JoinType::Inner | JoinType::Semi) {
+            let tv = match (&self.statistics, &right.statistics) {
+                (_, None) => TruthValue::Maybe,
+                (None, _) => TruthValue::Maybe,
+                (Some(l), Some(r)) => {
+                    let l_eval_stats = l.eval_expression_list(left_on, &self.schema)?;
+                    let r_eval_stats = r.eval_expression_list(right_on, &right.schema)?;
+                    let mut curr_tv = TruthValue::Maybe;
+                    for (lc, rc) in l_eval_stats
+                        .columns
+                        .values()
+                        .zip(r_eval_stats.columns.values())
+                    {
+                        if let TruthValue::False = lc.equal(rc)?.to_truth_value() {
+                            curr_tv = TruthValue::False;
+                            break;
+                        }
                     }
+                    curr_tv
                 }
-                curr_tv
+            };
+            if let TruthValue::False = tv {
+                return Ok(Self::empty(Some(join_schema.into())));
             }
-        };
-        if let TruthValue::False = tv {
-            return Ok(Self::empty(Some(join_schema.into())));
         }
 
         // TODO(Clark): Elide concatenations where possible by doing a chunk-aware local table join.
         let lt = self.concat_or_get(io_stats.clone())?;
         let rt = right.concat_or_get(io_stats)?;
 
-        match (lt.as_slice(), rt.as_slice()) {
-            ([], _) | (_, []) => Ok(Self::empty(Some(join_schema.into()))),
-            ([lt], [rt]) => {
-                let joined_table = lt.hash_join(rt, left_on, right_on)?;
-                Ok(MicroPartition::new_loaded(
-                    join_schema.into(),
-                    vec![joined_table].into(),
-                    None,
-                ))
+        let empty_left;
+        let empty_right;
+        let (lt, rt) = match (lt.as_slice(), rt.as_slice()) {
+            (l, r) if is_empty_if(l.is_empty(), r.is_empty()) => {
+                return Ok(Self::empty(Some(join_schema.into())));
+            }
+            ([], [rt]) => {
+                empty_left = Table::empty(Some(self.schema.clone()))?;
+                (&empty_left, rt)
+            }
+            ([lt], []) => {
+                empty_right = Table::empty(Some(right.schema.clone()))?;
+                (lt, &empty_right)
             }
+            ([lt], [rt]) => (lt, rt),
             _ => unreachable!(),
-        }
+        };
+        let joined_table = table_join(lt, rt, left_on, right_on, how)?;
+        Ok(MicroPartition::new_loaded(
+            join_schema.into(),
+            vec![joined_table].into(),
+            None,
+        ))
+    }
+
+    pub fn hash_join(
+        &self,
+        right: &Self,
+        left_on: &[Expr],
+        right_on: &[Expr],
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::hash_join");
+        self.join(right, io_stats, left_on, right_on, how, Table::hash_join)
     }
 
     pub fn sort_merge_join(
@@ -64,54 +106,19 @@ impl MicroPartition {
         right: &Self,
         left_on: &[Expr],
         right_on: &[Expr],
+        how: JoinType,
         is_sorted: bool,
     ) -> DaftResult<Self> {
         let io_stats = IOStatsContext::new("MicroPartition::sort_merge_join");
-        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on)?;
-
-        if self.len() == 0 || right.len() == 0 {
-            return Ok(Self::empty(Some(join_schema.into())));
-        }
-
-        let tv = match (&self.statistics, &right.statistics) {
-            (_, None) => TruthValue::Maybe,
-            (None, _) => TruthValue::Maybe,
-            (Some(l), Some(r)) => {
-                let l_eval_stats = l.eval_expression_list(left_on, &self.schema)?;
-                let r_eval_stats = r.eval_expression_list(right_on, &right.schema)?;
-                let mut curr_tv = TruthValue::Maybe;
-                for (lc, rc) in l_eval_stats
-                    .columns
-                    .values()
-                    .zip(r_eval_stats.columns.values())
-                {
-                    if let TruthValue::False = lc.equal(rc)?.to_truth_value() {
-                        curr_tv = TruthValue::False;
-                        break;
-                    }
-                }
-                curr_tv
-            }
-        };
-        if let TruthValue::False = tv {
-            return Ok(Self::empty(Some(join_schema.into())));
-        }
-
-        // TODO(Clark): Elide concatenations where possible by doing a chunk-aware local table join.
-        let lt = self.concat_or_get(io_stats.clone())?;
-        let rt = right.concat_or_get(io_stats)?;
-
-        match (lt.as_slice(), rt.as_slice()) {
-            ([], _) | (_, []) => Ok(Self::empty(Some(join_schema.into()))),
-            ([lt], [rt]) => {
-                let joined_table = lt.sort_merge_join(rt, left_on, right_on, is_sorted)?;
-                Ok(MicroPartition::new_loaded(
-                    join_schema.into(),
-                    vec![joined_table].into(),
-                    None,
-                ))
-            }
-            _ => unreachable!(),
-        }
+        self.join(
+            right,
+            io_stats,
+            left_on,
+            right_on,
+            how,
+            |lt, rt, left_on, right_on, how| {
+                lt.sort_merge_join(rt, left_on, right_on, how, is_sorted)
+            },
+        )
     }
 }
@@ -8,3 +8,5 @@ mod partition;
 mod slice;
 mod sort;
 mod take;
+mod window;
+mod write;
@@ -0,0 +1,38 @@
+use common_error::DaftResult;
+use daft_dsl::{Expr, WindowFrame};
+use daft_io::IOStatsContext;
+use daft_table::Table;
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    pub fn window(
+        &self,
+        to_window: &[Expr],
+        partition_by: &[Expr],
+        order_by: &[Expr],
+        descending: &[bool],
+        frame: &WindowFrame,
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::window");
+
+        let tables = self.concat_or_get(io_stats)?;
+
+        let windowed = match tables.as_slice() {
+            [] => Table::empty(Some(self.schema.clone()))?.window(
+                to_window,
+                partition_by,
+                order_by,
+                descending,
+                frame,
+            )?,
+            [t] => t.window(to_window, partition_by, order_by, descending, frame)?,
+            _ => unreachable!(),
+        };
+        Ok(MicroPartition::new_loaded(
+            windowed.schema.clone(),
+            vec![windowed].into(),
+            None,
+        ))
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::DaftResult;
+use daft_io::{IOConfig, IOStatsContext};
+use daft_parquet::write::ParquetWriteOptions;
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    /// Writes this partition as Parquet files under `root_dir`, returning the written `path`s and their `num_rows`.
+    pub fn write_parquet(
+        &self,
+        root_dir: &str,
+        compression: Option<String>,
+        io_config: Arc<IOConfig>,
+        execution_config: &DaftExecutionConfig,
+        multithreaded_io: bool,
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new(format!("MicroPartition::write_parquet: {root_dir}"));
+
+        let tables = self.tables_or_read(io_stats.clone())?;
+        let runtime_handle = daft_io::get_runtime(multithreaded_io)?;
+        let io_client = daft_io::get_io_client(multithreaded_io, io_config)?;
+        let options = ParquetWriteOptions::from_execution_config(execution_config, compression);
+
+        let written = daft_parquet::write::write_parquet(
+            tables.as_slice(),
+            &self.schema,
+            root_dir,
+            &options,
+            io_client,
+            Some(io_stats),
+            runtime_handle,
+        )?;
+        Ok(MicroPartition::new_loaded(
+            written.schema.clone(),
+            Arc::new(vec![written]),
+            None,
+        ))
+    }
+}
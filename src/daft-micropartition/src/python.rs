@@ -3,20 +3,22 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use common_daft_config::PyDaftExecutionConfig;
 use common_error::DaftResult;
 use daft_core::{
     python::{datatype::PyTimeUnit, schema::PySchema, PySeries},
     schema::Schema,
-    Series,
+    JoinType, Series,
 };
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
-use daft_dsl::python::PyExpr;
+use daft_dsl::{python::PyExpr, WindowFrame};
 use daft_io::{python::IOConfig, IOStatsContext};
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 use daft_parquet::read::ParquetSchemaInferenceOptions;
 use daft_scan::{python::pylib::PyScanTask, storage_config::PyStorageConfig, ScanTask};
 use daft_stats::TableStatistics;
-use daft_table::python::PyTable;
+use daft_table::{python::PyTable, Table};
+use futures::{stream::BoxStream, StreamExt};
 use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes, Python};
 
 use crate::micropartition::{MicroPartition, TableState};
@@ -87,6 +89,26 @@ impl PyMicroPartition {
             .into())
     }
 
+    #[staticmethod]
+    #[pyo3(signature = (scan_task, max_tables_in_flight=1))]
+    pub fn stream_scan_task(
+        scan_task: PyScanTask,
+        max_tables_in_flight: usize,
+    ) -> PyResult<PyTableStream> {
+        let io_stats = IOStatsContext::new(format!(
+            "MicroPartition::stream_scan_task for {:?}",
+            scan_task.0.sources
+        ));
+        let stream = crate::micropartition::stream_scan_task(
+            scan_task.into(),
+            Some(io_stats),
+            max_tables_in_flight,
+        )?;
+        Ok(PyTableStream {
+            stream: Some(stream),
+        })
+    }
+
     #[staticmethod]
     pub fn from_tables(tables: Vec<PyTable>) -> PyResult<Self> {
         match &tables[..] {
@@ -222,19 +244,57 @@ impl PyMicroPartition {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn window(
+        &self,
+        py: Python,
+        to_window: Vec<PyExpr>,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+        frame_start: Option<i64>,
+        frame_end: Option<i64>,
+    ) -> PyResult<Self> {
+        let converted_to_window: Vec<daft_dsl::Expr> =
+            to_window.into_iter().map(|e| e.into()).collect();
+        let converted_partition_by: Vec<daft_dsl::Expr> =
+            partition_by.into_iter().map(|e| e.into()).collect();
+        let converted_order_by: Vec<daft_dsl::Expr> =
+            order_by.into_iter().map(|e| e.into()).collect();
+        let frame = WindowFrame::new(frame_start, frame_end)?;
+        py.allow_threads(|| {
+            Ok(self
+                .inner
+                .window(
+                    converted_to_window.as_slice(),
+                    converted_partition_by.as_slice(),
+                    converted_order_by.as_slice(),
+                    descending.as_slice(),
+                    &frame,
+                )?
+                .into())
+        })
+    }
+
     pub fn hash_join(
         &self,
         py: Python,
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
     ) -> PyResult<Self> {
         let left_exprs: Vec<daft_dsl::Expr> = left_on.into_iter().map(|e| e.into()).collect();
         let right_exprs: Vec<daft_dsl::Expr> = right_on.into_iter().map(|e| e.into()).collect();
         py.allow_threads(|| {
             Ok(self
                 .inner
-                .hash_join(&right.inner, left_exprs.as_slice(), right_exprs.as_slice())?
+                .hash_join(
+                    &right.inner,
+                    left_exprs.as_slice(),
+                    right_exprs.as_slice(),
+                    how,
+                )?
                 .into())
         })
     }
@@ -245,6 +305,7 @@ impl PyMicroPartition {
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
         is_sorted: bool,
     ) -> PyResult<Self> {
         let left_exprs: Vec<daft_dsl::Expr> = left_on.into_iter().map(|e| e.into()).collect();
@@ -256,6 +317,7 @@ impl PyMicroPartition {
                     &right.inner,
                     left_exprs.as_slice(),
                     right_exprs.as_slice(),
+                    how,
                     is_sorted,
                 )?
                 .into())
@@ -417,6 +479,31 @@ impl PyMicroPartition {
         })
     }
 
+    pub fn write_parquet(
+        &self,
+        py: Python,
+        root_dir: &str,
+        compression: Option<String>,
+        io_config: Option<IOConfig>,
+        execution_config: Option<PyDaftExecutionConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<Self> {
+        py.allow_threads(|| {
+            let io_config = io_config.unwrap_or_default().config.into();
+            let execution_config = execution_config.unwrap_or_default().config;
+            Ok(self
+                .inner
+                .write_parquet(
+                    root_dir,
+                    compression,
+                    io_config,
+                    execution_config.as_ref(),
+                    multithreaded_io.unwrap_or(true),
+                )?
+                .into())
+        })
+    }
+
     pub fn add_monotonically_increasing_id(
         &self,
         py: Python,
@@ -692,6 +779,38 @@ impl PyMicroPartition {
     }
 }
 
+/// Iterator over the Tables of a streamed ScanTask, see [`crate::stream_scan_task`].
+///
+/// Dropping or closing the iterator stops the reads of the ScanTask.
+#[pyclass(module = "daft.daft")]
+struct PyTableStream {
+    stream: Option<BoxStream<'static, DaftResult<Table>>>,
+}
+
+#[pymethods]
+impl PyTableStream {
+    pub fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    pub fn __next__(&mut self, py: Python) -> PyResult<Option<PyTable>> {
+        let Some(stream) = self.stream.as_mut() else {
+            return Ok(None);
+        };
+        match py.allow_threads(|| futures::executor::block_on(stream.next())) {
+            Some(table) => Ok(Some(table?.into())),
+            None => {
+                self.stream = None;
+                Ok(None)
+            }
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.stream = None;
+    }
+}
+
 pub(crate) fn read_json_into_py_table(
     py: Python,
     uri: &str,
@@ -723,7 +842,7 @@ pub(crate) fn read_csv_into_py_table(
     py: Python,
     uri: &str,
     has_header: bool,
-    delimiter: Option<char>,
+    delimiter: Option<&str>,
     double_quote: bool,
     schema: PySchema,
     storage_config: PyStorageConfig,
@@ -839,5 +958,6 @@ impl From<MicroPartition> for PyMicroPartition {
 
 pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_class::<PyMicroPartition>()?;
+    parent.add_class::<PyTableStream>()?;
     Ok(())
 }
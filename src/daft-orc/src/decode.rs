@@ -0,0 +1,305 @@
+//! Decoders of the streams of an ORC column into arrow2 arrays.
+//!
+//! The arrow2 ORC decoders only cover a few types and expect every column to have a PRESENT stream,
+//! which writers leave out for columns without nulls, so the columns are decoded here instead, with
+//! the run-length decoders of `orc-format`.
+
+use std::io::Read;
+
+use arrow2::{
+    array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::{DataType, TimeUnit},
+    error::{Error, Result},
+    io::orc::format::{
+        error::Error as OrcError,
+        proto::{column_encoding::Kind as EncodingKind, stream::Kind as StreamKind},
+        read::{
+            decode::{BooleanIter, Float, FloatIter, SignedRleV2Iter, UnsignedRleV2Iter},
+            decompress::Decompressor,
+            Column,
+        },
+    },
+    offset::Offsets,
+    types::NativeType,
+};
+
+/// Seconds from the UNIX epoch to 2015-01-01 00:00:00, which ORC timestamps are relative to.
+const ORC_TIMESTAMP_EPOCH: i64 = 1_420_070_400;
+
+/// Deserializes an ORC column as an array of `data_type`.
+pub(crate) fn deserialize(data_type: &DataType, column: &Column) -> Result<Box<dyn Array>> {
+    let validity = read_validity(column)?;
+    // Only the non-null values are stored.
+    let num_values = validity
+        .as_ref()
+        .map_or(column.number_of_rows(), |v| v.len() - v.unset_bits());
+    let data_type = data_type.clone();
+    let array = match data_type {
+        DataType::Boolean => {
+            let values = BooleanIter::new(get_stream(column, StreamKind::Data)?, num_values)
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            let values = spread(values, validity.as_ref()).into_iter().collect();
+            BooleanArray::try_new(data_type, values, validity)?.boxed()
+        }
+        DataType::Int8 => {
+            let values = read_bytes_rle(get_stream(column, StreamKind::Data)?, num_values)?
+                .into_iter()
+                .map(|byte| byte as i8)
+                .collect();
+            primitive(data_type, values, validity)?
+        }
+        DataType::Int16 => primitive(data_type, read_ints::<i16>(column, num_values)?, validity)?,
+        DataType::Int32 | DataType::Date32 => {
+            primitive(data_type, read_ints::<i32>(column, num_values)?, validity)?
+        }
+        DataType::Int64 => primitive(data_type, read_ints::<i64>(column, num_values)?, validity)?,
+        DataType::Float32 => {
+            primitive(data_type, read_floats::<f32>(column, num_values)?, validity)?
+        }
+        DataType::Float64 => {
+            primitive(data_type, read_floats::<f64>(column, num_values)?, validity)?
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            let values = read_timestamps(column, num_values)?;
+            primitive(data_type, values, validity)?
+        }
+        DataType::Decimal(_, scale) => {
+            let values = read_decimals(column, num_values, scale)?;
+            primitive(data_type, values, validity)?
+        }
+        DataType::Utf8 => {
+            let (offsets, values) = read_binary(column, num_values, validity.as_ref())?;
+            Utf8Array::<i32>::try_new(data_type, offsets.into(), values.into(), validity)?.boxed()
+        }
+        DataType::Binary => {
+            let (offsets, values) = read_binary(column, num_values, validity.as_ref())?;
+            BinaryArray::<i32>::try_new(data_type, offsets.into(), values.into(), validity)?.boxed()
+        }
+        data_type => {
+            return Err(Error::NotYetImplemented(format!(
+                "Deserializing {data_type:?} from ORC"
+            )))
+        }
+    };
+    Ok(array)
+}
+
+fn get_stream(column: &Column, kind: StreamKind) -> Result<Decompressor> {
+    Ok(column.get_stream(kind, vec![])?)
+}
+
+fn read_validity(column: &Column) -> Result<Option<Bitmap>> {
+    let present = match column.get_stream(StreamKind::Present, vec![]) {
+        Ok(present) => present,
+        // Columns without nulls don't have a PRESENT stream.
+        Err(OrcError::InvalidKind(..)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let validity = BooleanIter::new(present, column.number_of_rows())
+        .collect::<std::result::Result<MutableBitmap, _>>()?;
+    Ok(Some(validity.into()))
+}
+
+/// Places the non-null `values` at the valid slots of `validity`, with defaults in the null slots.
+fn spread<T: Default>(values: Vec<T>, validity: Option<&Bitmap>) -> Vec<T> {
+    match validity {
+        Some(validity) => {
+            let mut values = values.into_iter();
+            validity
+                .iter()
+                .map(|is_valid| {
+                    is_valid
+                        .then(|| values.next())
+                        .flatten()
+                        .unwrap_or_default()
+                })
+                .collect()
+        }
+        None => values,
+    }
+}
+
+fn primitive<T: NativeType + Default>(
+    data_type: DataType,
+    values: Vec<T>,
+    validity: Option<Bitmap>,
+) -> Result<Box<dyn Array>> {
+    let values = spread(values, validity.as_ref());
+    Ok(PrimitiveArray::try_new(data_type, values.into(), validity)?.boxed())
+}
+
+fn out_of_spec(message: &str) -> Error {
+    Error::ExternalFormat(format!("Invalid ORC column: {message}"))
+}
+
+/// Decodes the byte run-length encoding, which is used for byte columns.
+fn read_bytes_rle<R: Read>(mut reader: R, num_values: usize) -> Result<Vec<u8>> {
+    let mut values = Vec::with_capacity(num_values);
+    let mut header = [0u8; 1];
+    while values.len() < num_values {
+        reader.read_exact(&mut header)?;
+        let header = header[0] as i8;
+        if header >= 0 {
+            // A run of `header + 3` copies of the next byte.
+            let mut value = [0u8; 1];
+            reader.read_exact(&mut value)?;
+            values.extend(std::iter::repeat(value[0]).take(header as usize + 3));
+        } else {
+            // `-header` literal bytes.
+            let start = values.len();
+            values.resize(start + header.unsigned_abs() as usize, 0);
+            reader.read_exact(&mut values[start..])?;
+        }
+    }
+    values.truncate(num_values);
+    Ok(values)
+}
+
+fn read_ints<T: TryFrom<i64>>(column: &Column, num_values: usize) -> Result<Vec<T>> {
+    SignedRleV2Iter::new(get_stream(column, StreamKind::Data)?, num_values, vec![])
+        .map(|value| T::try_from(value?).map_err(|_| out_of_spec("integer out of range")))
+        .collect()
+}
+
+fn read_floats<T: Float>(column: &Column, num_values: usize) -> Result<Vec<T>> {
+    Ok(
+        FloatIter::<T, _>::new(get_stream(column, StreamKind::Data)?, num_values)
+            .collect::<std::result::Result<Vec<_>, _>>()?,
+    )
+}
+
+/// Timestamps are stored as seconds since the ORC epoch in the DATA stream, and as nanoseconds in
+/// the SECONDARY stream, where the low 3 bits hold the number of trailing decimal zeros dropped.
+fn read_timestamps(column: &Column, num_values: usize) -> Result<Vec<i64>> {
+    let seconds = SignedRleV2Iter::new(get_stream(column, StreamKind::Data)?, num_values, vec![]);
+    let nanos = UnsignedRleV2Iter::new(
+        get_stream(column, StreamKind::Secondary)?,
+        num_values,
+        vec![],
+    );
+    seconds
+        .zip(nanos)
+        .map(|(seconds, nanos)| {
+            let mut seconds = seconds? + ORC_TIMESTAMP_EPOCH;
+            let nanos = nanos?;
+            let zeros = (nanos & 7) as u32;
+            let nanos = match zeros {
+                0 => nanos >> 3,
+                zeros => (nanos >> 3) * 10u64.pow(zeros + 1),
+            } as i64;
+            // Writers truncate the seconds of timestamps before the epoch towards zero, so they're
+            // one too high when there is a fractional part (of more than a millisecond, for
+            // compatibility with the legacy Java writer).
+            if seconds < 0 && nanos > 999_999 {
+                seconds -= 1;
+            }
+            seconds
+                .checked_mul(1_000_000_000)
+                .and_then(|seconds| seconds.checked_add(nanos))
+                .ok_or_else(|| out_of_spec("timestamp out of range"))
+        })
+        .collect()
+}
+
+/// Decimals are stored as unbounded zigzag-encoded varints in the DATA stream, and with their scale
+/// in the SECONDARY stream, which is rescaled to the scale of the column.
+fn read_decimals(column: &Column, num_values: usize, scale: usize) -> Result<Vec<i128>> {
+    let mut data = get_stream(column, StreamKind::Data)?;
+    let scales = SignedRleV2Iter::new(
+        get_stream(column, StreamKind::Secondary)?,
+        num_values,
+        vec![],
+    );
+    scales
+        .map(|value_scale| {
+            let value = read_zigzag_varint(&mut data)?;
+            let value_scale = value_scale?;
+            let rescaled = match value_scale - scale as i64 {
+                0 => Some(value),
+                diff if diff < 0 => 10i128
+                    .checked_pow(diff.unsigned_abs() as u32)
+                    .and_then(|factor| value.checked_mul(factor)),
+                diff => 10i128.checked_pow(diff as u32).map(|factor| value / factor),
+            };
+            rescaled.ok_or_else(|| out_of_spec("decimal out of range"))
+        })
+        .collect()
+}
+
+fn read_zigzag_varint<R: Read>(reader: &mut R) -> Result<i128> {
+    let mut value = 0u128;
+    let mut byte = [0u8; 1];
+    for shift in (0..128).step_by(7) {
+        reader.read_exact(&mut byte)?;
+        value |= u128::from(byte[0] & 0x7F) << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok((value >> 1) as i128 ^ -((value & 1) as i128));
+        }
+    }
+    Err(out_of_spec("decimal varint too long"))
+}
+
+/// Reads the offsets and bytes of a string or binary column, which is either stored directly, with
+/// the LENGTH stream holding the length of each value, or with a dictionary, whose entries'
+/// lengths are in the LENGTH stream and whose indices are in the DATA stream.
+fn read_binary(
+    column: &Column,
+    num_values: usize,
+    validity: Option<&Bitmap>,
+) -> Result<(Offsets<i32>, Vec<u8>)> {
+    let read_lengths = |num_lengths: usize| {
+        UnsignedRleV2Iter::new(get_stream(column, StreamKind::Length)?, num_lengths, vec![])
+            .map(|length| usize::try_from(length?).map_err(|_| Error::Overflow))
+            .collect::<Result<Vec<_>>>()
+    };
+    let (lengths, data, indices) = match column.encoding().kind() {
+        EncodingKind::DictionaryV2 => {
+            let dictionary_size = column
+                .dictionary_size()
+                .ok_or_else(|| out_of_spec("missing dictionary size"))?;
+            let indices =
+                UnsignedRleV2Iter::new(get_stream(column, StreamKind::Data)?, num_values, vec![])
+                    .map(|index| usize::try_from(index?).map_err(|_| Error::Overflow))
+                    .collect::<Result<Vec<_>>>()?;
+            let lengths = read_lengths(dictionary_size)?;
+            (lengths, StreamKind::DictionaryData, Some(indices))
+        }
+        _ => (read_lengths(num_values)?, StreamKind::Data, None),
+    };
+    let mut starts = Vec::with_capacity(lengths.len() + 1);
+    starts.push(0);
+    for length in lengths.iter() {
+        starts.push(starts.last().unwrap() + length);
+    }
+    let mut bytes = vec![0; *starts.last().unwrap()];
+    get_stream(column, data)?.read_exact(&mut bytes)?;
+
+    let value_at = |i: usize| {
+        let i = match &indices {
+            Some(indices) => indices[i],
+            None => i,
+        };
+        (i + 1 < starts.len())
+            .then(|| &bytes[starts[i]..starts[i + 1]])
+            .ok_or_else(|| out_of_spec("dictionary index out of range"))
+    };
+    let mut offsets = Offsets::<i32>::with_capacity(column.number_of_rows());
+    let mut values = Vec::with_capacity(bytes.len());
+    let mut next_value = 0;
+    let is_valid: Box<dyn Iterator<Item = bool>> = match validity {
+        Some(validity) => Box::new(validity.iter()),
+        None => Box::new(std::iter::repeat(true).take(num_values)),
+    };
+    for is_valid in is_valid {
+        if is_valid {
+            let value = value_at(next_value)?;
+            next_value += 1;
+            offsets.try_push_usize(value.len())?;
+            values.extend_from_slice(value);
+        } else {
+            offsets.extend_constant(1);
+        }
+    }
+    Ok((offsets, values))
+}
@@ -0,0 +1,81 @@
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use bytes::Bytes;
+
+/// A window of fetched bytes of a file, which can be read from as if it were the whole file.
+///
+/// The ORC readers seek to absolute offsets, including offsets relative to the end of the file, so
+/// the window keeps track of where it starts and of the size of the file.
+pub(crate) struct FileWindow {
+    start: u64,
+    file_size: u64,
+    cursor: Cursor<Bytes>,
+}
+
+impl FileWindow {
+    pub fn new(start: usize, file_size: usize, bytes: Bytes) -> Self {
+        Self {
+            start: start as u64,
+            file_size: file_size as u64,
+            cursor: Cursor::new(bytes),
+        }
+    }
+}
+
+impl Read for FileWindow {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl Seek for FileWindow {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => Some(pos),
+            SeekFrom::End(offset) => self.file_size.checked_add_signed(offset),
+            SeekFrom::Current(offset) => {
+                (self.start + self.cursor.position()).checked_add_signed(offset)
+            }
+        };
+        let relative_pos = pos
+            .and_then(|pos| pos.checked_sub(self.start))
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "Seek to {pos:?} is outside of the fetched bytes starting at {}",
+                        self.start
+                    ),
+                )
+            })?;
+        self.cursor.set_position(relative_pos);
+        Ok(self.start + relative_pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Seek, SeekFrom};
+
+    use bytes::Bytes;
+
+    use super::FileWindow;
+
+    #[test]
+    fn test_file_window() {
+        let mut window = FileWindow::new(10, 15, Bytes::from_static(b"abcde"));
+        let mut buf = [0; 2];
+
+        assert_eq!(window.seek(SeekFrom::End(-2)).unwrap(), 13);
+        window.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"de");
+
+        assert_eq!(window.seek(SeekFrom::Start(11)).unwrap(), 11);
+        window.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"bc");
+
+        assert_eq!(window.seek(SeekFrom::Current(-3)).unwrap(), 10);
+        assert!(window.seek(SeekFrom::Start(9)).is_err());
+        assert!(window.seek(SeekFrom::End(-6)).is_err());
+    }
+}
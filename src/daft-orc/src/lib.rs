@@ -0,0 +1,77 @@
+#![feature(let_chains)]
+use common_error::DaftError;
+use snafu::Snafu;
+
+mod decode;
+mod file_window;
+mod metadata;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod read;
+pub mod schema;
+mod statistics;
+
+pub use metadata::{read_orc_metadata, OrcMetadata};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+pub use read::{read_orc, read_orc_bulk};
+pub use schema::read_orc_schema;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+    #[snafu(display("Unable to parse ORC data from \"{}\": {}", path, source))]
+    OrcError {
+        path: String,
+        source: arrow2::error::Error,
+    },
+    #[snafu(display("ORC file \"{}\" is not supported: {}", path, message))]
+    Unsupported { path: String, message: String },
+    #[snafu(display(
+        "File: {} has {} stripes, but stripe {} was requested",
+        path,
+        total_stripes,
+        stripe
+    ))]
+    StripeOutOfBounds {
+        path: String,
+        stripe: i64,
+        total_stripes: usize,
+    },
+    #[snafu(display(
+        "Unable to run expression on ORC stripe statistics of \"{}\": {}",
+        path,
+        source
+    ))]
+    UnableToRunExpressionOnStats {
+        path: String,
+        source: daft_stats::Error,
+    },
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> DaftError {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => DaftError::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Error::IOError { source: err }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(feature = "python")]
+pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_orc))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_orc_schema))?;
+    Ok(())
+}
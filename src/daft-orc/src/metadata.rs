@@ -0,0 +1,209 @@
+use std::{ops::Range, sync::Arc};
+
+use arrow2::{
+    datatypes::{DataType as ArrowType, Field as ArrowField, Schema as ArrowSchema, TimeUnit},
+    error::Error as ArrowError,
+    io::orc::format::{
+        proto::{r#type::Kind as TypeKind, Type},
+        read::{read_metadata, FileMetadata},
+    },
+};
+use daft_io::{IOClient, IOStatsRef};
+use snafu::ResultExt;
+
+use crate::{file_window::FileWindow, OrcSnafu};
+
+/// Size of the speculative read of the tail of a file, which holds its metadata.
+const TAIL_READ_SIZE: usize = 64 * 1024;
+
+/// The metadata of an ORC file: its footer (schema, stripes and file statistics) and its stripe
+/// statistics.
+pub struct OrcMetadata {
+    pub uri: String,
+    pub size: usize,
+    pub file: FileMetadata,
+}
+
+impl OrcMetadata {
+    pub fn num_stripes(&self) -> usize {
+        self.file.footer.stripes.len()
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.file.footer.number_of_rows() as usize
+    }
+
+    pub fn stripe_num_rows(&self, stripe: usize) -> usize {
+        self.file.footer.stripes[stripe].number_of_rows() as usize
+    }
+
+    /// Byte range of a stripe, i.e. its indexes, data and footer.
+    pub fn stripe_range(&self, stripe: usize) -> Range<usize> {
+        let info = &self.file.footer.stripes[stripe];
+        let start = info.offset() as usize;
+        start..start + (info.index_length() + info.data_length() + info.footer_length()) as usize
+    }
+
+    pub fn arrow_schema(&self) -> super::Result<ArrowSchema> {
+        let types = &self.file.footer.types;
+        let root = types
+            .first()
+            .ok_or_else(|| ArrowError::ExternalFormat("ORC file has no types".to_string()));
+        match root.and_then(|root| infer_type(root, types)) {
+            Ok(ArrowType::Struct(fields)) => Ok(fields.into()),
+            Ok(_) => Err(ArrowError::ExternalFormat(
+                "ORC root type must be a struct".to_string(),
+            )),
+            Err(err) => Err(err),
+        }
+        .context(OrcSnafu {
+            path: self.uri.clone(),
+        })
+    }
+
+    /// ORC column ids of the top-level fields. Column 0 is the root struct, and nested types are
+    /// numbered depth-first, so these aren't contiguous when there are nested fields.
+    pub fn top_level_column_ids(&self) -> Vec<u32> {
+        self.file
+            .footer
+            .types
+            .first()
+            .map(|root| root.subtypes.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Maps an ORC type to an arrow type. Unlike arrow2's `infer_schema`, this also covers dates,
+/// timestamps, decimals and the bounded string types.
+fn infer_type(orc_type: &Type, types: &[Type]) -> Result<ArrowType, ArrowError> {
+    let data_type = match orc_type.kind() {
+        TypeKind::Boolean => ArrowType::Boolean,
+        TypeKind::Byte => ArrowType::Int8,
+        TypeKind::Short => ArrowType::Int16,
+        TypeKind::Int => ArrowType::Int32,
+        TypeKind::Long => ArrowType::Int64,
+        TypeKind::Float => ArrowType::Float32,
+        TypeKind::Double => ArrowType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => ArrowType::Utf8,
+        TypeKind::Binary => ArrowType::Binary,
+        TypeKind::Date => ArrowType::Date32,
+        TypeKind::Timestamp => ArrowType::Timestamp(TimeUnit::Nanosecond, None),
+        // Hive's defaults for decimals declared without a precision or scale.
+        TypeKind::Decimal => ArrowType::Decimal(
+            orc_type.precision.unwrap_or(38) as usize,
+            orc_type.scale.unwrap_or(10) as usize,
+        ),
+        TypeKind::Struct => ArrowType::Struct(
+            orc_type
+                .subtypes
+                .iter()
+                .zip(orc_type.field_names.iter())
+                .map(|(id, name)| {
+                    let field_type = types.get(*id as usize).ok_or_else(|| {
+                        ArrowError::ExternalFormat(format!("ORC type {id} not found"))
+                    })?;
+                    Ok(ArrowField::new(name, infer_type(field_type, types)?, true))
+                })
+                .collect::<Result<Vec<_>, ArrowError>>()?,
+        ),
+        kind => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Reading {kind:?} from ORC"
+            )))
+        }
+    };
+    Ok(data_type)
+}
+
+pub async fn read_orc_metadata(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<OrcMetadata> {
+    let size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+    let mut start = size.saturating_sub(TAIL_READ_SIZE);
+    let mut tail = io_client
+        .single_url_get(uri.to_string(), Some(start..size), io_stats.clone())
+        .await?
+        .bytes()
+        .await?;
+    if let Some(tail_size) = metadata_size(&tail)
+        && tail_size > tail.len()
+        && tail_size <= size
+    {
+        // The metadata didn't fit in the speculative read.
+        start = size - tail_size;
+        tail = io_client
+            .single_url_get(uri.to_string(), Some(start..size), io_stats)
+            .await?
+            .bytes()
+            .await?;
+    }
+    let file = read_metadata(&mut FileWindow::new(start, size, tail))
+        .map_err(arrow2::error::Error::from)
+        .context(OrcSnafu { path: uri })?;
+    Ok(OrcMetadata {
+        uri: uri.to_string(),
+        size,
+        file,
+    })
+}
+
+/// Number of bytes at the end of an ORC file taken up by its metadata, footer, postscript and
+/// postscript length, as read from the postscript.
+fn metadata_size(tail: &[u8]) -> Option<usize> {
+    let (&postscript_len, rest) = tail.split_last()?;
+    let mut postscript = rest.get(rest.len().checked_sub(postscript_len as usize)?..)?;
+    let (mut footer_len, mut metadata_len) = (0, 0);
+    while !postscript.is_empty() {
+        let key = read_varint(&mut postscript)?;
+        // Protobuf fields 1 and 5 of the postscript are the footer and metadata lengths.
+        match (key >> 3, key & 0x7) {
+            (1, 0) => footer_len = read_varint(&mut postscript)?,
+            (5, 0) => metadata_len = read_varint(&mut postscript)?,
+            (_, 0) => {
+                read_varint(&mut postscript)?;
+            }
+            (_, 2) => {
+                let len = read_varint(&mut postscript)? as usize;
+                postscript = postscript.get(len..)?;
+            }
+            _ => return None,
+        }
+    }
+    Some(1 + postscript_len as usize + footer_len as usize + metadata_len as usize)
+}
+
+fn read_varint(buf: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for (i, byte) in buf.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7F) << (i * 7);
+        if byte & 0x80 == 0 {
+            *buf = &buf[i + 1..];
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::metadata_size;
+
+    #[test]
+    fn test_metadata_size() {
+        // Postscript with footerLength = 300, compression = NONE, metadataLength = 20, and magic "ORC".
+        let postscript = [
+            0x08, 0xAC, 0x02, 0x10, 0x00, 0x28, 0x14, 0x82, 0xF4, 0x03, 0x03, b'O', b'R', b'C',
+        ];
+        let mut tail = vec![0; 16];
+        tail.extend_from_slice(&postscript);
+        tail.push(postscript.len() as u8);
+        assert_eq!(metadata_size(&tail), Some(1 + 14 + 300 + 20));
+        assert_eq!(metadata_size(&tail[1..]), Some(1 + 14 + 300 + 20));
+        assert_eq!(metadata_size(&[]), None);
+        assert_eq!(metadata_size(&[100]), None);
+    }
+}
@@ -0,0 +1,581 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::Array,
+    datatypes::{DataType as ArrowType, Field as ArrowField, Schema as ArrowSchema, TimeUnit},
+    io::orc::format::{
+        proto::column_encoding::Kind as EncodingKind,
+        read::{read_stripe_column, read_stripe_footer},
+    },
+};
+use bytes::Bytes;
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    schema::{Schema, SchemaRef},
+    utils::arrow::cast_array_for_daft_if_needed,
+    Series,
+};
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_stats::TruthValue;
+use daft_table::Table;
+use futures::{StreamExt, TryStreamExt};
+use snafu::{futures::TryFutureExt, ResultExt};
+
+use crate::{
+    decode::deserialize,
+    file_window::FileWindow,
+    metadata::{read_orc_metadata, OrcMetadata},
+    statistics::stripe_to_table_stats,
+    OrcSnafu, UnableToRunExpressionOnStatsSnafu,
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_orc(
+    uri: &str,
+    columns: Option<&[&str]>,
+    num_rows: Option<usize>,
+    stripes: Option<Vec<i64>>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> DaftResult<Table> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        read_orc_single(
+            uri,
+            columns.map(|cols| cols.iter().map(|col| col.to_string()).collect()),
+            num_rows,
+            stripes,
+            predicate,
+            io_client,
+            io_stats,
+        )
+        .await
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_orc_bulk(
+    uris: &[&str],
+    columns: Option<&[&str]>,
+    num_rows: Option<usize>,
+    stripes: Option<Vec<Option<Vec<i64>>>>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Table>> {
+    if let Some(ref stripes) = stripes
+        && stripes.len() != uris.len()
+    {
+        return Err(DaftError::ValueError(format!(
+            "Mismatch of length of `uris` and `stripes`. {} vs {}",
+            uris.len(),
+            stripes.len()
+        )));
+    }
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let _rt_guard = runtime_handle.enter();
+    let owned_columns: Option<Vec<String>> =
+        columns.map(|cols| cols.iter().map(|col| col.to_string()).collect());
+    let tables = runtime_handle.block_on(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(uris.iter().enumerate().map(|(i, uri)| {
+            let (uri, columns, stripes, predicate, io_client, io_stats) = (
+                uri.to_string(),
+                owned_columns.clone(),
+                stripes.as_ref().and_then(|stripes| stripes[i].clone()),
+                predicate.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_orc_single(
+                    &uri, columns, num_rows, stripes, predicate, io_client, io_stats,
+                )
+                .await
+            })
+            .context(crate::JoinSnafu)
+        }));
+        task_stream
+            .buffered(num_parallel_tasks)
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+async fn read_orc_single(
+    uri: &str,
+    columns: Option<Vec<String>>,
+    num_rows: Option<usize>,
+    stripes: Option<Vec<i64>>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Table> {
+    let original_columns = columns.clone();
+    let original_num_rows = num_rows;
+    let mut columns = columns;
+    let mut num_rows = num_rows;
+    if let Some(ref pred) = predicate {
+        // The limit can only be applied after filtering, which may need extra columns.
+        num_rows = None;
+        if let Some(req_columns) = columns.as_mut() {
+            for c in get_required_columns(pred) {
+                if !req_columns.contains(&c) {
+                    req_columns.push(c);
+                }
+            }
+        }
+    }
+
+    let metadata = read_orc_metadata(uri, io_client.clone(), io_stats.clone()).await?;
+    let arrow_schema = metadata.arrow_schema()?;
+    let stripes = select_stripes(&metadata, &arrow_schema, stripes, predicate.as_ref())?;
+
+    // Pair each projected field with its ORC column id.
+    let column_ids = metadata.top_level_column_ids();
+    let fields = arrow_schema
+        .fields
+        .iter()
+        .zip(column_ids)
+        .filter(|(field, _)| {
+            columns
+                .as_ref()
+                .map_or(true, |columns| columns.contains(&field.name))
+        })
+        .map(|(field, column_id)| (field.clone(), column_id))
+        .collect::<Vec<_>>();
+    if let Some(columns) = columns.as_ref()
+        && let Some(missing) = columns
+            .iter()
+            .find(|c| !fields.iter().any(|(field, _)| &field.name == *c))
+    {
+        return Err(DaftError::FieldNotFound(format!(
+            "Column {missing} not found in ORC file {uri}"
+        )));
+    }
+    let daft_schema = Arc::new(Schema::try_from(&ArrowSchema::from(
+        fields
+            .iter()
+            .map(|(field, _)| field.clone())
+            .collect::<Vec<_>>(),
+    ))?);
+
+    let mut table = if fields.is_empty() {
+        // No column needs decoding, so the rows are counted from the stripe information in the
+        // file footer instead of fetching the stripes.
+        let rows = stripes
+            .iter()
+            .map(|stripe| metadata.stripe_num_rows(*stripe))
+            .sum::<usize>();
+        Table::new_with_size(daft_schema, vec![], num_rows.map_or(rows, |n| rows.min(n)))?
+    } else {
+        read_stripes(
+            &metadata,
+            &stripes,
+            &fields,
+            daft_schema,
+            num_rows,
+            io_client,
+            io_stats,
+        )
+        .await?
+    };
+    if let Some(columns) = columns.as_ref() {
+        table = table.get_columns(columns.as_slice())?;
+    }
+
+    if let Some(predicate) = predicate {
+        table = table.filter(&[predicate])?;
+        if let Some(oc) = original_columns {
+            table = table.get_columns(oc.as_slice())?;
+        }
+        if let Some(nr) = original_num_rows {
+            table = table.head(nr)?;
+        }
+    }
+    Ok(table)
+}
+
+/// Fetches and decodes the given fields of the selected stripes, stopping once `num_rows` rows
+/// are read.
+async fn read_stripes(
+    metadata: &OrcMetadata,
+    stripes: &[usize],
+    fields: &[(ArrowField, u32)],
+    daft_schema: SchemaRef,
+    num_rows: Option<usize>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Table> {
+    let fetch_stripe = |stripe: usize| {
+        let (uri, range, io_client, io_stats) = (
+            metadata.uri.clone(),
+            metadata.stripe_range(stripe),
+            io_client.clone(),
+            io_stats.clone(),
+        );
+        async move {
+            let bytes = io_client
+                .single_url_get(uri, Some(range.clone()), io_stats)
+                .await?
+                .bytes()
+                .await?;
+            Ok::<_, crate::Error>((stripe, range.start, bytes))
+        }
+    };
+    let fetched = match num_rows {
+        // Without a limit every selected stripe is needed, so fetch them concurrently.
+        None => futures::future::try_join_all(stripes.iter().map(|s| fetch_stripe(*s))).await?,
+        // Otherwise fetch stripes one at a time until there are enough rows.
+        Some(num_rows) => {
+            let mut fetched = vec![];
+            let mut rows = 0;
+            for stripe in stripes.iter() {
+                if rows >= num_rows {
+                    break;
+                }
+                fetched.push(fetch_stripe(*stripe).await?);
+                rows += metadata.stripe_num_rows(*stripe);
+            }
+            fetched
+        }
+    };
+
+    let mut remaining = num_rows.unwrap_or(usize::MAX);
+    let mut tables = Vec::with_capacity(fetched.len());
+    for (stripe, start, bytes) in fetched {
+        let arrays = decode_stripe(metadata, stripe, start, bytes, fields)?;
+        let all_series = arrays
+            .into_iter()
+            .zip(daft_schema.fields.values())
+            .map(|(array, field)| {
+                Series::try_from_field_and_arrow_array(
+                    Arc::new(field.clone()),
+                    cast_array_for_daft_if_needed(array),
+                )
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let table = Table::new_with_size(
+            daft_schema.clone(),
+            all_series,
+            metadata.stripe_num_rows(stripe),
+        )?;
+        let table = if table.len() > remaining {
+            table.head(remaining)?
+        } else {
+            table
+        };
+        remaining -= table.len();
+        tables.push(table);
+    }
+    if tables.is_empty() {
+        Table::empty(Some(daft_schema))
+    } else {
+        Table::concat(&tables)
+    }
+}
+
+/// Selects the stripes to read: the requested stripes (or all of them), minus the stripes whose
+/// statistics show that no row can satisfy the predicate.
+fn select_stripes(
+    metadata: &OrcMetadata,
+    arrow_schema: &ArrowSchema,
+    stripes: Option<Vec<i64>>,
+    predicate: Option<&ExprRef>,
+) -> DaftResult<Vec<usize>> {
+    let total_stripes = metadata.num_stripes();
+    let stripes = match stripes {
+        Some(stripes) => stripes
+            .into_iter()
+            .map(|stripe| {
+                usize::try_from(stripe)
+                    .ok()
+                    .filter(|s| *s < total_stripes)
+                    .ok_or_else(|| crate::Error::StripeOutOfBounds {
+                        path: metadata.uri.clone(),
+                        stripe,
+                        total_stripes,
+                    })
+            })
+            .collect::<crate::Result<Vec<_>>>()?,
+        None => (0..total_stripes).collect(),
+    };
+    let Some(predicate) = predicate else {
+        return Ok(stripes);
+    };
+    let schema = Schema::try_from(arrow_schema)?;
+    let mut selected = Vec::with_capacity(stripes.len());
+    for stripe in stripes {
+        let stats = stripe_to_table_stats(metadata, stripe, &schema)?;
+        let evaled = stats.eval_expression(predicate).with_context(|_| {
+            UnableToRunExpressionOnStatsSnafu {
+                path: metadata.uri.clone(),
+            }
+        })?;
+        if evaled.to_truth_value() != TruthValue::False {
+            selected.push(stripe);
+        }
+    }
+    Ok(selected)
+}
+
+/// Decodes the given fields of a stripe from its fetched bytes, which start at offset `start`.
+fn decode_stripe(
+    metadata: &OrcMetadata,
+    stripe: usize,
+    start: usize,
+    bytes: Bytes,
+    fields: &[(ArrowField, u32)],
+) -> crate::Result<Vec<Box<dyn Array>>> {
+    let path = &metadata.uri;
+    let mut window = FileWindow::new(start, metadata.size, bytes);
+    let mut scratch = vec![];
+    let footer = read_stripe_footer(&mut window, &metadata.file, stripe, &mut scratch)
+        .map_err(arrow2::error::Error::from)
+        .context(OrcSnafu { path })?;
+    fields
+        .iter()
+        .map(|(field, column_id)| {
+            let encoding = footer
+                .columns
+                .get(*column_id as usize)
+                .map(|encoding| encoding.kind());
+            check_encoding(path, field, encoding)?;
+            let column = read_stripe_column(
+                &mut window,
+                &metadata.file,
+                stripe,
+                footer.clone(),
+                *column_id,
+                std::mem::take(&mut scratch),
+            )
+            .map_err(arrow2::error::Error::from)
+            .context(OrcSnafu { path })?;
+            deserialize(&field.data_type, &column).context(OrcSnafu { path })
+        })
+        .collect()
+}
+
+/// Integers and lengths are only decoded from the RLEv2 encoding used by ORC v0.12 writers, and
+/// strings can also be dictionary-encoded. Booleans, bytes and floats have a single encoding.
+fn check_encoding(
+    path: &str,
+    field: &ArrowField,
+    encoding: Option<EncodingKind>,
+) -> crate::Result<()> {
+    let supported = match field.data_type {
+        ArrowType::Boolean | ArrowType::Int8 | ArrowType::Float32 | ArrowType::Float64 => true,
+        ArrowType::Int16
+        | ArrowType::Int32
+        | ArrowType::Int64
+        | ArrowType::Date32
+        | ArrowType::Timestamp(TimeUnit::Nanosecond, None)
+        | ArrowType::Decimal(..)
+        | ArrowType::Binary => encoding == Some(EncodingKind::DirectV2),
+        ArrowType::Utf8 => matches!(
+            encoding,
+            Some(EncodingKind::DirectV2 | EncodingKind::DictionaryV2)
+        ),
+        _ => false,
+    };
+    if supported {
+        Ok(())
+    } else {
+        Err(crate::Error::Unsupported {
+            path: path.to_string(),
+            message: format!(
+                "column {} of type {:?} with encoding {:?} can't be decoded",
+                field.name, field.data_type, encoding
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow2::array::{Int128Array, Int32Array, Int64Array, Int8Array, Utf8Array};
+    use common_error::DaftResult;
+    use daft_core::datatypes::{DataType, TimeUnit};
+    use daft_io::{IOClient, IOConfig};
+
+    use super::read_orc;
+    use crate::read_orc_schema;
+
+    /// Two stripes, of 5 and 3 rows, with a byte, date, timestamp, decimal(10, 2), dictionary-encoded
+    /// string and direct string column. Some columns have nulls and others have no PRESENT stream.
+    fn fixture() -> String {
+        format!("{}/test/dictionary_encoded.orc", env!("CARGO_MANIFEST_DIR"),)
+    }
+
+    #[test]
+    fn test_orc_read_encodings() -> DaftResult<()> {
+        let uri = fixture();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let schema = read_orc_schema(&uri, io_client.clone(), None)?;
+        assert_eq!(
+            schema.names(),
+            vec!["byte", "date", "ts", "dec", "dict", "str"]
+        );
+        assert_eq!(schema.get_field("byte")?.dtype, DataType::Int8);
+        assert_eq!(schema.get_field("date")?.dtype, DataType::Date);
+        assert_eq!(
+            schema.get_field("ts")?.dtype,
+            DataType::Timestamp(TimeUnit::Nanoseconds, None)
+        );
+        assert_eq!(schema.get_field("dec")?.dtype, DataType::Decimal128(10, 2));
+
+        let table = read_orc(&uri, None, None, None, None, io_client, None, true)?;
+        assert_eq!(table.len(), 8);
+        assert_eq!(table.schema.as_ref(), &schema);
+
+        let byte = table.get_column("byte")?.to_arrow();
+        assert_eq!(
+            byte.as_any().downcast_ref::<Int8Array>().unwrap(),
+            &Int8Array::from_slice([1, -2, 3, 127, -128, 0, 0, 0])
+        );
+        let date = table.get_column("date")?.to_arrow();
+        let date = date.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(
+            date.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![
+                Some(0),
+                Some(19000),
+                None,
+                Some(-1),
+                Some(18628),
+                Some(1),
+                Some(2),
+                Some(3)
+            ]
+        );
+        let ts = table.get_column("ts")?.to_arrow();
+        let ts = ts.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(
+            ts.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![
+                Some(1_420_070_400_000_000_000),
+                // 1969-12-31 23:59:58.5, before the UNIX epoch with a fractional second.
+                Some(-1_500_000_000),
+                None,
+                Some(1_709_210_096_123_456_789),
+                Some(100_000_000),
+                None,
+                Some(0),
+                None
+            ]
+        );
+        // The last value of the first stripe is stored with a scale of 3, as 1.230.
+        let dec = table.get_column("dec")?.to_arrow();
+        let dec = dec.as_any().downcast_ref::<Int128Array>().unwrap();
+        assert_eq!(
+            dec.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![
+                Some(1234),
+                Some(-5),
+                None,
+                Some(9_999_999_999),
+                Some(123),
+                Some(100),
+                Some(250),
+                Some(-375)
+            ]
+        );
+        let dict = table.get_column("dict")?.to_arrow();
+        let dict = dict.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+        assert_eq!(
+            dict.iter().collect::<Vec<_>>(),
+            vec![
+                Some("b"),
+                Some("a"),
+                None,
+                Some("b"),
+                Some("c"),
+                Some("z"),
+                Some("z"),
+                Some("z")
+            ]
+        );
+        let str = table.get_column("str")?.to_arrow();
+        let str = str.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+        assert_eq!(
+            str.iter().collect::<Vec<_>>(),
+            vec![
+                Some("x"),
+                Some(""),
+                Some("hello"),
+                Some("ORC"),
+                Some("é"),
+                None,
+                Some("y"),
+                None
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_orc_read_limit_and_empty_projection() -> DaftResult<()> {
+        let uri = fixture();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_orc(
+            &uri,
+            Some(&["dict"]),
+            Some(6),
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.len(), 6);
+        assert_eq!(table.column_names(), vec!["dict"]);
+
+        // Without any column to decode, the rows are counted from the stripe information.
+        let table = read_orc(
+            &uri,
+            Some(&[]),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.len(), 8);
+        assert!(table.column_names().is_empty());
+        let table = read_orc(
+            &uri,
+            Some(&[]),
+            Some(6),
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.len(), 6);
+        let table = read_orc(
+            &uri,
+            Some(&[]),
+            None,
+            Some(vec![1]),
+            None,
+            io_client,
+            None,
+            true,
+        )?;
+        assert_eq!(table.len(), 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::schema::Schema;
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+
+use crate::metadata::read_orc_metadata;
+
+pub fn read_orc_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let runtime_handle = get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        let metadata = read_orc_metadata(uri, io_client, io_stats).await?;
+        Schema::try_from(&metadata.arrow_schema()?)
+    })
+}
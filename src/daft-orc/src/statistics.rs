@@ -0,0 +1,83 @@
+use arrow2::io::orc::format::proto::ColumnStatistics;
+use common_error::DaftResult;
+use daft_core::{
+    datatypes::{Float64Array, Int64Array, Utf8Array},
+    schema::Schema,
+    DataType, IntoSeries, Series,
+};
+use daft_stats::{ColumnRangeStatistics, TableStatistics};
+use indexmap::IndexMap;
+
+use crate::metadata::OrcMetadata;
+
+/// Converts the statistics of a stripe to [`TableStatistics`] over the top-level fields of `schema`.
+pub(crate) fn stripe_to_table_stats(
+    metadata: &OrcMetadata,
+    stripe: usize,
+    schema: &Schema,
+) -> DaftResult<TableStatistics> {
+    let stripe_stats = metadata.file.metadata.stripe_stats.get(stripe);
+    let columns = schema
+        .fields
+        .values()
+        .zip(metadata.top_level_column_ids())
+        .map(|(field, column_id)| {
+            let stats = stripe_stats
+                .and_then(|stats| stats.col_stats.get(column_id as usize))
+                .map(|stats| orc_statistics_to_column_range_statistics(stats, &field.dtype))
+                .transpose()?
+                .unwrap_or(ColumnRangeStatistics::Missing);
+            Ok((field.name.clone(), stats))
+        })
+        .collect::<DaftResult<IndexMap<_, _>>>()?;
+    Ok(TableStatistics { columns })
+}
+
+fn orc_statistics_to_column_range_statistics(
+    stats: &ColumnStatistics,
+    dtype: &DataType,
+) -> DaftResult<ColumnRangeStatistics> {
+    if !ColumnRangeStatistics::supports_dtype(dtype) || stats.number_of_values == Some(0) {
+        return Ok(ColumnRangeStatistics::Missing);
+    }
+    let bounds: Option<(Series, Series)> = match dtype {
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => stats
+            .int_statistics
+            .as_ref()
+            .and_then(|s| s.minimum.zip(s.maximum))
+            .map(|(lower, upper)| {
+                (
+                    Int64Array::from(("lower", [lower].as_slice())).into_series(),
+                    Int64Array::from(("upper", [upper].as_slice())).into_series(),
+                )
+            }),
+        DataType::Float32 | DataType::Float64 => stats
+            .double_statistics
+            .as_ref()
+            .and_then(|s| s.minimum.zip(s.maximum))
+            .map(|(lower, upper)| {
+                (
+                    Float64Array::from(("lower", [lower].as_slice())).into_series(),
+                    Float64Array::from(("upper", [upper].as_slice())).into_series(),
+                )
+            }),
+        DataType::Utf8 => stats
+            .string_statistics
+            .as_ref()
+            .and_then(|s| s.minimum.as_ref().zip(s.maximum.as_ref()))
+            .map(|(lower, upper)| {
+                (
+                    Utf8Array::from(("lower", [lower.as_str()].as_slice())).into_series(),
+                    Utf8Array::from(("upper", [upper.as_str()].as_slice())).into_series(),
+                )
+            }),
+        _ => None,
+    };
+    match bounds {
+        Some((lower, upper)) => Ok(ColumnRangeStatistics::new(
+            Some(lower.cast(dtype)?),
+            Some(upper.cast(dtype)?),
+        )?),
+        None => Ok(ColumnRangeStatistics::Missing),
+    }
+}
@@ -1,19 +1,24 @@
 use std::{
     collections::{BTreeMap, HashSet},
+    pin::Pin,
     sync::Arc,
 };
 
 use arrow2::io::parquet::read::schema::infer_schema_with_options;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_core::{
-    datatypes::Field, schema::Schema, utils::arrow::cast_array_for_daft_if_needed, Series,
+    datatypes::Field,
+    schema::{Schema, SchemaRef},
+    utils::arrow::cast_array_for_daft_if_needed,
+    Series,
 };
 use daft_dsl::ExprRef;
 use daft_io::{IOClient, IOStatsRef};
 use daft_stats::TruthValue;
 use daft_table::Table;
-use futures::{future::try_join_all, StreamExt};
+use futures::{future::try_join_all, stream::BoxStream, AsyncRead, AsyncReadExt, StreamExt};
 use parquet2::{
+    metadata::ColumnChunkMetaData,
     page::{CompressedPage, Page},
     read::get_owned_page_stream_from_column_start,
     FallibleStreamingIterator,
@@ -22,6 +27,7 @@ use snafu::ResultExt;
 
 use crate::{
     metadata::read_parquet_metadata,
+    page_pruning::{prune_row_ranges, select_pages, PageLocations, PageSelection},
     read::ParquetSchemaInferenceOptions,
     read_planner::{CoalescePass, RangesContainer, ReadPlanner, SplitLargeRequestPass},
     statistics, JoinSnafu, OneShotRecvSnafu, UnableToConvertRowGroupMetadataToStatsSnafu,
@@ -33,6 +39,7 @@ use arrow2::io::parquet::read::column_iter_to_arrays;
 pub(crate) struct ParquetReaderBuilder {
     pub uri: String,
     pub metadata: parquet2::metadata::FileMetaData,
+    file_size: usize,
     selected_columns: Option<HashSet<String>>,
     row_start_offset: usize,
     limit: Option<usize>,
@@ -207,6 +214,7 @@ impl ParquetReaderBuilder {
         Ok(ParquetReaderBuilder {
             uri: uri.into(),
             metadata,
+            file_size: size,
             selected_columns: None,
             row_start_offset: 0,
             limit: None,
@@ -300,7 +308,14 @@ impl ParquetReaderBuilder {
             &self.uri,
         )?;
 
-        ParquetFileReader::new(self.uri, self.metadata, arrow_schema, row_ranges)
+        ParquetFileReader::new(
+            self.uri,
+            self.file_size,
+            self.metadata,
+            arrow_schema,
+            row_ranges,
+            self.predicate,
+        )
     }
 }
 
@@ -311,25 +326,79 @@ pub(crate) struct RowGroupRange {
     pub num_rows: usize,
 }
 
+#[derive(Clone)]
 pub(crate) struct ParquetFileReader {
     uri: String,
+    file_size: usize,
     metadata: Arc<parquet2::metadata::FileMetaData>,
     arrow_schema: arrow2::datatypes::SchemaRef,
     row_ranges: Arc<Vec<RowGroupRange>>,
+    predicate: Option<ExprRef>,
+    page_locations: Arc<PageLocations>,
+}
+
+/// Selects the pages of a column chunk to read for a range of rows, which is every page unless the page locations
+/// of the column chunk are known.
+fn select_column_chunk_pages(
+    column: &ColumnChunkMetaData,
+    page_locations: &PageLocations,
+    column_index: usize,
+    row_group_num_rows: usize,
+    row_range: &RowGroupRange,
+) -> PageSelection {
+    page_locations
+        .get(&(row_range.row_group_index, column_index))
+        .and_then(|locations| {
+            select_pages(
+                column,
+                locations,
+                row_group_num_rows,
+                row_range.start,
+                row_range.num_rows,
+            )
+        })
+        .unwrap_or_else(|| PageSelection::all(column))
+}
+
+/// Reads the selected pages of a column chunk as a single stream of bytes.
+fn page_selection_reader(
+    uri: &str,
+    ranges: &RangesContainer,
+    selection: &PageSelection,
+) -> DaftResult<Pin<Box<dyn AsyncRead + Send>>> {
+    let mut readers = selection
+        .byte_ranges
+        .iter()
+        .map(|range| {
+            Ok(Box::pin(ranges.get_range_reader(range.clone())?) as Pin<Box<dyn AsyncRead + Send>>)
+        })
+        .collect::<DaftResult<Vec<_>>>()?
+        .into_iter();
+    let first = readers.next().ok_or_else(|| {
+        DaftError::InternalError(format!(
+            "Parquet file: {uri} has a column chunk page selection without any byte ranges"
+        ))
+    })?;
+    Ok(readers.fold(first, |left, right| Box::pin(left.chain(right))))
 }
 
 impl ParquetFileReader {
     fn new(
         uri: String,
+        file_size: usize,
         metadata: parquet2::metadata::FileMetaData,
         arrow_schema: arrow2::datatypes::Schema,
         row_ranges: Vec<RowGroupRange>,
+        predicate: Option<ExprRef>,
     ) -> super::Result<Self> {
         Ok(ParquetFileReader {
             uri,
+            file_size,
             metadata: Arc::new(metadata),
             arrow_schema: arrow_schema.into(),
             row_ranges: Arc::new(row_ranges),
+            predicate,
+            page_locations: Default::default(),
         })
     }
 
@@ -337,6 +406,33 @@ impl ParquetFileReader {
         &self.arrow_schema
     }
 
+    /// Prunes the row ranges to read with the bloom filters and page indexes of the file if there is a predicate,
+    /// so that only the pages that may satisfy the predicate are fetched.
+    pub async fn prune_pages(
+        mut self,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        let Some(predicate) = self.predicate.clone() else {
+            return Ok(self);
+        };
+        let daft_schema = Schema::try_from(self.arrow_schema.as_ref())?;
+        let (row_ranges, page_locations) = prune_row_ranges(
+            &self.uri,
+            self.file_size,
+            &self.metadata,
+            &daft_schema,
+            &predicate,
+            self.row_ranges.as_ref().clone(),
+            io_client,
+            io_stats,
+        )
+        .await?;
+        self.row_ranges = Arc::new(row_ranges);
+        self.page_locations = Arc::new(page_locations);
+        Ok(self)
+    }
+
     fn naive_read_plan(&self) -> super::Result<ReadPlanner> {
         let arrow_fields = &self.arrow_schema.fields;
 
@@ -354,14 +450,21 @@ impl ParquetFileReader {
                 let field_name = field.name.clone();
                 let filtered_cols = columns
                     .iter()
-                    .filter(|x| x.descriptor().path_in_schema[0] == field_name)
+                    .enumerate()
+                    .filter(|(_, x)| x.descriptor().path_in_schema[0] == field_name)
                     .collect::<Vec<_>>();
 
-                for col in filtered_cols {
-                    let (start, len) = col.byte_range();
-                    let end = start + len;
-
-                    read_planner.add_range(start as usize, end as usize);
+                for (col_idx, col) in filtered_cols {
+                    let selection = select_column_chunk_pages(
+                        col,
+                        &self.page_locations,
+                        col_idx,
+                        rg.num_rows(),
+                        row_group_range,
+                    );
+                    for range in selection.byte_ranges {
+                        read_planner.add_range(range.start, range.end);
+                    }
                 }
             }
         }
@@ -401,6 +504,7 @@ impl ParquetFileReader {
             .iter()
             .map(|field| {
                 let owned_row_ranges = self.row_ranges.clone();
+                let page_locations = self.page_locations.clone();
 
                 let field_handles = owned_row_ranges
                     .iter()
@@ -413,7 +517,6 @@ impl ParquetFileReader {
                             .row_groups
                             .get(row_range.row_group_index)
                             .expect("Row Group index should be in bounds");
-                        let num_rows = rg.num_rows().min(row_range.start + row_range.num_rows);
                         let columns = rg.columns();
                         let field_name = &field.name;
                         let filtered_cols_idx = columns
@@ -423,40 +526,42 @@ impl ParquetFileReader {
                             .map(|(i, _)| i)
                             .collect::<Vec<_>>();
 
-                        let range_readers = filtered_cols_idx
+                        // Only flat columns have page locations, so a field either reads every page of its
+                        // column chunks or the selected pages of a single column chunk.
+                        let mut first_row = 0;
+                        let column_readers = filtered_cols_idx
                             .iter()
                             .map(|i| {
-                                let c = columns.get(*i).unwrap();
-                                let (start, len) = c.byte_range();
-                                let end: u64 = start + len;
-                                let range_reader = ranges
-                                    .get_range_reader(start as usize..end as usize)
-                                    .unwrap();
-
-                                Box::pin(range_reader)
+                                let selection = select_column_chunk_pages(
+                                    columns.get(*i).unwrap(),
+                                    &page_locations,
+                                    *i,
+                                    rg.num_rows(),
+                                    &row_range,
+                                );
+                                first_row = selection.first_row;
+                                let range_reader =
+                                    page_selection_reader(&self.uri, &ranges, &selection)?;
+                                Ok((selection.metadata, range_reader))
                             })
-                            .collect::<Vec<_>>();
-                        let metadata = metadata.clone();
+                            .collect::<DaftResult<Vec<_>>>()?;
+                        // Rows are counted from the first row of the pages that are read.
+                        let num_rows =
+                            rg.num_rows().min(row_range.start + row_range.num_rows) - first_row;
+                        let row_range = RowGroupRange {
+                            start: row_range.start - first_row,
+                            ..row_range
+                        };
                         let handle = tokio::task::spawn(async move {
-                            let mut decompressed_iters =
-                                Vec::with_capacity(filtered_cols_idx.len());
-                            let mut ptypes = Vec::with_capacity(filtered_cols_idx.len());
-
-                            for (col_idx, range_reader) in
-                                filtered_cols_idx.into_iter().zip(range_readers)
-                            {
-                                let col = metadata
-                                    .row_groups
-                                    .get(row_range.row_group_index)
-                                    .expect("Row Group index should be in bounds")
-                                    .columns()
-                                    .get(col_idx)
-                                    .expect("Column index should be in bounds");
+                            let mut decompressed_iters = Vec::with_capacity(column_readers.len());
+                            let mut ptypes = Vec::with_capacity(column_readers.len());
+
+                            for (col, range_reader) in column_readers {
                                 ptypes.push(col.descriptor().descriptor.primitive_type.clone());
 
                                 let compressed_page_stream =
                                     get_owned_page_stream_from_column_start(
-                                        col,
+                                        &col,
                                         range_reader,
                                         vec![],
                                         Arc::new(|_, _| true),
@@ -566,6 +671,166 @@ impl ParquetFileReader {
         Table::new(daft_schema, all_series)
     }
 
+    /// Streams the row ranges one at a time as [`Table`]s of at most `chunk_size` rows.
+    ///
+    /// The column chunks of a row range are fetched whole, but only decoded a chunk of rows at a time as the
+    /// stream is polled. Page locations aren't used, so that every column is decoded from the start of its row
+    /// group and the chunks of the columns line up.
+    pub fn stream_tables(
+        self,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+        chunk_size: usize,
+    ) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+        let daft_schema: SchemaRef = Schema::try_from(self.arrow_schema.as_ref())?.into();
+        let chunk_size = chunk_size.max(1);
+        Ok(async_stream::try_stream! {
+            for row_range in self.row_ranges.iter().copied() {
+                let reader = ParquetFileReader {
+                    row_ranges: Arc::new(vec![row_range]),
+                    page_locations: Default::default(),
+                    ..self.clone()
+                };
+                let ranges = reader.prebuffer_ranges(io_client.clone(), io_stats.clone())?;
+                let mut chunks = reader
+                    .decode_row_range(row_range, ranges, daft_schema.clone(), chunk_size)
+                    .await?;
+                while let Some(table) = chunks.recv().await {
+                    yield table?;
+                }
+            }
+        }
+        .boxed())
+    }
+
+    /// Decodes a row range on a blocking thread, which sends a [`Table`] per chunk of `chunk_size` rows and
+    /// only decodes the next chunk once the previous one has been received.
+    async fn decode_row_range(
+        &self,
+        row_range: RowGroupRange,
+        ranges: Arc<RangesContainer>,
+        daft_schema: SchemaRef,
+        chunk_size: usize,
+    ) -> DaftResult<tokio::sync::mpsc::Receiver<DaftResult<Table>>> {
+        let rt_handle = tokio::runtime::Handle::current();
+        let rg = self
+            .metadata
+            .row_groups
+            .get(row_range.row_group_index)
+            .expect("Row Group index should be in bounds");
+        let num_rows = rg.num_rows().min(row_range.start + row_range.num_rows);
+
+        let mut fields = Vec::with_capacity(self.arrow_schema.fields.len());
+        let mut field_ptypes = Vec::with_capacity(self.arrow_schema.fields.len());
+        for field in self.arrow_schema.fields.iter() {
+            let mut decompressed_iters = vec![];
+            let mut ptypes = vec![];
+            for col in rg
+                .columns()
+                .iter()
+                .filter(|x| x.descriptor().path_in_schema[0] == field.name)
+            {
+                let selection = PageSelection::all(col);
+                let range_reader = page_selection_reader(&self.uri, &ranges, &selection)?;
+                ptypes.push(col.descriptor().descriptor.primitive_type.clone());
+                let compressed_page_stream = get_owned_page_stream_from_column_start(
+                    col,
+                    range_reader,
+                    vec![],
+                    Arc::new(|_, _| true),
+                    // See `read_from_ranges_into_table` for this limit on the size of page headers.
+                    256 * 1024 * 1024,
+                )
+                .await
+                .with_context(|_| UnableToCreateParquetPageStreamSnafu::<String> {
+                    path: self.uri.clone(),
+                })?;
+                let page_stream = streaming_decompression(compressed_page_stream);
+                decompressed_iters.push(StreamIterator::new(
+                    Box::pin(page_stream),
+                    rt_handle.clone(),
+                ));
+            }
+            fields.push((field.clone(), decompressed_iters));
+            field_ptypes.push(ptypes);
+        }
+
+        let uri = self.uri.clone();
+        let (send, recv) = tokio::sync::mpsc::channel(1);
+        tokio::task::spawn_blocking(move || {
+            let result = (|| -> DaftResult<()> {
+                // A projection without columns only has a number of rows.
+                if fields.is_empty() {
+                    for start in (row_range.start..num_rows).step_by(chunk_size) {
+                        let len = chunk_size.min(num_rows - start);
+                        let table = Table::new_with_size(daft_schema.clone(), vec![], len)?;
+                        if send.blocking_send(Ok(table)).is_err() {
+                            break;
+                        }
+                    }
+                    return Ok(());
+                }
+                let mut arr_iters = fields
+                    .into_iter()
+                    .zip(field_ptypes.iter())
+                    .map(|((field, decompressed_iters), ptypes)| {
+                        let arr_iter = column_iter_to_arrays(
+                            decompressed_iters,
+                            ptypes.iter().collect(),
+                            field.clone(),
+                            Some(chunk_size),
+                            num_rows,
+                        )?;
+                        Ok((field, arr_iter))
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+
+                let mut curr_index = 0;
+                loop {
+                    let mut all_series = Vec::with_capacity(arr_iters.len());
+                    for (field, arr_iter) in arr_iters.iter_mut() {
+                        let Some(arr) = arr_iter.next() else {
+                            break;
+                        };
+                        all_series.push(Series::try_from((
+                            field.name.as_str(),
+                            cast_array_for_daft_if_needed(arr?),
+                        ))?);
+                    }
+                    if all_series.is_empty() {
+                        return Ok(());
+                    }
+                    let len = all_series[0].len();
+                    if all_series.len() != arr_iters.len()
+                        || all_series.iter().any(|s| s.len() != len)
+                    {
+                        return Err(super::Error::ParquetColumnsDontHaveEqualRows {
+                            path: uri.clone(),
+                        }
+                        .into());
+                    }
+                    let mut table = Table::new(daft_schema.clone(), all_series)?;
+                    // Throw away the rows before the start of the row range.
+                    let offset = row_range.start.saturating_sub(curr_index).min(len);
+                    curr_index += len;
+                    if offset == len {
+                        continue;
+                    } else if offset > 0 {
+                        table = table.slice(offset, len)?;
+                    }
+                    // Stop decoding once the stream is dropped.
+                    if send.blocking_send(Ok(table)).is_err() {
+                        return Ok(());
+                    }
+                }
+            })();
+            if let Err(err) = result {
+                let _ = send.blocking_send(Err(err));
+            }
+        });
+        Ok(recv)
+    }
+
     pub async fn read_from_ranges_into_arrow_arrays(
         self,
         ranges: Arc<RangesContainer>,
@@ -577,6 +842,7 @@ impl ParquetFileReader {
             .iter()
             .map(|field| {
                 let owned_row_ranges = self.row_ranges.clone();
+                let page_locations = self.page_locations.clone();
 
                 let field_handles = owned_row_ranges
                     .iter()
@@ -589,7 +855,6 @@ impl ParquetFileReader {
                             .row_groups
                             .get(row_range.row_group_index)
                             .expect("Row Group index should be in bounds");
-                        let num_rows = rg.num_rows().min(row_range.start + row_range.num_rows);
                         let columns = rg.columns();
                         let field_name = &field.name;
                         let filtered_cols_idx = columns
@@ -599,40 +864,42 @@ impl ParquetFileReader {
                             .map(|(i, _)| i)
                             .collect::<Vec<_>>();
 
-                        let range_readers = filtered_cols_idx
+                        // Only flat columns have page locations, so a field either reads every page of its
+                        // column chunks or the selected pages of a single column chunk.
+                        let mut first_row = 0;
+                        let column_readers = filtered_cols_idx
                             .iter()
                             .map(|i| {
-                                let c = columns.get(*i).unwrap();
-                                let (start, len) = c.byte_range();
-                                let end: u64 = start + len;
-                                let range_reader = ranges
-                                    .get_range_reader(start as usize..end as usize)
-                                    .unwrap();
-
-                                Box::pin(range_reader)
+                                let selection = select_column_chunk_pages(
+                                    columns.get(*i).unwrap(),
+                                    &page_locations,
+                                    *i,
+                                    rg.num_rows(),
+                                    &row_range,
+                                );
+                                first_row = selection.first_row;
+                                let range_reader =
+                                    page_selection_reader(&self.uri, &ranges, &selection)?;
+                                Ok((selection.metadata, range_reader))
                             })
-                            .collect::<Vec<_>>();
-                        let metadata = metadata.clone();
+                            .collect::<DaftResult<Vec<_>>>()?;
+                        // Rows are counted from the first row of the pages that are read.
+                        let num_rows =
+                            rg.num_rows().min(row_range.start + row_range.num_rows) - first_row;
+                        let row_range = RowGroupRange {
+                            start: row_range.start - first_row,
+                            ..row_range
+                        };
                         let handle = tokio::task::spawn(async move {
-                            let mut decompressed_iters =
-                                Vec::with_capacity(filtered_cols_idx.len());
-                            let mut ptypes = Vec::with_capacity(filtered_cols_idx.len());
-
-                            for (col_idx, range_reader) in
-                                filtered_cols_idx.into_iter().zip(range_readers)
-                            {
-                                let col = metadata
-                                    .row_groups
-                                    .get(row_range.row_group_index)
-                                    .expect("Row Group index should be in bounds")
-                                    .columns()
-                                    .get(col_idx)
-                                    .expect("Column index should be in bounds");
+                            let mut decompressed_iters = Vec::with_capacity(column_readers.len());
+                            let mut ptypes = Vec::with_capacity(column_readers.len());
+
+                            for (col, range_reader) in column_readers {
                                 ptypes.push(col.descriptor().descriptor.primitive_type.clone());
 
                                 let compressed_page_stream =
                                     get_owned_page_stream_from_column_start(
-                                        col,
+                                        &col,
                                         range_reader,
                                         vec![],
                                         Arc::new(|_, _| true),
@@ -0,0 +1,772 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::{Cursor, Read, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
+
+use common_error::DaftResult;
+use daft_core::{array::ops::as_arrow::AsArrow, schema::Schema, DataType, Series};
+use daft_dsl::{
+    optimization::{get_required_columns, split_conjuction},
+    Expr, Operator,
+};
+use daft_io::{IOClient, IOStatsRef};
+use daft_stats::{ColumnRangeStatistics, TableStatistics, TruthValue};
+use futures::{future::try_join_all, AsyncReadExt};
+use itertools::Itertools;
+use parquet2::{
+    bloom_filter::{hash_byte, hash_native, is_in_set},
+    metadata::{ColumnChunkMetaData, FileMetaData, RowGroupMetaData},
+    read::{read_columns_indexes, read_pages_locations},
+    schema::types::PhysicalType,
+    thrift_format::{
+        thrift::protocol::TCompactInputProtocol, BloomFilterAlgorithm, BloomFilterCompression,
+        BloomFilterHash, BloomFilterHeader, PageLocation,
+    },
+};
+use snafu::ResultExt;
+
+use crate::{
+    file::RowGroupRange,
+    read_planner::{CoalescePass, ReadPlanner},
+    statistics, InternalIOSnafu, UnableToReadParquetPageIndexSnafu,
+    UnableToRunExpressionOnStatsSnafu,
+};
+
+/// Page locations of flat column chunks, keyed by row group index and column index within the row group.
+pub(crate) type PageLocations = HashMap<(usize, usize), Vec<PageLocation>>;
+
+/// Upper bound on the size of a serialized bloom filter header, which is fetched before its bitset.
+const BLOOM_FILTER_HEADER_SIZE: usize = 64;
+
+/// The data pages of a column chunk that are needed to read a range of rows from its row group.
+pub(crate) struct PageSelection {
+    /// Byte ranges of the dictionary page, if any, and of the contiguous selected data pages.
+    pub byte_ranges: Vec<Range<usize>>,
+    /// Column chunk metadata that only accounts for the values in the selected pages.
+    pub metadata: ColumnChunkMetaData,
+    /// Index of the first row of the first selected data page within the row group.
+    pub first_row: usize,
+}
+
+impl PageSelection {
+    /// Selects every page of a column chunk.
+    pub fn all(column: &ColumnChunkMetaData) -> Self {
+        let (start, len) = column.byte_range();
+        Self {
+            byte_ranges: vec![start as usize..(start + len) as usize],
+            metadata: column.clone(),
+            first_row: 0,
+        }
+    }
+}
+
+/// Selects the data pages of a column chunk that overlap the rows `start..start + num_rows` of its row group.
+pub(crate) fn select_pages(
+    column: &ColumnChunkMetaData,
+    locations: &[PageLocation],
+    row_group_num_rows: usize,
+    start: usize,
+    num_rows: usize,
+) -> Option<PageSelection> {
+    let page_start = |i: usize| locations[i].first_row_index as usize;
+    let page_end = |i: usize| {
+        locations
+            .get(i + 1)
+            .map_or(row_group_num_rows, |l| l.first_row_index as usize)
+    };
+    let first = (0..locations.len()).rposition(|i| page_start(i) <= start)?;
+    let last = (first..locations.len())
+        .take_while(|i| page_start(*i) < start + num_rows)
+        .last()
+        .unwrap_or(first);
+
+    // Everything from the start of the column chunk up to the first data page is its dictionary page.
+    let (column_start, _) = column.byte_range();
+    let mut byte_ranges = vec![];
+    if (column_start as i64) < locations[0].offset {
+        byte_ranges.push(column_start as usize..locations[0].offset as usize);
+    }
+    byte_ranges.push(
+        locations[first].offset as usize
+            ..(locations[last].offset + locations[last].compressed_page_size as i64) as usize,
+    );
+
+    // The column is flat, so the number of values in the selected pages is their number of rows.
+    let mut column_chunk = column.column_chunk().clone();
+    if let Some(column_metadata) = column_chunk.meta_data.as_mut() {
+        column_metadata.num_values = (page_end(last) - page_start(first)) as i64;
+    }
+    Some(PageSelection {
+        byte_ranges,
+        metadata: ColumnChunkMetaData::new(column_chunk, column.descriptor().clone()),
+        first_row: page_start(first),
+    })
+}
+
+/// Refines the row ranges of a read with a predicate, using the bloom filters and page indexes of the file.
+///
+/// Row groups whose bloom filters rule out every value of an equality or `is_in` predicate are dropped, and the
+/// remaining row ranges are narrowed to the pages whose column index statistics may satisfy the predicate. The page
+/// locations of the flat columns that are read are returned, so that only the selected pages need to be fetched.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn prune_row_ranges(
+    uri: &str,
+    file_size: usize,
+    metadata: &FileMetaData,
+    schema: &Schema,
+    predicate: &Expr,
+    row_ranges: Vec<RowGroupRange>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(Vec<RowGroupRange>, PageLocations)> {
+    let row_ranges = prune_with_bloom_filters(
+        uri,
+        file_size,
+        metadata,
+        schema,
+        predicate,
+        row_ranges,
+        io_client.clone(),
+        io_stats.clone(),
+    )
+    .await?;
+    prune_with_page_indexes(
+        uri, metadata, schema, predicate, row_ranges, io_client, io_stats,
+    )
+    .await
+}
+
+fn find_flat_column<'a>(
+    rg: &'a RowGroupMetaData,
+    name: &str,
+) -> Option<(usize, &'a ColumnChunkMetaData)> {
+    rg.columns()
+        .iter()
+        .enumerate()
+        .find(|(_, c)| is_flat(c) && c.descriptor().path_in_schema[0] == name)
+}
+
+fn is_flat(column: &ColumnChunkMetaData) -> bool {
+    column.descriptor().path_in_schema.len() == 1
+        && column.descriptor().descriptor.max_rep_level == 0
+}
+
+/// Returns the column and the values that it must be equal to for a term of a conjunction to be true.
+fn equality_term(expr: &Expr) -> Option<(&str, Series)> {
+    match expr {
+        Expr::BinaryOp {
+            op: Operator::Eq,
+            left,
+            right,
+        } => match (left.as_ref(), right.as_ref()) {
+            (Expr::Column(name), Expr::Literal(lit)) | (Expr::Literal(lit), Expr::Column(name)) => {
+                Some((name.as_ref(), lit.to_series()))
+            }
+            _ => None,
+        },
+        Expr::IsIn(child, items) => match (child.as_ref(), items.as_ref()) {
+            (Expr::Column(name), Expr::Literal(lit)) => Some((name.as_ref(), lit.to_series())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Hashes values as they would be inserted into the bloom filter of a column, or returns None if bloom filters
+/// can't be used to look up these values in the column.
+fn bloom_filter_hashes(
+    values: &Series,
+    daft_dtype: &DataType,
+    physical_type: &PhysicalType,
+) -> DaftResult<Option<Vec<u64>>> {
+    let hashes = match (daft_dtype, physical_type) {
+        (DataType::Int8 | DataType::Int16 | DataType::Int32, PhysicalType::Int32)
+            if values.data_type().is_integer() =>
+        {
+            // Values that don't fit in the column can't be equal to any of its values.
+            values
+                .cast(&DataType::Int64)?
+                .i64()?
+                .as_arrow()
+                .iter()
+                .flatten()
+                .filter_map(|v| i32::try_from(*v).ok())
+                .map(hash_native)
+                .collect()
+        }
+        (DataType::Int64, PhysicalType::Int64) if values.data_type().is_integer() => values
+            .cast(&DataType::Int64)?
+            .i64()?
+            .as_arrow()
+            .iter()
+            .flatten()
+            .map(|v| hash_native(*v))
+            .collect(),
+        (DataType::Utf8, PhysicalType::ByteArray) if values.data_type() == &DataType::Utf8 => {
+            values
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .flatten()
+                .map(|v| hash_byte(v.as_bytes()))
+                .collect()
+        }
+        (DataType::Binary, PhysicalType::ByteArray) if values.data_type() == &DataType::Binary => {
+            values
+                .binary()?
+                .as_arrow()
+                .iter()
+                .flatten()
+                .map(hash_byte)
+                .collect()
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(hashes))
+}
+
+/// Parses a split-block bloom filter header, returning its size and the size of the bitset that follows it.
+fn parse_bloom_filter_header(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut reader = Cursor::new(bytes);
+    let header = {
+        let mut prot = TCompactInputProtocol::new(&mut reader, bytes.len());
+        BloomFilterHeader::read_from_in_protocol(&mut prot).ok()?
+    };
+    let supported = matches!(header.algorithm, BloomFilterAlgorithm::BLOCK(_))
+        && matches!(header.hash, BloomFilterHash::XXHASH(_))
+        && matches!(header.compression, BloomFilterCompression::UNCOMPRESSED(_));
+    if !supported {
+        return None;
+    }
+    Some((
+        reader.position() as usize,
+        usize::try_from(header.num_bytes).ok()?,
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn prune_with_bloom_filters(
+    uri: &str,
+    file_size: usize,
+    metadata: &FileMetaData,
+    schema: &Schema,
+    predicate: &Expr,
+    row_ranges: Vec<RowGroupRange>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Vec<RowGroupRange>> {
+    let terms = split_conjuction(predicate)
+        .into_iter()
+        .filter_map(equality_term)
+        .collect::<Vec<_>>();
+    if terms.is_empty() {
+        return Ok(row_ranges);
+    }
+
+    // Row group index, bloom filter offset and hashed values of each bloom filter to check.
+    let mut checks = vec![];
+    for rg_idx in row_ranges.iter().map(|r| r.row_group_index).unique() {
+        let rg = &metadata.row_groups[rg_idx];
+        for (name, values) in terms.iter() {
+            let (Some((_, column)), Ok(field)) =
+                (find_flat_column(rg, name), schema.get_field(name))
+            else {
+                continue;
+            };
+            let Some(offset) = column.metadata().bloom_filter_offset else {
+                continue;
+            };
+            if let Some(hashes) =
+                bloom_filter_hashes(values, &field.dtype, &column.physical_type())?
+            {
+                checks.push((rg_idx, offset as usize, hashes));
+            }
+        }
+    }
+    if checks.is_empty() {
+        return Ok(row_ranges);
+    }
+
+    let header_ranges = checks
+        .iter()
+        .map(|(_, offset, _)| *offset..(offset + BLOOM_FILTER_HEADER_SIZE).min(file_size))
+        .collect::<Vec<_>>();
+    let headers = fetch_ranges(uri, &header_ranges, io_client.clone(), io_stats.clone()).await?;
+    let (bitset_checks, bitset_ranges): (Vec<_>, Vec<_>) = checks
+        .into_iter()
+        .zip(headers)
+        .filter_map(|((rg_idx, offset, hashes), header)| {
+            // Bloom filters that can't be read are skipped, since they can only be used to prune.
+            let (header_size, num_bytes) = parse_bloom_filter_header(&header)?;
+            let start = offset + header_size;
+            Some(((rg_idx, hashes), start..start + num_bytes))
+        })
+        .unzip();
+    let bitsets = fetch_ranges(uri, &bitset_ranges, io_client, io_stats).await?;
+
+    let pruned_row_groups = bitset_checks
+        .into_iter()
+        .zip(bitsets)
+        .filter(|((_, hashes), bitset)| !hashes.iter().any(|h| is_in_set(bitset, *h)))
+        .map(|((rg_idx, _), _)| rg_idx)
+        .collect::<HashSet<_>>();
+    Ok(row_ranges
+        .into_iter()
+        .filter(|r| !pruned_row_groups.contains(&r.row_group_index))
+        .collect())
+}
+
+async fn prune_with_page_indexes(
+    uri: &str,
+    metadata: &FileMetaData,
+    schema: &Schema,
+    predicate: &Expr,
+    row_ranges: Vec<RowGroupRange>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(Vec<RowGroupRange>, PageLocations)> {
+    let predicate_columns = get_required_columns(predicate);
+
+    // Offset indexes of every flat column that is read, and column indexes of the columns in the predicate.
+    let mut offset_index_reads = vec![];
+    let mut column_index_reads = vec![];
+    for rg_idx in row_ranges.iter().map(|r| r.row_group_index).unique() {
+        let rg = &metadata.row_groups[rg_idx];
+        for (name, _) in schema.fields.iter() {
+            let Some((col_idx, column)) = find_flat_column(rg, name) else {
+                continue;
+            };
+            let chunk = column.column_chunk();
+            if let (Some(offset), Some(length)) =
+                (chunk.offset_index_offset, chunk.offset_index_length)
+            {
+                offset_index_reads.push((rg_idx, col_idx, offset as usize, length as usize));
+                if predicate_columns.contains(name)
+                    && let (Some(offset), Some(length)) =
+                        (chunk.column_index_offset, chunk.column_index_length)
+                {
+                    column_index_reads.push((rg_idx, col_idx, offset as usize, length as usize));
+                }
+            }
+        }
+    }
+    if offset_index_reads.is_empty() {
+        return Ok((row_ranges, PageLocations::new()));
+    }
+
+    let index_ranges = offset_index_reads
+        .iter()
+        .chain(column_index_reads.iter())
+        .map(|(_, _, offset, length)| *offset..offset + length)
+        .collect::<Vec<_>>();
+    let mut index_bytes = fetch_ranges(uri, &index_ranges, io_client, io_stats).await?;
+    let column_index_bytes = index_bytes.split_off(offset_index_reads.len());
+
+    let mut page_locations = PageLocations::new();
+    for ((rg_idx, col_idx, offset, _), bytes) in offset_index_reads.into_iter().zip(index_bytes) {
+        let column = &metadata.row_groups[rg_idx].columns()[col_idx];
+        let mut window = FileWindow::new(offset, bytes);
+        let locations = read_pages_locations(&mut window, std::slice::from_ref(column))
+            .context(UnableToReadParquetPageIndexSnafu { path: uri })?;
+        if let Some(locations) = locations.into_iter().next()
+            && !locations.is_empty()
+        {
+            page_locations.insert((rg_idx, col_idx), locations);
+        }
+    }
+
+    // Rows of each row group that may satisfy the predicate, according to the column index of each column.
+    let mut selected_rows: HashMap<usize, Vec<Range<usize>>> = HashMap::new();
+    for ((rg_idx, col_idx, offset, _), bytes) in
+        column_index_reads.into_iter().zip(column_index_bytes)
+    {
+        let rg = &metadata.row_groups[rg_idx];
+        let column = &rg.columns()[col_idx];
+        let Some(locations) = page_locations.get(&(rg_idx, col_idx)) else {
+            continue;
+        };
+        let mut window = FileWindow::new(offset, bytes);
+        let indexes = read_columns_indexes(&mut window, std::slice::from_ref(column))
+            .context(UnableToReadParquetPageIndexSnafu { path: uri })?;
+        let Some(index) = indexes.first() else {
+            continue;
+        };
+        let name = &column.descriptor().path_in_schema[0];
+        let field = schema.get_field(name)?;
+        let page_stats = statistics::column_index_to_page_statistics(index.as_ref(), &field.dtype);
+        if page_stats.len() != locations.len() {
+            continue;
+        }
+
+        let mut table_stats = TableStatistics {
+            columns: schema
+                .fields
+                .keys()
+                .map(|name| (name.clone(), ColumnRangeStatistics::Missing))
+                .collect(),
+        };
+        let mut column_rows: Vec<Range<usize>> = vec![];
+        for (i, stats) in page_stats.into_iter().enumerate() {
+            table_stats.columns.insert(name.clone(), stats);
+            let evaled = table_stats.eval_expression(predicate).with_context(|_| {
+                UnableToRunExpressionOnStatsSnafu {
+                    path: uri.to_string(),
+                }
+            })?;
+            if evaled.to_truth_value() == TruthValue::False {
+                continue;
+            }
+            let page_rows = locations[i].first_row_index as usize
+                ..locations
+                    .get(i + 1)
+                    .map_or(rg.num_rows(), |l| l.first_row_index as usize);
+            match column_rows.last_mut() {
+                Some(last) if last.end == page_rows.start => last.end = page_rows.end,
+                _ => column_rows.push(page_rows),
+            }
+        }
+        let rows = match selected_rows.remove(&rg_idx) {
+            Some(rows) => intersect_rows(&rows, &column_rows),
+            None => column_rows,
+        };
+        selected_rows.insert(rg_idx, rows);
+    }
+
+    let row_ranges = row_ranges
+        .into_iter()
+        .flat_map(
+            |row_range| match selected_rows.get(&row_range.row_group_index) {
+                Some(rows) => intersect_rows(
+                    &[row_range.start..row_range.start + row_range.num_rows],
+                    rows,
+                )
+                .into_iter()
+                .map(|rows| RowGroupRange {
+                    row_group_index: row_range.row_group_index,
+                    start: rows.start,
+                    num_rows: rows.len(),
+                })
+                .collect(),
+                None => vec![row_range],
+            },
+        )
+        .collect();
+    Ok((row_ranges, page_locations))
+}
+
+/// Intersects two sorted lists of disjoint row ranges.
+fn intersect_rows(left: &[Range<usize>], right: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut intersection = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        let start = left[i].start.max(right[j].start);
+        let end = left[i].end.min(right[j].end);
+        if start < end {
+            intersection.push(start..end);
+        }
+        if left[i].end < right[j].end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    intersection
+}
+
+/// Fetches byte ranges of a file, coalescing nearby ranges into fewer requests.
+async fn fetch_ranges(
+    uri: &str,
+    ranges: &[Range<usize>],
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Vec<Vec<u8>>> {
+    let mut read_planner = ReadPlanner::new(uri);
+    for range in ranges.iter().filter(|r| !r.is_empty()) {
+        read_planner.add_range(range.start, range.end);
+    }
+    read_planner.add_pass(Box::new(CoalescePass {
+        max_hole_size: 1024 * 1024,
+        max_request_size: 16 * 1024 * 1024,
+    }));
+    read_planner.run_passes()?;
+    let ranges_container = read_planner.collect(io_client, io_stats)?;
+    try_join_all(ranges.iter().map(|range| {
+        let ranges_container = ranges_container.clone();
+        async move {
+            let mut bytes = Vec::with_capacity(range.len());
+            if !range.is_empty() {
+                let mut reader = Box::pin(ranges_container.get_range_reader(range.clone())?);
+                reader
+                    .read_to_end(&mut bytes)
+                    .await
+                    .context(InternalIOSnafu { path: uri })?;
+            }
+            DaftResult::<Vec<u8>>::Ok(bytes)
+        }
+    }))
+    .await
+}
+
+/// Bytes fetched from a file at an offset, which can be read with the absolute file offsets used by Parquet
+/// metadata.
+struct FileWindow {
+    offset: u64,
+    cursor: Cursor<Vec<u8>>,
+}
+
+impl FileWindow {
+    fn new(offset: usize, bytes: Vec<u8>) -> Self {
+        Self {
+            offset: offset as u64,
+            cursor: Cursor::new(bytes),
+        }
+    }
+}
+
+impl Read for FileWindow {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl Seek for FileWindow {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.offset).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "Seek to {pos} is before the fetched bytes at {}",
+                            self.offset
+                        ),
+                    )
+                })?)
+            }
+            pos => pos,
+        };
+        Ok(self.cursor.seek(pos)? + self.offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, sync::Arc};
+
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{Field, Utf8Array},
+        schema::Schema,
+        series::IntoSeries,
+        DataType,
+    };
+    use daft_dsl::{col, lit, Expr};
+    use daft_io::{IOClient, IOConfig};
+    use itertools::Itertools;
+    use parquet2::{
+        metadata::FileMetaData,
+        read::{read_metadata, read_pages_locations},
+    };
+
+    use super::{
+        intersect_rows, parse_bloom_filter_header, prune_row_ranges, prune_with_bloom_filters,
+        select_pages, BLOOM_FILTER_HEADER_SIZE,
+    };
+    use crate::file::RowGroupRange;
+
+    /// A file with 2 row groups of 500 rows, with an `id` column of plain-encoded int64s and a `name` column of
+    /// dictionary-encoded strings. Every column chunk has 5 data pages of 100 rows, a bloom filter, and column and
+    /// offset indexes.
+    fn fixture() -> (String, usize, FileMetaData) {
+        let path = format!(
+            "{}/test/pages_and_bloom_filters.parquet",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+        let mut file = File::open(&path).unwrap();
+        let metadata = read_metadata(&mut file).unwrap();
+        let size = file.metadata().unwrap().len() as usize;
+        (path, size, metadata)
+    }
+
+    fn fixture_schema() -> DaftResult<Schema> {
+        Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("name", DataType::Utf8),
+        ])
+    }
+
+    fn all_row_ranges(metadata: &FileMetaData) -> Vec<RowGroupRange> {
+        metadata
+            .row_groups
+            .iter()
+            .enumerate()
+            .map(|(i, rg)| RowGroupRange {
+                row_group_index: i,
+                start: 0,
+                num_rows: rg.num_rows(),
+            })
+            .collect()
+    }
+
+    /// Returns the row groups that are left after pruning the fixture with its bloom filters.
+    async fn row_groups_after_bloom_filters(predicate: Expr) -> DaftResult<Vec<usize>> {
+        let (path, size, metadata) = fixture();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let row_ranges = prune_with_bloom_filters(
+            &path,
+            size,
+            &metadata,
+            &fixture_schema()?,
+            &predicate,
+            all_row_ranges(&metadata),
+            io_client,
+            None,
+        )
+        .await?;
+        Ok(row_ranges.iter().map(|r| r.row_group_index).collect())
+    }
+
+    #[test]
+    fn test_intersect_rows() {
+        assert_eq!(
+            intersect_rows(&[0..10, 20..30], &[5..25, 28..40]),
+            vec![5..10, 20..25, 28..30]
+        );
+        assert_eq!(intersect_rows(&[0..10], &[10..20]), vec![]);
+        assert_eq!(intersect_rows(&[0..100], &[]), vec![]);
+    }
+
+    #[test]
+    fn test_select_pages() {
+        let (path, _, metadata) = fixture();
+        let rg = &metadata.row_groups[0];
+        let locations = read_pages_locations(&mut File::open(path).unwrap(), rg.columns()).unwrap();
+        let page_range = |locations: &[parquet2::thrift_format::PageLocation], i: usize| {
+            let location = &locations[i];
+            location.offset as usize
+                ..(location.offset + location.compressed_page_size as i64) as usize
+        };
+
+        // Only the data pages that overlap the rows are selected from a column chunk without a dictionary page.
+        let id = &rg.columns()[0];
+        let selection = select_pages(id, &locations[0], rg.num_rows(), 150, 100).unwrap();
+        assert_eq!(
+            selection.byte_ranges,
+            vec![page_range(&locations[0], 1).start..page_range(&locations[0], 2).end]
+        );
+        assert_eq!(selection.first_row, 100);
+        assert_eq!(selection.metadata.num_values(), 200);
+
+        // The dictionary page is always selected, followed by the data pages that overlap the rows.
+        let name = &rg.columns()[1];
+        let selection = select_pages(name, &locations[1], rg.num_rows(), 450, 50).unwrap();
+        let (column_start, _) = name.byte_range();
+        assert_eq!(
+            selection.byte_ranges,
+            vec![
+                column_start as usize..locations[1][0].offset as usize,
+                page_range(&locations[1], 4)
+            ]
+        );
+        assert_eq!(selection.first_row, 400);
+        assert_eq!(selection.metadata.num_values(), 100);
+
+        // Reading a single row selects the page it's in.
+        let selection = select_pages(id, &locations[0], rg.num_rows(), 299, 1).unwrap();
+        assert_eq!(selection.byte_ranges, vec![page_range(&locations[0], 2)]);
+        assert_eq!(selection.first_row, 200);
+        assert_eq!(selection.metadata.num_values(), 100);
+    }
+
+    #[test]
+    fn test_parse_bloom_filter_header() -> DaftResult<()> {
+        let (path, _, metadata) = fixture();
+        let bytes = std::fs::read(path)?;
+        let offsets = metadata
+            .row_groups
+            .iter()
+            .flat_map(|rg| rg.columns())
+            .map(|column| column.metadata().bloom_filter_offset.unwrap() as usize)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets.len(), 4);
+        // The bloom filters are written back to back, each one a header followed by its bitset.
+        for (start, next_start) in offsets.into_iter().tuple_windows() {
+            let (header_size, num_bytes) =
+                parse_bloom_filter_header(&bytes[start..start + BLOOM_FILTER_HEADER_SIZE]).unwrap();
+            assert!(header_size < BLOOM_FILTER_HEADER_SIZE);
+            assert_eq!(start + header_size + num_bytes, next_start);
+        }
+        assert_eq!(parse_bloom_filter_header(&[]), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prune_with_bloom_filters() -> DaftResult<()> {
+        // Row group 0 has ids 0 to 499, and row group 1 has ids 500 to 999.
+        assert_eq!(
+            row_groups_after_bloom_filters(col("id").eq(&lit(10i64))).await?,
+            vec![0]
+        );
+        assert_eq!(
+            row_groups_after_bloom_filters(lit(700i64).eq(&col("id"))).await?,
+            vec![1]
+        );
+        let names = Utf8Array::from(("names", ["name-700", "name-5000"].as_slice())).into_series();
+        assert_eq!(
+            row_groups_after_bloom_filters(col("name").is_in(&lit(names))).await?,
+            vec![1]
+        );
+        assert_eq!(
+            row_groups_after_bloom_filters(col("id").eq(&lit(5000i64))).await?,
+            Vec::<usize>::new()
+        );
+        // Every term of a conjunction must be satisfiable.
+        assert_eq!(
+            row_groups_after_bloom_filters(
+                col("id")
+                    .eq(&lit(10i64))
+                    .and(&col("name").eq(&lit("name-700")))
+            )
+            .await?,
+            Vec::<usize>::new()
+        );
+        // Predicates without equality terms can't be checked against bloom filters.
+        assert_eq!(
+            row_groups_after_bloom_filters(col("id").lt(&lit(10i64))).await?,
+            vec![0, 1]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prune_with_page_indexes() -> DaftResult<()> {
+        let (path, size, metadata) = fixture();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let (row_ranges, page_locations) = prune_row_ranges(
+            &path,
+            size,
+            &metadata,
+            &fixture_schema()?,
+            &col("id").lt(&lit(150i64)),
+            all_row_ranges(&metadata),
+            io_client,
+            None,
+        )
+        .await?;
+        // Only the first two pages of the first row group can have ids less than 150.
+        assert_eq!(
+            row_ranges
+                .iter()
+                .map(|r| (r.row_group_index, r.start, r.num_rows))
+                .collect::<Vec<_>>(),
+            vec![(0, 0, 200)]
+        );
+        assert_eq!(page_locations.len(), 4);
+        assert!(page_locations
+            .values()
+            .all(|locations| locations.len() == 5));
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ use daft_io::{get_runtime, parse_url, IOClient, IOStatsRef, SourceType};
 use daft_table::Table;
 use futures::{
     future::{join_all, try_join_all},
+    stream::BoxStream,
     StreamExt, TryStreamExt,
 };
 use itertools::Itertools;
@@ -134,7 +135,10 @@ async fn read_parquet_single(
             builder
         };
 
-        let parquet_reader = builder.build()?;
+        let parquet_reader = builder
+            .build()?
+            .prune_pages(io_client.clone(), io_stats.clone())
+            .await?;
         let ranges = parquet_reader.prebuffer_ranges(io_client, io_stats)?;
         Ok((
             metadata,
@@ -392,6 +396,87 @@ pub fn read_parquet(
         .await
     })
 }
+
+/// Streams a Parquet file as [`Table`]s of at most `batch_size` rows, rather than reading it into a
+/// single [`Table`].
+///
+/// The metadata of the file is fetched once, and row groups are then fetched one at a time and
+/// decoded `batch_size` rows at a time as the stream is polled, so that at most a single row group
+/// of the file is held in memory at once. The stream must be polled from within a Tokio runtime.
+#[allow(clippy::too_many_arguments)]
+pub fn stream_parquet(
+    uri: String,
+    columns: Option<Vec<String>>,
+    num_rows: Option<usize>,
+    row_groups: Option<Vec<i64>>,
+    predicate: Option<ExprRef>,
+    batch_size: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    schema_infer_options: ParquetSchemaInferenceOptions,
+    field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
+) -> BoxStream<'static, DaftResult<Table>> {
+    async_stream::try_stream! {
+        // The columns of the predicate are read as well, and dropped once it's applied.
+        let mut read_columns = columns.clone();
+        if let (Some(pred), Some(read_columns)) = (&predicate, read_columns.as_mut()) {
+            for c in get_required_columns(pred) {
+                if !read_columns.contains(&c) {
+                    read_columns.push(c);
+                }
+            }
+        }
+        let builder = ParquetReaderBuilder::from_uri(
+            uri.as_str(),
+            io_client.clone(),
+            io_stats.clone(),
+            field_id_mapping,
+        )
+        .await?
+        .set_infer_schema_options(schema_infer_options);
+        let builder = match read_columns {
+            Some(read_columns) => builder.prune_columns(read_columns.as_slice())?,
+            None => builder,
+        };
+        let builder = match row_groups {
+            Some(row_groups) => builder.set_row_groups(row_groups.as_slice())?,
+            None => builder,
+        };
+        // The limit only applies to the rows that pass the predicate.
+        let builder = match &predicate {
+            Some(pred) => builder.set_filter(pred.clone()),
+            None => builder.limit(None, num_rows)?,
+        };
+        let mut tables = builder
+            .build()?
+            .stream_tables(io_client, io_stats, batch_size)?;
+
+        let mut remaining_rows = num_rows;
+        while let Some(table) = tables.next().await {
+            if remaining_rows == Some(0) {
+                break;
+            }
+            let mut table = table?;
+            if let Some(pred) = &predicate {
+                table = table.filter(&[pred.clone()])?;
+                if let Some(columns) = &columns {
+                    table = table.get_columns(columns.as_slice())?;
+                }
+            }
+            if let Some(rows_left) = remaining_rows {
+                if table.len() > rows_left {
+                    table = table.head(rows_left)?;
+                }
+                remaining_rows = Some(rows_left - table.len());
+            }
+            if !table.is_empty() {
+                yield table;
+            }
+        }
+    }
+    .boxed()
+}
+
 pub type ArrowChunk = Vec<Box<dyn arrow2::array::Array>>;
 pub type ParquetPyarrowChunk = (arrow2::datatypes::SchemaRef, Vec<ArrowChunk>);
 #[allow(clippy::too_many_arguments)]
@@ -705,11 +790,15 @@ pub fn read_parquet_statistics(
 mod tests {
     use std::sync::Arc;
 
-    use common_error::DaftResult;
+    use common_error::{DaftError, DaftResult};
 
+    use daft_core::array::ops::as_arrow::AsArrow;
+    use daft_dsl::{col, lit, ExprRef};
     use daft_io::{IOClient, IOConfig};
+    use daft_table::Table;
+    use futures::{StreamExt, TryStreamExt};
 
-    use super::read_parquet;
+    use super::{read_parquet, stream_parquet};
     #[test]
     fn test_parquet_read_from_s3() -> DaftResult<()> {
         let file = "s3://daft-public-data/test_fixtures/parquet-dev/mvp.parquet";
@@ -736,4 +825,141 @@ mod tests {
 
         Ok(())
     }
+
+    /// The file of the page pruning tests, with 2 row groups of 500 rows and an `id` column holding
+    /// 0 to 999.
+    fn fixture_path() -> String {
+        format!(
+            "{}/test/pages_and_bloom_filters.parquet",
+            env!("CARGO_MANIFEST_DIR"),
+        )
+    }
+
+    fn stream_fixture(
+        columns: Option<Vec<&str>>,
+        num_rows: Option<usize>,
+        predicate: Option<ExprRef>,
+        batch_size: usize,
+    ) -> DaftResult<Vec<Table>> {
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        daft_io::get_runtime(true)?.block_on(
+            stream_parquet(
+                fixture_path(),
+                columns.map(|cols| cols.into_iter().map(String::from).collect()),
+                num_rows,
+                None,
+                predicate,
+                batch_size,
+                io_client,
+                None,
+                Default::default(),
+                None,
+            )
+            .try_collect(),
+        )
+    }
+
+    fn lens(tables: &[Table]) -> Vec<usize> {
+        tables.iter().map(Table::len).collect()
+    }
+
+    fn ids(tables: &[Table]) -> DaftResult<Vec<i64>> {
+        Ok(Table::concat(tables)?
+            .get_column("id")?
+            .i64()?
+            .as_arrow()
+            .values()
+            .to_vec())
+    }
+
+    #[test]
+    fn test_parquet_stream_batch_size_and_limit() -> DaftResult<()> {
+        // Batches don't span row groups.
+        let tables = stream_fixture(None, None, None, 150)?;
+        assert_eq!(lens(&tables), vec![150, 150, 150, 50, 150, 150, 150, 50]);
+        assert_eq!(ids(&tables)?, (0..1000).collect::<Vec<_>>());
+
+        let tables = stream_fixture(None, Some(420), None, 150)?;
+        assert_eq!(lens(&tables), vec![150, 150, 120]);
+        assert_eq!(ids(&tables)?, (0..420).collect::<Vec<_>>());
+
+        // An empty projection still has the rows of the file.
+        let tables = stream_fixture(Some(vec![]), Some(600), None, 400)?;
+        assert_eq!(lens(&tables), vec![400, 100, 100]);
+        assert!(tables.iter().all(|table| table.num_columns() == 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_stream_predicate() -> DaftResult<()> {
+        // The limit applies to the rows that pass the predicate, and batches without any are skipped.
+        let tables = stream_fixture(
+            Some(vec!["id"]),
+            Some(10),
+            Some(col("id").gt_eq(&lit(695i64))),
+            100,
+        )?;
+        assert_eq!(lens(&tables), vec![5, 5]);
+        assert_eq!(ids(&tables)?, (695..705).collect::<Vec<_>>());
+
+        // The columns of the predicate are dropped once it's applied.
+        let tables = stream_fixture(
+            Some(vec!["id"]),
+            None,
+            Some(col("name").eq(&lit("name-42"))),
+            100,
+        )?;
+        assert!(tables.iter().all(|table| table.num_columns() == 1));
+        assert_eq!(ids(&tables)?, vec![42]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_stream_drop_early() -> DaftResult<()> {
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        daft_io::get_runtime(true)?.block_on(async {
+            let mut tables = stream_parquet(
+                fixture_path(),
+                None,
+                None,
+                None,
+                None,
+                10,
+                io_client,
+                None,
+                Default::default(),
+                None,
+            );
+            assert_eq!(tables.try_next().await?.map(|table| table.len()), Some(10));
+            // Dropping the stream stops the decoding of the row group.
+            drop(tables);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_parquet_stream_errors() -> DaftResult<()> {
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let mut tables = stream_parquet(
+            format!("{}/test/missing.parquet", env!("CARGO_MANIFEST_DIR")),
+            None,
+            None,
+            None,
+            None,
+            10,
+            io_client,
+            None,
+            Default::default(),
+            None,
+        );
+        let runtime_handle = daft_io::get_runtime(true)?;
+        assert!(matches!(
+            runtime_handle.block_on(tables.next()),
+            Some(Err(DaftError::FileNotFound { .. }))
+        ));
+        assert!(runtime_handle.block_on(tables.next()).is_none());
+
+        assert!(stream_fixture(Some(vec!["missing"]), None, None, 10).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,105 @@
+use daft_core::DataType;
+use daft_stats::ColumnRangeStatistics;
+use parquet2::{
+    indexes::{BooleanIndex, ByteIndex, FixedLenByteIndex, Index, NativeIndex, PageIndex},
+    schema::types::{PhysicalType, PrimitiveType},
+    statistics::{
+        BinaryStatistics, BooleanStatistics, FixedLenStatistics, PrimitiveStatistics, Statistics,
+    },
+    types::NativeType,
+};
+
+use super::column_range::parquet_statistics_to_column_range_statistics;
+
+fn native_page_statistics<T: NativeType>(index: &dyn Index) -> Vec<Box<dyn Statistics>> {
+    let index = index.as_any().downcast_ref::<NativeIndex<T>>().unwrap();
+    index
+        .indexes
+        .iter()
+        .map(|page| {
+            Box::new(PrimitiveStatistics::<T> {
+                primitive_type: index.primitive_type.clone(),
+                null_count: page.null_count,
+                distinct_count: None,
+                min_value: page.min,
+                max_value: page.max,
+            }) as Box<dyn Statistics>
+        })
+        .collect()
+}
+
+fn byte_page_statistics<S: Statistics + 'static>(
+    primitive_type: &PrimitiveType,
+    indexes: &[PageIndex<Vec<u8>>],
+    make_stats: fn(PrimitiveType, &PageIndex<Vec<u8>>) -> S,
+) -> Vec<Box<dyn Statistics>> {
+    indexes
+        .iter()
+        .map(|page| Box::new(make_stats(primitive_type.clone(), page)) as Box<dyn Statistics>)
+        .collect()
+}
+
+/// Converts the column index of a column chunk into the statistics of each of its data pages.
+///
+/// Pages whose statistics can't be converted to the Daft type are returned as missing statistics.
+pub(crate) fn column_index_to_page_statistics(
+    index: &dyn Index,
+    daft_dtype: &DataType,
+) -> Vec<ColumnRangeStatistics> {
+    if !ColumnRangeStatistics::supports_dtype(daft_dtype) {
+        return vec![];
+    }
+    let page_stats = match index.physical_type() {
+        PhysicalType::Boolean => {
+            let index = index.as_any().downcast_ref::<BooleanIndex>().unwrap();
+            index
+                .indexes
+                .iter()
+                .map(|page| {
+                    Box::new(BooleanStatistics {
+                        null_count: page.null_count,
+                        distinct_count: None,
+                        max_value: page.max,
+                        min_value: page.min,
+                    }) as Box<dyn Statistics>
+                })
+                .collect()
+        }
+        PhysicalType::Int32 => native_page_statistics::<i32>(index),
+        PhysicalType::Int64 => native_page_statistics::<i64>(index),
+        PhysicalType::Int96 => native_page_statistics::<[u32; 3]>(index),
+        PhysicalType::Float => native_page_statistics::<f32>(index),
+        PhysicalType::Double => native_page_statistics::<f64>(index),
+        PhysicalType::ByteArray => {
+            let index = index.as_any().downcast_ref::<ByteIndex>().unwrap();
+            byte_page_statistics(&index.primitive_type, &index.indexes, |ptype, page| {
+                BinaryStatistics {
+                    primitive_type: ptype,
+                    null_count: page.null_count,
+                    distinct_count: None,
+                    min_value: page.min.clone(),
+                    max_value: page.max.clone(),
+                }
+            })
+        }
+        PhysicalType::FixedLenByteArray(_) => {
+            let index = index.as_any().downcast_ref::<FixedLenByteIndex>().unwrap();
+            byte_page_statistics(&index.primitive_type, &index.indexes, |ptype, page| {
+                FixedLenStatistics {
+                    primitive_type: ptype,
+                    null_count: page.null_count,
+                    distinct_count: None,
+                    min_value: page.min.clone(),
+                    max_value: page.max.clone(),
+                }
+            })
+        }
+    };
+    page_stats
+        .iter()
+        .map(|stats| {
+            parquet_statistics_to_column_range_statistics(stats.as_ref(), daft_dtype)
+                .unwrap_or(ColumnRangeStatistics::Missing)
+        })
+        .collect()
+}
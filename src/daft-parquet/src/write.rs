@@ -0,0 +1,424 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow2::array::Array;
+use arrow2::chunk::Chunk;
+use arrow2::io::parquet::write::{
+    transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+};
+use bytes::Bytes;
+use common_daft_config::DaftExecutionConfig;
+use common_error::{DaftError, DaftResult};
+use daft_core::datatypes::{UInt64Array, Utf8Array};
+use daft_core::schema::SchemaRef;
+use daft_core::IntoSeries;
+use daft_io::{IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::StreamExt;
+use snafu::ResultExt;
+use tokio::runtime::Runtime;
+
+use crate::{InternalIOSnafu, JoinSnafu, UnableToWriteParquetFileSnafu};
+
+/// Size of the chunks handed to the object store while a file is being encoded.
+const UPLOAD_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+#[derive(Clone, Debug)]
+pub struct ParquetWriteOptions {
+    pub target_file_size: usize,
+    pub target_row_group_size: usize,
+    pub inflation_factor: f64,
+    pub compression: Option<String>,
+}
+
+impl ParquetWriteOptions {
+    pub fn from_execution_config(
+        config: &DaftExecutionConfig,
+        compression: Option<String>,
+    ) -> Self {
+        Self {
+            target_file_size: config.parquet_target_filesize,
+            target_row_group_size: config.parquet_target_row_group_size,
+            inflation_factor: config.parquet_inflation_factor,
+            compression,
+        }
+    }
+
+    /// Splits `num_rows` rows totalling `size_bytes` in memory into (rows per file, rows per row group),
+    /// using the inflation factor to estimate the on-disk size of the data.
+    fn rows_per_file_and_row_group(&self, num_rows: usize, size_bytes: usize) -> (usize, usize) {
+        let target_num_files =
+            ((size_bytes as f64) / (self.target_file_size as f64) / self.inflation_factor)
+                .ceil()
+                .max(1.) as usize;
+        let rows_per_file = ((num_rows as f64) / (target_num_files as f64))
+            .ceil()
+            .max(1.) as usize;
+
+        let target_row_groups =
+            ((size_bytes as f64) / (self.target_row_group_size as f64) / self.inflation_factor)
+                .ceil()
+                .max(1.) as usize;
+        let rows_per_row_group = (((num_rows as f64) / (target_row_groups as f64)).ceil() as usize)
+            .min(rows_per_file)
+            .max(1);
+        (rows_per_file, rows_per_row_group)
+    }
+}
+
+fn compression_from_str(compression: Option<&str>) -> DaftResult<CompressionOptions> {
+    match compression.map(|c| c.to_lowercase()).as_deref() {
+        None | Some("snappy") => Ok(CompressionOptions::Snappy),
+        Some("none") | Some("uncompressed") => Ok(CompressionOptions::Uncompressed),
+        Some("gzip") => Ok(CompressionOptions::Gzip(None)),
+        Some("zstd") => Ok(CompressionOptions::Zstd(None)),
+        Some("brotli") => Ok(CompressionOptions::Brotli(None)),
+        // Matches pyarrow, where "lz4" refers to the LZ4_RAW codec.
+        Some("lz4") | Some("lz4_raw") => Ok(CompressionOptions::Lz4Raw),
+        Some(other) => Err(DaftError::ValueError(format!(
+            "Unsupported compression for parquet writes: {other}"
+        ))),
+    }
+}
+
+/// [`Write`] sink that forwards encoded bytes to an upload stream in `UPLOAD_CHUNK_SIZE` chunks.
+struct ChannelWriter {
+    buffer: Vec<u8>,
+    sender: tokio::sync::mpsc::Sender<Result<Bytes, daft_io::Error>>,
+}
+
+impl ChannelWriter {
+    fn new(sender: tokio::sync::mpsc::Sender<Result<Bytes, daft_io::Error>>) -> Self {
+        Self {
+            buffer: Vec::with_capacity(UPLOAD_CHUNK_SIZE),
+            sender,
+        }
+    }
+
+    fn send_buffer(&mut self) -> std::io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buffer, Vec::with_capacity(UPLOAD_CHUNK_SIZE));
+        self.sender.blocking_send(Ok(chunk.into())).map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "upload stream closed before parquet file was fully written",
+            )
+        })
+    }
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= UPLOAD_CHUNK_SIZE {
+            self.send_buffer()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn encode_parquet_file<W: Write>(
+    table: &Table,
+    rows_per_row_group: usize,
+    compression: CompressionOptions,
+    writer: W,
+    uri: &str,
+) -> DaftResult<W> {
+    let arrow_schema = table.schema.to_arrow()?;
+    let options = WriteOptions {
+        write_statistics: true,
+        compression,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+    let encodings = arrow_schema
+        .fields
+        .iter()
+        .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+        .collect::<Vec<_>>();
+
+    let num_rows = table.len();
+    let chunks = (0..num_rows)
+        .step_by(rows_per_row_group)
+        .map(|start| {
+            let row_group = table.slice(start, (start + rows_per_row_group).min(num_rows))?;
+            let arrays = (0..row_group.num_columns())
+                .map(|i| Ok(row_group.get_column_by_index(i)?.to_arrow()))
+                .collect::<DaftResult<Vec<Box<dyn Array>>>>()?;
+            Ok(Chunk::new(arrays))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+
+    let row_groups = RowGroupIterator::try_new(
+        chunks.into_iter().map(Ok),
+        &arrow_schema,
+        options,
+        encodings,
+    )
+    .context(UnableToWriteParquetFileSnafu { path: uri })?;
+    let mut file_writer = FileWriter::try_new(writer, arrow_schema, options)
+        .context(UnableToWriteParquetFileSnafu { path: uri })?;
+    for row_group in row_groups {
+        let row_group = row_group.context(UnableToWriteParquetFileSnafu { path: uri })?;
+        file_writer
+            .write(row_group)
+            .context(UnableToWriteParquetFileSnafu { path: uri })?;
+    }
+    file_writer
+        .end(None)
+        .context(UnableToWriteParquetFileSnafu { path: uri })?;
+    Ok(file_writer.into_inner())
+}
+
+async fn write_parquet_single(
+    table: Table,
+    uri: String,
+    rows_per_row_group: usize,
+    compression: CompressionOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<()> {
+    let (sender, receiver) = tokio::sync::mpsc::channel(2);
+    let encode_uri = uri.clone();
+    let encode_task = tokio::task::spawn_blocking(move || {
+        let error_sender = sender.clone();
+        let result = encode_parquet_file(
+            &table,
+            rows_per_row_group,
+            compression,
+            ChannelWriter::new(sender),
+            &encode_uri,
+        )
+        .and_then(|mut writer| {
+            Ok(writer
+                .send_buffer()
+                .context(InternalIOSnafu { path: &encode_uri })?)
+        });
+        if let Err(err) = &result {
+            // Fail the upload rather than letting it commit a truncated file.
+            let _ = error_sender.blocking_send(Err(daft_io::Error::UnableToWriteToFile {
+                path: encode_uri.clone(),
+                source: err.to_string().into(),
+            }));
+        }
+        result
+    });
+
+    let upload_result = io_client
+        .single_url_put_multipart(
+            uri.clone(),
+            tokio_stream::wrappers::ReceiverStream::new(receiver).boxed(),
+            io_stats,
+        )
+        .await;
+    let encode_result = encode_task.await.context(JoinSnafu { path: uri })?;
+    // An encoding failure is forwarded into the upload stream, so the upload error is reported first.
+    upload_result?;
+    encode_result
+}
+
+/// Writes `tables` as one or more Parquet files under `root_dir`, sized according to `options`.
+/// If there are no rows to write, a single file holding only `schema` is written.
+///
+/// Returns a table with the `path` and `num_rows` of every file written.
+pub async fn write_parquet_async(
+    tables: &[Table],
+    schema: &SchemaRef,
+    root_dir: &str,
+    options: &ParquetWriteOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Table> {
+    let compression = compression_from_str(options.compression.as_deref())?;
+    let table = match tables {
+        [] => Table::empty(Some(schema.clone()))?,
+        [table] => table.clone(),
+        _ => Table::concat(tables)?,
+    };
+    let num_rows = table.len();
+    let (rows_per_file, rows_per_row_group) =
+        options.rows_per_file_and_row_group(num_rows, table.size_bytes()?);
+
+    let file_id = uuid::Uuid::new_v4();
+    let root_dir = root_dir.trim_end_matches('/');
+    let mut paths = vec![];
+    let mut row_counts = vec![];
+    let mut writes = vec![];
+    let file_starts = if num_rows == 0 {
+        vec![0]
+    } else {
+        (0..num_rows).step_by(rows_per_file).collect()
+    };
+    for (i, start) in file_starts.into_iter().enumerate() {
+        let file = table.slice(start, (start + rows_per_file).min(num_rows))?;
+        let uri = format!("{root_dir}/{file_id}-{i}.parquet");
+        paths.push(uri.clone());
+        row_counts.push(file.len() as u64);
+        writes.push(write_parquet_single(
+            file,
+            uri,
+            rows_per_row_group,
+            compression,
+            io_client.clone(),
+            io_stats.clone(),
+        ));
+    }
+    futures::future::try_join_all(writes).await?;
+
+    let path_refs = paths.iter().map(|p| p.as_str()).collect::<Vec<_>>();
+    Table::from_columns(vec![
+        Utf8Array::from(("path", path_refs.as_slice())).into_series(),
+        UInt64Array::from(("num_rows", row_counts)).into_series(),
+    ])
+}
+
+pub fn write_parquet(
+    tables: &[Table],
+    schema: &SchemaRef,
+    root_dir: &str,
+    options: &ParquetWriteOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    runtime_handle: Arc<Runtime>,
+) -> DaftResult<Table> {
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        write_parquet_async(tables, schema, root_dir, options, io_client, io_stats).await
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::datatypes::{Field, Int64Array, Utf8Array};
+    use daft_core::schema::Schema;
+    use daft_core::{DataType, IntoSeries};
+    use daft_table::Table;
+
+    use super::{write_parquet, ParquetWriteOptions};
+    use crate::read::{read_parquet, ParquetSchemaInferenceOptions};
+
+    #[test]
+    fn test_parquet_write_round_trip() -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let root_dir = dir.path().to_string_lossy().replace('\\', "/");
+        let table = Table::from_columns(vec![
+            Int64Array::from(("a", (0..1000).collect::<Vec<i64>>())).into_series(),
+            Utf8Array::from((
+                "b",
+                (0..1000)
+                    .map(|i| if i % 2 == 0 { "even" } else { "odd" })
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            ))
+            .into_series(),
+        ])?;
+        let options = ParquetWriteOptions {
+            target_file_size: 4 * 1024,
+            target_row_group_size: 1024,
+            inflation_factor: 1.0,
+            compression: Some("zstd".to_string()),
+        };
+
+        let io_client = Arc::new(daft_io::IOClient::new(Default::default())?);
+        let runtime_handle = daft_io::get_runtime(true)?;
+        let written = write_parquet(
+            &[table.clone()],
+            &table.schema,
+            &root_dir,
+            &options,
+            io_client.clone(),
+            None,
+            runtime_handle.clone(),
+        )?;
+        assert!(written.len() > 1);
+
+        let paths = written.get_column("path")?.utf8()?.clone();
+        let mut tables = vec![];
+        for path in paths.as_arrow().iter() {
+            tables.push(read_parquet(
+                path.unwrap(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                io_client.clone(),
+                None,
+                runtime_handle.clone(),
+                ParquetSchemaInferenceOptions::default(),
+            )?);
+        }
+        let num_rows = written.get_column("num_rows")?.u64()?;
+        for (table, expected) in tables.iter().zip(num_rows.as_arrow().values_iter()) {
+            assert_eq!(table.len() as u64, *expected);
+        }
+        let read_back = Table::concat(tables.as_slice())?;
+        for name in ["a", "b"] {
+            assert_eq!(
+                read_back.get_column(name)?.to_arrow(),
+                table.get_column(name)?.to_arrow()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_write_empty() -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let root_dir = dir.path().to_string_lossy().replace('\\', "/");
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])?);
+
+        let io_client = Arc::new(daft_io::IOClient::new(Default::default())?);
+        let runtime_handle = daft_io::get_runtime(true)?;
+        let written = write_parquet(
+            &[],
+            &schema,
+            &root_dir,
+            &ParquetWriteOptions {
+                target_file_size: 4 * 1024,
+                target_row_group_size: 1024,
+                inflation_factor: 1.0,
+                compression: None,
+            },
+            io_client.clone(),
+            None,
+            runtime_handle.clone(),
+        )?;
+        assert_eq!(written.len(), 1);
+        assert_eq!(written.get_column("num_rows")?.u64()?.get(0), Some(0));
+
+        let path = written
+            .get_column("path")?
+            .utf8()?
+            .get(0)
+            .unwrap()
+            .to_string();
+        let read_back = read_parquet(
+            &path,
+            None,
+            None,
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            runtime_handle,
+            ParquetSchemaInferenceOptions::default(),
+        )?;
+        assert_eq!(read_back.len(), 0);
+        assert_eq!(read_back.schema, schema);
+        Ok(())
+    }
+}
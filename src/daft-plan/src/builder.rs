@@ -16,7 +16,7 @@ use common_error::{DaftError, DaftResult};
 use common_io_config::IOConfig;
 use daft_core::schema::Schema;
 use daft_core::schema::SchemaRef;
-use daft_dsl::Expr;
+use daft_dsl::{ApproxPercentileParams, Expr, WindowFrame};
 use daft_scan::{file_format::FileFormat, Pushdowns, ScanExternalInfo, ScanOperatorRef};
 
 #[cfg(feature = "python")]
@@ -62,6 +62,20 @@ fn check_for_agg(expr: &Expr) -> bool {
             if_false,
             predicate,
         } => check_for_agg(if_true) || check_for_agg(if_false) || check_for_agg(predicate),
+        Window(window_expr) => window_expr.children().iter().any(|e| check_for_agg(e)),
+    }
+}
+
+fn check_for_window(expr: &Expr) -> bool {
+    matches!(expr, Expr::Window(..)) || expr.children().iter().any(|e| check_for_window(e))
+}
+
+fn check_no_window_exprs(exprs: &[Expr], op_name: &str) -> DaftResult<()> {
+    match exprs.iter().find(|e| check_for_window(e)) {
+        Some(expr) => Err(DaftError::ValueError(format!(
+            "Window functions are only supported in df.window(), but got one in {op_name}: {expr}"
+        ))),
+        None => Ok(()),
     }
 }
 
@@ -87,6 +101,31 @@ fn extract_agg_expr(expr: &Expr) -> DaftResult<daft_dsl::AggExpr> {
                 AnyValue(e, ignore_nulls) => AnyValue(Alias(e, name.clone()).into(), ignore_nulls),
                 List(e) => List(Alias(e, name.clone()).into()),
                 Concat(e) => Concat(Alias(e, name.clone()).into()),
+                CountDistinct(e) => CountDistinct(Alias(e, name.clone()).into()),
+                SumDistinct(e) => SumDistinct(Alias(e, name.clone()).into()),
+                ListDistinct(e) => ListDistinct(Alias(e, name.clone()).into()),
+                ApproxSketch(e) => ApproxSketch(Alias(e, name.clone()).into()),
+                MergeSketch(e) => MergeSketch(Alias(e, name.clone()).into()),
+                ApproxPercentile(ApproxPercentileParams {
+                    child: e,
+                    percentiles,
+                    force_list_output,
+                }) => ApproxPercentile(ApproxPercentileParams {
+                    child: Alias(e, name.clone()).into(),
+                    percentiles,
+                    force_list_output,
+                }),
+                HllSketch(e) => HllSketch(Alias(e, name.clone()).into()),
+                HllMerge(e) => HllMerge(Alias(e, name.clone()).into()),
+                ApproxCountDistinct(e) => ApproxCountDistinct(Alias(e, name.clone()).into()),
+                Variance(e, ddof) => Variance(Alias(e, name.clone()).into(), ddof),
+                Stddev(e, ddof) => Stddev(Alias(e, name.clone()).into(), ddof),
+                Skew(e) => Skew(Alias(e, name.clone()).into()),
+                Covar(x, y, ddof) => Covar(Alias(x, name.clone()).into(), y, ddof),
+                Corr(x, y) => Corr(Alias(x, name.clone()).into(), y),
+                Moments(e) => Moments(Alias(e, name.clone()).into()),
+                CoMoments(x, y) => CoMoments(Alias(x, name.clone()).into(), y),
+                MergeMoments(e) => MergeMoments(Alias(e, name.clone()).into()),
                 MapGroups { func, inputs } => MapGroups {
                     func,
                     inputs: inputs
@@ -109,9 +148,29 @@ fn extract_and_check_agg_expr(expr: &Expr) -> DaftResult<daft_dsl::AggExpr> {
 
     let agg_expr = extract_agg_expr(expr)?;
     let has_nested_agg = match &agg_expr {
-        Count(e, _) | Sum(e) | Mean(e) | Min(e) | Max(e) | AnyValue(e, _) | List(e) | Concat(e) => {
-            check_for_agg(e)
-        }
+        Count(e, _)
+        | Sum(e)
+        | Mean(e)
+        | Min(e)
+        | Max(e)
+        | AnyValue(e, _)
+        | List(e)
+        | Concat(e)
+        | CountDistinct(e)
+        | SumDistinct(e)
+        | ListDistinct(e)
+        | ApproxSketch(e)
+        | MergeSketch(e)
+        | ApproxPercentile(ApproxPercentileParams { child: e, .. })
+        | HllSketch(e)
+        | HllMerge(e)
+        | ApproxCountDistinct(e)
+        | Variance(e, _)
+        | Stddev(e, _)
+        | Skew(e)
+        | Moments(e)
+        | MergeMoments(e) => check_for_agg(e),
+        Covar(x, y, _) | Corr(x, y) | CoMoments(x, y) => check_for_agg(x) || check_for_agg(y),
         MapGroups { inputs, .. } => inputs.iter().any(check_for_agg),
     };
 
@@ -187,6 +246,7 @@ impl LogicalPlanBuilder {
                 )));
             }
         }
+        check_no_window_exprs(&projection, "projection")?;
 
         let logical_plan: LogicalPlan =
             logical_ops::Project::try_new(self.plan.clone(), projection, resource_request)?.into();
@@ -199,6 +259,7 @@ impl LogicalPlanBuilder {
                 "Aggregation expressions are not currently supported in filter: {predicate}\nIf you would like to have this feature, please see https://github.com/Eventual-Inc/Daft/issues/1979#issue-2170913383"
             )));
         }
+        check_no_window_exprs(std::slice::from_ref(&predicate), "filter")?;
 
         let logical_plan: LogicalPlan =
             logical_ops::Filter::try_new(self.plan.clone(), predicate)?.into();
@@ -219,6 +280,7 @@ impl LogicalPlanBuilder {
                 )));
             }
         }
+        check_no_window_exprs(&to_explode, "explode")?;
 
         let logical_plan: LogicalPlan =
             logical_ops::Explode::try_new(self.plan.clone(), to_explode)?.into();
@@ -233,6 +295,7 @@ impl LogicalPlanBuilder {
                 )));
             }
         }
+        check_no_window_exprs(&sort_by, "sort")?;
 
         let logical_plan: LogicalPlan =
             logical_ops::Sort::try_new(self.plan.clone(), sort_by, descending)?.into();
@@ -251,6 +314,7 @@ impl LogicalPlanBuilder {
                 )));
             }
         }
+        check_no_window_exprs(&partition_by, "hash repartition")?;
 
         let logical_plan: LogicalPlan = logical_ops::Repartition::try_new(
             self.plan.clone(),
@@ -295,6 +359,8 @@ impl LogicalPlanBuilder {
     }
 
     pub fn aggregate(&self, agg_exprs: Vec<Expr>, groupby_exprs: Vec<Expr>) -> DaftResult<Self> {
+        check_no_window_exprs(&agg_exprs, "aggregation")?;
+        check_no_window_exprs(&groupby_exprs, "groupby")?;
         let agg_exprs = agg_exprs
             .iter()
             .map(extract_and_check_agg_expr)
@@ -321,6 +387,7 @@ impl LogicalPlanBuilder {
                     )));
                 }
             }
+            check_no_window_exprs(side, "join")?;
         }
 
         let logical_plan: LogicalPlan = logical_ops::Join::try_new(
@@ -335,6 +402,36 @@ impl LogicalPlanBuilder {
         Ok(logical_plan.into())
     }
 
+    pub fn window(
+        &self,
+        window_exprs: Vec<Expr>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<Expr>,
+        descending: Vec<bool>,
+        frame: Option<WindowFrame>,
+    ) -> DaftResult<Self> {
+        for expr in partition_by.iter().chain(order_by.iter()) {
+            if check_for_agg(expr) {
+                return Err(DaftError::ValueError(format!(
+                    "Aggregation expressions are not currently supported in window partition_by or order_by: {expr}"
+                )));
+            }
+        }
+        check_no_window_exprs(&partition_by, "window partition_by")?;
+        check_no_window_exprs(&order_by, "window order_by")?;
+
+        let logical_plan: LogicalPlan = logical_ops::Window::try_new(
+            self.plan.clone(),
+            window_exprs,
+            partition_by,
+            order_by,
+            descending,
+            frame,
+        )?
+        .into();
+        Ok(logical_plan.into())
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         let logical_plan: LogicalPlan =
             logical_ops::Concat::try_new(self.plan.clone(), other.plan.clone())?.into();
@@ -579,6 +676,35 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    pub fn window(
+        &self,
+        window_exprs: Vec<PyExpr>,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+        frame: Option<(Option<i64>, Option<i64>)>,
+    ) -> PyResult<Self> {
+        let window_exprs = window_exprs
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<Expr>>();
+        let partition_by = partition_by
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<Expr>>();
+        let order_by = order_by
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<Expr>>();
+        let frame = frame
+            .map(|(start, end)| WindowFrame::new(start, end))
+            .transpose()?;
+        Ok(self
+            .builder
+            .window(window_exprs, partition_by, order_by, descending, frame)?
+            .into())
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         Ok(self.builder.concat(&other.builder)?.into())
     }
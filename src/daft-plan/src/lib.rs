@@ -4,7 +4,6 @@
 
 mod builder;
 mod display;
-mod join;
 mod logical_ops;
 mod logical_optimization;
 mod logical_plan;
@@ -20,8 +19,8 @@ mod test;
 mod treenode;
 
 pub use builder::{LogicalPlanBuilder, PyLogicalPlanBuilder};
+pub use daft_core::join::{JoinStrategy, JoinType};
 use daft_scan::file_format::FileFormat;
-pub use join::{JoinStrategy, JoinType};
 pub use logical_plan::LogicalPlan;
 pub use partitioning::ClusteringSpec;
 pub use physical_plan::PhysicalPlanScheduler;
@@ -33,7 +32,8 @@ use pyo3::prelude::*;
 #[cfg(feature = "python")]
 use {
     daft_scan::file_format::{
-        CsvSourceConfig, JsonSourceConfig, ParquetSourceConfig, PyFileFormatConfig,
+        ArrowIpcSourceConfig, AvroSourceConfig, CsvSourceConfig, JsonSourceConfig, OrcSourceConfig,
+        ParquetSourceConfig, PyFileFormatConfig,
     },
     daft_scan::storage_config::{NativeStorageConfig, PyStorageConfig, PythonStorageConfig},
 };
@@ -48,9 +48,10 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_class::<ParquetSourceConfig>()?;
     parent.add_class::<JsonSourceConfig>()?;
     parent.add_class::<CsvSourceConfig>()?;
+    parent.add_class::<ArrowIpcSourceConfig>()?;
+    parent.add_class::<AvroSourceConfig>()?;
+    parent.add_class::<OrcSourceConfig>()?;
     parent.add_class::<DatabaseSourceConfig>()?;
-    parent.add_class::<JoinType>()?;
-    parent.add_class::<JoinStrategy>()?;
     parent.add_class::<PhysicalPlanScheduler>()?;
     parent.add_class::<ResourceRequest>()?;
     parent.add_class::<FileInfos>()?;
@@ -53,6 +53,26 @@ impl Join {
         join_type: JoinType,
         join_strategy: Option<JoinStrategy>,
     ) -> logical_plan::Result<Self> {
+        if left_on.len() != right_on.len() {
+            return Err(DaftError::ValueError(format!(
+                "Length of left_on does not match length of right_on for Join {} vs {}",
+                left_on.len(),
+                right_on.len()
+            )))
+            .context(CreationSnafu);
+        }
+        if join_type == JoinType::Cross && !left_on.is_empty() {
+            return Err(DaftError::ValueError(
+                "Cross joins must not have join keys".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
+        if join_type != JoinType::Cross && left_on.is_empty() {
+            return Err(DaftError::ValueError(
+                "No columns were passed in to join on".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
         for (on_exprs, schema) in [(&left_on, left.schema()), (&right_on, right.schema())] {
             let on_fields = on_exprs
                 .iter()
@@ -72,7 +92,10 @@ impl Join {
         let mut right_input_mapping = indexmap::IndexMap::new();
         // Schema inference ported from existing behaviour for parity,
         // but contains bug https://github.com/Eventual-Inc/Daft/issues/1294
-        let output_schema = {
+        let output_schema = if join_type.is_left_only() {
+            // Semi and anti joins only filter the left side.
+            left.schema()
+        } else {
             let left_join_keys = left_on
                 .iter()
                 .map(|e| e.name())
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use daft_core::datatypes::FieldID;
 use daft_core::schema::{Schema, SchemaRef};
-use daft_dsl::{optimization, AggExpr, Expr, ExprRef};
+use daft_dsl::{optimization, AggExpr, ApproxPercentileParams, Expr, ExprRef};
 use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use snafu::ResultExt;
@@ -207,6 +207,8 @@ fn replace_column_with_semantic_id(
     } else {
         match e.as_ref() {
             Expr::Column(_) | Expr::Literal(_) => Transformed::No(e),
+            // Window functions are only evaluated by the Window op, never in projections.
+            Expr::Window(_) => Transformed::No(e),
             Expr::Agg(agg_expr) => replace_column_with_semantic_id_aggexpr(
                 agg_expr.clone(),
                 subexprs_to_replace,
@@ -353,6 +355,22 @@ fn replace_column_with_semantic_id(
     }
 }
 
+/// Replaces subexpressions in both inputs of a two-input aggregation, which is transformed if either input is.
+fn replace_pair_with_semantic_id(
+    x: &ExprRef,
+    y: &ExprRef,
+    subexprs_to_replace: &IndexSet<FieldID>,
+    schema: &Schema,
+) -> Transformed<(ExprRef, ExprRef)> {
+    let x = replace_column_with_semantic_id(x.clone(), subexprs_to_replace, schema);
+    let y = replace_column_with_semantic_id(y.clone(), subexprs_to_replace, schema);
+    if x.is_no() && y.is_no() {
+        Transformed::No((x.unwrap().clone(), y.unwrap().clone()))
+    } else {
+        Transformed::Yes((x.unwrap().clone(), y.unwrap().clone()))
+    }
+}
+
 fn replace_column_with_semantic_id_aggexpr(
     e: AggExpr,
     subexprs_to_replace: &IndexSet<FieldID>,
@@ -400,6 +418,89 @@ fn replace_column_with_semantic_id_aggexpr(
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::Concat, |_| e.clone())
         }
+        AggExpr::CountDistinct(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::CountDistinct, |_| e.clone())
+        }
+        AggExpr::SumDistinct(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::SumDistinct, |_| e.clone())
+        }
+        AggExpr::ListDistinct(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::ListDistinct, |_| e.clone())
+        }
+        AggExpr::ApproxSketch(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::ApproxSketch, |_| e.clone())
+        }
+        AggExpr::MergeSketch(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::MergeSketch, |_| e.clone())
+        }
+        AggExpr::ApproxPercentile(ApproxPercentileParams {
+            ref child,
+            ref percentiles,
+            force_list_output,
+        }) => replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+            .map_yes_no(
+                |transformed_child| {
+                    AggExpr::ApproxPercentile(ApproxPercentileParams {
+                        child: transformed_child,
+                        percentiles: percentiles.clone(),
+                        force_list_output,
+                    })
+                },
+                |_| e.clone(),
+            ),
+        AggExpr::HllSketch(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::HllSketch, |_| e.clone())
+        }
+        AggExpr::HllMerge(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::HllMerge, |_| e.clone())
+        }
+        AggExpr::ApproxCountDistinct(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::ApproxCountDistinct, |_| e.clone())
+        }
+        AggExpr::Variance(ref child, ddof) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::Variance(transformed_child, ddof),
+                |_| e.clone(),
+            )
+        }
+        AggExpr::Stddev(ref child, ddof) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::Stddev(transformed_child, ddof),
+                |_| e.clone(),
+            )
+        }
+        AggExpr::Skew(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::Skew, |_| e.clone())
+        }
+        AggExpr::Moments(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::Moments, |_| e.clone())
+        }
+        AggExpr::MergeMoments(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::MergeMoments, |_| e.clone())
+        }
+        AggExpr::Covar(ref x, ref y, ddof) => {
+            replace_pair_with_semantic_id(x, y, subexprs_to_replace, schema)
+                .map_yes_no(|(x, y)| AggExpr::Covar(x, y, ddof), |_| e.clone())
+        }
+        AggExpr::Corr(ref x, ref y) => {
+            replace_pair_with_semantic_id(x, y, subexprs_to_replace, schema)
+                .map_yes_no(|(x, y)| AggExpr::Corr(x, y), |_| e.clone())
+        }
+        AggExpr::CoMoments(ref x, ref y) => {
+            replace_pair_with_semantic_id(x, y, subexprs_to_replace, schema)
+                .map_yes_no(|(x, y)| AggExpr::CoMoments(x, y), |_| e.clone())
+        }
         AggExpr::MapGroups { func, inputs } => {
             let transforms = inputs
                 .iter()
@@ -35,6 +35,7 @@ impl Source {
                 scan_op,
                 partitioning_keys,
                 pushdowns,
+                ..
             }) => {
                 use itertools::Itertools;
                 res.extend(scan_op.0.multiline_display());
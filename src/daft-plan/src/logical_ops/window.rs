@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use daft_core::schema::{Schema, SchemaRef};
+use daft_dsl::{AggExpr, Expr, WindowFrame};
+
+use crate::logical_plan::{self, CreationSnafu};
+use crate::LogicalPlan;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+
+    /// Window functions and aggregations to compute for every row.
+    pub window_exprs: Vec<Expr>,
+
+    /// Rows with equal values for these expressions are windowed together.
+    pub partition_by: Vec<Expr>,
+
+    /// Ordering of the rows within each partition.
+    pub order_by: Vec<Expr>,
+    pub descending: Vec<bool>,
+
+    /// Rows around the current row that aggregations are computed over.
+    pub frame: WindowFrame,
+
+    pub output_schema: SchemaRef,
+}
+
+impl Window {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        window_exprs: Vec<Expr>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<Expr>,
+        descending: Vec<bool>,
+        frame: Option<WindowFrame>,
+    ) -> logical_plan::Result<Self> {
+        if window_exprs.is_empty() {
+            return Err(DaftError::ValueError(
+                "df.window() must be given at least one window expression".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
+        if order_by.len() != descending.len() {
+            return Err(DaftError::ValueError(format!(
+                "Length of order_by does not match length of descending for Window {} vs {}",
+                order_by.len(),
+                descending.len()
+            )))
+            .context(CreationSnafu);
+        }
+        for expr in &window_exprs {
+            let mut window_fn = expr;
+            while let Expr::Alias(child, _) = window_fn {
+                window_fn = child;
+            }
+            if !matches!(
+                window_fn,
+                Expr::Window(..)
+                    | Expr::Agg(
+                        AggExpr::Count(..)
+                            | AggExpr::Sum(..)
+                            | AggExpr::Mean(..)
+                            | AggExpr::Min(..)
+                            | AggExpr::Max(..)
+                    )
+            ) {
+                return Err(DaftError::ValueError(format!(
+                    "Expected a window function or a count, sum, mean, min or max aggregation in window, but got: {expr}"
+                )))
+                .context(CreationSnafu);
+            }
+            if window_fn
+                .children()
+                .iter()
+                .any(|e| contains_agg_or_window(e))
+            {
+                return Err(DaftError::ValueError(format!(
+                    "Nested aggregations and window functions are not supported in window: {expr}"
+                )))
+                .context(CreationSnafu);
+            }
+        }
+
+        let output_schema = {
+            let upstream_schema = input.schema();
+            let window_fields = window_exprs
+                .iter()
+                .map(|e| e.to_field(&upstream_schema))
+                .collect::<common_error::DaftResult<Vec<_>>>()
+                .context(CreationSnafu)?;
+            let fields = upstream_schema
+                .fields
+                .values()
+                .cloned()
+                .chain(window_fields)
+                .collect();
+            Schema::new(fields).context(CreationSnafu)?.into()
+        };
+        let frame = frame.unwrap_or_else(|| WindowFrame::default_for(!order_by.is_empty()));
+
+        Ok(Self {
+            input,
+            window_exprs,
+            partition_by,
+            order_by,
+            descending,
+            frame,
+            output_schema,
+        })
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Window: {}",
+            self.window_exprs.iter().map(|e| e.to_string()).join(", ")
+        ));
+        if !self.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.partition_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        if !self.order_by.is_empty() {
+            res.push(format!(
+                "Order by = {}",
+                self.order_by
+                    .iter()
+                    .zip(self.descending.iter())
+                    .map(|(e, d)| format!(
+                        "({}, {})",
+                        e,
+                        if *d { "descending" } else { "ascending" }
+                    ))
+                    .join(", ")
+            ));
+        }
+        res.push(format!("Frame = {}", self.frame));
+        res.push(format!(
+            "Output schema = {}",
+            self.output_schema.short_string()
+        ));
+        res
+    }
+}
+
+/// Whether `expr` contains an aggregation or window function.
+fn contains_agg_or_window(expr: &Expr) -> bool {
+    matches!(expr, Expr::Agg(..) | Expr::Window(..))
+        || expr.children().iter().any(|e| contains_agg_or_window(e))
+}
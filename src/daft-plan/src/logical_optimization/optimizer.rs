@@ -7,8 +7,9 @@ use crate::LogicalPlan;
 use super::{
     logical_plan_tracker::LogicalPlanTracker,
     rules::{
-        ApplyOrder, DropRepartition, OptimizerRule, PushDownFilter, PushDownLimit,
-        PushDownProjection, Transformed,
+        ApplyOrder, DropRepartition, EliminateCommonSubexpressions, OptimizerRule,
+        PushDownAggregation, PushDownFilter, PushDownLimit, PushDownProjection, ReorderJoins,
+        SimplifyExpressions, Transformed,
     },
 };
 
@@ -129,19 +130,40 @@ pub struct Optimizer {
 impl Optimizer {
     pub fn new(config: OptimizerConfig) -> Self {
         // Default rule batches.
-        let rule_batches: Vec<RuleBatch> = vec![RuleBatch::new(
-            vec![
-                Box::new(DropRepartition::new()),
-                Box::new(PushDownFilter::new()),
-                Box::new(PushDownProjection::new()),
-                Box::new(PushDownLimit::new()),
-            ],
-            // Use a fixed-point policy for the pushdown rules: PushDownProjection can produce a Filter node
-            // at the current node, which would require another batch application in order to have a chance to push
-            // that Filter node through upstream nodes.
-            // TODO(Clark): Refine this fixed-point policy.
-            RuleExecutionStrategy::FixedPoint(Some(3)),
-        )];
+        let rule_batches: Vec<RuleBatch> = vec![
+            RuleBatch::new(
+                vec![
+                    Box::new(SimplifyExpressions::new()),
+                    Box::new(DropRepartition::new()),
+                    Box::new(PushDownFilter::new()),
+                    Box::new(PushDownProjection::new()),
+                    Box::new(PushDownLimit::new()),
+                ],
+                // Use a fixed-point policy for the pushdown rules: PushDownProjection can produce a Filter node
+                // at the current node, which would require another batch application in order to have a chance to push
+                // that Filter node through upstream nodes.
+                // TODO(Clark): Refine this fixed-point policy.
+                RuleExecutionStrategy::FixedPoint(Some(3)),
+            ),
+            // Push aggregations into the scans once the other pushdowns have settled: the pushed-down aggregation is
+            // only valid as long as no filters or limits are pushed into the same scan afterwards.
+            RuleBatch::new(
+                vec![Box::new(PushDownAggregation::new())],
+                RuleExecutionStrategy::Once,
+            ),
+            // Eliminate common subexpressions after the pushdown rules, since merging projections while pushing them
+            // down would inline the materialized subexpressions again.
+            RuleBatch::new(
+                vec![Box::new(EliminateCommonSubexpressions::new())],
+                RuleExecutionStrategy::Once,
+            ),
+            // Reorder joins once filters, projections and limits have been pushed into the scans, so that the
+            // cardinality estimates of the joined relations reflect them.
+            RuleBatch::new(
+                vec![Box::new(ReorderJoins::new())],
+                RuleExecutionStrategy::Once,
+            ),
+        ];
         Self::with_rule_batches(rule_batches, config)
     }
 
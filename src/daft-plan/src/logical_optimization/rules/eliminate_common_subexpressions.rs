@@ -0,0 +1,444 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_error::DaftResult;
+use common_treenode::TreeNode;
+use daft_core::schema::Schema;
+use daft_dsl::{
+    col,
+    optimization::{get_required_columns, replace_columns_with_expressions, requires_computation},
+    AggExpr, Expr,
+};
+use indexmap::IndexMap;
+
+use crate::{
+    logical_ops::{Aggregate, Filter, Project},
+    LogicalPlan, ResourceRequest,
+};
+
+use super::{ApplyOrder, OptimizerRule, Transformed};
+
+/// Optimization rule for eliminating common subexpressions across plan nodes.
+///
+/// Repeated subexpressions within a single Project are already factored out when the Project is constructed;
+/// this rule handles the remaining cases:
+/// 1. Subexpressions that are repeated across the aggregations and group-by expressions of an Aggregate are
+///    hoisted into a new Project below it, so that they are evaluated once and then referenced by column.
+/// 2. Expressions in a Project, Filter or Aggregate that recompute a column already materialized by the Project
+///    directly below it are rewritten to reference that column.
+///
+/// Subexpressions are matched by their semantic ID, and the output names of all rewritten expressions are preserved.
+#[derive(Default, Debug)]
+pub struct EliminateCommonSubexpressions {}
+
+impl EliminateCommonSubexpressions {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Rewrite the expressions of a Project, Filter or Aggregate that recompute a column materialized by the
+    /// upstream Project to reference that column instead.
+    fn try_reuse_upstream_columns(
+        &self,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let Some(LogicalPlan::Project(upstream)) = plan.children().first().map(|c| c.as_ref())
+        else {
+            return Ok(Transformed::No(plan));
+        };
+        let materialized = MaterializedColumns::new(upstream);
+        if materialized.computed.is_empty() {
+            return Ok(Transformed::No(plan));
+        }
+        let new_plan: LogicalPlan = match plan.as_ref() {
+            LogicalPlan::Project(Project {
+                input,
+                projection,
+                resource_request,
+                ..
+            }) => {
+                let new_projection = projection
+                    .iter()
+                    .map(|e| rewrite_named_expr(e, |e| materialized.rewrite(e)))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                if new_projection == *projection {
+                    return Ok(Transformed::No(plan));
+                }
+                Project::try_new(input.clone(), new_projection, resource_request.clone())?.into()
+            }
+            LogicalPlan::Filter(Filter { input, predicate }) => {
+                let new_predicate = materialized.rewrite(predicate.clone())?;
+                if new_predicate == *predicate {
+                    return Ok(Transformed::No(plan));
+                }
+                Filter::try_new(input.clone(), new_predicate)?.into()
+            }
+            LogicalPlan::Aggregate(Aggregate {
+                input,
+                aggregations,
+                groupby,
+                ..
+            }) => {
+                let new_aggregations = aggregations
+                    .iter()
+                    .map(|agg| rewrite_agg_children(agg, |e| materialized.rewrite(e)))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                let new_groupby = groupby
+                    .iter()
+                    .map(|e| rewrite_named_expr(e, |e| materialized.rewrite(e)))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                if new_aggregations == *aggregations && new_groupby == *groupby {
+                    return Ok(Transformed::No(plan));
+                }
+                Aggregate::try_new(input.clone(), new_aggregations, new_groupby)?.into()
+            }
+            _ => return Ok(Transformed::No(plan)),
+        };
+        Ok(Transformed::Yes(new_plan.into()))
+    }
+
+    /// Hoist subexpressions that are repeated across the aggregations and group-by expressions of an Aggregate
+    /// into a new Project below it.
+    fn try_hoist_aggregate_subexpressions(
+        &self,
+        aggregate: &Aggregate,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let schema = aggregate.input.schema();
+        let roots = aggregate
+            .groupby
+            .iter()
+            .cloned()
+            .chain(
+                aggregate
+                    .aggregations
+                    .iter()
+                    .flat_map(|agg| Expr::Agg(agg.clone()).children())
+                    .map(|e| e.as_ref().clone()),
+            )
+            .collect::<Vec<_>>();
+        if !has_repeated_subexpression(&roots, &schema) {
+            return Ok(Transformed::No(plan));
+        }
+
+        // Computed roots are materialized under their semantic ID, and any columns that the remaining roots
+        // reference are passed through.
+        let mut projection = IndexMap::new();
+        let mut hoist = |expr: Expr| -> DaftResult<Expr> {
+            if !requires_computation(&expr) {
+                for name in get_required_columns(&expr) {
+                    projection
+                        .entry(name.clone())
+                        .or_insert_with(|| col(name.as_str()));
+                }
+                return Ok(expr);
+            }
+            let name = expr.name()?.to_string();
+            let id = expr.semantic_id(&schema).id;
+            let unaliased = match expr {
+                Expr::Alias(child, _) => child.as_ref().clone(),
+                expr => expr,
+            };
+            projection
+                .entry(id.to_string())
+                .or_insert_with(|| unaliased.alias(id.clone()));
+            Ok(if *id == name {
+                col(id)
+            } else {
+                col(id).alias(name)
+            })
+        };
+        let new_groupby = aggregate
+            .groupby
+            .iter()
+            .map(|e| hoist(e.clone()))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let new_aggregations = aggregate
+            .aggregations
+            .iter()
+            .map(|agg| rewrite_agg_children(agg, &mut hoist))
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        // The hoisted expressions are evaluated over the output of the upstream Project, so they keep its resource
+        // request (e.g. GPUs for a UDF), which the Project would otherwise lose if they are merged later on.
+        let resource_request = match aggregate.input.as_ref() {
+            LogicalPlan::Project(Project {
+                resource_request, ..
+            }) => resource_request.clone(),
+            _ => ResourceRequest::default(),
+        };
+        // Constructing the Project factors out any subexpressions that are repeated within the hoisted expressions.
+        let new_input: LogicalPlan = Project::try_new(
+            aggregate.input.clone(),
+            projection.into_values().collect(),
+            resource_request,
+        )?
+        .into();
+        let new_aggregate: LogicalPlan =
+            Aggregate::try_new(new_input.into(), new_aggregations, new_groupby)?.into();
+        Ok(Transformed::Yes(new_aggregate.into()))
+    }
+}
+
+impl OptimizerRule for EliminateCommonSubexpressions {
+    fn apply_order(&self) -> ApplyOrder {
+        ApplyOrder::TopDown
+    }
+
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let plan = match self.try_reuse_upstream_columns(plan)? {
+            Transformed::Yes(new_plan) => {
+                return Ok(self
+                    .try_optimize(new_plan.clone())?
+                    .or(Transformed::Yes(new_plan)))
+            }
+            Transformed::No(plan) => plan,
+        };
+        match plan.as_ref() {
+            LogicalPlan::Aggregate(aggregate) => {
+                self.try_hoist_aggregate_subexpressions(aggregate, plan.clone())
+            }
+            _ => Ok(Transformed::No(plan)),
+        }
+    }
+}
+
+/// The columns computed by a Project, keyed by the semantic ID of their expression over the Project's input.
+struct MaterializedColumns {
+    input_schema: Arc<Schema>,
+    // Output column name -> unaliased expression over the Project's input.
+    definitions: HashMap<String, Expr>,
+    // Semantic ID of a computed expression -> output column name.
+    computed: HashMap<Arc<str>, String>,
+}
+
+impl MaterializedColumns {
+    fn new(project: &Project) -> Self {
+        let input_schema = project.input.schema();
+        let mut definitions = HashMap::new();
+        let mut computed = HashMap::new();
+        for expr in project.projection.iter() {
+            let name = expr.name().unwrap().to_string();
+            if requires_computation(expr) {
+                computed
+                    .entry(expr.semantic_id(&input_schema).id)
+                    .or_insert_with(|| name.clone());
+            }
+            let unaliased = match expr {
+                Expr::Alias(child, _) => child.as_ref().clone(),
+                expr => expr.clone(),
+            };
+            definitions.insert(name, unaliased);
+        }
+        Self {
+            input_schema,
+            definitions,
+            computed,
+        }
+    }
+
+    /// Replace the outermost subexpressions of an expression over the Project's output that recompute one of
+    /// its materialized columns with a reference to that column.
+    fn rewrite(&self, expr: Expr) -> DaftResult<Expr> {
+        expr.transform_down(&|e| {
+            if matches!(e, Expr::Alias(..) | Expr::Agg(..)) || !requires_computation(&e) {
+                return Ok(common_treenode::Transformed::No(e));
+            }
+            let id = replace_columns_with_expressions(&e, &self.definitions)
+                .semantic_id(&self.input_schema)
+                .id;
+            Ok(match self.computed.get(&id) {
+                Some(name) => common_treenode::Transformed::Yes(col(name.as_str())),
+                None => common_treenode::Transformed::No(e),
+            })
+        })
+    }
+}
+
+/// Whether any computed subexpression occurs more than once across the provided expressions.
+fn has_repeated_subexpression(exprs: &[Expr], schema: &Schema) -> bool {
+    let mut counts: HashMap<Arc<str>, usize> = HashMap::new();
+    let mut to_visit = exprs.iter().cloned().map(Arc::new).collect::<Vec<_>>();
+    while let Some(expr) = to_visit.pop() {
+        if !matches!(expr.as_ref(), Expr::Alias(..)) && requires_computation(&expr) {
+            let count = counts.entry(expr.semantic_id(schema).id).or_default();
+            *count += 1;
+            if *count > 1 {
+                return true;
+            }
+        }
+        to_visit.extend(expr.children());
+    }
+    false
+}
+
+/// Rewrite an expression whose output name must be preserved, re-aliasing it if the rewrite changed its name.
+fn rewrite_named_expr(
+    expr: &Expr,
+    rewrite: impl FnOnce(Expr) -> DaftResult<Expr>,
+) -> DaftResult<Expr> {
+    let name = expr.name()?;
+    let rewritten = rewrite(expr.clone())?;
+    if rewritten.name()? == name {
+        Ok(rewritten)
+    } else {
+        Ok(rewritten.alias(name))
+    }
+}
+
+/// Rewrite the inputs of an aggregation, preserving their names so that the aggregation's output name is unchanged.
+fn rewrite_agg_children(
+    agg: &AggExpr,
+    mut rewrite: impl FnMut(Expr) -> DaftResult<Expr>,
+) -> DaftResult<AggExpr> {
+    match Expr::Agg(agg.clone()).map_children(|child| rewrite_named_expr(&child, &mut rewrite))? {
+        Expr::Agg(new_agg) => Ok(new_agg),
+        _ => unreachable!("Rewriting the children of an aggregation should produce an aggregation"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{datatypes::Field, DataType};
+    use daft_dsl::{col, lit};
+
+    use crate::{
+        logical_optimization::{
+            rules::EliminateCommonSubexpressions, test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan, ResourceRequest,
+    };
+
+    /// Helper that creates an optimizer with the EliminateCommonSubexpressions rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![Box::new(EliminateCommonSubexpressions::new())],
+        )
+    }
+
+    fn dummy_fields() -> Vec<Field> {
+        vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]
+    }
+
+    /// Tests that a subexpression repeated across aggregations is hoisted into a Project below the Aggregate.
+    ///
+    /// Aggregate(sum(a + b as s), max(a + b as m), groupby=[b])
+    /// -> Aggregate(sum(ab as s), max(ab as m), groupby=[b]) <- Project(b, a + b as ab)
+    #[test]
+    fn hoist_repeated_aggregate_subexpression() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(dummy_fields()));
+        let a_plus_b = col("a") + col("b");
+        let plan = scan
+            .aggregate(
+                vec![a_plus_b.alias("s").sum(), a_plus_b.alias("m").max()],
+                vec![col("b")],
+            )?
+            .build();
+        let id = a_plus_b.semantic_id(&scan.schema()).id;
+        let expected = scan
+            .project(
+                vec![col("b"), a_plus_b.alias(id.clone())],
+                Default::default(),
+            )?
+            .aggregate(
+                vec![
+                    col(id.clone()).alias("s").sum(),
+                    col(id.clone()).alias("m").max(),
+                ],
+                vec![col("b")],
+            )?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that the Project hoisted below an Aggregate keeps the resource request of the upstream Project.
+    ///
+    /// Aggregate(sum(a + b as s), max(a + b as m), groupby=[b]) <- Project(a, b, num_gpus=1)
+    /// -> Aggregate(sum(ab as s), max(ab as m), groupby=[b]) <- Project(b, a + b as ab, num_gpus=1)
+    ///    <- Project(a, b, num_gpus=1)
+    #[test]
+    fn hoisted_project_keeps_resource_request() -> DaftResult<()> {
+        let resource_request = ResourceRequest::new_internal(None, Some(1.0), None);
+        let project = dummy_scan_node(dummy_scan_operator(dummy_fields()))
+            .project(vec![col("a"), col("b")], resource_request.clone())?;
+        let a_plus_b = col("a") + col("b");
+        let plan = project
+            .aggregate(
+                vec![a_plus_b.alias("s").sum(), a_plus_b.alias("m").max()],
+                vec![col("b")],
+            )?
+            .build();
+        let id = a_plus_b.semantic_id(&project.schema()).id;
+        let expected = project
+            .project(vec![col("b"), a_plus_b.alias(id.clone())], resource_request)?
+            .aggregate(
+                vec![
+                    col(id.clone()).alias("s").sum(),
+                    col(id.clone()).alias("m").max(),
+                ],
+                vec![col("b")],
+            )?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that expressions recomputing a column materialized by the upstream Project reference it instead.
+    ///
+    /// Filter(a + b > 3) <- Project(a + b as c, a, b) -> Filter(c > 3) <- Project(a + b as c, a, b)
+    /// Aggregate(sum(a + b as s), groupby=[a]) <- Project(a + b as c, a, b)
+    /// -> Aggregate(sum(c as s), groupby=[a]) <- Project(a + b as c, a, b)
+    #[test]
+    fn reuse_upstream_materialized_column() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(dummy_fields()));
+        let project = scan.project(
+            vec![(col("a") + col("b")).alias("c"), col("a"), col("b")],
+            Default::default(),
+        )?;
+        let plan = project.filter((col("a") + col("b")).gt(&lit(3)))?.build();
+        let expected = project.filter(col("c").gt(&lit(3)))?.build();
+        assert_optimized_plan_eq(plan, expected)?;
+
+        let plan = project
+            .aggregate(vec![(col("a") + col("b")).alias("s").sum()], vec![col("a")])?
+            .build();
+        let expected = project
+            .aggregate(vec![col("c").alias("s").sum()], vec![col("a")])?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that plans without common subexpressions are left unchanged.
+    ///
+    /// Aggregate(sum(a + b as s), max(a - b as m), groupby=[b]) -> (unchanged)
+    #[test]
+    fn no_common_subexpressions() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(dummy_fields()));
+        let plan = scan
+            .aggregate(
+                vec![
+                    (col("a") + col("b")).alias("s").sum(),
+                    (col("a") - col("b")).alias("m").max(),
+                ],
+                vec![col("b")],
+            )?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
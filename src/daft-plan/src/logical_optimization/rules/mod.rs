@@ -1,11 +1,19 @@
 mod drop_repartition;
+mod eliminate_common_subexpressions;
+mod push_down_aggregation;
 mod push_down_filter;
 mod push_down_limit;
 mod push_down_projection;
+mod reorder_joins;
 mod rule;
+mod simplify_expressions;
 
 pub use drop_repartition::DropRepartition;
+pub use eliminate_common_subexpressions::EliminateCommonSubexpressions;
+pub use push_down_aggregation::PushDownAggregation;
 pub use push_down_filter::PushDownFilter;
 pub use push_down_limit::PushDownLimit;
 pub use push_down_projection::PushDownProjection;
+pub use reorder_joins::ReorderJoins;
 pub use rule::{ApplyOrder, OptimizerRule, Transformed};
+pub use simplify_expressions::SimplifyExpressions;
@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_dsl::{col, lit, AggExpr, Expr};
+use daft_scan::AggregationPushdown;
+
+use crate::{
+    logical_ops::{Aggregate, Project, Source},
+    source_info::SourceInfo,
+    LogicalPlan,
+};
+
+use super::{ApplyOrder, OptimizerRule, Transformed};
+
+/// Optimization rule for pushing Aggregations into the scan.
+///
+/// An Aggregate directly over a Source is pushed into the Source if it only counts rows or takes mins and maxes of
+/// columns, grouped by columns, and the scan operator can compute it (e.g. from file metadata, without reading any
+/// data pages). Each scan task then yields its partial aggregation, which is merged by an Aggregate above the Source:
+///
+/// Aggregate(count(a), min(b), groupby=[p]) <- Source
+/// -> Aggregate(sum(a), min(b), groupby=[p]) <- Source[aggregation=(count(a), min(b), groupby=[p])]
+///
+/// Without group-by columns, the sum of the partial counts is null if there are no scan tasks, so the merged counts
+/// are coalesced to 0 by a Project above the merging Aggregate.
+#[derive(Default, Debug)]
+pub struct PushDownAggregation {}
+
+impl PushDownAggregation {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for PushDownAggregation {
+    fn apply_order(&self) -> ApplyOrder {
+        ApplyOrder::TopDown
+    }
+
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let LogicalPlan::Aggregate(Aggregate {
+            input,
+            aggregations,
+            groupby,
+            ..
+        }) = plan.as_ref()
+        else {
+            return Ok(Transformed::No(plan));
+        };
+        let LogicalPlan::Source(source) = input.as_ref() else {
+            return Ok(Transformed::No(plan));
+        };
+        let external_info = match source.source_info.as_ref() {
+            // Aggregation pushdown is not supported for in-memory sources.
+            #[cfg(feature = "python")]
+            SourceInfo::InMemoryInfo(_) => return Ok(Transformed::No(plan)),
+            SourceInfo::ExternalInfo(external_info) => external_info,
+        };
+        // The pushed-down aggregation is computed over all rows of each scan task, so it can't be combined with
+        // pushdowns that drop rows within a scan task.
+        let pushdowns = &external_info.pushdowns;
+        if pushdowns.filters.is_some()
+            || pushdowns.limit.is_some()
+            || pushdowns.aggregation.is_some()
+        {
+            return Ok(Transformed::No(plan));
+        }
+        // Only aggregations whose partial results can be merged are pushed down.
+        if !aggregations
+            .iter()
+            .all(|agg| matches!(agg, AggExpr::Count(..) | AggExpr::Min(_) | AggExpr::Max(_)))
+            || !groupby.iter().all(|e| matches!(e, Expr::Column(_)))
+        {
+            return Ok(Transformed::No(plan));
+        }
+        let aggregation = AggregationPushdown::try_new(
+            aggregations.clone(),
+            groupby.iter().cloned().map(Arc::new).collect(),
+            &source.output_schema,
+        )?;
+        if !external_info.scan_op.0.can_absorb_aggregation(&aggregation) {
+            return Ok(Transformed::No(plan));
+        }
+
+        let merge_aggregations = aggregations
+            .iter()
+            .map(|agg| {
+                let partial = Arc::new(col(agg.name()?));
+                Ok(match agg {
+                    AggExpr::Count(..) => AggExpr::Sum(partial),
+                    AggExpr::Min(_) => AggExpr::Min(partial),
+                    AggExpr::Max(_) => AggExpr::Max(partial),
+                    _ => unreachable!("only counts, mins and maxes are pushed down"),
+                })
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let merge_groupby = groupby
+            .iter()
+            .map(|e| Ok(col(e.name()?)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let new_source: LogicalPlan = Source::new(
+            aggregation.output_schema.clone(),
+            SourceInfo::ExternalInfo(
+                external_info
+                    .with_pushdowns(pushdowns.with_aggregation(Some(Arc::new(aggregation)))),
+            )
+            .into(),
+        )
+        .into();
+        let new_plan: LogicalPlan =
+            Aggregate::try_new(new_source.into(), merge_aggregations, merge_groupby)?.into();
+        if !groupby.is_empty()
+            || !aggregations
+                .iter()
+                .any(|agg| matches!(agg, AggExpr::Count(..)))
+        {
+            return Ok(Transformed::Yes(new_plan.into()));
+        }
+        let projection = aggregations
+            .iter()
+            .map(|agg| {
+                let merged = col(agg.name()?);
+                Ok(match agg {
+                    AggExpr::Count(..) => merged.fill_null(&lit(0u64)),
+                    _ => merged,
+                })
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let new_plan: LogicalPlan =
+            Project::try_new(new_plan.into(), projection, Default::default())?.into();
+        Ok(Transformed::Yes(new_plan.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{count_mode::CountMode, datatypes::Field, DataType};
+    use daft_dsl::{col, lit};
+    use daft_scan::AggregationPushdown;
+
+    use crate::{
+        builder::LogicalPlanBuilder,
+        logical_ops::Source,
+        logical_optimization::{
+            rules::PushDownAggregation, test::assert_optimized_plan_with_rules_eq,
+        },
+        source_info::SourceInfo,
+        test::{dummy_scan_node, dummy_scan_operator, dummy_scan_operator_with_stats},
+        LogicalPlan,
+    };
+
+    /// Helper that creates an optimizer with the PushDownAggregation rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![Box::new(PushDownAggregation::new())],
+        )
+    }
+
+    fn dummy_fields() -> Vec<Field> {
+        vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Float64),
+        ]
+    }
+
+    /// Rebuilds the provided scan with the aggregations of the provided Aggregate pushed into it.
+    fn with_aggregation(
+        scan: &LogicalPlanBuilder,
+        plan: &LogicalPlan,
+    ) -> DaftResult<LogicalPlanBuilder> {
+        let scan_plan = scan.build();
+        let (LogicalPlan::Source(source), LogicalPlan::Aggregate(aggregate)) =
+            (scan_plan.as_ref(), plan)
+        else {
+            panic!("expected an Aggregate over a Source");
+        };
+        let external_info = match source.source_info.as_ref() {
+            #[cfg(feature = "python")]
+            SourceInfo::InMemoryInfo(_) => panic!("expected an external Source"),
+            SourceInfo::ExternalInfo(external_info) => external_info,
+        };
+        let aggregation =
+            AggregationPushdown::try_new(aggregate.aggregations.clone(), vec![], &scan.schema())?;
+        let output_schema = aggregation.output_schema.clone();
+        let pushdowns = external_info
+            .pushdowns
+            .with_aggregation(Some(Arc::new(aggregation)));
+        Ok(LogicalPlan::from(Source::new(
+            output_schema,
+            SourceInfo::ExternalInfo(external_info.with_pushdowns(pushdowns)).into(),
+        ))
+        .into())
+    }
+
+    /// Tests that a count and a min/max of an integer column are pushed into a scan that can derive them from
+    /// metadata, with an Aggregate above the scan merging the per-scan-task results.
+    ///
+    /// Aggregate(count(a), max(b as m)) <- Source
+    /// -> Project(fill_null(a, 0), m) <- Aggregate(sum(a), max(m)) <- Source[aggregation=(count(a), max(b as m))]
+    #[test]
+    fn count_and_max_pushed_into_scan() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator_with_stats(dummy_fields(), 100, None));
+        let plan = scan
+            .aggregate(
+                vec![col("a").count(CountMode::All), col("b").alias("m").max()],
+                vec![],
+            )?
+            .build();
+        let expected = with_aggregation(&scan, &plan)?
+            .aggregate(vec![col("a").sum(), col("m").max()], vec![])?
+            .project(
+                vec![col("a").fill_null(&lit(0u64)), col("m")],
+                Default::default(),
+            )?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that aggregations that can't be derived from metadata aren't pushed into the scan.
+    #[test]
+    fn non_metadata_aggregation_not_pushed_into_scan() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator_with_stats(dummy_fields(), 100, None));
+        // Mean isn't mergeable, and float statistics aren't exact.
+        let plan = scan.aggregate(vec![col("a").mean()], vec![])?.build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        let plan = scan.aggregate(vec![col("c").min()], vec![])?.build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that aggregations aren't pushed into scan operators that can't absorb them.
+    #[test]
+    fn aggregation_not_pushed_into_non_absorbing_scan() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(dummy_fields()));
+        let plan = scan
+            .aggregate(vec![col("a").count(CountMode::All)], vec![])?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
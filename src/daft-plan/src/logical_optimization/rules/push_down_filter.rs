@@ -16,7 +16,7 @@ use daft_scan::{rewrite_predicate_for_partitioning, PredicateGroups};
 use crate::{
     logical_ops::{Concat, Filter, Project, Source},
     source_info::SourceInfo,
-    LogicalPlan,
+    JoinType, LogicalPlan,
 };
 
 use super::{ApplyOrder, OptimizerRule, Transformed};
@@ -224,55 +224,181 @@ impl OptimizerRule for PushDownFilter {
                 new_concat.into()
             }
             LogicalPlan::Join(child_join) => {
-                // Push filter into each side of the join.
+                // Push filter predicates into each side of the join.
                 // TODO(Clark): Merge filter predicate with on predicate, if present.
-                // TODO(Clark): Duplicate filters for joined columns so filters can be pushed down to both sides.
-
-                // Get all input columns for predicate.
-                let predicate_cols: HashSet<_> = get_required_columns(&filter.predicate)
+                let join_type = child_join.join_type;
+                let left_schema = child_join.left.schema();
+                let right_schema = child_join.right.schema();
+                // Pairs of join key columns, which are equal for all rows produced by a match.
+                let key_pairs = child_join
+                    .left_on
+                    .iter()
+                    .zip(child_join.right_on.iter())
+                    .filter_map(|(l, r)| match (l, r) {
+                        (Expr::Column(l), Expr::Column(r))
+                            if matches!(
+                                (left_schema.get_field(l), right_schema.get_field(r)),
+                                (Ok(l_field), Ok(r_field)) if l_field.dtype == r_field.dtype
+                            ) =>
+                        {
+                            Some((l.to_string(), r.to_string()))
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                let left_to_right_keys = key_pairs
+                    .iter()
+                    .map(|(l, r)| (l.as_str(), r.as_str()))
+                    .collect::<HashMap<_, _>>();
+                let right_to_left_keys = key_pairs
                     .iter()
-                    .cloned()
-                    .collect();
-                // Only push the filter into the left side of the join if the left side of the join has all columns
-                // required by the predicate.
-                let left_cols: HashSet<_> =
-                    child_join.left.schema().names().iter().cloned().collect();
-                let can_push_left = left_cols
-                    .intersection(&predicate_cols)
-                    .collect::<HashSet<_>>()
-                    .len()
-                    == predicate_cols.len();
-                // Only push the filter into the right side of the join if the right side of the join has all columns
-                // required by the predicate.
-                let right_cols: HashSet<_> =
-                    child_join.right.schema().names().iter().cloned().collect();
-                let can_push_right = right_cols
-                    .intersection(&predicate_cols)
-                    .collect::<HashSet<_>>()
-                    .len()
-                    == predicate_cols.len();
-                if !can_push_left && !can_push_right {
+                    .map(|(l, r)| (r.as_str(), l.as_str()))
+                    .collect::<HashMap<_, _>>();
+                // Resolve a column of the join output to the columns of the left and right sides that it is equal
+                // to for all output rows; columns of a side that is padded with nulls for unmatched rows from the
+                // other side don't resolve to that side.
+                let resolve = |name: &str| -> (Option<String>, Option<String>) {
+                    if left_schema.fields.contains_key(name) {
+                        let right_key = left_to_right_keys.get(name).map(|r| r.to_string());
+                        match join_type {
+                            JoinType::Inner => (Some(name.to_string()), right_key),
+                            JoinType::Left | JoinType::Semi | JoinType::Anti | JoinType::Cross => {
+                                (Some(name.to_string()), None)
+                            }
+                            // Join keys shared by both sides take their values from the right side for rows that
+                            // only exist on the right side.
+                            JoinType::Right => (None, right_key.filter(|r| r == name)),
+                            JoinType::Outer => (None, None),
+                        }
+                    } else if let Some(right_name) = child_join.right_input_mapping.get(name) {
+                        let left_key = right_to_left_keys
+                            .get(right_name.as_str())
+                            .map(|l| l.to_string());
+                        match join_type {
+                            JoinType::Inner => (left_key, Some(right_name.clone())),
+                            JoinType::Right | JoinType::Cross => (None, Some(right_name.clone())),
+                            _ => (None, None),
+                        }
+                    } else {
+                        (None, None)
+                    }
+                };
+                // Filters can't be pushed into a side of the join that is padded with nulls for unmatched rows
+                // from the other side, nor into the right side of a join that doesn't output the right side.
+                let (left_preserved, right_preserved) = match join_type {
+                    JoinType::Inner | JoinType::Cross => (true, true),
+                    JoinType::Left | JoinType::Semi | JoinType::Anti => (true, false),
+                    JoinType::Right => (false, true),
+                    JoinType::Outer => (false, false),
+                };
+
+                let mut left_predicates = vec![];
+                let mut right_predicates = vec![];
+                let mut remaining_predicates = vec![];
+                for predicate in split_conjuction(&filter.predicate) {
+                    let resolved = get_required_columns(predicate)
+                        .into_iter()
+                        .map(|name| {
+                            let sides = resolve(&name);
+                            (name, sides)
+                        })
+                        .collect::<Vec<_>>();
+                    let mut left_predicate = if left_preserved {
+                        rewrite_columns(
+                            predicate,
+                            resolved.iter().map(|(name, (l, _))| (name, l.clone())),
+                        )
+                    } else {
+                        None
+                    };
+                    let mut right_predicate = if right_preserved {
+                        rewrite_columns(
+                            predicate,
+                            resolved.iter().map(|(name, (_, r))| (name, r.clone())),
+                        )
+                    } else {
+                        None
+                    };
+                    // A predicate on the join keys of the preserved side can also be applied to the join keys of the
+                    // other side, since the rows of the other side that it filters out can only match rows of the
+                    // preserved side that are filtered out.
+                    match join_type {
+                        JoinType::Left | JoinType::Semi if left_predicate.is_some() => {
+                            right_predicate = rewrite_columns(
+                                predicate,
+                                resolved.iter().map(|(name, (l, _))| {
+                                    let r =
+                                        l.as_ref().and_then(|l| left_to_right_keys.get(l.as_str()));
+                                    (name, r.map(|r| r.to_string()))
+                                }),
+                            );
+                        }
+                        JoinType::Right if right_predicate.is_some() => {
+                            left_predicate = rewrite_columns(
+                                predicate,
+                                resolved.iter().map(|(name, (_, r))| {
+                                    let l =
+                                        r.as_ref().and_then(|r| right_to_left_keys.get(r.as_str()));
+                                    (name, l.map(|l| l.to_string()))
+                                }),
+                            );
+                        }
+                        _ => {}
+                    }
+                    if left_predicate.is_none() && right_predicate.is_none() {
+                        remaining_predicates.push(predicate.clone());
+                    }
+                    left_predicates.extend(left_predicate);
+                    right_predicates.extend(right_predicate);
+                }
+                if join_type == JoinType::Inner {
+                    // Rows with null join keys never match in an inner join, so they can be filtered out of both
+                    // sides, even if none of the Filter's predicates can be pushed down, unless a side already
+                    // filters them out.
+                    let left_applied = applied_predicates(&child_join.left);
+                    let right_applied = applied_predicates(&child_join.right);
+                    for (l, r) in key_pairs.iter() {
+                        let left_not_null = col(l.as_str()).not_null();
+                        if !left_predicates.contains(&left_not_null)
+                            && !left_applied.contains(&left_not_null)
+                        {
+                            left_predicates.push(left_not_null);
+                        }
+                        let right_not_null = col(r.as_str()).not_null();
+                        if !right_predicates.contains(&right_not_null)
+                            && !right_applied.contains(&right_not_null)
+                        {
+                            right_predicates.push(right_not_null);
+                        }
+                    }
+                }
+                if left_predicates.is_empty() && right_predicates.is_empty() {
                     return Ok(Transformed::No(plan));
                 }
-                let new_left: Arc<LogicalPlan> = if can_push_left {
-                    LogicalPlan::from(Filter::try_new(
-                        child_join.left.clone(),
-                        filter.predicate.clone(),
-                    )?)
-                    .into()
-                } else {
-                    child_join.left.clone()
-                };
-                let new_right: Arc<LogicalPlan> = if can_push_right {
-                    LogicalPlan::from(Filter::try_new(
-                        child_join.right.clone(),
-                        filter.predicate.clone(),
-                    )?)
-                    .into()
+
+                let new_left: Arc<LogicalPlan> =
+                    if let Some(left_predicate) = conjuct(left_predicates) {
+                        LogicalPlan::from(Filter::try_new(child_join.left.clone(), left_predicate)?)
+                            .into()
+                    } else {
+                        child_join.left.clone()
+                    };
+                let new_right: Arc<LogicalPlan> = if let Some(right_predicate) =
+                    conjuct(right_predicates)
+                {
+                    LogicalPlan::from(Filter::try_new(child_join.right.clone(), right_predicate)?)
+                        .into()
                 } else {
                     child_join.right.clone()
                 };
-                child_plan.with_new_children(&[new_left, new_right]).into()
+                let new_join: Arc<LogicalPlan> =
+                    child_plan.with_new_children(&[new_left, new_right]).into();
+                if let Some(remaining_predicate) = conjuct(remaining_predicates) {
+                    // Keep the predicates that couldn't be pushed into either side above the join.
+                    LogicalPlan::from(Filter::try_new(new_join, remaining_predicate)?).into()
+                } else {
+                    new_join
+                }
             }
             _ => return Ok(Transformed::No(plan)),
         };
@@ -280,6 +406,41 @@ impl OptimizerRule for PushDownFilter {
     }
 }
 
+/// Rewrite a predicate to reference the provided replacement columns, if every column it references has one.
+fn rewrite_columns<'a>(
+    predicate: &Expr,
+    replacements: impl Iterator<Item = (&'a String, Option<String>)>,
+) -> Option<Expr> {
+    let mapping = replacements
+        .map(|(name, replacement)| replacement.map(|r| (name.clone(), col(r))))
+        .collect::<Option<HashMap<_, _>>>()?;
+    Some(replace_columns_with_expressions(predicate, &mapping))
+}
+
+/// The conjuncts of the predicates already applied to the output of a plan, by Filters or by the filter pushdowns of
+/// a scan.
+fn applied_predicates(plan: &LogicalPlan) -> Vec<Expr> {
+    match plan {
+        LogicalPlan::Filter(Filter { input, predicate }) => split_conjuction(predicate)
+            .into_iter()
+            .cloned()
+            .chain(applied_predicates(input))
+            .collect(),
+        LogicalPlan::Source(Source { source_info, .. }) => match source_info.as_ref() {
+            SourceInfo::ExternalInfo(external_info) => external_info
+                .pushdowns
+                .filters
+                .iter()
+                .chain(external_info.pushdowns.partition_filters.iter())
+                .flat_map(|filters| split_conjuction(filters).into_iter().cloned())
+                .collect(),
+            #[cfg(feature = "python")]
+            SourceInfo::InMemoryInfo(_) => vec![],
+        },
+        _ => vec![],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -602,7 +763,9 @@ mod tests {
         Ok(())
     }
 
-    /// Tests that Filter commutes with Join.
+    /// Tests that Filter commutes with Join, and is only pushed into the side of the Join that its columns come from
+    /// even if the other side has a column with the same name; IS NOT NULL filters on the join keys are inferred for
+    /// both sides.
     #[rstest]
     fn filter_commutes_with_join(
         #[values(false, true)] push_into_left_scan: bool,
@@ -622,6 +785,8 @@ mod tests {
         );
         let join_on = vec![col("b")];
         let pred = col("a").lt(&lit(2));
+        let left_pred = pred.and(&col("b").not_null());
+        let right_pred = col("b").not_null();
         let plan = left_scan_plan
             .join(
                 &right_scan_plan,
@@ -635,18 +800,18 @@ mod tests {
         let expected_left_filter_scan = if push_into_left_scan {
             dummy_scan_node_with_pushdowns(
                 scan_op.clone(),
-                Pushdowns::default().with_filters(Some(pred.clone().into())),
+                Pushdowns::default().with_filters(Some(left_pred.clone().into())),
             )
         } else {
-            left_scan_plan.filter(pred.clone())?
+            left_scan_plan.filter(left_pred)?
         };
         let expected_right_filter_scan = if push_into_right_scan {
             dummy_scan_node_with_pushdowns(
                 scan_op,
-                Pushdowns::default().with_filters(Some(pred.into())),
+                Pushdowns::default().with_filters(Some(right_pred.clone().into())),
             )
         } else {
-            right_scan_plan.filter(pred)?
+            right_scan_plan.filter(right_pred)?
         };
         let expected = expected_left_filter_scan
             .join(
@@ -681,6 +846,7 @@ mod tests {
         let right_scan_plan = dummy_scan_node(right_scan_op.clone());
         let join_on = vec![col("b")];
         let pred = col("a").lt(&lit(2));
+        let left_pred = pred.and(&col("b").not_null());
         let plan = left_scan_plan
             .join(
                 &right_scan_plan,
@@ -694,14 +860,18 @@ mod tests {
         let expected_left_filter_scan = if push_into_left_scan {
             dummy_scan_node_with_pushdowns(
                 left_scan_op.clone(),
-                Pushdowns::default().with_filters(Some(pred.clone().into())),
+                Pushdowns::default().with_filters(Some(left_pred.clone().into())),
             )
         } else {
-            left_scan_plan.filter(pred.clone())?
+            left_scan_plan.filter(left_pred)?
         };
+        let expected_right_filter_scan = dummy_scan_node_with_pushdowns(
+            right_scan_op,
+            Pushdowns::default().with_filters(Some(col("b").not_null().into())),
+        );
         let expected = expected_left_filter_scan
             .join(
-                &right_scan_plan,
+                &expected_right_filter_scan,
                 join_on.clone(),
                 join_on.clone(),
                 JoinType::Inner,
@@ -732,6 +902,7 @@ mod tests {
         );
         let join_on = vec![col("b")];
         let pred = col("c").lt(&lit(2.0));
+        let right_pred = pred.and(&col("b").not_null());
         let plan = left_scan_plan
             .join(
                 &right_scan_plan,
@@ -745,12 +916,16 @@ mod tests {
         let expected_right_filter_scan = if push_into_right_scan {
             dummy_scan_node_with_pushdowns(
                 right_scan_op.clone(),
-                Pushdowns::default().with_filters(Some(pred.clone().into())),
+                Pushdowns::default().with_filters(Some(right_pred.clone().into())),
             )
         } else {
-            right_scan_plan.filter(pred.clone())?
+            right_scan_plan.filter(right_pred)?
         };
-        let expected = left_scan_plan
+        let expected_left_filter_scan = dummy_scan_node_with_pushdowns(
+            left_scan_op,
+            Pushdowns::default().with_filters(Some(col("b").not_null().into())),
+        );
+        let expected = expected_left_filter_scan
             .join(
                 &expected_right_filter_scan,
                 join_on.clone(),
@@ -787,7 +962,7 @@ mod tests {
             Pushdowns::default().with_limit(if push_into_right_scan { None } else { Some(1) }),
         );
         let join_on = vec![col("b")];
-        let pred = col("b").lt(&lit(2));
+        let pred = col("b").lt(&lit(2)).and(&col("b").not_null());
         let plan = left_scan_plan
             .join(
                 &right_scan_plan,
@@ -796,7 +971,7 @@ mod tests {
                 JoinType::Inner,
                 None,
             )?
-            .filter(pred.clone())?
+            .filter(col("b").lt(&lit(2)))?
             .build();
         let expected_left_filter_scan = if push_into_left_scan {
             dummy_scan_node_with_pushdowns(
@@ -826,4 +1001,171 @@ mod tests {
         assert_optimized_plan_eq(plan, expected)?;
         Ok(())
     }
+
+    /// Tests that Filter is not pushed into a side of a Join that is padded with nulls for unmatched rows.
+    #[rstest]
+    fn filter_not_pushed_into_null_padded_side_of_join(
+        #[values(JoinType::Left, JoinType::Outer)] join_type: JoinType,
+    ) -> DaftResult<()> {
+        let left_scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let right_scan_op = dummy_scan_operator(vec![
+            Field::new("b", DataType::Utf8),
+            Field::new("c", DataType::Float64),
+        ]);
+        let join_on = vec![col("b")];
+        let plan = dummy_scan_node(left_scan_op)
+            .join(
+                &dummy_scan_node(right_scan_op),
+                join_on.clone(),
+                join_on,
+                join_type,
+                None,
+            )?
+            .filter(col("c").lt(&lit(2.0)))?
+            .build();
+        // Plan should be unchanged after optimization.
+        let expected = plan.clone();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that a Filter on a join key is pushed into both sides of an inner Join through the key equivalence, even
+    /// if the join keys have different names.
+    ///
+    /// Filter(a < 2) <- Join(a = c) -> Join(a = c) <- [Source(a < 2 & a IS NOT NULL), Source(c < 2 & c IS NOT NULL)]
+    #[test]
+    fn filter_on_join_key_pushed_into_both_sides_via_key_equivalence() -> DaftResult<()> {
+        let left_scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let right_scan_op = dummy_scan_operator(vec![
+            Field::new("c", DataType::Int64),
+            Field::new("d", DataType::Utf8),
+        ]);
+        let plan = dummy_scan_node(left_scan_op.clone())
+            .join(
+                &dummy_scan_node(right_scan_op.clone()),
+                vec![col("a")],
+                vec![col("c")],
+                JoinType::Inner,
+                None,
+            )?
+            .filter(col("a").lt(&lit(2)))?
+            .build();
+        let expected = dummy_scan_node_with_pushdowns(
+            left_scan_op,
+            Pushdowns::default()
+                .with_filters(Some(col("a").lt(&lit(2)).and(&col("a").not_null()).into())),
+        )
+        .join(
+            &dummy_scan_node_with_pushdowns(
+                right_scan_op,
+                Pushdowns::default()
+                    .with_filters(Some(col("c").lt(&lit(2)).and(&col("c").not_null()).into())),
+            ),
+            vec![col("a")],
+            vec![col("c")],
+            JoinType::Inner,
+            None,
+        )?
+        .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that IS NOT NULL is inferred for the keys of an inner Join even if none of the Filter's predicates can be
+    /// pushed into either side, and that it isn't inferred again for sides that already filter out null keys.
+    ///
+    /// Filter(b == d) <- Join(a = c)
+    /// -> Filter(b == d) <- Join(a = c) <- [Source(a IS NOT NULL), Source(c IS NOT NULL)]
+    #[test]
+    fn not_null_inferred_for_inner_join_keys_without_pushed_filter() -> DaftResult<()> {
+        let left_scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let right_scan_op = dummy_scan_operator(vec![
+            Field::new("c", DataType::Int64),
+            Field::new("d", DataType::Utf8),
+        ]);
+        let plan = dummy_scan_node(left_scan_op.clone())
+            .join(
+                &dummy_scan_node(right_scan_op.clone()),
+                vec![col("a")],
+                vec![col("c")],
+                JoinType::Inner,
+                None,
+            )?
+            .filter(col("b").eq(&col("d")))?
+            .build();
+        let expected = dummy_scan_node_with_pushdowns(
+            left_scan_op,
+            Pushdowns::default().with_filters(Some(col("a").not_null().into())),
+        )
+        .join(
+            &dummy_scan_node_with_pushdowns(
+                right_scan_op,
+                Pushdowns::default().with_filters(Some(col("c").not_null().into())),
+            ),
+            vec![col("a")],
+            vec![col("c")],
+            JoinType::Inner,
+            None,
+        )?
+        .filter(col("b").eq(&col("d")))?
+        .build();
+        assert_optimized_plan_eq(plan, expected.clone())?;
+        // Optimizing again leaves the plan unchanged.
+        assert_optimized_plan_eq(expected.clone(), expected)?;
+        Ok(())
+    }
+
+    /// Tests that for a left Join, a Filter on the left join key is pushed into the left side and derived for the
+    /// right side, while a Filter on a right column stays above the Join.
+    ///
+    /// Filter(a < 2 & d == "x") <- Join(a = c, left)
+    /// -> Filter(d == "x") <- Join(a = c, left) <- [Source(a < 2), Source(c < 2)]
+    #[test]
+    fn filter_pushed_into_preserved_side_of_left_join() -> DaftResult<()> {
+        let left_scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let right_scan_op = dummy_scan_operator(vec![
+            Field::new("c", DataType::Int64),
+            Field::new("d", DataType::Utf8),
+        ]);
+        let plan = dummy_scan_node(left_scan_op.clone())
+            .join(
+                &dummy_scan_node(right_scan_op.clone()),
+                vec![col("a")],
+                vec![col("c")],
+                JoinType::Left,
+                None,
+            )?
+            .filter(col("a").lt(&lit(2)).and(&col("d").eq(&lit("x"))))?
+            .build();
+        let expected = dummy_scan_node_with_pushdowns(
+            left_scan_op,
+            Pushdowns::default().with_filters(Some(col("a").lt(&lit(2)).into())),
+        )
+        .join(
+            &dummy_scan_node_with_pushdowns(
+                right_scan_op,
+                Pushdowns::default().with_filters(Some(col("c").lt(&lit(2)).into())),
+            ),
+            vec![col("a")],
+            vec![col("c")],
+            JoinType::Left,
+            None,
+        )?
+        .filter(col("d").eq(&lit("x")))?
+        .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
 }
@@ -435,6 +435,45 @@ impl PushDownProjection {
                     Ok(Transformed::No(plan))
                 }
             }
+            LogicalPlan::Window(window) => {
+                // Get the input columns required by the projection and by the window.
+                let window_input_schema = window.input.schema();
+                let combined_dependencies = plan
+                    .required_columns()
+                    .iter()
+                    .flatten()
+                    .filter(|name| window_input_schema.fields.contains_key(*name))
+                    .chain(upstream_plan.required_columns().iter().flatten())
+                    .cloned()
+                    .collect::<IndexSet<_>>();
+
+                // Skip optimization if no columns would be pruned.
+                if window_input_schema.names().len() == combined_dependencies.len() {
+                    return Ok(Transformed::No(plan));
+                }
+
+                let new_subprojection: LogicalPlan = {
+                    let pushdown_column_exprs = combined_dependencies
+                        .into_iter()
+                        .map(|s| Expr::Column(s.into()))
+                        .collect::<Vec<_>>();
+
+                    Project::try_new(
+                        window.input.clone(),
+                        pushdown_column_exprs,
+                        Default::default(),
+                    )?
+                    .into()
+                };
+
+                let new_upstream = upstream_plan.with_new_children(&[new_subprojection.into()]);
+                let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
+                // Retry optimization now that the upstream node is different.
+                let new_plan = self
+                    .try_optimize(new_plan.clone())?
+                    .or(Transformed::Yes(new_plan));
+                Ok(new_plan)
+            }
             LogicalPlan::Distinct(_) => {
                 // Cannot push down past a Distinct,
                 // since Distinct implicitly requires all parent columns.
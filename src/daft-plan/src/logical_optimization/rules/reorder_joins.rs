@@ -0,0 +1,1069 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use common_error::DaftResult;
+use daft_core::DataType;
+use daft_dsl::{col, Expr};
+use daft_scan::{ScanExternalInfo, ScanTaskRef};
+use daft_stats::{ColumnRangeStatistics, TableStatistics};
+
+use crate::{
+    logical_ops::{Filter, Join, Limit, Project, Sample},
+    source_info::SourceInfo,
+    JoinType, LogicalPlan, ResourceRequest,
+};
+
+use super::{ApplyOrder, OptimizerRule, Transformed};
+
+// Join trees with fewer relations than this are left as-is, since there is no order to choose.
+const MIN_RELATIONS_TO_REORDER: usize = 3;
+// The join order search is exhaustive over subsets of relations, so we cap the size of the join graph.
+const MAX_RELATIONS_TO_REORDER: usize = 10;
+// Fraction of rows assumed to pass a filter whose selectivity we can't estimate.
+const FILTER_SELECTIVITY: f64 = 0.2;
+
+/// Optimization rule for reordering trees of inner joins using cardinality and size estimates.
+///
+/// A tree of inner equi-joins is flattened into a join graph of base relations connected by equivalence
+/// classes of join keys. The row count and size of each relation is estimated from its scan tasks' metadata
+/// and [`TableStatistics`], and the left-deep join order (without cross products) that minimizes the total
+/// estimated size of the intermediate results is chosen by dynamic programming over subsets of relations.
+/// For every join in the new order, the smaller input is placed on the left, which is the build side of
+/// the hash join.
+///
+/// Scans are only estimated if their operator can list their scan tasks without reading data or running queries
+/// (see [`daft_scan::ScanOperator::can_estimate_size_from_scan_tasks`]). Listing can still be expensive (e.g.
+/// globbing a bucket), so the estimate of each scan is computed once and cached for the lifetime of the rule,
+/// and the listed scan tasks are handed over to the translation of the plan.
+#[derive(Default, Debug)]
+pub struct ReorderJoins {
+    scan_stats: Mutex<HashMap<ScanExternalInfo, Option<RelationStats>>>,
+}
+
+impl ReorderJoins {
+    pub fn new() -> Self {
+        Self {
+            scan_stats: Default::default(),
+        }
+    }
+}
+
+impl OptimizerRule for ReorderJoins {
+    fn apply_order(&self) -> ApplyOrder {
+        ApplyOrder::Delegated
+    }
+
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        self.reorder(plan)
+    }
+}
+
+impl ReorderJoins {
+    fn reorder(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        if let LogicalPlan::Join(join) = plan.as_ref()
+            && is_reorderable(join)
+            && let Some(graph) = JoinGraph::try_from_join_tree(&plan)
+            && (MIN_RELATIONS_TO_REORDER..=MAX_RELATIONS_TO_REORDER)
+                .contains(&graph.relations.len())
+            && let Some(new_plan) = self.try_reorder_graph(&plan, graph)?
+        {
+            return Ok(if new_plan == plan {
+                Transformed::No(plan)
+            } else {
+                Transformed::Yes(new_plan)
+            });
+        }
+        self.reorder_children(plan)
+    }
+
+    fn reorder_children(
+        &self,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let results = plan
+            .children()
+            .into_iter()
+            .map(|child| self.reorder(child.clone()))
+            .collect::<DaftResult<Vec<_>>>()?;
+        if results.iter().all(|result| result.is_no()) {
+            return Ok(Transformed::No(plan));
+        }
+        let new_children = results
+            .into_iter()
+            .map(|result| result.unwrap().clone())
+            .collect::<Vec<_>>();
+        Ok(Transformed::Yes(
+            plan.with_new_children(&new_children).into(),
+        ))
+    }
+
+    /// Rebuild the join tree rooted at `plan` in the cheapest join order, returning None if no order could be
+    /// chosen (e.g. because a relation has no size estimate).
+    fn try_reorder_graph(
+        &self,
+        plan: &Arc<LogicalPlan>,
+        graph: JoinGraph,
+    ) -> DaftResult<Option<Arc<LogicalPlan>>> {
+        let Some(stats) = graph
+            .relations
+            .iter()
+            .map(|relation| self.estimate_stats(relation))
+            .collect::<DaftResult<Option<Vec<_>>>>()?
+        else {
+            return Ok(None);
+        };
+        let cost_model = CostModel::new(&graph, stats);
+        let Some(order) = cost_model.best_order() else {
+            return Ok(None);
+        };
+        // Reorder any join trees underneath the relations of this join graph.
+        let relations = graph
+            .relations
+            .iter()
+            .map(|relation| Ok(self.reorder(relation.clone())?.unwrap().clone()))
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let mut new_plan = relations[order[0]].clone();
+        let mut joined = 1usize << order[0];
+        for &next in &order[1..] {
+            let (current_keys, next_keys) = graph.join_keys(&new_plan, joined, next);
+            let next_plan = relations[next].clone();
+            let join = if cost_model.size_bytes(1 << next) < cost_model.size_bytes(joined) {
+                Join::try_new(
+                    next_plan,
+                    new_plan,
+                    next_keys,
+                    current_keys,
+                    JoinType::Inner,
+                    None,
+                )?
+            } else {
+                Join::try_new(
+                    new_plan,
+                    next_plan,
+                    current_keys,
+                    next_keys,
+                    JoinType::Inner,
+                    None,
+                )?
+            };
+            new_plan = LogicalPlan::Join(join).into();
+            joined |= 1 << next;
+        }
+
+        // Restore the column order of the original join tree.
+        let schema = plan.schema();
+        let new_schema = new_plan.schema();
+        if new_schema != schema {
+            let same_fields = new_schema.fields.len() == schema.fields.len()
+                && schema
+                    .fields
+                    .iter()
+                    .all(|(name, field)| new_schema.fields.get(name) == Some(field));
+            if !same_fields {
+                return Ok(None);
+            }
+            let projection = schema
+                .names()
+                .iter()
+                .map(|name| col(name.as_str()))
+                .collect();
+            new_plan = LogicalPlan::Project(Project::try_new(
+                new_plan,
+                projection,
+                ResourceRequest::default(),
+            )?)
+            .into();
+        }
+        Ok(Some(new_plan))
+    }
+}
+
+/// Whether a join can be freely reordered with the joins around it: an inner equi-join on columns, with no
+/// user-provided strategy, where every column name shared by both sides is joined on itself (so that no right
+/// side columns are renamed).
+fn is_reorderable(join: &Join) -> bool {
+    if join.join_type != JoinType::Inner
+        || join.join_strategy.is_some()
+        || join.left_on.is_empty()
+        || !join
+            .left_on
+            .iter()
+            .chain(join.right_on.iter())
+            .all(|e| matches!(e, Expr::Column(_)))
+    {
+        return false;
+    }
+    let left_schema = join.left.schema();
+    join.right
+        .schema()
+        .names()
+        .iter()
+        .filter(|name| left_schema.fields.contains_key(name.as_str()))
+        .all(|name| {
+            join.left_on.iter().zip(join.right_on.iter()).any(|(l, r)| {
+                matches!((l, r), (Expr::Column(l), Expr::Column(r)) if &**l == name.as_str() && &**r == name.as_str())
+            })
+        })
+}
+
+/// Whether the relation is in the set of relations, represented as a bitmask over relation indices.
+fn contains(relations: usize, relation: usize) -> bool {
+    relations & (1 << relation) != 0
+}
+
+/// A column of a relation in a join graph.
+type RelationColumn = (usize, String);
+
+/// A flattened tree of reorderable inner joins.
+#[derive(Debug)]
+struct JoinGraph {
+    // Inputs of the join tree, in their original left-to-right order.
+    relations: Vec<Arc<LogicalPlan>>,
+    // Equivalence classes of relation columns that are equal under the join conditions.
+    key_classes: Vec<Vec<RelationColumn>>,
+}
+
+impl JoinGraph {
+    fn try_from_join_tree(plan: &Arc<LogicalPlan>) -> Option<Self> {
+        let mut relations = vec![];
+        let mut edges = vec![];
+        Self::flatten(plan, &mut relations, &mut edges)?;
+
+        // Union-find over the relation columns referenced by the join conditions.
+        let mut ids: HashMap<RelationColumn, usize> = HashMap::new();
+        let mut members: Vec<RelationColumn> = vec![];
+        let mut parents: Vec<usize> = vec![];
+        fn find(parents: &mut [usize], mut id: usize) -> usize {
+            while parents[id] != id {
+                parents[id] = parents[parents[id]];
+                id = parents[id];
+            }
+            id
+        }
+        for (left, right) in edges {
+            let [left, right] = [left, right].map(|member| {
+                *ids.entry(member.clone()).or_insert_with(|| {
+                    members.push(member);
+                    parents.push(parents.len());
+                    parents.len() - 1
+                })
+            });
+            let (left_root, right_root) = (find(&mut parents, left), find(&mut parents, right));
+            parents[right_root] = left_root;
+        }
+        let mut class_ids: HashMap<usize, usize> = HashMap::new();
+        let mut key_classes: Vec<Vec<RelationColumn>> = vec![];
+        for (id, member) in members.into_iter().enumerate() {
+            let root = find(&mut parents, id);
+            let class_id = *class_ids.entry(root).or_insert_with(|| {
+                key_classes.push(vec![]);
+                key_classes.len() - 1
+            });
+            key_classes[class_id].push(member);
+        }
+        Some(Self {
+            relations,
+            key_classes,
+        })
+    }
+
+    fn flatten(
+        plan: &Arc<LogicalPlan>,
+        relations: &mut Vec<Arc<LogicalPlan>>,
+        edges: &mut Vec<(RelationColumn, RelationColumn)>,
+    ) -> Option<()> {
+        match plan.as_ref() {
+            LogicalPlan::Join(join) if is_reorderable(join) => {
+                let left_start = relations.len();
+                Self::flatten(&join.left, relations, edges)?;
+                let right_start = relations.len();
+                Self::flatten(&join.right, relations, edges)?;
+                let right_end = relations.len();
+                for (l, r) in join.left_on.iter().zip(join.right_on.iter()) {
+                    let (Expr::Column(l), Expr::Column(r)) = (l, r) else {
+                        return None;
+                    };
+                    let l = Self::resolve(relations, left_start, right_start, l)?;
+                    let r = Self::resolve(relations, right_start, right_end, r)?;
+                    edges.push((l, r));
+                }
+                Some(())
+            }
+            _ => {
+                relations.push(plan.clone());
+                Some(())
+            }
+        }
+    }
+
+    /// Find the first relation in `relations[start..end]` providing the named column.
+    fn resolve(
+        relations: &[Arc<LogicalPlan>],
+        start: usize,
+        end: usize,
+        name: &str,
+    ) -> Option<RelationColumn> {
+        (start..end)
+            .find(|&i| relations[i].schema().fields.contains_key(name))
+            .map(|i| (i, name.to_string()))
+    }
+
+    /// Build the join keys for joining `relation` onto the join tree `current` of the `joined` relations.
+    fn join_keys(
+        &self,
+        current: &LogicalPlan,
+        joined: usize,
+        relation: usize,
+    ) -> (Vec<Expr>, Vec<Expr>) {
+        let mut current_keys = vec![];
+        let mut relation_keys = vec![];
+        let current_schema = current.schema();
+        let relation_schema = self.relations[relation].schema();
+        // Columns present on both sides must be joined on themselves so they are merged in the output.
+        let shared_names = relation_schema
+            .names()
+            .into_iter()
+            .filter(|name| current_schema.fields.contains_key(name.as_str()))
+            .collect::<HashSet<_>>();
+        for class in &self.key_classes {
+            let relation_columns = class
+                .iter()
+                .filter(|(i, _)| *i == relation)
+                .map(|(_, name)| name)
+                .collect::<Vec<_>>();
+            let current_columns = class
+                .iter()
+                .filter(|(i, _)| contains(joined, *i))
+                .map(|(_, name)| name)
+                .collect::<Vec<_>>();
+            let (Some(relation_column), Some(current_column)) =
+                (relation_columns.first(), current_columns.first())
+            else {
+                continue;
+            };
+            let shared = relation_columns
+                .iter()
+                .filter(|name| shared_names.contains(name.as_str()))
+                .collect::<Vec<_>>();
+            if shared.is_empty() {
+                current_keys.push(col(current_column.as_str()));
+                relation_keys.push(col(relation_column.as_str()));
+            } else {
+                for name in shared {
+                    current_keys.push(col(name.as_str()));
+                    relation_keys.push(col(name.as_str()));
+                }
+            }
+        }
+        (current_keys, relation_keys)
+    }
+}
+
+/// Estimated size of a relation in a join graph.
+#[derive(Debug, Clone)]
+struct RelationStats {
+    num_rows: f64,
+    // Number of rows before any filters were applied, used to bound the number of distinct join keys on the other
+    // side of a (presumed foreign key) join.
+    unfiltered_num_rows: f64,
+    size_bytes: f64,
+    statistics: Option<TableStatistics>,
+}
+
+impl RelationStats {
+    fn new(num_rows: f64, size_bytes: f64, statistics: Option<TableStatistics>) -> Self {
+        Self {
+            num_rows,
+            unfiltered_num_rows: num_rows,
+            size_bytes,
+            statistics,
+        }
+    }
+
+    fn scaled(self, fraction: f64) -> Self {
+        Self {
+            num_rows: self.num_rows * fraction,
+            size_bytes: self.size_bytes * fraction,
+            ..self
+        }
+    }
+
+    fn limited(self, limit: f64) -> Self {
+        if self.num_rows <= limit {
+            self
+        } else {
+            let fraction = limit / self.num_rows;
+            self.scaled(fraction)
+        }
+    }
+
+    fn row_size_bytes(&self) -> f64 {
+        if self.num_rows > 0.0 {
+            self.size_bytes / self.num_rows
+        } else {
+            0.0
+        }
+    }
+}
+
+impl ReorderJoins {
+    /// Estimate the size of a relation, returning None if it can't be estimated.
+    fn estimate_stats(&self, plan: &LogicalPlan) -> DaftResult<Option<RelationStats>> {
+        let stats = match plan {
+            LogicalPlan::Source(source) => match source.source_info.as_ref() {
+                #[cfg(feature = "python")]
+                SourceInfo::InMemoryInfo(info) => {
+                    let row_size = source.output_schema.estimate_row_size_bytes();
+                    let num_rows = if row_size > 0.0 {
+                        info.size_bytes as f64 / row_size
+                    } else {
+                        0.0
+                    };
+                    Some(RelationStats::new(num_rows, info.size_bytes as f64, None))
+                }
+                SourceInfo::ExternalInfo(info) => self.estimate_scan_stats(info)?,
+            },
+            LogicalPlan::Filter(Filter { input, .. }) => self
+                .estimate_stats(input)?
+                .map(|stats| stats.scaled(FILTER_SELECTIVITY)),
+            LogicalPlan::Limit(Limit { input, limit, .. }) => self
+                .estimate_stats(input)?
+                .map(|stats| stats.limited(*limit as f64)),
+            LogicalPlan::Sample(Sample {
+                input, fraction, ..
+            }) => self
+                .estimate_stats(input)?
+                .map(|stats| stats.scaled(*fraction)),
+            LogicalPlan::Project(Project { input, .. }) => {
+                self.estimate_stats(input)?.map(|input_stats| {
+                    let input_row_size = input.schema().estimate_row_size_bytes();
+                    let size_bytes = if input_row_size > 0.0 {
+                        input_stats.size_bytes * plan.schema().estimate_row_size_bytes()
+                            / input_row_size
+                    } else {
+                        input_stats.size_bytes
+                    };
+                    // Projected columns may be computed, so column statistics no longer apply.
+                    RelationStats {
+                        size_bytes,
+                        statistics: None,
+                        ..input_stats
+                    }
+                })
+            }
+            LogicalPlan::Sort(..)
+            | LogicalPlan::Repartition(..)
+            | LogicalPlan::Distinct(..)
+            | LogicalPlan::Explode(..)
+            | LogicalPlan::MonotonicallyIncreasingId(..)
+            | LogicalPlan::Window(..) => self.estimate_stats(plan.children()[0])?,
+            _ => None,
+        };
+        Ok(stats)
+    }
+
+    fn estimate_scan_stats(&self, info: &ScanExternalInfo) -> DaftResult<Option<RelationStats>> {
+        if let Some(stats) = self.scan_stats.lock().unwrap().get(info) {
+            return Ok(stats.clone());
+        }
+        if !info.scan_op.0.can_estimate_size_from_scan_tasks() {
+            return Ok(None);
+        }
+        let scan_tasks = info
+            .scan_op
+            .0
+            .to_scan_tasks(info.pushdowns.clone())?
+            .collect::<DaftResult<Vec<_>>>()?;
+        let stats = scan_tasks_stats(&scan_tasks)?.map(|mut stats| {
+            if info.pushdowns.filters.is_some() {
+                stats = stats.scaled(FILTER_SELECTIVITY);
+            }
+            if let Some(limit) = info.pushdowns.limit {
+                stats = stats.limited(limit as f64);
+            }
+            stats
+        });
+        info.listed_scan_tasks.set(scan_tasks);
+        self.scan_stats
+            .lock()
+            .unwrap()
+            .insert(info.clone(), stats.clone());
+        Ok(stats)
+    }
+}
+
+/// Combined estimate of a scan's tasks, or None if a task has neither a row count nor a size estimate.
+fn scan_tasks_stats(scan_tasks: &[ScanTaskRef]) -> DaftResult<Option<RelationStats>> {
+    let mut num_rows = 0.0;
+    let mut size_bytes = 0.0;
+    for scan_task in scan_tasks {
+        let row_size = scan_task.materialized_schema().estimate_row_size_bytes();
+        let task_num_rows = scan_task.metadata.as_ref().map(|m| m.length as f64);
+        let Some(task_size_bytes) = scan_task
+            .estimate_in_memory_size_bytes(None)
+            .map(|size| size as f64)
+            .or_else(|| task_num_rows.map(|rows| rows * row_size))
+        else {
+            return Ok(None);
+        };
+        num_rows += task_num_rows.unwrap_or_else(|| {
+            if row_size > 0.0 {
+                task_size_bytes / row_size
+            } else {
+                0.0
+            }
+        });
+        size_bytes += task_size_bytes;
+    }
+    // Column statistics are only known if every task has them.
+    let mut statistics = scan_tasks
+        .iter()
+        .map(|scan_task| scan_task.statistics.clone())
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default()
+        .into_iter();
+    let statistics = match statistics.next() {
+        Some(first) => Some(statistics.try_fold(first, |acc, stats| acc.union(&stats))?),
+        None => None,
+    };
+    Ok(Some(RelationStats::new(num_rows, size_bytes, statistics)))
+}
+
+/// Number of distinct values of an integer column, bounded by the width of its value range.
+fn integer_range_width(stats: &ColumnRangeStatistics) -> Option<f64> {
+    match stats {
+        ColumnRangeStatistics::Loaded(lower, upper) if lower.data_type().is_integer() => {
+            let lower = lower.cast(&DataType::Int64).ok()?.i64().ok()?.get(0)?;
+            let upper = upper.cast(&DataType::Int64).ok()?.i64().ok()?.get(0)?;
+            Some((upper as f64) - (lower as f64) + 1.0)
+        }
+        _ => None,
+    }
+}
+
+/// Cardinality and size estimates for joins of subsets of the relations in a join graph, where a subset is a
+/// bitmask over the relation indices.
+struct CostModel {
+    stats: Vec<RelationStats>,
+    // For each key class, the estimated number of distinct keys of each relation in the class.
+    distinct_counts: Vec<Vec<(usize, f64)>>,
+}
+
+impl CostModel {
+    fn new(graph: &JoinGraph, stats: Vec<RelationStats>) -> Self {
+        let distinct_counts = graph
+            .key_classes
+            .iter()
+            .map(|class| {
+                let mut counts: Vec<(usize, f64)> = vec![];
+                for (relation, name) in class {
+                    let count = Self::distinct_count(&stats, class, *relation, name);
+                    match counts.iter_mut().find(|(i, _)| i == relation) {
+                        Some((_, existing)) => *existing = existing.min(count),
+                        None => counts.push((*relation, count)),
+                    }
+                }
+                counts
+            })
+            .collect();
+        Self {
+            stats,
+            distinct_counts,
+        }
+    }
+
+    fn distinct_count(
+        stats: &[RelationStats],
+        class: &[RelationColumn],
+        relation: usize,
+        name: &str,
+    ) -> f64 {
+        let relation_stats = &stats[relation];
+        relation_stats
+            .statistics
+            .as_ref()
+            .and_then(|s| s.columns.get(name))
+            .and_then(integer_range_width)
+            // Without statistics, assume that the keys on one side of the join are drawn from the (unique) keys on
+            // the other side, as in a foreign key join.
+            .unwrap_or_else(|| {
+                class
+                    .iter()
+                    .filter(|(i, _)| *i != relation)
+                    .map(|(i, _)| stats[*i].unfiltered_num_rows)
+                    .fold(0.0, f64::max)
+            })
+            .min(relation_stats.num_rows)
+            .max(1.0)
+    }
+
+    fn cardinality(&self, relations: usize) -> f64 {
+        let mut cardinality = self
+            .stats
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| contains(relations, *i))
+            .map(|(_, stats)| stats.num_rows)
+            .product::<f64>();
+        for counts in &self.distinct_counts {
+            let mut counts = counts
+                .iter()
+                .filter(|(i, _)| contains(relations, *i))
+                .map(|(_, count)| *count)
+                .collect::<Vec<_>>();
+            // Each key in the class matches with probability 1 / (number of distinct keys) for all but the
+            // relation with the fewest distinct keys.
+            counts.sort_by(|a, b| a.total_cmp(b));
+            cardinality /= counts.iter().skip(1).product::<f64>();
+        }
+        cardinality
+    }
+
+    fn size_bytes(&self, relations: usize) -> f64 {
+        let row_size = self
+            .stats
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| contains(relations, *i))
+            .map(|(_, stats)| stats.row_size_bytes())
+            .sum::<f64>();
+        self.cardinality(relations) * row_size
+    }
+
+    /// Find the left-deep join order without cross products that minimizes the total size of the intermediate join
+    /// results, or None if the join graph is not connected.
+    fn best_order(&self) -> Option<Vec<usize>> {
+        let num_relations = self.stats.len();
+        let all_relations = (1usize << num_relations) - 1;
+        let mut best: Vec<Option<(f64, Vec<usize>)>> = vec![None; all_relations + 1];
+        for i in 0..num_relations {
+            best[1 << i] = Some((0.0, vec![i]));
+        }
+        for joined in 1..all_relations {
+            let Some((cost, order)) = best[joined].clone() else {
+                continue;
+            };
+            for next in 0..num_relations {
+                if contains(joined, next) || !self.is_connected(joined, next) {
+                    continue;
+                }
+                let next_joined = joined | (1 << next);
+                let next_cost = cost + self.size_bytes(next_joined);
+                if best[next_joined]
+                    .as_ref()
+                    .map_or(true, |(best_cost, _)| next_cost < *best_cost)
+                {
+                    let mut next_order = order.clone();
+                    next_order.push(next);
+                    best[next_joined] = Some((next_cost, next_order));
+                }
+            }
+        }
+        best[all_relations].take().map(|(_, order)| order)
+    }
+
+    /// Whether the relation is connected to any of the relations in the `joined` set by a join condition.
+    fn is_connected(&self, joined: usize, relation: usize) -> bool {
+        self.distinct_counts.iter().any(|counts| {
+            counts.iter().any(|(i, _)| *i == relation)
+                && counts.iter().any(|(i, _)| contains(joined, *i))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{datatypes::Field, DataType, IntoSeries};
+    use daft_dsl::{col, lit};
+    use daft_stats::TableStatistics;
+    use daft_table::Table;
+
+    use crate::{
+        logical_optimization::{rules::ReorderJoins, test::assert_optimized_plan_with_rules_eq},
+        source_info::SourceInfo,
+        test::{
+            dummy_scan_node, dummy_scan_operator, dummy_scan_operator_with_expensive_stats,
+            dummy_scan_operator_with_stats,
+        },
+        JoinType, LogicalPlan, ResourceRequest,
+    };
+
+    /// Helper that creates an optimizer with the ReorderJoins rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(plan, expected, vec![Box::new(ReorderJoins::new())])
+    }
+
+    fn int_fields(names: &[&str]) -> Vec<Field> {
+        names
+            .iter()
+            .map(|name| Field::new(*name, DataType::Int64))
+            .collect()
+    }
+
+    /// Column statistics with the provided (min, max) ranges for integer columns.
+    fn int_range_stats(ranges: &[(&str, i64, i64)]) -> DaftResult<TableStatistics> {
+        let columns = ranges
+            .iter()
+            .map(|(name, lower, upper)| {
+                daft_core::datatypes::Int64Array::from((*name, vec![*lower, *upper])).into_series()
+            })
+            .collect();
+        TableStatistics::from_stats_table(&Table::from_columns(columns)?)
+    }
+
+    /// Tests that a chain of joins is reordered to join the small tables first, with the smaller side as the
+    /// build side.
+    ///
+    /// big(1M rows) -k1- mid(1K rows) -k2- small(10 rows)
+    #[test]
+    fn reorder_three_table_chain() -> DaftResult<()> {
+        let big = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k1", "x"]),
+            1_000_000,
+            None,
+        ));
+        let mid = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k1", "k2"]),
+            1_000,
+            None,
+        ));
+        let small = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k2", "y"]),
+            10,
+            None,
+        ));
+        let plan = big
+            .join(
+                &mid,
+                vec![col("k1")],
+                vec![col("k1")],
+                JoinType::Inner,
+                None,
+            )?
+            .join(
+                &small,
+                vec![col("k2")],
+                vec![col("k2")],
+                JoinType::Inner,
+                None,
+            )?
+            .build();
+        let expected = small
+            .join(
+                &mid,
+                vec![col("k2")],
+                vec![col("k2")],
+                JoinType::Inner,
+                None,
+            )?
+            .join(
+                &big,
+                vec![col("k1")],
+                vec![col("k1")],
+                JoinType::Inner,
+                None,
+            )?
+            .project(
+                vec![col("k1"), col("x"), col("k2"), col("y")],
+                ResourceRequest::default(),
+            )?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that the filtered dimension of a star join is joined first, followed by the remaining dimensions
+    /// from narrowest to widest.
+    #[test]
+    fn reorder_four_table_star_with_filter() -> DaftResult<()> {
+        let fact = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["a_id", "b_id", "c_id"]),
+            1_000_000,
+            None,
+        ));
+        let dim_a = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["a_id", "a1", "a2"]),
+            100,
+            None,
+        ));
+        let dim_b = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["b_id", "b"]),
+            1_000,
+            None,
+        ))
+        .filter(col("b").lt(&lit(5)))?;
+        let dim_c = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["c_id", "c"]),
+            10,
+            None,
+        ));
+        let plan = fact
+            .join(
+                &dim_a,
+                vec![col("a_id")],
+                vec![col("a_id")],
+                JoinType::Inner,
+                None,
+            )?
+            .join(
+                &dim_b,
+                vec![col("b_id")],
+                vec![col("b_id")],
+                JoinType::Inner,
+                None,
+            )?
+            .join(
+                &dim_c,
+                vec![col("c_id")],
+                vec![col("c_id")],
+                JoinType::Inner,
+                None,
+            )?
+            .build();
+        let joined_b = dim_b.join(
+            &fact,
+            vec![col("b_id")],
+            vec![col("b_id")],
+            JoinType::Inner,
+            None,
+        )?;
+        let joined_c = dim_c.join(
+            &joined_b,
+            vec![col("c_id")],
+            vec![col("c_id")],
+            JoinType::Inner,
+            None,
+        )?;
+        let expected = dim_a
+            .join(
+                &joined_c,
+                vec![col("a_id")],
+                vec![col("a_id")],
+                JoinType::Inner,
+                None,
+            )?
+            .project(
+                ["a_id", "b_id", "c_id", "a1", "a2", "b", "c"]
+                    .into_iter()
+                    .map(col)
+                    .collect(),
+                ResourceRequest::default(),
+            )?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that column range statistics are used to estimate join selectivity in a six table star join.
+    ///
+    /// Only 1% of the fact table's k1 values and 10% of its k3 values have a match in their dimension tables, so those
+    /// dimensions are joined first.
+    #[test]
+    fn reorder_six_table_star_with_stats() -> DaftResult<()> {
+        let fact = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k1", "k2", "k3", "k4", "k5"]),
+            1_000_000,
+            Some(int_range_stats(&[
+                ("k1", 0, 999),
+                ("k2", 0, 99),
+                ("k3", 0, 9_999),
+                ("k4", 0, 49),
+                ("k5", 0, 19),
+            ])?),
+        ));
+        let d1 = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k1", "v1"]),
+            10,
+            None,
+        ));
+        let d2 = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k2", "v2a", "v2b"]),
+            100,
+            None,
+        ));
+        let d3 = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k3", "v3"]),
+            1_000,
+            None,
+        ));
+        let d4 = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k4", "v4"]),
+            50,
+            None,
+        ));
+        let d5 = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k5", "v5a", "v5b", "v5c"]),
+            20,
+            None,
+        ));
+        let plan = fact
+            .join(&d1, vec![col("k1")], vec![col("k1")], JoinType::Inner, None)?
+            .join(&d2, vec![col("k2")], vec![col("k2")], JoinType::Inner, None)?
+            .join(&d3, vec![col("k3")], vec![col("k3")], JoinType::Inner, None)?
+            .join(&d4, vec![col("k4")], vec![col("k4")], JoinType::Inner, None)?
+            .join(&d5, vec![col("k5")], vec![col("k5")], JoinType::Inner, None)?
+            .build();
+        let mut expected = fact.clone();
+        for (dim, key) in [
+            (&d1, "k1"),
+            (&d3, "k3"),
+            (&d4, "k4"),
+            (&d2, "k2"),
+            (&d5, "k5"),
+        ] {
+            expected = dim.join(
+                &expected,
+                vec![col(key)],
+                vec![col(key)],
+                JoinType::Inner,
+                None,
+            )?;
+        }
+        let expected = expected
+            .project(
+                [
+                    "k1", "k2", "k3", "k4", "k5", "v1", "v2a", "v2b", "v3", "v4", "v5a", "v5b",
+                    "v5c",
+                ]
+                .into_iter()
+                .map(col)
+                .collect(),
+                ResourceRequest::default(),
+            )?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that joins of two tables are not reordered.
+    #[test]
+    fn two_table_join_not_reordered() -> DaftResult<()> {
+        let big = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k", "x"]),
+            1_000_000,
+            None,
+        ));
+        let small = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k", "y"]),
+            10,
+            None,
+        ));
+        let plan = big
+            .join(
+                &small,
+                vec![col("k")],
+                vec![col("k")],
+                JoinType::Inner,
+                None,
+            )?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that joins are not reordered when the size of a relation can't be estimated.
+    #[test]
+    fn join_without_size_estimates_not_reordered() -> DaftResult<()> {
+        let t1 = dummy_scan_node(dummy_scan_operator(int_fields(&["k1", "x"])));
+        let t2 = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k1", "k2"]),
+            1_000,
+            None,
+        ));
+        let t3 = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k2", "y"]),
+            10,
+            None,
+        ));
+        let plan = t1
+            .join(&t2, vec![col("k1")], vec![col("k1")], JoinType::Inner, None)?
+            .join(&t3, vec![col("k2")], vec![col("k2")], JoinType::Inner, None)?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that scans whose scan tasks are expensive to list aren't listed, so their joins aren't reordered.
+    #[test]
+    fn join_with_expensive_size_estimates_not_reordered() -> DaftResult<()> {
+        let t1 = dummy_scan_node(dummy_scan_operator_with_expensive_stats(
+            int_fields(&["k1", "x"]),
+            1_000_000,
+        ));
+        let t2 = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k1", "k2"]),
+            1_000,
+            None,
+        ));
+        let t3 = dummy_scan_node(dummy_scan_operator_with_stats(
+            int_fields(&["k2", "y"]),
+            10,
+            None,
+        ));
+        let plan = t1
+            .join(&t2, vec![col("k1")], vec![col("k1")], JoinType::Inner, None)?
+            .join(&t3, vec![col("k2")], vec![col("k2")], JoinType::Inner, None)?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that the scan tasks listed to estimate the size of the scans are kept for the translation of the
+    /// plan.
+    #[test]
+    fn listed_scan_tasks_handed_over() -> DaftResult<()> {
+        let scans = [
+            (&["k1", "x"], 1_000_000),
+            (&["k1", "k2"], 1_000),
+            (&["k2", "y"], 10),
+        ]
+        .map(|(fields, num_rows)| {
+            dummy_scan_node(dummy_scan_operator_with_stats(
+                int_fields(fields),
+                num_rows,
+                None,
+            ))
+        });
+        let plan = scans[0]
+            .join(
+                &scans[1],
+                vec![col("k1")],
+                vec![col("k1")],
+                JoinType::Inner,
+                None,
+            )?
+            .join(
+                &scans[2],
+                vec![col("k2")],
+                vec![col("k2")],
+                JoinType::Inner,
+                None,
+            )?
+            .build();
+        assert!(!ReorderJoins::new().reorder(plan)?.is_no());
+        for scan in scans {
+            let LogicalPlan::Source(source) = scan.build().as_ref() else {
+                unreachable!()
+            };
+            let SourceInfo::ExternalInfo(info) = source.source_info.as_ref() else {
+                unreachable!()
+            };
+            assert_eq!(
+                info.listed_scan_tasks.take().map(|tasks| tasks.len()),
+                Some(1)
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,358 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::TreeNode;
+use daft_core::{schema::Schema, DataType, Series};
+use daft_dsl::{binary_op, Expr, LiteralValue, Operator};
+use daft_table::Table;
+
+use crate::{
+    logical_ops::{Aggregate, Filter, Project},
+    LogicalPlan,
+};
+
+use super::{ApplyOrder, OptimizerRule, Transformed};
+
+/// Optimization rule for simplifying the expressions of Filter, Project and Aggregate nodes.
+///
+/// Subexpressions over literals are folded into a single literal, boolean identities (`x & true`, `x | false`,
+/// `x & x`, `x | x` and `~(~x)`) are eliminated, casts of an expression to its own type and nested aliases are
+/// removed, and comparisons with a literal on the left are flipped so that the column is on the left, which is
+/// the form that stats-based pruning of scan tasks expects.
+#[derive(Default, Debug)]
+pub struct SimplifyExpressions {}
+
+impl SimplifyExpressions {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for SimplifyExpressions {
+    fn apply_order(&self) -> ApplyOrder {
+        ApplyOrder::TopDown
+    }
+
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        match plan.as_ref() {
+            LogicalPlan::Filter(Filter { input, predicate }) => {
+                let new_predicate = simplify_expr(predicate.clone(), &input.schema())?;
+                if new_predicate == *predicate {
+                    return Ok(Transformed::No(plan));
+                }
+                // Filter is a no-op.
+                if new_predicate == Expr::Literal(LiteralValue::Boolean(true)) {
+                    return Ok(Transformed::Yes(input.clone()));
+                }
+                let new_filter: LogicalPlan = Filter::try_new(input.clone(), new_predicate)?.into();
+                Ok(Transformed::Yes(new_filter.into()))
+            }
+            LogicalPlan::Project(Project {
+                input,
+                projection,
+                resource_request,
+                ..
+            }) => {
+                let schema = input.schema();
+                let new_projection = projection
+                    .iter()
+                    .map(|e| simplify_named_expr(e, &schema))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                if new_projection == *projection {
+                    return Ok(Transformed::No(plan));
+                }
+                let new_project: LogicalPlan =
+                    Project::try_new(input.clone(), new_projection, resource_request.clone())?
+                        .into();
+                Ok(Transformed::Yes(new_project.into()))
+            }
+            LogicalPlan::Aggregate(Aggregate {
+                input,
+                aggregations,
+                groupby,
+                ..
+            }) => {
+                let schema = input.schema();
+                let new_aggregations = aggregations
+                    .iter()
+                    .map(|agg| {
+                        let expr = Expr::Agg(agg.clone());
+                        // Aggregations can't be aliased, so keep the original if simplification renames it.
+                        match simplify_expr(expr.clone(), &schema)? {
+                            Expr::Agg(new_agg) if expr.name()? == new_agg.name()? => Ok(new_agg),
+                            _ => Ok(agg.clone()),
+                        }
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                let new_groupby = groupby
+                    .iter()
+                    .map(|e| simplify_named_expr(e, &schema))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                if new_aggregations == *aggregations && new_groupby == *groupby {
+                    return Ok(Transformed::No(plan));
+                }
+                let new_aggregate: LogicalPlan =
+                    Aggregate::try_new(input.clone(), new_aggregations, new_groupby)?.into();
+                Ok(Transformed::Yes(new_aggregate.into()))
+            }
+            _ => Ok(Transformed::No(plan)),
+        }
+    }
+}
+
+/// Simplify an expression whose output name must be preserved, re-aliasing it if simplification changed its name.
+fn simplify_named_expr(expr: &Expr, schema: &Schema) -> DaftResult<Expr> {
+    let name = expr.name()?;
+    let simplified = simplify_expr(expr.clone(), schema)?;
+    if simplified.name()? == name {
+        Ok(simplified)
+    } else {
+        Ok(simplified.alias(name))
+    }
+}
+
+/// Simplify an expression bottom-up; the name of the simplified expression may differ from the original.
+fn simplify_expr(expr: Expr, schema: &Schema) -> DaftResult<Expr> {
+    expr.transform_up(&|e| {
+        Ok(match simplify_node(&e, schema) {
+            Some(simplified) => common_treenode::Transformed::Yes(simplified),
+            None => common_treenode::Transformed::No(e),
+        })
+    })
+}
+
+/// Simplify a single expression node whose children have already been simplified.
+fn simplify_node(expr: &Expr, schema: &Schema) -> Option<Expr> {
+    if let Some(folded) = fold_literals(expr) {
+        return Some(folded);
+    }
+    let is_boolean = |e: &Expr| matches!(e.get_type(schema), Ok(DataType::Boolean));
+    match expr {
+        // Aliases within an expression only rename intermediate results.
+        Expr::Alias(child, _) => Some(child.as_ref().clone()),
+        Expr::Cast(child, dtype) if matches!(child.get_type(schema), Ok(child_dtype) if child_dtype == *dtype) => {
+            Some(child.as_ref().clone())
+        }
+        Expr::Not(child) => match child.as_ref() {
+            Expr::Not(inner) if is_boolean(inner) => Some(inner.as_ref().clone()),
+            _ => None,
+        },
+        Expr::BinaryOp { op, left, right } => match (op, left.as_ref(), right.as_ref()) {
+            (Operator::And, e, Expr::Literal(LiteralValue::Boolean(true)))
+            | (Operator::And, Expr::Literal(LiteralValue::Boolean(true)), e)
+            | (Operator::Or, e, Expr::Literal(LiteralValue::Boolean(false)))
+            | (Operator::Or, Expr::Literal(LiteralValue::Boolean(false)), e)
+                if is_boolean(e) =>
+            {
+                Some(e.clone())
+            }
+            (Operator::And | Operator::Or, l, r) if l == r && is_boolean(l) => Some(l.clone()),
+            (op, l @ Expr::Literal(_), r) if !matches!(r, Expr::Literal(_)) => {
+                flip_comparison(*op).map(|flipped| binary_op(flipped, r, l))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The comparison operator that gives the same result when its operands are swapped.
+fn flip_comparison(op: Operator) -> Option<Operator> {
+    match op {
+        Operator::Eq => Some(Operator::Eq),
+        Operator::NotEq => Some(Operator::NotEq),
+        Operator::Lt => Some(Operator::Gt),
+        Operator::LtEq => Some(Operator::GtEq),
+        Operator::Gt => Some(Operator::Lt),
+        Operator::GtEq => Some(Operator::LtEq),
+        _ => None,
+    }
+}
+
+/// Evaluate an expression whose children are all scalar literals into a single literal, if the result can be
+/// represented as one.
+fn fold_literals(expr: &Expr) -> Option<Expr> {
+    match expr {
+        // Functions may be non-deterministic (e.g. Python UDFs), so we leave them to be evaluated at runtime.
+        Expr::Literal(_)
+        | Expr::Column(_)
+        | Expr::Agg(_)
+        | Expr::Window(_)
+        | Expr::Function { .. } => return None,
+        _ => {}
+    }
+    let all_scalar_literals = expr
+        .children()
+        .iter()
+        .all(|child| matches!(child.as_ref(), Expr::Literal(value) if is_scalar_literal(value)));
+    if !all_scalar_literals {
+        return None;
+    }
+    // Literals evaluate to single-element series, so the table doesn't need any columns. Expressions that fail to
+    // evaluate are left for execution to report.
+    let table = Table::empty(None)
+        .ok()?
+        .eval_expression_list(std::slice::from_ref(expr))
+        .ok()?;
+    series_to_literal(table.get_column_by_index(0).ok()?).map(Expr::Literal)
+}
+
+fn is_scalar_literal(value: &LiteralValue) -> bool {
+    match value {
+        LiteralValue::Series(_) => false,
+        #[cfg(feature = "python")]
+        LiteralValue::Python(_) => false,
+        _ => true,
+    }
+}
+
+fn series_to_literal(series: &Series) -> Option<LiteralValue> {
+    if series.len() != 1 {
+        return None;
+    }
+    let value = match series.data_type() {
+        DataType::Null => LiteralValue::Null,
+        DataType::Boolean => LiteralValue::Boolean(series.bool().ok()?.get(0)?),
+        DataType::Utf8 => LiteralValue::Utf8(series.utf8().ok()?.get(0)?.to_string()),
+        DataType::Int32 => LiteralValue::Int32(series.i32().ok()?.get(0)?),
+        DataType::UInt32 => LiteralValue::UInt32(series.u32().ok()?.get(0)?),
+        DataType::Int64 => LiteralValue::Int64(series.i64().ok()?.get(0)?),
+        DataType::UInt64 => LiteralValue::UInt64(series.u64().ok()?.get(0)?),
+        DataType::Float64 => LiteralValue::Float64(series.f64().ok()?.get(0)?),
+        // Typed nulls and other types don't have a literal representation.
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{datatypes::Field, DataType};
+    use daft_dsl::{col, lit};
+
+    use crate::{
+        logical_optimization::{
+            rules::SimplifyExpressions, test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    /// Helper that creates an optimizer with the SimplifyExpressions rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![Box::new(SimplifyExpressions::new())],
+        )
+    }
+
+    fn dummy_fields() -> Vec<Field> {
+        vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Boolean),
+        ]
+    }
+
+    /// Tests that literal subexpressions are folded and that the literal is moved to the right of the comparison.
+    ///
+    /// Filter(lit(1) + lit(2) > a) -> Filter(a < lit(3))
+    #[test]
+    fn fold_literals_and_normalize_comparison() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(dummy_fields()));
+        let plan = scan.filter((lit(1) + lit(2)).gt(&col("a")))?.build();
+        let expected = scan.filter(col("a").lt(&lit(3)))?.build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that boolean identities are eliminated, and that a Filter whose predicate folds to true is removed.
+    ///
+    /// Filter(b & lit(true)) -> Filter(b)
+    /// Filter(~(~b) | lit(false)) -> Filter(b)
+    /// Filter(lit(true) | lit(false)) -> Source
+    #[test]
+    fn boolean_identities() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(dummy_fields()));
+        let expected = scan.filter(col("b"))?.build();
+        let plan = scan.filter(col("b").and(&lit(true)))?.build();
+        assert_optimized_plan_eq(plan, expected.clone())?;
+        let plan = scan.filter(col("b").not().not().or(&lit(false)))?.build();
+        assert_optimized_plan_eq(plan, expected)?;
+        let plan = scan.filter(lit(true).or(&lit(false)))?.build();
+        assert_optimized_plan_eq(plan, scan.build())?;
+        Ok(())
+    }
+
+    /// Tests that redundant casts and aliases are removed from projections while keeping the output names.
+    ///
+    /// Project(a.cast(Int64), a.alias("x").alias("y"), lit(1) < a, b & b)
+    /// -> Project(a, a.alias("y"), (a > lit(1)).alias("literal"), b)
+    #[test]
+    fn simplify_projection() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(dummy_fields()));
+        let plan = scan
+            .project(
+                vec![
+                    col("a").cast(&DataType::Int64),
+                    col("a").alias("x").alias("y"),
+                    lit(1).lt(&col("a")),
+                    col("b").and(&col("b")),
+                ],
+                Default::default(),
+            )?
+            .build();
+        let expected = scan
+            .project(
+                vec![
+                    col("a"),
+                    col("a").alias("y"),
+                    col("a").gt(&lit(1)).alias("literal"),
+                    col("b"),
+                ],
+                Default::default(),
+            )?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that aggregation inputs are simplified.
+    ///
+    /// Aggregate(sum(a + (lit(1) - lit(1))), groupby=[b & lit(true)]) -> Aggregate(sum(a + lit(0)), groupby=[b])
+    #[test]
+    fn simplify_aggregation() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(dummy_fields()));
+        let plan = scan
+            .aggregate(
+                vec![(col("a") + (lit(1) - lit(1))).sum()],
+                vec![col("b").and(&lit(true)).alias("b")],
+            )?
+            .build();
+        let expected = scan
+            .aggregate(vec![(col("a") + lit(0)).sum()], vec![col("b")])?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that expressions that are already simple are left untouched.
+    #[test]
+    fn simple_expressions_unchanged() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(dummy_fields()));
+        let plan = scan
+            .filter(col("a").lt(&lit(3)).and(&col("b")))?
+            .project(vec![col("a") + lit(1), col("b")], Default::default())?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
@@ -25,6 +25,7 @@ pub enum LogicalPlan {
     Sink(Sink),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
 }
 
 impl LogicalPlan {
@@ -50,6 +51,7 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => {
                 schema.clone()
             }
+            Self::Window(Window { output_schema, .. }) => output_schema.clone(),
         }
     }
 
@@ -116,6 +118,16 @@ impl LogicalPlan {
                     .collect();
                 vec![res]
             }
+            Self::Window(window) => {
+                let res = window
+                    .window_exprs
+                    .iter()
+                    .chain(window.partition_by.iter())
+                    .chain(window.order_by.iter())
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![res]
+            }
             Self::Join(join) => {
                 let left = join.left_on.iter().flat_map(get_required_columns).collect();
                 let right = join
@@ -146,6 +158,7 @@ impl LogicalPlan {
             Self::Sink(Sink { input, .. }) => vec![input],
             Self::Sample(Sample { input, .. }) => vec![input],
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => vec![input],
+            Self::Window(Window { input, .. }) => vec![input],
         }
     }
 
@@ -164,6 +177,7 @@ impl LogicalPlan {
                 Self::Distinct(_) => Self::Distinct(Distinct::new(input.clone())),
                 Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::try_new(input.clone(), aggregations.clone(), groupby.clone()).unwrap()),
                 Self::Sink(Sink { sink_info, .. }) => Self::Sink(Sink::try_new(input.clone(), sink_info.clone()).unwrap()),
+                Self::Window(Window { window_exprs, partition_by, order_by, descending, frame, .. }) => Self::Window(Window::try_new(input.clone(), window_exprs.clone(), partition_by.clone(), order_by.clone(), descending.clone(), Some(*frame)).unwrap()),
                 _ => panic!("Logical op {} has two inputs, but got one", self),
             },
             [input1, input2] => match self {
@@ -207,6 +221,7 @@ impl LogicalPlan {
             Self::Sink(..) => "Sink",
             Self::Sample(..) => "Sample",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::Window(..) => "Window",
         };
         name.to_string()
     }
@@ -229,6 +244,7 @@ impl LogicalPlan {
                 vec![format!("Sample: {fraction}", fraction = sample.fraction)]
             }
             Self::MonotonicallyIncreasingId(_) => vec!["MonotonicallyIncreasingId".to_string()],
+            Self::Window(window) => window.multiline_display(),
         }
     }
 
@@ -291,3 +307,4 @@ impl_from_data_struct_for_logical_plan!(Join);
 impl_from_data_struct_for_logical_plan!(Sink);
 impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
+impl_from_data_struct_for_logical_plan!(Window);
@@ -218,8 +218,8 @@ impl Project {
                     predicate: newpred.into(),
                 })
             }
-            // Cannot have agg exprs in partition specs.
-            Expr::Agg(_) => Err(()),
+            // Cannot have agg or window exprs in partition specs.
+            Expr::Agg(_) | Expr::Window(_) => Err(()),
         }
     }
 
@@ -0,0 +1,69 @@
+use daft_dsl::{Expr, WindowFrame};
+use itertools::Itertools;
+
+use crate::physical_plan::PhysicalPlanRef;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Window {
+    // Upstream node.
+    pub input: PhysicalPlanRef,
+
+    /// Window functions and frame aggregations to evaluate.
+    pub window_exprs: Vec<Expr>,
+
+    /// Window partitioning. Every window partition must be fully contained in a single input partition.
+    pub partition_by: Vec<Expr>,
+
+    /// Ordering of rows within each window partition.
+    pub order_by: Vec<Expr>,
+    pub descending: Vec<bool>,
+
+    /// Rows of each window partition that frame aggregations are computed over.
+    pub frame: WindowFrame,
+}
+
+impl Window {
+    pub(crate) fn new(
+        input: PhysicalPlanRef,
+        window_exprs: Vec<Expr>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<Expr>,
+        descending: Vec<bool>,
+        frame: WindowFrame,
+    ) -> Self {
+        Self {
+            input,
+            window_exprs,
+            partition_by,
+            order_by,
+            descending,
+            frame,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Window: {}",
+            self.window_exprs.iter().map(|e| e.to_string()).join(", ")
+        ));
+        if !self.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.partition_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        if !self.order_by.is_empty() {
+            let pairs = self
+                .order_by
+                .iter()
+                .zip(self.descending.iter())
+                .map(|(ob, d)| format!("({}, {})", ob, if *d { "descending" } else { "ascending" }))
+                .join(", ");
+            res.push(format!("Order by = {}", pairs));
+        }
+        res.push(format!("Frame = {}", self.frame));
+        res
+    }
+}
@@ -25,6 +25,7 @@ use crate::{
         UnknownClusteringConfig,
     },
     physical_ops::*,
+    JoinType,
 };
 
 #[cfg(feature = "python")]
@@ -47,6 +48,7 @@ pub enum PhysicalPlan {
     Split(Split),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
     Coalesce(Coalesce),
     Flatten(Flatten),
     FanoutRandom(FanoutRandom),
@@ -91,6 +93,32 @@ impl PhysicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 input.clustering_spec().clone()
             }
+            Self::Window(Window {
+                input,
+                partition_by,
+                order_by,
+                descending,
+                ..
+            }) => {
+                // Each output partition is sorted by the window partitioning and ordering keys.
+                let num_partitions = input.clustering_spec().num_partitions();
+                if !partition_by.is_empty() {
+                    ClusteringSpec::Hash(HashClusteringConfig::new(
+                        num_partitions,
+                        partition_by.clone(),
+                    ))
+                    .into()
+                } else if !order_by.is_empty() {
+                    ClusteringSpec::Range(RangeClusteringConfig::new(
+                        num_partitions,
+                        order_by.clone(),
+                        descending.clone(),
+                    ))
+                    .into()
+                } else {
+                    input.clustering_spec()
+                }
+            }
 
             Self::Sort(Sort {
                 input,
@@ -162,6 +190,7 @@ impl PhysicalPlan {
                 left,
                 right,
                 left_on,
+                join_type,
                 ..
             }) => {
                 let input_clustering_spec = left.clustering_spec();
@@ -174,6 +203,10 @@ impl PhysicalPlan {
                     // TODO(Clark): Consolidate this logic with the planner logic when we push the partition spec
                     // to be an entirely planner-side concept.
                     1 => input_clustering_spec,
+                    // Rows that only exist on the right side don't have values for the left join keys.
+                    num_partitions if matches!(join_type, JoinType::Right | JoinType::Outer) => {
+                        ClusteringSpec::Unknown(UnknownClusteringConfig::new(num_partitions)).into()
+                    }
                     num_partitions => ClusteringSpec::Hash(HashClusteringConfig::new(
                         num_partitions,
                         left_on.clone(),
@@ -188,17 +221,26 @@ impl PhysicalPlan {
                 left,
                 right,
                 left_on,
+                join_type,
                 ..
-            }) => ClusteringSpec::Range(RangeClusteringConfig::new(
-                max(
+            }) => {
+                let num_partitions = max(
                     left.clustering_spec().num_partitions(),
                     right.clustering_spec().num_partitions(),
-                ),
-                left_on.clone(),
-                // TODO(Clark): Propagate descending vec once sort-merge join supports descending sort orders.
-                std::iter::repeat(false).take(left_on.len()).collect(),
-            ))
-            .into(),
+                );
+                // Outer joins append their unmatched rows after the merged rows, so their output isn't sorted.
+                if matches!(join_type, JoinType::Inner | JoinType::Semi | JoinType::Anti) {
+                    ClusteringSpec::Range(RangeClusteringConfig::new(
+                        num_partitions,
+                        left_on.clone(),
+                        // TODO(Clark): Propagate descending vec once sort-merge join supports descending sort orders.
+                        std::iter::repeat(false).take(left_on.len()).collect(),
+                    ))
+                    .into()
+                } else {
+                    ClusteringSpec::Unknown(UnknownClusteringConfig::new(num_partitions)).into()
+                }
+            }
             Self::TabularWriteParquet(TabularWriteParquet { input, .. }) => input.clustering_spec(),
             Self::TabularWriteCsv(TabularWriteCsv { input, .. }) => input.clustering_spec(),
             Self::TabularWriteJson(TabularWriteJson { input, .. }) => input.clustering_spec(),
@@ -228,6 +270,9 @@ impl PhysicalPlan {
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 input.approximate_size_bytes()
             }
+            // Assume that the appended window columns are small relative to the input.
+            // TODO: Estimate the size of the window columns from their dtypes.
+            Self::Window(Window { input, .. }) => input.approximate_size_bytes(),
             Self::Sample(Sample {
                 input, fraction, ..
             }) => input
@@ -312,6 +357,7 @@ impl PhysicalPlan {
             Self::SortMergeJoin(SortMergeJoin { left, right, .. }) => vec![left, right],
             Self::Concat(Concat { input, other }) => vec![input, other],
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => vec![input],
+            Self::Window(Window { input, .. }) => vec![input],
         }
     }
 
@@ -337,6 +383,7 @@ impl PhysicalPlan {
                 Self::FanoutByHash(FanoutByHash { num_partitions, partition_by, .. }) => Self::FanoutByHash(FanoutByHash::new(input.clone(), *num_partitions, partition_by.clone())),
                 Self::FanoutByRange(FanoutByRange { num_partitions, sort_by, descending, .. }) => Self::FanoutByRange(FanoutByRange::new(input.clone(), *num_partitions, sort_by.clone(), descending.clone())),
                 Self::ReduceMerge(..) => Self::ReduceMerge(ReduceMerge::new(input.clone())),
+                Self::Window(Window { window_exprs, partition_by, order_by, descending, frame, .. }) => Self::Window(Window::new(input.clone(), window_exprs.clone(), partition_by.clone(), order_by.clone(), descending.clone(), *frame)),
                 Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::new(input.clone(), aggregations.clone(), groupby.clone())),
                 Self::TabularWriteParquet(TabularWriteParquet { schema, file_info, .. }) => Self::TabularWriteParquet(TabularWriteParquet::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteCsv(TabularWriteCsv { schema, file_info, .. }) => Self::TabularWriteCsv(TabularWriteCsv::new(schema.clone(), file_info.clone(), input.clone())),
@@ -394,6 +441,7 @@ impl PhysicalPlan {
             Self::TabularWriteCsv(..) => "TabularWriteCsv",
             Self::TabularWriteJson(..) => "TabularWriteJson",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::Window(..) => "Window",
             #[cfg(feature = "python")]
             Self::IcebergWrite(..) => "IcebergWrite",
         };
@@ -432,6 +480,7 @@ impl PhysicalPlan {
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
                 monotonically_increasing_id.multiline_display()
             }
+            Self::Window(window) => window.multiline_display(),
             #[cfg(feature = "python")]
             Self::IcebergWrite(iceberg_info) => iceberg_info.multiline_display(),
         }
@@ -705,6 +754,41 @@ impl PhysicalPlan {
                     .call1((upstream_iter, column_name))?;
                 Ok(py_iter.into())
             }
+            PhysicalPlan::Window(Window {
+                input,
+                window_exprs,
+                partition_by,
+                order_by,
+                descending,
+                frame,
+            }) => {
+                let upstream_iter = input.to_partition_tasks(py, psets)?;
+                let window_pyexprs: Vec<PyExpr> = window_exprs
+                    .iter()
+                    .map(|expr| PyExpr::from(expr.clone()))
+                    .collect();
+                let partition_by_pyexprs: Vec<PyExpr> = partition_by
+                    .iter()
+                    .map(|expr| PyExpr::from(expr.clone()))
+                    .collect();
+                let order_by_pyexprs: Vec<PyExpr> = order_by
+                    .iter()
+                    .map(|expr| PyExpr::from(expr.clone()))
+                    .collect();
+                let py_iter = py
+                    .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                    .getattr(pyo3::intern!(py, "window"))?
+                    .call1((
+                        upstream_iter,
+                        window_pyexprs,
+                        partition_by_pyexprs,
+                        order_by_pyexprs,
+                        descending.clone(),
+                        frame.start,
+                        frame.end,
+                    ))?;
+                Ok(py_iter.into())
+            }
             PhysicalPlan::Sort(Sort {
                 input,
                 sort_by,
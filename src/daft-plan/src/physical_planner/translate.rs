@@ -6,19 +6,20 @@ use std::{
 };
 
 use common_daft_config::DaftExecutionConfig;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use daft_core::count_mode::CountMode;
-use daft_core::DataType;
+use daft_core::{schema::Schema, DataType};
 use daft_dsl::Expr;
-use daft_scan::ScanExternalInfo;
+use daft_scan::{ScanExternalInfo, ScanTaskRef};
+use itertools::Itertools;
 
 use crate::logical_ops::{
     Aggregate as LogicalAggregate, Distinct as LogicalDistinct, Explode as LogicalExplode,
     Filter as LogicalFilter, Join as LogicalJoin, Limit as LogicalLimit,
     MonotonicallyIncreasingId as LogicalMonotonicallyIncreasingId, Project as LogicalProject,
     Repartition as LogicalRepartition, Sample as LogicalSample, Sink as LogicalSink,
-    Sort as LogicalSort, Source,
+    Sort as LogicalSort, Source, Window as LogicalWindow,
 };
 use crate::logical_plan::LogicalPlan;
 use crate::partitioning::{
@@ -28,7 +29,7 @@ use crate::physical_plan::PhysicalPlan;
 use crate::sink_info::{OutputFileInfo, SinkInfo};
 use crate::source_info::SourceInfo;
 use crate::FileFormat;
-use crate::{physical_ops::*, JoinStrategy};
+use crate::{physical_ops::*, JoinStrategy, JoinType};
 
 #[cfg(feature = "python")]
 use crate::physical_ops::InMemoryScan;
@@ -44,9 +45,15 @@ pub(super) fn translate_single_logical_node(
                 pushdowns,
                 scan_op,
                 source_schema,
+                listed_scan_tasks,
                 ..
             }) => {
-                let scan_tasks = scan_op.0.to_scan_tasks(pushdowns.clone())?;
+                // Reuse the scan tasks listed while optimizing the plan, if any.
+                let scan_tasks: Box<dyn Iterator<Item = DaftResult<ScanTaskRef>>> =
+                    match listed_scan_tasks.take() {
+                        Some(scan_tasks) => Box::new(scan_tasks.into_iter().map(Ok)),
+                        None => scan_op.0.to_scan_tasks(pushdowns.clone())?,
+                    };
 
                 let scan_tasks = daft_scan::scan_task_iters::split_by_row_groups(
                     scan_tasks,
@@ -54,6 +61,17 @@ pub(super) fn translate_single_logical_node(
                     cfg.scan_tasks_min_size_bytes,
                     cfg.scan_tasks_max_size_bytes,
                 );
+                let scan_tasks = daft_scan::scan_task_iters::split_by_stripes(
+                    scan_tasks,
+                    cfg.orc_split_stripes_max_files,
+                    cfg.scan_tasks_min_size_bytes,
+                    cfg.scan_tasks_max_size_bytes,
+                );
+                let scan_tasks = daft_scan::scan_task_iters::split_by_byte_ranges(
+                    scan_tasks,
+                    cfg.split_csv_by_byte_ranges,
+                    cfg.scan_tasks_max_size_bytes,
+                );
 
                 // Apply transformations on the ScanTasks to optimize
                 let scan_tasks = daft_scan::scan_task_iters::merge_by_sizes(
@@ -261,6 +279,13 @@ pub(super) fn translate_single_logical_node(
         }) => {
             use daft_dsl::AggExpr::{self, *};
             use daft_dsl::Expr::Column;
+            use daft_dsl::{
+                functions::{
+                    moments::{moments_stat, MomentsExpr},
+                    sketch::{hll_cardinality, sketch_percentile},
+                },
+                ApproxPercentileParams,
+            };
             let input_physical = physical_children.pop().expect("requires 1 input");
 
             let num_input_partitions = input_physical.clustering_spec().num_partitions();
@@ -271,6 +296,179 @@ pub(super) fn translate_single_logical_node(
                     aggregations.clone(),
                     groupby.clone(),
                 )),
+                _ if aggregations.iter().any(|agg_expr| {
+                    matches!(
+                        agg_expr,
+                        CountDistinct(_) | SumDistinct(_) | ListDistinct(_)
+                    )
+                }) =>
+                {
+                    let schema = logical_plan.schema();
+                    let distinct_inputs = aggregations
+                        .iter()
+                        .map(|agg_expr| match agg_expr {
+                            CountDistinct(e) | SumDistinct(e) | ListDistinct(e) => Some(e),
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>();
+                    match distinct_inputs {
+                        Some(distinct_inputs)
+                            if distinct_inputs
+                                .iter()
+                                .map(|e| e.semantic_id(&schema))
+                                .all_equal() =>
+                        {
+                            // All aggregations are distinct aggregations of the same values, so we first dedup the
+                            // (group keys, value) pairs locally and across partitions by repartitioning on them. Every
+                            // distinct value of a group then lives in exactly one partition, so each partition can
+                            // aggregate its own values and the partial results are merged in a second stage.
+                            let value_id: Arc<str> = format!(
+                                "{}.local_distinct()",
+                                distinct_inputs[0].semantic_id(&schema).id
+                            )
+                            .into();
+                            let mut dedup_keys = groupby.clone();
+                            dedup_keys.push(Column(value_id.clone()));
+                            let num_partitions = min(
+                                num_input_partitions,
+                                cfg.shuffle_aggregation_default_partitions,
+                            );
+
+                            let mut local_dedup_keys = groupby.clone();
+                            local_dedup_keys.push(distinct_inputs[0].alias(value_id.clone()));
+                            let local_dedup = PhysicalPlan::Aggregate(Aggregate::new(
+                                input_physical.into(),
+                                vec![],
+                                local_dedup_keys,
+                            ));
+                            let split_op = PhysicalPlan::FanoutByHash(FanoutByHash::new(
+                                local_dedup.into(),
+                                num_partitions,
+                                dedup_keys,
+                            ));
+                            let dedup_plan =
+                                PhysicalPlan::ReduceMerge(ReduceMerge::new(split_op.into()));
+
+                            // Semantic column name -> AggExpr
+                            let mut first_stage_aggs: HashMap<Arc<str>, AggExpr> = HashMap::new();
+                            let mut second_stage_aggs: HashMap<Arc<str>, AggExpr> = HashMap::new();
+                            let mut final_exprs: Vec<Expr> = groupby.clone();
+                            for agg_expr in aggregations {
+                                let output_name = agg_expr.name().unwrap();
+                                let value = Column(value_id.clone());
+                                let (first_stage_agg, partial_id) = match agg_expr {
+                                    CountDistinct(_) => {
+                                        let partial_id = CountDistinct(value.clone().into())
+                                            .semantic_id(&schema)
+                                            .id;
+                                        (
+                                            CountDistinct(value.alias(partial_id.clone()).into()),
+                                            partial_id,
+                                        )
+                                    }
+                                    SumDistinct(_) => {
+                                        let partial_id = SumDistinct(value.clone().into())
+                                            .semantic_id(&schema)
+                                            .id;
+                                        (
+                                            SumDistinct(value.alias(partial_id.clone()).into()),
+                                            partial_id,
+                                        )
+                                    }
+                                    ListDistinct(_) => {
+                                        let partial_id = ListDistinct(value.clone().into())
+                                            .semantic_id(&schema)
+                                            .id;
+                                        (
+                                            ListDistinct(value.alias(partial_id.clone()).into()),
+                                            partial_id,
+                                        )
+                                    }
+                                    _ => unreachable!(),
+                                };
+                                // Distinct counts and sums of disjoint values are summed, and distinct lists
+                                // are concatenated.
+                                let partial = Column(partial_id.clone());
+                                let (second_stage_agg, merged_id) = match agg_expr {
+                                    ListDistinct(_) => {
+                                        let merged_id =
+                                            Concat(partial.clone().into()).semantic_id(&schema).id;
+                                        (Concat(partial.alias(merged_id.clone()).into()), merged_id)
+                                    }
+                                    _ => {
+                                        let merged_id =
+                                            Sum(partial.clone().into()).semantic_id(&schema).id;
+                                        (Sum(partial.alias(merged_id.clone()).into()), merged_id)
+                                    }
+                                };
+                                first_stage_aggs
+                                    .entry(partial_id)
+                                    .or_insert(first_stage_agg);
+                                second_stage_aggs
+                                    .entry(merged_id.clone())
+                                    .or_insert(second_stage_agg);
+                                final_exprs.push(Column(merged_id).alias(output_name));
+                            }
+
+                            let first_stage_agg = PhysicalPlan::Aggregate(Aggregate::new(
+                                dedup_plan.into(),
+                                first_stage_aggs.values().cloned().collect(),
+                                groupby.clone(),
+                            ));
+                            let gather_plan = if groupby.is_empty() {
+                                PhysicalPlan::Coalesce(Coalesce::new(
+                                    first_stage_agg.into(),
+                                    num_partitions,
+                                    1,
+                                ))
+                            } else {
+                                let split_op = PhysicalPlan::FanoutByHash(FanoutByHash::new(
+                                    first_stage_agg.into(),
+                                    num_partitions,
+                                    groupby.clone(),
+                                ));
+                                PhysicalPlan::ReduceMerge(ReduceMerge::new(split_op.into()))
+                            };
+                            let second_stage_agg = PhysicalPlan::Aggregate(Aggregate::new(
+                                gather_plan.into(),
+                                second_stage_aggs.values().cloned().collect(),
+                                groupby.clone(),
+                            ));
+
+                            let clustering_spec = second_stage_agg.clustering_spec().clone();
+                            PhysicalPlan::Project(Project::try_new(
+                                second_stage_agg.into(),
+                                final_exprs,
+                                Default::default(),
+                                clustering_spec,
+                            )?)
+                        }
+                        _ => {
+                            // Distinct aggregations mixed with other aggregations or over different values dedup
+                            // the values of each group locally, so every group needs to be colocated in a single
+                            // partition and all aggregations are done in a single stage.
+                            let gather_plan = if groupby.is_empty() {
+                                PhysicalPlan::Coalesce(Coalesce::new(
+                                    input_physical.into(),
+                                    num_input_partitions,
+                                    1,
+                                ))
+                            } else {
+                                let split_op = PhysicalPlan::FanoutByHash(FanoutByHash::new(
+                                    input_physical.into(),
+                                    num_input_partitions,
+                                    groupby.clone(),
+                                ));
+                                PhysicalPlan::ReduceMerge(ReduceMerge::new(split_op.into()))
+                            };
+                            PhysicalPlan::Aggregate(Aggregate::new(
+                                gather_plan.into(),
+                                aggregations.clone(),
+                                groupby.clone(),
+                            ))
+                        }
+                    }
+                }
                 _ => {
                     let schema = logical_plan.schema();
 
@@ -426,6 +624,248 @@ pub(super) fn translate_single_logical_node(
                                 final_exprs
                                     .push(Column(concat_of_concat_id.clone()).alias(output_name));
                             }
+                            ApproxSketch(e) => {
+                                let sketch_id = agg_expr.semantic_id(&schema).id;
+                                let merge_sketch_id = MergeSketch(Column(sketch_id.clone()).into())
+                                    .semantic_id(&schema)
+                                    .id;
+                                first_stage_aggs
+                                    .entry(sketch_id.clone())
+                                    .or_insert(ApproxSketch(
+                                        e.alias(sketch_id.clone()).clone().into(),
+                                    ));
+                                second_stage_aggs.entry(merge_sketch_id.clone()).or_insert(
+                                    MergeSketch(
+                                        Column(sketch_id.clone())
+                                            .alias(merge_sketch_id.clone())
+                                            .into(),
+                                    ),
+                                );
+                                final_exprs
+                                    .push(Column(merge_sketch_id.clone()).alias(output_name));
+                            }
+                            MergeSketch(e) => {
+                                let merge_id = agg_expr.semantic_id(&schema).id;
+                                let merge_of_merge_id =
+                                    MergeSketch(Column(merge_id.clone()).into())
+                                        .semantic_id(&schema)
+                                        .id;
+                                first_stage_aggs
+                                    .entry(merge_id.clone())
+                                    .or_insert(MergeSketch(
+                                        e.alias(merge_id.clone()).clone().into(),
+                                    ));
+                                second_stage_aggs
+                                    .entry(merge_of_merge_id.clone())
+                                    .or_insert(MergeSketch(
+                                        Column(merge_id.clone())
+                                            .alias(merge_of_merge_id.clone())
+                                            .into(),
+                                    ));
+                                final_exprs
+                                    .push(Column(merge_of_merge_id.clone()).alias(output_name));
+                            }
+                            ApproxPercentile(ApproxPercentileParams {
+                                child: e,
+                                percentiles,
+                                force_list_output,
+                            }) => {
+                                // Build a sketch per partition, merge the sketches in the reduce stage, and only
+                                // compute the percentiles from the final merged sketches.
+                                let sketch_id = ApproxSketch(e.clone()).semantic_id(&schema).id;
+                                let merge_sketch_id = MergeSketch(Column(sketch_id.clone()).into())
+                                    .semantic_id(&schema)
+                                    .id;
+                                first_stage_aggs
+                                    .entry(sketch_id.clone())
+                                    .or_insert(ApproxSketch(
+                                        e.alias(sketch_id.clone()).clone().into(),
+                                    ));
+                                second_stage_aggs.entry(merge_sketch_id.clone()).or_insert(
+                                    MergeSketch(
+                                        Column(sketch_id.clone())
+                                            .alias(merge_sketch_id.clone())
+                                            .into(),
+                                    ),
+                                );
+                                let percentiles =
+                                    percentiles.iter().map(|p| p.0).collect::<Vec<_>>();
+                                final_exprs.push(
+                                    sketch_percentile(
+                                        &Column(merge_sketch_id.clone()),
+                                        &percentiles,
+                                        *force_list_output,
+                                    )
+                                    .alias(output_name),
+                                );
+                            }
+                            HllSketch(e) => {
+                                let sketch_id = agg_expr.semantic_id(&schema).id;
+                                let merge_sketch_id = HllMerge(Column(sketch_id.clone()).into())
+                                    .semantic_id(&schema)
+                                    .id;
+                                first_stage_aggs
+                                    .entry(sketch_id.clone())
+                                    .or_insert(HllSketch(
+                                        e.alias(sketch_id.clone()).clone().into(),
+                                    ));
+                                second_stage_aggs.entry(merge_sketch_id.clone()).or_insert(
+                                    HllMerge(
+                                        Column(sketch_id.clone())
+                                            .alias(merge_sketch_id.clone())
+                                            .into(),
+                                    ),
+                                );
+                                final_exprs
+                                    .push(Column(merge_sketch_id.clone()).alias(output_name));
+                            }
+                            HllMerge(e) => {
+                                let merge_id = agg_expr.semantic_id(&schema).id;
+                                let merge_of_merge_id = HllMerge(Column(merge_id.clone()).into())
+                                    .semantic_id(&schema)
+                                    .id;
+                                first_stage_aggs
+                                    .entry(merge_id.clone())
+                                    .or_insert(HllMerge(e.alias(merge_id.clone()).clone().into()));
+                                second_stage_aggs
+                                    .entry(merge_of_merge_id.clone())
+                                    .or_insert(HllMerge(
+                                        Column(merge_id.clone())
+                                            .alias(merge_of_merge_id.clone())
+                                            .into(),
+                                    ));
+                                final_exprs
+                                    .push(Column(merge_of_merge_id.clone()).alias(output_name));
+                            }
+                            ApproxCountDistinct(e) => {
+                                // Build a HyperLogLog sketch per partition, merge the sketches in the reduce
+                                // stage, and only estimate the cardinality from the final merged sketches.
+                                let sketch_id = HllSketch(e.clone()).semantic_id(&schema).id;
+                                let merge_sketch_id = HllMerge(Column(sketch_id.clone()).into())
+                                    .semantic_id(&schema)
+                                    .id;
+                                first_stage_aggs
+                                    .entry(sketch_id.clone())
+                                    .or_insert(HllSketch(
+                                        e.alias(sketch_id.clone()).clone().into(),
+                                    ));
+                                second_stage_aggs.entry(merge_sketch_id.clone()).or_insert(
+                                    HllMerge(
+                                        Column(sketch_id.clone())
+                                            .alias(merge_sketch_id.clone())
+                                            .into(),
+                                    ),
+                                );
+                                final_exprs.push(
+                                    hll_cardinality(&Column(merge_sketch_id.clone()))
+                                        .alias(output_name),
+                                );
+                            }
+                            Variance(e, _) | Stddev(e, _) | Skew(e) => {
+                                // Build mergeable moments per partition, merge them in the reduce stage, and only
+                                // compute the statistic from the final merged moments.
+                                let moments_id = Moments(e.clone()).semantic_id(&schema).id;
+                                let merge_moments_id =
+                                    MergeMoments(Column(moments_id.clone()).into())
+                                        .semantic_id(&schema)
+                                        .id;
+                                first_stage_aggs
+                                    .entry(moments_id.clone())
+                                    .or_insert(Moments(e.alias(moments_id.clone()).clone().into()));
+                                second_stage_aggs.entry(merge_moments_id.clone()).or_insert(
+                                    MergeMoments(
+                                        Column(moments_id.clone())
+                                            .alias(merge_moments_id.clone())
+                                            .into(),
+                                    ),
+                                );
+                                let stat = match agg_expr {
+                                    Variance(_, ddof) => MomentsExpr::Variance { ddof: *ddof },
+                                    Stddev(_, ddof) => MomentsExpr::Stddev { ddof: *ddof },
+                                    _ => MomentsExpr::Skew,
+                                };
+                                final_exprs.push(
+                                    moments_stat(&Column(merge_moments_id.clone()), stat)
+                                        .alias(output_name),
+                                );
+                            }
+                            Covar(x, y, _) | Corr(x, y) => {
+                                let co_moments_id =
+                                    CoMoments(x.clone(), y.clone()).semantic_id(&schema).id;
+                                let merge_co_moments_id =
+                                    MergeMoments(Column(co_moments_id.clone()).into())
+                                        .semantic_id(&schema)
+                                        .id;
+                                first_stage_aggs
+                                    .entry(co_moments_id.clone())
+                                    .or_insert(CoMoments(
+                                        x.alias(co_moments_id.clone()).clone().into(),
+                                        y.clone(),
+                                    ));
+                                second_stage_aggs
+                                    .entry(merge_co_moments_id.clone())
+                                    .or_insert(MergeMoments(
+                                        Column(co_moments_id.clone())
+                                            .alias(merge_co_moments_id.clone())
+                                            .into(),
+                                    ));
+                                let stat = match agg_expr {
+                                    Covar(_, _, ddof) => MomentsExpr::Covar { ddof: *ddof },
+                                    _ => MomentsExpr::Corr,
+                                };
+                                final_exprs.push(
+                                    moments_stat(&Column(merge_co_moments_id.clone()), stat)
+                                        .alias(output_name),
+                                );
+                            }
+                            Moments(_) | CoMoments(..) => {
+                                let moments_id = agg_expr.semantic_id(&schema).id;
+                                let merge_moments_id =
+                                    MergeMoments(Column(moments_id.clone()).into())
+                                        .semantic_id(&schema)
+                                        .id;
+                                let first_stage_agg = match agg_expr {
+                                    Moments(e) => Moments(e.alias(moments_id.clone()).into()),
+                                    CoMoments(x, y) => {
+                                        CoMoments(x.alias(moments_id.clone()).into(), y.clone())
+                                    }
+                                    _ => unreachable!(),
+                                };
+                                first_stage_aggs
+                                    .entry(moments_id.clone())
+                                    .or_insert(first_stage_agg);
+                                second_stage_aggs.entry(merge_moments_id.clone()).or_insert(
+                                    MergeMoments(
+                                        Column(moments_id.clone())
+                                            .alias(merge_moments_id.clone())
+                                            .into(),
+                                    ),
+                                );
+                                final_exprs
+                                    .push(Column(merge_moments_id.clone()).alias(output_name));
+                            }
+                            MergeMoments(e) => {
+                                let merge_id = agg_expr.semantic_id(&schema).id;
+                                let merge_of_merge_id =
+                                    MergeMoments(Column(merge_id.clone()).into())
+                                        .semantic_id(&schema)
+                                        .id;
+                                first_stage_aggs
+                                    .entry(merge_id.clone())
+                                    .or_insert(MergeMoments(e.alias(merge_id.clone()).into()));
+                                second_stage_aggs
+                                    .entry(merge_of_merge_id.clone())
+                                    .or_insert(MergeMoments(
+                                        Column(merge_id.clone())
+                                            .alias(merge_of_merge_id.clone())
+                                            .into(),
+                                    ));
+                                final_exprs
+                                    .push(Column(merge_of_merge_id.clone()).alias(output_name));
+                            }
+                            CountDistinct(_) | SumDistinct(_) | ListDistinct(_) => {
+                                unreachable!("Distinct aggregations are planned separately")
+                            }
                             MapGroups { func, inputs } => {
                                 let func_id = agg_expr.semantic_id(&schema).id;
                                 // No first stage aggregation for MapGroups, do all the work in the second stage.
@@ -485,6 +925,49 @@ pub(super) fn translate_single_logical_node(
 
             Ok(result_plan)
         }
+        LogicalPlan::Window(LogicalWindow {
+            window_exprs,
+            partition_by,
+            order_by,
+            descending,
+            frame,
+            ..
+        }) => {
+            let mut input_physical = physical_children.pop().expect("requires 1 input");
+            let input_clustering_spec = input_physical.clustering_spec();
+            let num_partitions = input_clustering_spec.num_partitions();
+            // Every window partition needs to be colocated in a single physical partition, so we either
+            // hash-partition on the window partitioning keys or gather everything into one partition.
+            if num_partitions > 1 {
+                if partition_by.is_empty() {
+                    input_physical = PhysicalPlan::Coalesce(Coalesce::new(
+                        input_physical.into(),
+                        num_partitions,
+                        1,
+                    ));
+                } else if input_clustering_spec.as_ref()
+                    != &ClusteringSpec::Hash(HashClusteringConfig::new(
+                        num_partitions,
+                        partition_by.clone(),
+                    ))
+                {
+                    let split_op = PhysicalPlan::FanoutByHash(FanoutByHash::new(
+                        input_physical.into(),
+                        num_partitions,
+                        partition_by.clone(),
+                    ));
+                    input_physical = PhysicalPlan::ReduceMerge(ReduceMerge::new(split_op.into()));
+                }
+            }
+            Ok(PhysicalPlan::Window(Window::new(
+                input_physical.into(),
+                window_exprs.clone(),
+                partition_by.clone(),
+                order_by.clone(),
+                descending.clone(),
+                *frame,
+            )))
+        }
         LogicalPlan::Concat(..) => {
             let other_physical = physical_children.pop().expect("requires 1 inputs");
             let input_physical = physical_children.pop().expect("requires 2 inputs");
@@ -494,11 +977,12 @@ pub(super) fn translate_single_logical_node(
             )))
         }
         LogicalPlan::Join(LogicalJoin {
+            left,
+            right,
             left_on,
             right_on,
             join_type,
             join_strategy,
-            output_schema,
             ..
         }) => {
             let mut right_physical = physical_children.pop().expect("requires 1 inputs");
@@ -563,24 +1047,36 @@ pub(super) fn translate_single_logical_node(
                 (None, Some(right_size_bytes)) => (Some(right_size_bytes), true),
                 (None, None) => (None, false),
             };
+            // Every receiver partition is joined with the entire broadcast side, so the broadcast side can't be a side
+            // whose unmatched rows are kept by the join, since those rows would then be emitted once per receiver
+            // partition.
+            let broadcast_left_is_larger = match join_type {
+                JoinType::Inner | JoinType::Cross => Some(left_is_larger),
+                JoinType::Left | JoinType::Semi | JoinType::Anti => Some(true),
+                JoinType::Right => Some(false),
+                JoinType::Outer => None,
+            };
             let is_larger_partitioned = if left_is_larger {
                 is_left_hash_partitioned || is_left_sort_partitioned
             } else {
                 is_right_hash_partitioned || is_right_sort_partitioned
             };
             let join_strategy = join_strategy.unwrap_or_else(|| {
-                let is_primitive = |exprs: &Vec<Expr>| exprs.iter().map(|e| e.name().unwrap()).all(|col| {
-                    let dtype = &output_schema.get_field(col).unwrap().dtype;
+                let is_primitive = |exprs: &Vec<Expr>, schema: &Schema| exprs.iter().all(|e| {
+                    let dtype = &e.to_field(schema).unwrap().dtype;
                     dtype.is_integer() || dtype.is_floating() || matches!(dtype, DataType::Utf8 | DataType::Binary | DataType::Boolean)
                 });
-                // If larger table is not already partitioned on the join key AND the smaller table is under broadcast size threshold, use broadcast join.
-                if !is_larger_partitioned && let Some(smaller_size_bytes) = smaller_size_bytes && smaller_size_bytes <= cfg.broadcast_join_size_bytes_threshold {
+                // A cross join has no join keys to partition on, so one side must be broadcast.
+                if *join_type == JoinType::Cross {
+                    JoinStrategy::Broadcast
+                // If larger table is not already partitioned on the join key AND the smaller table is under broadcast size threshold AND the join type allows broadcasting the smaller table, use broadcast join.
+                } else if !is_larger_partitioned && let Some(smaller_size_bytes) = smaller_size_bytes && smaller_size_bytes <= cfg.broadcast_join_size_bytes_threshold && broadcast_left_is_larger == Some(left_is_larger) {
                     JoinStrategy::Broadcast
                 // Larger side of join is range-partitioned on the join column, so we use a sort-merge join.
                 // TODO(Clark): Support non-primitive dtypes for sort-merge join (e.g. temporal types).
                 // TODO(Clark): Also do a sort-merge join if a downstream op needs the table to be sorted on the join key.
                 // TODO(Clark): Look into defaulting to sort-merge join over hash join under more input partitioning setups.
-                } else if is_primitive(left_on) && is_primitive(right_on) && (is_left_sort_partitioned || is_right_sort_partitioned)
+                } else if is_primitive(left_on, &left.schema()) && is_primitive(right_on, &right.schema()) && (is_left_sort_partitioned || is_right_sort_partitioned)
                 && (!is_larger_partitioned
                     || (left_is_larger && is_left_sort_partitioned
                         || !left_is_larger && is_right_sort_partitioned)) {
@@ -590,8 +1086,18 @@ pub(super) fn translate_single_logical_node(
                     JoinStrategy::Hash
                 }
             });
+            if *join_type == JoinType::Cross && join_strategy != JoinStrategy::Broadcast {
+                return Err(DaftError::ValueError(format!(
+                    "Cross joins must use the broadcast join strategy, but got: {join_strategy}"
+                )));
+            }
             match join_strategy {
                 JoinStrategy::Broadcast => {
+                    let Some(left_is_larger) = broadcast_left_is_larger else {
+                        return Err(DaftError::ValueError(format!(
+                            "Broadcast join does not support {join_type} joins"
+                        )));
+                    };
                     // If either the left or right side of the join are very small tables, perform a broadcast join with the
                     // entire smaller table broadcast to each of the partitions of the larger table.
                     if left_is_larger {
@@ -608,7 +1114,11 @@ pub(super) fn translate_single_logical_node(
                     )))
                 }
                 JoinStrategy::SortMerge => {
-                    let needs_presort = if cfg.sort_merge_join_sort_with_aligned_boundaries {
+                    let needs_presort = if *join_type != JoinType::Inner {
+                        // Merging partitions with unaligned boundaries joins each partition with several partitions
+                        // from the other side, which can only find unmatched rows for inner joins.
+                        true
+                    } else if cfg.sort_merge_join_sort_with_aligned_boundaries {
                         // Use the special-purpose presorting that ensures join inputs are sorted with aligned
                         // boundaries, allowing for a more efficient downstream merge-join (~one-to-one zip).
                         !is_left_sort_partitioned || !is_right_sort_partitioned
@@ -706,6 +1216,15 @@ pub(super) fn translate_single_logical_node(
                                 input_physical.into(),
                             )))
                         }
+                        FileFormat::ArrowIpc => Err(common_error::DaftError::ValueError(
+                            "Arrow IPC sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Avro => Err(common_error::DaftError::ValueError(
+                            "Avro sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Orc => Err(common_error::DaftError::ValueError(
+                            "ORC sink not yet implemented".to_string(),
+                        )),
                         FileFormat::Database => Err(common_error::DaftError::ValueError(
                             "Database sink not yet implemented".to_string(),
                         )),
@@ -744,9 +1263,13 @@ mod tests {
     use std::assert_matches::assert_matches;
     use std::sync::Arc;
 
+    use crate::physical_ops::{
+        Aggregate, BroadcastJoin, Coalesce, FanoutByHash, Project, ReduceMerge, Window,
+    };
     use crate::physical_plan::PhysicalPlan;
     use crate::physical_planner::plan;
     use crate::test::{dummy_scan_node, dummy_scan_operator};
+    use crate::{JoinStrategy, JoinType};
 
     /// Tests that planner drops a simple Repartition (e.g. df.into_partitions()) the child already has the desired number of partitions.
     ///
@@ -839,4 +1362,200 @@ mod tests {
         assert_matches!(physical_plan, PhysicalPlan::Project(_));
         Ok(())
     }
+
+    /// Tests that planner only shuffles the input of a Window if it isn't already hash-partitioned on the window
+    /// partitioning keys.
+    #[test]
+    fn window_shuffle_dropped_same_clustering_spec() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let scan = || {
+            dummy_scan_node(dummy_scan_operator(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Int64),
+            ]))
+            .hash_repartition(Some(10), vec![col("a")])
+        };
+        let window_input = |partition_by: Vec<Expr>| -> DaftResult<Arc<PhysicalPlan>> {
+            let logical_plan = scan()?
+                .window(
+                    vec![Expr::Agg(AggExpr::Sum(col("b").into())).alias("b_sum")],
+                    partition_by,
+                    vec![],
+                    vec![],
+                    None,
+                )?
+                .build();
+            match plan(logical_plan.as_ref(), cfg.clone())? {
+                PhysicalPlan::Window(Window { input, .. }) => Ok(input),
+                physical_plan => panic!("Expected a Window, but got {}", physical_plan.name()),
+            }
+        };
+        // Already hash-partitioned on "a", so the Window should sit directly on top of the repartition.
+        assert_matches!(
+            window_input(vec![col("a")])?.as_ref(),
+            PhysicalPlan::ReduceMerge(ReduceMerge { input }) if matches!(input.as_ref(), PhysicalPlan::FanoutByHash(FanoutByHash { input, .. }) if matches!(input.as_ref(), PhysicalPlan::TabularScan(_)))
+        );
+        // Partitioning on "b" requires another shuffle.
+        assert_matches!(
+            window_input(vec![col("b")])?.as_ref(),
+            PhysicalPlan::ReduceMerge(ReduceMerge { input }) if matches!(input.as_ref(), PhysicalPlan::FanoutByHash(FanoutByHash { input, .. }) if matches!(input.as_ref(), PhysicalPlan::ReduceMerge(_)))
+        );
+        // No window partitioning gathers everything into a single partition.
+        assert_matches!(
+            window_input(vec![])?.as_ref(),
+            PhysicalPlan::Coalesce(Coalesce { num_to: 1, .. })
+        );
+        Ok(())
+    }
+
+    /// Tests that distinct aggregations mixed with other aggregations are planned in a single stage, after
+    /// colocating every group in one partition.
+    #[test]
+    fn mixed_distinct_agg_planned_in_single_stage() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let agg_input = |groupby: Vec<Expr>| -> DaftResult<Arc<PhysicalPlan>> {
+            let logical_plan = dummy_scan_node(dummy_scan_operator(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Int64),
+            ]))
+            .into_partitions(10)?
+            .aggregate(
+                vec![
+                    col("b").count_distinct().alias("b_count_distinct"),
+                    col("b").sum().alias("b_sum"),
+                ],
+                groupby,
+            )?
+            .build();
+            match plan(logical_plan.as_ref(), cfg.clone())? {
+                PhysicalPlan::Aggregate(Aggregate { input, .. }) => Ok(input),
+                physical_plan => panic!("Expected an Aggregate, but got {}", physical_plan.name()),
+            }
+        };
+        assert_matches!(
+            agg_input(vec![col("a")])?.as_ref(),
+            PhysicalPlan::ReduceMerge(ReduceMerge { input }) if matches!(input.as_ref(), PhysicalPlan::FanoutByHash(_))
+        );
+        assert_matches!(
+            agg_input(vec![])?.as_ref(),
+            PhysicalPlan::Coalesce(Coalesce { num_to: 1, .. })
+        );
+        Ok(())
+    }
+
+    /// Tests that distinct aggregations of the same values first dedup the (group keys, value) pairs across
+    /// partitions, and then aggregate in two stages.
+    #[test]
+    fn distinct_agg_dedups_before_aggregating() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        for groupby in [vec![col("a")], vec![]] {
+            let logical_plan = dummy_scan_node(dummy_scan_operator(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Int64),
+            ]))
+            .into_partitions(10)?
+            .aggregate(
+                vec![
+                    col("b").count_distinct().alias("b_count_distinct"),
+                    col("b").sum_distinct().alias("b_sum_distinct"),
+                ],
+                groupby.clone(),
+            )?
+            .build();
+            let physical_plan = plan(logical_plan.as_ref(), cfg.clone())?;
+            let PhysicalPlan::Project(Project { input, .. }) = physical_plan else {
+                panic!("Expected a Project, but got {}", physical_plan.name());
+            };
+            let PhysicalPlan::Aggregate(Aggregate { input, .. }) = input.as_ref() else {
+                panic!("Expected an Aggregate, but got {}", input.name());
+            };
+            let first_stage_input = match input.as_ref() {
+                PhysicalPlan::Coalesce(Coalesce {
+                    input, num_to: 1, ..
+                }) if groupby.is_empty() => input,
+                PhysicalPlan::ReduceMerge(ReduceMerge { input }) if !groupby.is_empty() => {
+                    let PhysicalPlan::FanoutByHash(FanoutByHash { input, .. }) = input.as_ref()
+                    else {
+                        panic!("Expected a FanoutByHash, but got {}", input.name());
+                    };
+                    input
+                }
+                gather_plan => panic!("Unexpected gather plan {}", gather_plan.name()),
+            };
+            let PhysicalPlan::Aggregate(Aggregate {
+                input,
+                aggregations,
+                ..
+            }) = first_stage_input.as_ref()
+            else {
+                panic!(
+                    "Expected an Aggregate, but got {}",
+                    first_stage_input.name()
+                );
+            };
+            assert_eq!(aggregations.len(), 2);
+            // The dedup shuffle is on the group keys and the distinct values.
+            let PhysicalPlan::ReduceMerge(ReduceMerge { input }) = input.as_ref() else {
+                panic!("Expected a ReduceMerge, but got {}", input.name());
+            };
+            let PhysicalPlan::FanoutByHash(FanoutByHash {
+                input,
+                partition_by,
+                ..
+            }) = input.as_ref()
+            else {
+                panic!("Expected a FanoutByHash, but got {}", input.name());
+            };
+            assert_eq!(partition_by.len(), groupby.len() + 1);
+            assert_matches!(
+                input.as_ref(),
+                PhysicalPlan::Aggregate(Aggregate { aggregations, groupby: dedup_keys, .. })
+                    if aggregations.is_empty() && dedup_keys.len() == groupby.len() + 1
+            );
+        }
+        Ok(())
+    }
+
+    /// Tests that broadcast joins only broadcast a side of the join whose unmatched rows are dropped.
+    #[test]
+    fn broadcast_join_respects_join_type() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let scan = || {
+            dummy_scan_node(dummy_scan_operator(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+            ]))
+        };
+        for (join_type, expected_is_swapped) in [
+            (JoinType::Left, true),
+            (JoinType::Anti, true),
+            (JoinType::Right, false),
+        ] {
+            let logical_plan = scan()
+                .join(
+                    &scan(),
+                    vec![col("a")],
+                    vec![col("a")],
+                    join_type,
+                    Some(JoinStrategy::Broadcast),
+                )?
+                .build();
+            let physical_plan = plan(logical_plan.as_ref(), cfg.clone())?;
+            assert_matches!(
+                physical_plan,
+                PhysicalPlan::BroadcastJoin(BroadcastJoin { is_swapped, .. }) if is_swapped == expected_is_swapped
+            );
+        }
+        let logical_plan = scan()
+            .join(
+                &scan(),
+                vec![col("a")],
+                vec![col("a")],
+                JoinType::Outer,
+                Some(JoinStrategy::Broadcast),
+            )?
+            .build();
+        assert!(plan(logical_plan.as_ref(), cfg).is_err());
+        Ok(())
+    }
 }
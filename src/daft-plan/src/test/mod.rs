@@ -1,10 +1,16 @@
 use std::sync::Arc;
 
-use daft_core::{datatypes::Field, schema::Schema};
+use common_error::DaftResult;
+use daft_core::{
+    datatypes::Field,
+    schema::{Schema, SchemaRef},
+};
 use daft_scan::{
     file_format::FileFormatConfig, storage_config::NativeStorageConfig,
-    storage_config::StorageConfig, AnonymousScanOperator, Pushdowns, ScanOperator,
+    storage_config::StorageConfig, AggregationPushdown, AnonymousScanOperator, DataFileSource,
+    PartitionField, Pushdowns, ScanOperator, ScanTask, ScanTaskRef,
 };
+use daft_stats::{TableMetadata, TableStatistics};
 
 use crate::builder::LogicalPlanBuilder;
 
@@ -19,6 +25,101 @@ pub fn dummy_scan_operator(fields: Vec<Field>) -> Arc<dyn ScanOperator> {
     ))
 }
 
+/// Dummy scan operator over a single file with known row count and, optionally, column statistics.
+#[derive(Debug)]
+struct DummyStatsScanOperator {
+    schema: SchemaRef,
+    num_rows: usize,
+    statistics: Option<TableStatistics>,
+    can_estimate_size: bool,
+}
+
+impl ScanOperator for DummyStatsScanOperator {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn partitioning_keys(&self) -> &[PartitionField] {
+        &[]
+    }
+
+    fn can_absorb_filter(&self) -> bool {
+        false
+    }
+    fn can_absorb_select(&self) -> bool {
+        false
+    }
+    fn can_absorb_limit(&self) -> bool {
+        false
+    }
+    fn can_absorb_aggregation(&self, aggregation: &AggregationPushdown) -> bool {
+        aggregation.is_derivable_from_metadata(&self.schema, self.partitioning_keys())
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![
+            "DummyStatsScanOperator".to_string(),
+            format!("Num rows = {}", self.num_rows),
+        ]
+    }
+
+    fn to_scan_tasks(
+        &self,
+        pushdowns: Pushdowns,
+    ) -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTaskRef>>>> {
+        let scan_task = ScanTask::new(
+            vec![DataFileSource::AnonymousDataFile {
+                path: "/foo".to_string(),
+                chunk_spec: None,
+                size_bytes: None,
+                metadata: Some(TableMetadata {
+                    length: self.num_rows,
+                }),
+                partition_spec: None,
+                statistics: self.statistics.clone(),
+            }],
+            FileFormatConfig::Json(Default::default()).into(),
+            self.schema.clone(),
+            StorageConfig::Native(NativeStorageConfig::new_internal(true, None).into()).into(),
+            pushdowns,
+        );
+        Ok(Box::new(std::iter::once(Ok(scan_task.into()))))
+    }
+
+    fn can_estimate_size_from_scan_tasks(&self) -> bool {
+        self.can_estimate_size
+    }
+}
+
+/// Create a dummy scan operator containing the provided fields in its schema, whose single scan task reports
+/// the provided number of rows and column statistics.
+pub fn dummy_scan_operator_with_stats(
+    fields: Vec<Field>,
+    num_rows: usize,
+    statistics: Option<TableStatistics>,
+) -> Arc<dyn ScanOperator> {
+    Arc::new(DummyStatsScanOperator {
+        schema: Arc::new(Schema::new(fields).unwrap()),
+        num_rows,
+        statistics,
+        can_estimate_size: true,
+    })
+}
+
+/// Create a dummy scan operator like [`dummy_scan_operator_with_stats`], whose scan tasks are too expensive to
+/// list while optimizing a plan, like those of a database query.
+pub fn dummy_scan_operator_with_expensive_stats(
+    fields: Vec<Field>,
+    num_rows: usize,
+) -> Arc<dyn ScanOperator> {
+    Arc::new(DummyStatsScanOperator {
+        schema: Arc::new(Schema::new(fields).unwrap()),
+        num_rows,
+        statistics: None,
+        can_estimate_size: false,
+    })
+}
+
 /// Create a dummy scan node containing the provided fields in its schema.
 pub fn dummy_scan_node(scan_op: Arc<dyn ScanOperator>) -> LogicalPlanBuilder {
     dummy_scan_node_with_pushdowns(scan_op, Default::default())
@@ -4,8 +4,8 @@ use common_error::DaftResult;
 use daft_core::schema::SchemaRef;
 
 use crate::{
-    file_format::FileFormatConfig, storage_config::StorageConfig, DataFileSource, PartitionField,
-    Pushdowns, ScanOperator, ScanTask, ScanTaskRef,
+    file_format::FileFormatConfig, storage_config::StorageConfig, AggregationPushdown,
+    DataFileSource, PartitionField, Pushdowns, ScanOperator, ScanTask, ScanTaskRef,
 };
 #[derive(Debug)]
 pub struct AnonymousScanOperator {
@@ -49,6 +49,9 @@ impl ScanOperator for AnonymousScanOperator {
     fn can_absorb_limit(&self) -> bool {
         false
     }
+    fn can_absorb_aggregation(&self, _: &AggregationPushdown) -> bool {
+        false
+    }
 
     fn multiline_display(&self) -> Vec<String> {
         let mut lines = vec![
@@ -89,4 +92,8 @@ impl ScanOperator for AnonymousScanOperator {
             .into())
         })))
     }
+
+    fn can_estimate_size_from_scan_tasks(&self) -> bool {
+        true
+    }
 }
@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{schema::SchemaRef, DataType, Series};
+use daft_database::{read_sql_count, read_sql_min_max, read_sql_schema, DatabaseUrl};
+use daft_dsl::LiteralValue;
+use daft_stats::{TableMetadata, TableStatistics};
+use daft_table::Table;
+
+use crate::{
+    file_format::{DatabaseConnection, DatabaseSourceConfig, FileFormatConfig},
+    storage_config::StorageConfig,
+    AggregationPushdown, DataFileSource, PartitionField, Pushdowns, ScanOperator, ScanTask,
+    ScanTaskRef,
+};
+
+/// Scans the results of a SQL query against a database that is read natively, see
+/// [`DatabaseUrl`].
+///
+/// If a `partition_col` is given, the query is split into multiple scan tasks over equal ranges of
+/// that column's values, so that each task reads about `partition_size_bytes` (or into exactly
+/// `num_partitions` tasks if given).
+#[derive(Debug)]
+pub struct DatabaseScanOperator {
+    sql: String,
+    url: String,
+    database: DatabaseUrl,
+    schema: SchemaRef,
+    partition_col: Option<String>,
+    num_partitions: Option<usize>,
+    partition_size_bytes: usize,
+    storage_config: Arc<StorageConfig>,
+}
+
+impl DatabaseScanOperator {
+    pub fn try_new(
+        sql: String,
+        url: String,
+        partition_col: Option<String>,
+        num_partitions: Option<usize>,
+        partition_size_bytes: usize,
+        storage_config: Arc<StorageConfig>,
+    ) -> DaftResult<Self> {
+        if num_partitions.is_some() && partition_col.is_none() {
+            return Err(DaftError::ValueError(
+                "Failed to execute sql: partition_col must be specified when num_partitions is specified".to_string(),
+            ));
+        }
+        let database = DatabaseUrl::parse(&url)?;
+        let schema = read_sql_schema(&sql, &url, true)?;
+        Ok(Self {
+            sql,
+            url,
+            database,
+            schema: Arc::new(schema),
+            partition_col,
+            num_partitions,
+            partition_size_bytes,
+            storage_config,
+        })
+    }
+
+    fn scan_task(
+        &self,
+        sql: String,
+        num_rows: Option<usize>,
+        size_bytes: Option<u64>,
+        statistics: Option<TableStatistics>,
+        pushdowns: Pushdowns,
+    ) -> ScanTaskRef {
+        let file_format_config = FileFormatConfig::Database(DatabaseSourceConfig::new_internal(
+            sql,
+            DatabaseConnection::Url(self.url.clone()),
+        ));
+        ScanTask::new(
+            vec![DataFileSource::DatabaseDataSource {
+                path: self.database.to_string(),
+                chunk_spec: None,
+                size_bytes,
+                metadata: num_rows.map(|length| TableMetadata { length }),
+                partition_spec: None,
+                statistics,
+            }],
+            Arc::new(file_format_config),
+            self.schema.clone(),
+            self.storage_config.clone(),
+            pushdowns,
+        )
+        .into()
+    }
+
+    /// Splits the range between the smallest and largest values of `partition_col` into
+    /// `num_partitions` equal ranges, returning their `num_partitions + 1` bounds.
+    fn partition_bounds(
+        &self,
+        partition_col: &str,
+        num_partitions: usize,
+    ) -> DaftResult<Option<Vec<LiteralValue>>> {
+        let dtype = &self.schema.get_field(partition_col)?.dtype;
+        if !(dtype.is_numeric() || dtype.is_temporal()) {
+            return Err(DaftError::ValueError(format!(
+                "Failed to get partition bounds: {partition_col} is not a numeric or temporal type, and cannot be used for partitioning."
+            )));
+        }
+
+        let min_max = read_sql_min_max(&self.sql, &self.url, partition_col, true)?;
+        if min_max.len() != 2 {
+            // The column only holds nulls.
+            return Ok(None);
+        }
+        let min_max = min_max.get_column_by_index(0)?.cast(dtype)?;
+
+        let interpolate_i64 = |min: i64, max: i64| {
+            (0..=num_partitions)
+                .map(move |i| {
+                    min + ((max as i128 - min as i128) * i as i128 / num_partitions as i128) as i64
+                })
+                .collect::<Vec<_>>()
+        };
+        let bounds = match dtype {
+            DataType::Date => {
+                let physical = &min_max.date()?.physical;
+                interpolate_i64(
+                    physical.get(0).unwrap() as i64,
+                    physical.get(1).unwrap() as i64,
+                )
+                .into_iter()
+                .map(|v| LiteralValue::Date(v as i32))
+                .collect()
+            }
+            DataType::Timestamp(unit, tz) => {
+                let physical = &min_max.timestamp()?.physical;
+                interpolate_i64(physical.get(0).unwrap(), physical.get(1).unwrap())
+                    .into_iter()
+                    .map(|v| LiteralValue::Timestamp(v, *unit, tz.clone()))
+                    .collect()
+            }
+            dtype if dtype.is_integer() => {
+                let values = min_max.cast(&DataType::Int64)?;
+                let values = values.i64()?;
+                interpolate_i64(values.get(0).unwrap(), values.get(1).unwrap())
+                    .into_iter()
+                    .map(LiteralValue::Int64)
+                    .collect()
+            }
+            _ => {
+                let values = min_max.cast(&DataType::Float64)?;
+                let values = values.f64()?;
+                let (min, max) = (values.get(0).unwrap(), values.get(1).unwrap());
+                (0..=num_partitions)
+                    .map(|i| {
+                        if i == num_partitions {
+                            max
+                        } else {
+                            min + (max - min) * i as f64 / num_partitions as f64
+                        }
+                    })
+                    .map(LiteralValue::Float64)
+                    .collect()
+            }
+        };
+        Ok(Some(bounds))
+    }
+}
+
+impl ScanOperator for DatabaseScanOperator {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn partitioning_keys(&self) -> &[PartitionField] {
+        &[]
+    }
+
+    fn can_absorb_filter(&self) -> bool {
+        false
+    }
+    fn can_absorb_select(&self) -> bool {
+        false
+    }
+    fn can_absorb_limit(&self) -> bool {
+        false
+    }
+    fn can_absorb_aggregation(&self, _: &AggregationPushdown) -> bool {
+        false
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut lines = vec![
+            "DatabaseScanOperator".to_string(),
+            format!("SQL = {}", self.sql),
+            format!("URL = {}", self.database),
+        ];
+        if let Some(partition_col) = &self.partition_col {
+            lines.push(format!("Partition column = {partition_col}"));
+        }
+        lines.extend(self.storage_config.multiline_display());
+
+        lines
+    }
+
+    fn to_scan_tasks(
+        &self,
+        pushdowns: Pushdowns,
+    ) -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTaskRef>> + 'static>> {
+        let num_rows = read_sql_count(&self.sql, &self.url, true)?;
+        let size_bytes = (num_rows as f64 * self.schema.estimate_row_size_bytes()).ceil() as usize;
+        let partition_size_bytes = self.partition_size_bytes.max(1);
+        let num_partitions = self
+            .num_partitions
+            .unwrap_or((size_bytes + partition_size_bytes - 1) / partition_size_bytes);
+
+        let single_scan_task =
+            || -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTaskRef>>>> {
+                let task = self.scan_task(
+                    self.sql.clone(),
+                    Some(num_rows),
+                    Some(size_bytes as u64),
+                    None,
+                    pushdowns.clone(),
+                );
+                Ok(Box::new(std::iter::once(Ok(task))))
+            };
+        let Some(partition_col) = self.partition_col.as_deref() else {
+            return single_scan_task();
+        };
+        if num_partitions <= 1 {
+            return single_scan_task();
+        }
+        let Some(bounds) = self.partition_bounds(partition_col, num_partitions)? else {
+            return single_scan_task();
+        };
+
+        let dialect = self.database.dialect();
+        let Some(bounds_sql) = bounds
+            .iter()
+            .map(|bound| daft_dsl::Expr::Literal(bound.clone()).to_sql(dialect))
+            .collect::<Option<Vec<_>>>()
+        else {
+            // The bounds can't be written in this dialect, e.g. timestamps with a timezone.
+            return single_scan_task();
+        };
+
+        let dtype = &self.schema.get_field(partition_col)?.dtype;
+        let task_size_bytes = ((size_bytes + num_partitions - 1) / num_partitions) as u64;
+        let scan_tasks = (0..num_partitions)
+            .map(|i| {
+                let upper_op = if i < num_partitions - 1 { "<" } else { "<=" };
+                let sql = format!(
+                    "SELECT * FROM ({}) AS subquery WHERE {partition_col} >= {} AND {partition_col} {upper_op} {}",
+                    self.sql,
+                    bounds_sql[i],
+                    bounds_sql[i + 1]
+                );
+                let stats = Series::concat(&[&bounds[i].to_series(), &bounds[i + 1].to_series()])?
+                    .rename(partition_col)
+                    .cast(dtype)?;
+                let stats = TableStatistics::from_stats_table(&Table::from_columns(vec![stats])?)?;
+                Ok(self.scan_task(
+                    sql,
+                    None,
+                    Some(task_size_bytes),
+                    Some(stats),
+                    pushdowns.clone(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        Ok(Box::new(scan_tasks.into_iter()))
+    }
+}
@@ -3,6 +3,8 @@ use daft_core::{
     datatypes::{Field, TimeUnit},
     impl_bincode_py_state_serialization,
 };
+use daft_csv::RaggedRows;
+use daft_decoding::parse_mode::{ParseMode, DEFAULT_CORRUPT_RECORD_COLUMN_NAME};
 use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 use std::{collections::BTreeMap, str::FromStr, sync::Arc};
@@ -16,13 +18,16 @@ use {
     },
 };
 
-/// Format of a file, e.g. Parquet, CSV, JSON.
+/// Format of a file, e.g. Parquet, CSV, JSON, Arrow IPC, Avro, ORC.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
 pub enum FileFormat {
     Parquet,
     Csv,
     Json,
+    ArrowIpc,
+    Avro,
+    Orc,
     Database,
 }
 
@@ -38,6 +43,15 @@ impl FromStr for FileFormat {
             Ok(Csv)
         } else if file_format.trim().eq_ignore_ascii_case("json") {
             Ok(Json)
+        } else if ["arrow", "ipc", "arrow_ipc", "feather"]
+            .iter()
+            .any(|name| file_format.trim().eq_ignore_ascii_case(name))
+        {
+            Ok(ArrowIpc)
+        } else if file_format.trim().eq_ignore_ascii_case("avro") {
+            Ok(Avro)
+        } else if file_format.trim().eq_ignore_ascii_case("orc") {
+            Ok(Orc)
         } else if file_format.trim().eq_ignore_ascii_case("database") {
             Ok(Database)
         } else {
@@ -57,7 +71,9 @@ impl From<&FileFormatConfig> for FileFormat {
             FileFormatConfig::Parquet(_) => Self::Parquet,
             FileFormatConfig::Csv(_) => Self::Csv,
             FileFormatConfig::Json(_) => Self::Json,
-            #[cfg(feature = "python")]
+            FileFormatConfig::ArrowIpc(_) => Self::ArrowIpc,
+            FileFormatConfig::Avro(_) => Self::Avro,
+            FileFormatConfig::Orc(_) => Self::Orc,
             FileFormatConfig::Database(_) => Self::Database,
         }
     }
@@ -69,7 +85,9 @@ pub enum FileFormatConfig {
     Parquet(ParquetSourceConfig),
     Csv(CsvSourceConfig),
     Json(JsonSourceConfig),
-    #[cfg(feature = "python")]
+    ArrowIpc(ArrowIpcSourceConfig),
+    Avro(AvroSourceConfig),
+    Orc(OrcSourceConfig),
     Database(DatabaseSourceConfig),
 }
 
@@ -81,7 +99,9 @@ impl FileFormatConfig {
             Parquet(_) => "Parquet",
             Csv(_) => "Csv",
             Json(_) => "Json",
-            #[cfg(feature = "python")]
+            ArrowIpc(_) => "ArrowIpc",
+            Avro(_) => "Avro",
+            Orc(_) => "Orc",
             Database(_) => "Database",
         }
     }
@@ -91,7 +111,9 @@ impl FileFormatConfig {
             Self::Parquet(source) => source.multiline_display(),
             Self::Csv(source) => source.multiline_display(),
             Self::Json(source) => source.multiline_display(),
-            #[cfg(feature = "python")]
+            Self::ArrowIpc(source) => source.multiline_display(),
+            Self::Avro(source) => source.multiline_display(),
+            Self::Orc(source) => source.multiline_display(),
             Self::Database(source) => source.multiline_display(),
         }
     }
@@ -168,12 +190,19 @@ impl_bincode_py_state_serialization!(ParquetSourceConfig);
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
 pub struct CsvSourceConfig {
-    pub delimiter: Option<char>,
+    pub delimiter: Option<String>,
     pub has_headers: bool,
     pub double_quote: bool,
     pub quote: Option<char>,
     pub escape_char: Option<char>,
     pub comment: Option<char>,
+    pub null_values: Option<Vec<String>>,
+    pub true_values: Option<Vec<String>>,
+    pub false_values: Option<Vec<String>>,
+    pub skip_rows: usize,
+    pub ragged_rows: RaggedRows,
+    pub mode: ParseMode,
+    pub corrupt_record_column: String,
     pub buffer_size: Option<usize>,
     pub chunk_size: Option<usize>,
 }
@@ -181,7 +210,7 @@ pub struct CsvSourceConfig {
 impl CsvSourceConfig {
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
-        if let Some(delimiter) = self.delimiter {
+        if let Some(delimiter) = &self.delimiter {
             res.push(format!("Delimiter = {}", delimiter));
         }
         res.push(format!("Has headers = {}", self.has_headers));
@@ -195,6 +224,30 @@ impl CsvSourceConfig {
         if let Some(comment) = self.comment {
             res.push(format!("Comment = {}", comment));
         }
+        if let Some(null_values) = &self.null_values {
+            res.push(format!("Null values = {:?}", null_values));
+        }
+        if let Some(true_values) = &self.true_values {
+            res.push(format!("True values = {:?}", true_values));
+        }
+        if let Some(false_values) = &self.false_values {
+            res.push(format!("False values = {:?}", false_values));
+        }
+        if self.skip_rows > 0 {
+            res.push(format!("Skip rows = {}", self.skip_rows));
+        }
+        if self.ragged_rows != RaggedRows::Error {
+            res.push(format!("Ragged rows = {}", self.ragged_rows));
+        }
+        if self.mode != ParseMode::FailFast {
+            res.push(format!("Mode = {}", self.mode));
+        }
+        if self.mode == ParseMode::Permissive {
+            res.push(format!(
+                "Corrupt record column = {}",
+                self.corrupt_record_column
+            ));
+        }
         if let Some(buffer_size) = self.buffer_size {
             res.push(format!("Buffer size = {}", buffer_size));
         }
@@ -212,19 +265,51 @@ impl CsvSourceConfig {
     ///
     /// # Arguments
     ///
-    /// * `delimiter` - The character delmiting individual cells in the CSV data.
+    /// * `delimiter` - The string delmiting individual cells in the CSV data, which may span multiple bytes.
     /// * `has_headers` - Whether the CSV has a header row; if so, it will be skipped during data parsing.
+    /// * `null_values` - Values, in addition to empty fields, that should be parsed as null.
+    /// * `true_values` - Values, in addition to "true", that should be parsed as true.
+    /// * `false_values` - Values, in addition to "false", that should be parsed as false.
+    /// * `skip_rows` - Number of lines to skip at the start of each file, before the header.
+    /// * `ragged_rows` - How to handle rows with fewer or more fields than the schema, one of "error", "fill"
+    ///   or "reject".
+    /// * `mode` - How to handle malformed rows, one of "fail_fast", "drop_malformed" or "permissive".
+    /// * `corrupt_record_column` - The name of the column holding the malformed rows in permissive mode.
     /// * `buffer_size` - Size of the buffer (in bytes) used by the streaming reader.
     /// * `chunk_size` - Size of the chunks (in bytes) deserialized in parallel by the streaming reader.
     #[allow(clippy::too_many_arguments)]
     #[new]
+    #[pyo3(signature = (
+        has_headers,
+        double_quote,
+        delimiter,
+        quote,
+        escape_char,
+        comment,
+        null_values=None,
+        true_values=None,
+        false_values=None,
+        skip_rows=0,
+        ragged_rows=None,
+        mode=None,
+        corrupt_record_column=None,
+        buffer_size=None,
+        chunk_size=None
+    ))]
     fn new(
         has_headers: bool,
         double_quote: bool,
-        delimiter: Option<char>,
+        delimiter: Option<String>,
         quote: Option<char>,
         escape_char: Option<char>,
         comment: Option<char>,
+        null_values: Option<Vec<String>>,
+        true_values: Option<Vec<String>>,
+        false_values: Option<Vec<String>>,
+        skip_rows: usize,
+        ragged_rows: Option<&str>,
+        mode: Option<&str>,
+        corrupt_record_column: Option<String>,
         buffer_size: Option<usize>,
         chunk_size: Option<usize>,
     ) -> PyResult<Self> {
@@ -235,6 +320,14 @@ impl CsvSourceConfig {
             quote,
             escape_char,
             comment,
+            null_values,
+            true_values,
+            false_values,
+            skip_rows,
+            ragged_rows: ragged_rows.map(str::parse).transpose()?.unwrap_or_default(),
+            mode: mode.map(str::parse).transpose()?.unwrap_or_default(),
+            corrupt_record_column: corrupt_record_column
+                .unwrap_or_else(|| DEFAULT_CORRUPT_RECORD_COLUMN_NAME.to_string()),
             buffer_size,
             chunk_size,
         })
@@ -247,6 +340,8 @@ impl_bincode_py_state_serialization!(CsvSourceConfig);
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
 pub struct JsonSourceConfig {
+    pub mode: ParseMode,
+    pub corrupt_record_column: String,
     pub buffer_size: Option<usize>,
     pub chunk_size: Option<usize>,
 }
@@ -254,6 +349,8 @@ pub struct JsonSourceConfig {
 impl JsonSourceConfig {
     pub fn new_internal(buffer_size: Option<usize>, chunk_size: Option<usize>) -> Self {
         Self {
+            mode: ParseMode::FailFast,
+            corrupt_record_column: DEFAULT_CORRUPT_RECORD_COLUMN_NAME.to_string(),
             buffer_size,
             chunk_size,
         }
@@ -261,6 +358,15 @@ impl JsonSourceConfig {
 
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
+        if self.mode != ParseMode::FailFast {
+            res.push(format!("Mode = {}", self.mode));
+        }
+        if self.mode == ParseMode::Permissive {
+            res.push(format!(
+                "Corrupt record column = {}",
+                self.corrupt_record_column
+            ));
+        }
         if let Some(buffer_size) = self.buffer_size {
             res.push(format!("Buffer size = {}", buffer_size));
         }
@@ -286,53 +392,161 @@ impl JsonSourceConfig {
     ///
     /// * `buffer_size` - Size of the buffer (in bytes) used by the streaming reader.
     /// * `chunk_size` - Size of the chunks (in bytes) deserialized in parallel by the streaming reader.
+    /// * `mode` - How to handle malformed lines, one of "fail_fast", "drop_malformed" or "permissive".
+    /// * `corrupt_record_column` - The name of the column holding the malformed lines in permissive mode.
     #[new]
-    fn new(buffer_size: Option<usize>, chunk_size: Option<usize>) -> Self {
-        Self::new_internal(buffer_size, chunk_size)
+    #[pyo3(signature = (buffer_size=None, chunk_size=None, mode=None, corrupt_record_column=None))]
+    fn new(
+        buffer_size: Option<usize>,
+        chunk_size: Option<usize>,
+        mode: Option<&str>,
+        corrupt_record_column: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            mode: mode.map(str::parse).transpose()?.unwrap_or_default(),
+            corrupt_record_column: corrupt_record_column
+                .unwrap_or_else(|| DEFAULT_CORRUPT_RECORD_COLUMN_NAME.to_string()),
+            ..Self::new_internal(buffer_size, chunk_size)
+        })
     }
 }
 
 impl_bincode_py_state_serialization!(JsonSourceConfig);
 
-/// Configuration for a Database data source.
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg(feature = "python")]
+/// Configuration for an Arrow IPC (Feather v2) data source.
+///
+/// Both the random-access file format and the streaming format are supported, and the variant is
+/// detected from the file contents.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
-pub struct DatabaseSourceConfig {
-    pub sql: String,
-    #[serde(
-        serialize_with = "serialize_py_object",
-        deserialize_with = "deserialize_py_object"
-    )]
-    pub conn: PyObject,
+pub struct ArrowIpcSourceConfig {}
+
+impl ArrowIpcSourceConfig {
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![]
+    }
 }
 
 #[cfg(feature = "python")]
-impl PartialEq for DatabaseSourceConfig {
-    fn eq(&self, other: &Self) -> bool {
-        self.sql == other.sql
-            && Python::with_gil(|py| self.conn.as_ref(py).eq(other.conn.as_ref(py)).unwrap())
+#[pymethods]
+impl ArrowIpcSourceConfig {
+    /// Create a config for an Arrow IPC data source.
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl_bincode_py_state_serialization!(ArrowIpcSourceConfig);
+
+/// Configuration for an Avro object container file data source.
+///
+/// Each file is decoded with the schema it was written with, which is then resolved against the
+/// schema of the scan.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub struct AvroSourceConfig {}
+
+impl AvroSourceConfig {
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![]
     }
 }
 
 #[cfg(feature = "python")]
-impl Eq for DatabaseSourceConfig {}
+#[pymethods]
+impl AvroSourceConfig {
+    /// Create a config for an Avro data source.
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl_bincode_py_state_serialization!(AvroSourceConfig);
+
+/// Configuration for an ORC data source.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub struct OrcSourceConfig {}
+
+impl OrcSourceConfig {
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![]
+    }
+}
 
 #[cfg(feature = "python")]
-impl Hash for DatabaseSourceConfig {
+#[pymethods]
+impl OrcSourceConfig {
+    /// Create a config for an ORC data source.
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl_bincode_py_state_serialization!(OrcSourceConfig);
+
+/// Connection to the database that a Database data source reads from.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum DatabaseConnection {
+    /// URL of a database that is read natively, see [`daft_database::DatabaseUrl`].
+    Url(String),
+    /// Python `SQLConnection`, which reads the database with ConnectorX or SQLAlchemy.
+    #[cfg(feature = "python")]
+    Python(
+        #[serde(
+            serialize_with = "serialize_py_object",
+            deserialize_with = "deserialize_py_object"
+        )]
+        PyObject,
+    ),
+}
+
+impl PartialEq for DatabaseConnection {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Url(url), Self::Url(other)) => url == other,
+            #[cfg(feature = "python")]
+            (Self::Python(conn), Self::Python(other)) => {
+                Python::with_gil(|py| conn.as_ref(py).eq(other.as_ref(py)).unwrap())
+            }
+            #[cfg(feature = "python")]
+            _ => false,
+        }
+    }
+}
+
+impl Eq for DatabaseConnection {}
+
+impl Hash for DatabaseConnection {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.sql.hash(state);
-        let py_obj_hash = Python::with_gil(|py| self.conn.as_ref(py).hash());
-        match py_obj_hash {
-            Ok(hash) => hash.hash(state),
-            Err(_) => serde_json::to_vec(self).unwrap().hash(state),
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Url(url) => url.hash(state),
+            #[cfg(feature = "python")]
+            Self::Python(conn) => {
+                let py_obj_hash = Python::with_gil(|py| conn.as_ref(py).hash());
+                match py_obj_hash {
+                    Ok(hash) => hash.hash(state),
+                    Err(_) => serde_json::to_vec(self).unwrap().hash(state),
+                }
+            }
         }
     }
 }
 
-#[cfg(feature = "python")]
+/// Configuration for a Database data source.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub struct DatabaseSourceConfig {
+    pub sql: String,
+    pub conn: DatabaseConnection,
+}
+
 impl DatabaseSourceConfig {
-    pub fn new_internal(sql: String, conn: PyObject) -> Self {
+    pub fn new_internal(sql: String, conn: DatabaseConnection) -> Self {
         Self { sql, conn }
     }
 
@@ -346,10 +560,10 @@ impl DatabaseSourceConfig {
 #[cfg(feature = "python")]
 #[pymethods]
 impl DatabaseSourceConfig {
-    /// Create a config for a Database data source.
+    /// Create a config for a Database data source read through a Python `SQLConnection`.
     #[new]
     fn new(sql: &str, conn: PyObject) -> Self {
-        Self::new_internal(sql.to_string(), conn)
+        Self::new_internal(sql.to_string(), DatabaseConnection::Python(conn))
     }
 }
 
@@ -385,6 +599,24 @@ impl PyFileFormatConfig {
         Self(Arc::new(FileFormatConfig::Json(config)))
     }
 
+    /// Create an Arrow IPC file format config.
+    #[staticmethod]
+    fn from_arrow_ipc_config(config: ArrowIpcSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::ArrowIpc(config)))
+    }
+
+    /// Create an Avro file format config.
+    #[staticmethod]
+    fn from_avro_config(config: AvroSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Avro(config)))
+    }
+
+    /// Create an ORC file format config.
+    #[staticmethod]
+    fn from_orc_config(config: OrcSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Orc(config)))
+    }
+
     /// Create a Database file format config.
     #[staticmethod]
     fn from_database_config(config: DatabaseSourceConfig) -> Self {
@@ -400,6 +632,9 @@ impl PyFileFormatConfig {
             Parquet(config) => config.clone().into_py(py),
             Csv(config) => config.clone().into_py(py),
             Json(config) => config.clone().into_py(py),
+            ArrowIpc(config) => config.clone().into_py(py),
+            Avro(config) => config.clone().into_py(py),
+            Orc(config) => config.clone().into_py(py),
             Database(config) => config.clone().into_py(py),
         }
     }
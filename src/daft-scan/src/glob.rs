@@ -1,17 +1,19 @@
 use std::{sync::Arc, vec};
 
 use common_error::{DaftError, DaftResult};
-use daft_core::schema::SchemaRef;
-use daft_csv::CsvParseOptions;
+use daft_core::schema::{Schema, SchemaRef};
+use daft_csv::{CsvParseOptions, RaggedRows};
+use daft_decoding::parse_mode::{corrupt_record_field, ParseMode};
 use daft_io::{parse_url, FileMetadata, IOClient, IOStatsContext, IOStatsRef};
 use daft_parquet::read::ParquetSchemaInferenceOptions;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use snafu::Snafu;
 
 use crate::{
-    file_format::{CsvSourceConfig, FileFormatConfig, ParquetSourceConfig},
+    file_format::{CsvSourceConfig, FileFormatConfig, JsonSourceConfig, ParquetSourceConfig},
     storage_config::StorageConfig,
-    DataFileSource, PartitionField, Pushdowns, ScanOperator, ScanTask, ScanTaskRef,
+    AggregationPushdown, DataFileSource, PartitionField, Pushdowns, ScanOperator, ScanTask,
+    ScanTaskRef,
 };
 #[derive(Debug)]
 pub struct GlobScanOperator {
@@ -178,32 +180,70 @@ impl GlobScanOperator {
                 quote,
                 escape_char,
                 comment,
+                null_values,
+                true_values,
+                false_values,
+                skip_rows,
+                ragged_rows,
+                mode,
                 ..
             }) => {
+                // Malformed rows are handled while reading, so they shouldn't fail schema inference.
+                let ragged_rows = match (mode, ragged_rows) {
+                    (ParseMode::DropMalformed | ParseMode::Permissive, RaggedRows::Error) => {
+                        RaggedRows::Fill
+                    }
+                    _ => *ragged_rows,
+                };
                 let (schema, _) = daft_csv::metadata::read_csv_schema(
                     first_filepath.as_str(),
-                    Some(CsvParseOptions::new_with_defaults(
-                        *has_headers,
-                        *delimiter,
-                        *double_quote,
-                        *quote,
-                        *escape_char,
-                        *comment,
-                    )?),
+                    Some(
+                        CsvParseOptions::new_with_defaults(
+                            *has_headers,
+                            delimiter.as_deref(),
+                            *double_quote,
+                            *quote,
+                            *escape_char,
+                            *comment,
+                        )?
+                        .with_null_values(null_values.clone())
+                        .with_true_values(true_values.clone())
+                        .with_false_values(false_values.clone())
+                        .with_skip_rows(*skip_rows)
+                        .with_ragged_rows(ragged_rows),
+                    ),
                     None,
                     io_client,
                     Some(io_stats),
                 )?;
                 schema
             }
-            FileFormatConfig::Json(_) => daft_json::schema::read_json_schema(
+            FileFormatConfig::Json(JsonSourceConfig { mode, .. }) => {
+                daft_json::schema::read_json_schema(
+                    first_filepath.as_str(),
+                    None,
+                    None,
+                    // Malformed lines are handled while reading, so they shouldn't fail schema inference.
+                    *mode != ParseMode::FailFast,
+                    io_client,
+                    Some(io_stats),
+                )?
+            }
+            FileFormatConfig::ArrowIpc(_) => daft_ipc::schema::read_ipc_schema(
+                first_filepath.as_str(),
+                io_client,
+                Some(io_stats),
+            )?,
+            FileFormatConfig::Avro(_) => daft_avro::schema::read_avro_schema(
+                first_filepath.as_str(),
+                io_client,
+                Some(io_stats),
+            )?,
+            FileFormatConfig::Orc(_) => daft_orc::schema::read_orc_schema(
                 first_filepath.as_str(),
-                None,
-                None,
                 io_client,
                 Some(io_stats),
             )?,
-            #[cfg(feature = "python")]
             FileFormatConfig::Database(_) => {
                 return Err(DaftError::ValueError(
                     "Cannot glob a database source".to_string(),
@@ -211,6 +251,29 @@ impl GlobScanOperator {
             }
         };
 
+        // The corrupt record column isn't read from the files, so it is added after inference.
+        let inferred_schema = match file_format_config.as_ref() {
+            FileFormatConfig::Csv(CsvSourceConfig {
+                mode: ParseMode::Permissive,
+                corrupt_record_column,
+                ..
+            })
+            | FileFormatConfig::Json(JsonSourceConfig {
+                mode: ParseMode::Permissive,
+                corrupt_record_column,
+                ..
+            }) => {
+                let mut fields = inferred_schema
+                    .fields
+                    .into_values()
+                    .filter(|field| &field.name != corrupt_record_column)
+                    .collect::<Vec<_>>();
+                fields.push((&corrupt_record_field(corrupt_record_column)).into());
+                Schema::new(fields)?
+            }
+            _ => inferred_schema,
+        };
+
         let schema = match schema_hint {
             None => Arc::new(inferred_schema),
             Some(schema_hint) => Arc::new(inferred_schema.apply_hints(&schema_hint)?),
@@ -243,6 +306,13 @@ impl ScanOperator for GlobScanOperator {
     fn can_absorb_limit(&self) -> bool {
         false
     }
+    fn can_absorb_aggregation(&self, aggregation: &AggregationPushdown) -> bool {
+        // Parquet footers provide exact row counts and column statistics.
+        matches!(
+            self.file_format_config.as_ref(),
+            FileFormatConfig::Parquet(_)
+        ) && aggregation.is_derivable_from_metadata(&self.schema, self.partitioning_keys())
+    }
 
     fn multiline_display(&self) -> Vec<String> {
         let mut lines = vec![
@@ -300,4 +370,9 @@ impl ScanOperator for GlobScanOperator {
             .into())
         })))
     }
+
+    fn can_estimate_size_from_scan_tasks(&self) -> bool {
+        // Listing the files gives their sizes, without reading them.
+        true
+    }
 }
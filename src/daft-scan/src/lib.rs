@@ -4,15 +4,16 @@ use std::{
     borrow::Cow,
     fmt::{Debug, Display},
     hash::{Hash, Hasher},
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{
+    count_mode::CountMode,
     datatypes::Field,
     schema::{Schema, SchemaRef},
 };
-use daft_dsl::ExprRef;
+use daft_dsl::{AggExpr, Expr, ExprRef};
 use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
 use file_format::FileFormatConfig;
 use itertools::Itertools;
@@ -20,6 +21,8 @@ use serde::{Deserialize, Serialize};
 
 mod anonymous;
 pub use anonymous::AnonymousScanOperator;
+mod database;
+pub use database::DatabaseScanOperator;
 pub mod file_format;
 mod glob;
 use common_daft_config::DaftExecutionConfig;
@@ -103,15 +106,22 @@ impl From<Error> for pyo3::PyErr {
 pub enum ChunkSpec {
     /// Selection of Parquet row groups.
     Parquet(Vec<i64>),
+    /// Selection of ORC stripes.
+    Orc(Vec<i64>),
+    /// Byte range of a CSV or NDJSON file, covering the records that start within the range.
+    Bytes { start: usize, end: usize },
 }
 
 impl ChunkSpec {
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         match self {
-            Self::Parquet(chunks) => {
+            Self::Parquet(chunks) | Self::Orc(chunks) => {
                 res.push(format!("Chunks = {:?}", chunks));
             }
+            Self::Bytes { start, end } => {
+                res.push(format!("Bytes = {}..{}", start, end));
+            }
         }
         res
     }
@@ -391,6 +401,9 @@ impl ScanTask {
     }
 
     pub fn materialized_schema(&self) -> SchemaRef {
+        if let Some(aggregation) = &self.pushdowns.aggregation {
+            return aggregation.output_schema.clone();
+        }
         match &self.pushdowns.columns {
             None => self.schema.clone(),
             Some(columns) => Arc::new(Schema {
@@ -406,7 +419,14 @@ impl ScanTask {
     }
 
     pub fn num_rows(&self) -> Option<usize> {
-        if self.pushdowns.filters.is_some() {
+        if let Some(aggregation) = &self.pushdowns.aggregation {
+            // All sources of a ScanTask have the same partition values, so there is at most one group.
+            if aggregation.groupby.is_empty() {
+                Some(1)
+            } else {
+                None
+            }
+        } else if self.pushdowns.filters.is_some() {
             None
         } else {
             self.metadata.as_ref().map(|m| m.length)
@@ -447,11 +467,15 @@ impl ScanTask {
                     let config = config
                         .map_or_else(|| Cow::Owned(DaftExecutionConfig::default()), Cow::Borrowed);
                     let inflation_factor = match self.file_format_config.as_ref() {
-                        FileFormatConfig::Parquet(_) => config.parquet_inflation_factor,
+                        FileFormatConfig::Parquet(_) | FileFormatConfig::Orc(_) => {
+                            config.parquet_inflation_factor
+                        }
                         FileFormatConfig::Csv(_) | FileFormatConfig::Json(_) => {
                             config.csv_inflation_factor
                         }
-                        #[cfg(feature = "python")]
+                        // IPC data is (uncompressed) Arrow memory laid out on disk.
+                        FileFormatConfig::ArrowIpc(_) => 1.0,
+                        FileFormatConfig::Avro(_) => config.csv_inflation_factor,
                         FileFormatConfig::Database(_) => 0.0,
                     };
 
@@ -614,11 +638,19 @@ pub trait ScanOperator: Send + Sync + Debug {
     fn can_absorb_filter(&self) -> bool;
     fn can_absorb_select(&self) -> bool;
     fn can_absorb_limit(&self) -> bool;
+    fn can_absorb_aggregation(&self, aggregation: &AggregationPushdown) -> bool;
     fn multiline_display(&self) -> Vec<String>;
     fn to_scan_tasks(
         &self,
         pushdowns: Pushdowns,
     ) -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTaskRef>>>>;
+
+    /// Whether the scan tasks can be listed while optimizing a plan to estimate the size of the scan, i.e.
+    /// without reading any data or running queries. Listing files is fine, since the listed scan tasks are
+    /// handed over to the translation of the plan through [`ListedScanTasks`].
+    fn can_estimate_size_from_scan_tasks(&self) -> bool {
+        false
+    }
 }
 
 impl Display for dyn ScanOperator {
@@ -659,12 +691,42 @@ impl Display for ScanOperatorRef {
     }
 }
 
+/// Scan tasks that were listed while optimizing a plan, e.g. to estimate the size of a scan, and are taken
+/// by the translation of the plan so that it doesn't list them again.
+///
+/// They're shared by the clones of a [`ScanExternalInfo`], but don't take part in its equality or hash.
+#[derive(Debug, Clone, Default)]
+pub struct ListedScanTasks(Arc<Mutex<Option<Vec<ScanTaskRef>>>>);
+
+impl ListedScanTasks {
+    pub fn set(&self, scan_tasks: Vec<ScanTaskRef>) {
+        *self.0.lock().unwrap() = Some(scan_tasks);
+    }
+
+    pub fn take(&self) -> Option<Vec<ScanTaskRef>> {
+        self.0.lock().unwrap().take()
+    }
+}
+
+impl PartialEq for ListedScanTasks {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ListedScanTasks {}
+
+impl Hash for ListedScanTasks {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ScanExternalInfo {
     pub scan_op: ScanOperatorRef,
     pub source_schema: SchemaRef,
     pub partitioning_keys: Vec<PartitionField>,
     pub pushdowns: Pushdowns,
+    pub listed_scan_tasks: ListedScanTasks,
 }
 
 impl ScanExternalInfo {
@@ -679,6 +741,7 @@ impl ScanExternalInfo {
             source_schema,
             partitioning_keys,
             pushdowns,
+            listed_scan_tasks: Default::default(),
         }
     }
 
@@ -688,6 +751,7 @@ impl ScanExternalInfo {
             source_schema: self.source_schema.clone(),
             partitioning_keys: self.partitioning_keys.clone(),
             pushdowns,
+            listed_scan_tasks: Default::default(),
         }
     }
 }
@@ -702,11 +766,13 @@ pub struct Pushdowns {
     pub columns: Option<Arc<Vec<String>>>,
     /// Optional number of rows to read.
     pub limit: Option<usize>,
+    /// Optional aggregation to compute over the source data instead of returning its rows.
+    pub aggregation: Option<Arc<AggregationPushdown>>,
 }
 
 impl Default for Pushdowns {
     fn default() -> Self {
-        Self::new(None, None, None, None)
+        Self::new(None, None, None, None, None)
     }
 }
 
@@ -716,12 +782,14 @@ impl Pushdowns {
         partition_filters: Option<ExprRef>,
         columns: Option<Arc<Vec<String>>>,
         limit: Option<usize>,
+        aggregation: Option<Arc<AggregationPushdown>>,
     ) -> Self {
         Self {
             filters,
             partition_filters,
             columns,
             limit,
+            aggregation,
         }
     }
 
@@ -731,6 +799,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit,
+            aggregation: self.aggregation.clone(),
         }
     }
 
@@ -740,6 +809,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit: self.limit,
+            aggregation: self.aggregation.clone(),
         }
     }
 
@@ -749,6 +819,7 @@ impl Pushdowns {
             partition_filters,
             columns: self.columns.clone(),
             limit: self.limit,
+            aggregation: self.aggregation.clone(),
         }
     }
 
@@ -758,6 +829,17 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns,
             limit: self.limit,
+            aggregation: self.aggregation.clone(),
+        }
+    }
+
+    pub fn with_aggregation(&self, aggregation: Option<Arc<AggregationPushdown>>) -> Self {
+        Self {
+            filters: self.filters.clone(),
+            partition_filters: self.partition_filters.clone(),
+            columns: self.columns.clone(),
+            limit: self.limit,
+            aggregation,
         }
     }
 
@@ -775,6 +857,105 @@ impl Pushdowns {
         if let Some(limit) = self.limit {
             res.push(format!("Limit pushdown = {}", limit));
         }
+        if let Some(aggregation) = &self.aggregation {
+            res.extend(aggregation.multiline_display());
+        }
+        res
+    }
+}
+
+/// An aggregation that is computed by each scan task over its source data, producing one row per group.
+///
+/// Scan tasks may compute the aggregation from their metadata and statistics without reading any data, so only
+/// aggregations whose per-task results can be merged by a final aggregation (counts, mins and maxes) are pushed down.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct AggregationPushdown {
+    pub aggregations: Vec<AggExpr>,
+    pub groupby: Vec<ExprRef>,
+    /// Schema of the aggregated data: the group-by columns followed by the aggregations.
+    pub output_schema: SchemaRef,
+}
+
+impl AggregationPushdown {
+    pub fn try_new(
+        aggregations: Vec<AggExpr>,
+        groupby: Vec<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Self> {
+        let fields = groupby
+            .iter()
+            .map(|e| e.to_field(schema))
+            .chain(aggregations.iter().map(|agg| agg.to_field(schema)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Self {
+            aggregations,
+            groupby,
+            output_schema: Schema::new(fields)?.into(),
+        })
+    }
+
+    /// Whether this aggregation is fully determined by the row counts and column statistics of scan tasks, i.e. it
+    /// only counts all rows and takes the min or max of integer or temporal data columns, grouped by partition columns
+    /// with an identity transform.
+    pub fn is_derivable_from_metadata(
+        &self,
+        schema: &Schema,
+        partitioning_keys: &[PartitionField],
+    ) -> bool {
+        let partition_field =
+            |name: &str| partitioning_keys.iter().find(|pf| pf.field.name == name);
+        let groupby_derivable = self.groupby.iter().all(|e| match e.as_ref() {
+            Expr::Column(name) => matches!(
+                partition_field(name),
+                Some(pf) if matches!(pf.transform, None | Some(PartitionTransform::Identity))
+            ),
+            _ => false,
+        });
+        let aggregations_derivable = self.aggregations.iter().all(|agg| match agg {
+            AggExpr::Count(_, CountMode::All) => true,
+            AggExpr::Min(_) | AggExpr::Max(_) => match Self::min_max_column(agg) {
+                Some(name) => {
+                    partition_field(name).is_none()
+                        && matches!(
+                            schema.get_field(name),
+                            Ok(field) if field.dtype.is_integer() || field.dtype.is_temporal()
+                        )
+                }
+                None => false,
+            },
+            _ => false,
+        });
+        groupby_derivable && aggregations_derivable
+    }
+
+    /// The column that the provided aggregation takes the min or max of, if any.
+    ///
+    /// The plan builder pushes aliases into the children of aggregations, so aliased columns are included.
+    pub fn min_max_column(aggregation: &AggExpr) -> Option<&str> {
+        match aggregation {
+            AggExpr::Min(e) | AggExpr::Max(e) => match e.as_ref() {
+                Expr::Column(name) => Some(name.as_ref()),
+                Expr::Alias(e, _) if let Expr::Column(name) = e.as_ref() => Some(name.as_ref()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!(
+            "Aggregation pushdown = [{}]",
+            self.aggregations
+                .iter()
+                .map(|agg| agg.to_string())
+                .join(", ")
+        )];
+        if !self.groupby.is_empty() {
+            res.push(format!(
+                "Aggregation group by = [{}]",
+                self.groupby.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
         res
     }
 }
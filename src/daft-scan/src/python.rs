@@ -26,6 +26,8 @@ pub mod pylib {
     use serde::{Deserialize, Serialize};
 
     use crate::anonymous::AnonymousScanOperator;
+    use crate::database::DatabaseScanOperator;
+    use crate::AggregationPushdown;
     use crate::DataFileSource;
     use crate::PartitionField;
     use crate::Pushdowns;
@@ -92,6 +94,31 @@ pub mod pylib {
             })
         }
 
+        #[staticmethod]
+        pub fn sql_scan(
+            py: Python,
+            sql: String,
+            url: String,
+            storage_config: PyStorageConfig,
+            partition_col: Option<String>,
+            num_partitions: Option<usize>,
+            partition_size_bytes: usize,
+        ) -> PyResult<Self> {
+            py.allow_threads(|| {
+                let operator = Arc::new(DatabaseScanOperator::try_new(
+                    sql,
+                    url,
+                    partition_col,
+                    num_partitions,
+                    partition_size_bytes,
+                    storage_config.into(),
+                )?);
+                Ok(ScanOperatorHandle {
+                    scan_op: ScanOperatorRef(operator),
+                })
+            })
+        }
+
         #[staticmethod]
         pub fn from_python_scan_operator(py_scan: PyObject, py: Python) -> PyResult<Self> {
             let scan_op = ScanOperatorRef(Arc::new(PythonScanOperatorBridge::from_python_abc(
@@ -109,6 +136,7 @@ pub mod pylib {
         can_absorb_filter: bool,
         can_absorb_limit: bool,
         can_absorb_select: bool,
+        can_absorb_aggregation: bool,
         display_name: String,
     }
 
@@ -145,6 +173,11 @@ pub mod pylib {
                 .extract::<bool>(py)
         }
 
+        fn _can_absorb_aggregation(abc: &PyObject, py: Python) -> PyResult<bool> {
+            abc.call_method0(py, pyo3::intern!(py, "can_absorb_aggregation"))?
+                .extract::<bool>(py)
+        }
+
         fn _display_name(abc: &PyObject, py: Python) -> PyResult<String> {
             abc.call_method0(py, pyo3::intern!(py, "display_name"))?
                 .extract::<String>(py)
@@ -160,6 +193,7 @@ pub mod pylib {
             let can_absorb_filter = Self::_can_absorb_filter(&abc, py)?;
             let can_absorb_limit = Self::_can_absorb_limit(&abc, py)?;
             let can_absorb_select = Self::_can_absorb_select(&abc, py)?;
+            let can_absorb_aggregation = Self::_can_absorb_aggregation(&abc, py)?;
             let display_name = Self::_display_name(&abc, py)?;
 
             Ok(Self {
@@ -169,6 +203,7 @@ pub mod pylib {
                 can_absorb_filter,
                 can_absorb_limit,
                 can_absorb_select,
+                can_absorb_aggregation,
                 display_name,
             })
         }
@@ -190,6 +225,12 @@ pub mod pylib {
         fn can_absorb_select(&self) -> bool {
             self.can_absorb_select
         }
+        fn can_absorb_aggregation(&self, aggregation: &AggregationPushdown) -> bool {
+            // Python scan operators opt in to having aggregations computed from the metadata and statistics of their
+            // scan tasks.
+            self.can_absorb_aggregation
+                && aggregation.is_derivable_from_metadata(&self.schema, &self.partitioning_keys)
+        }
 
         fn multiline_display(&self) -> Vec<String> {
             let lines = vec![format!("PythonScanOperator: {}", self.display_name)];
@@ -458,6 +499,23 @@ pub mod pylib {
     pub struct PyPushdowns(Arc<Pushdowns>);
     #[pymethods]
     impl PyPushdowns {
+        #[new]
+        #[pyo3(signature = (columns=None, filters=None, partition_filters=None, limit=None))]
+        pub fn new(
+            columns: Option<Vec<String>>,
+            filters: Option<PyExpr>,
+            partition_filters: Option<PyExpr>,
+            limit: Option<usize>,
+        ) -> Self {
+            Self(Arc::new(Pushdowns::new(
+                filters.map(|f| f.expr.into()),
+                partition_filters.map(|f| f.expr.into()),
+                columns.map(Arc::new),
+                limit,
+                None,
+            )))
+        }
+
         pub fn __repr__(&self) -> PyResult<String> {
             Ok(format!("{:#?}", self.0))
         }
@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
+use daft_compression::CompressionCodec;
 use daft_io::IOStatsContext;
+use daft_orc::read_orc_metadata;
 use daft_parquet::read::read_parquet_metadata;
 
 use crate::{
-    file_format::{FileFormatConfig, ParquetSourceConfig},
+    file_format::{CsvSourceConfig, FileFormatConfig, OrcSourceConfig, ParquetSourceConfig},
     storage_config::StorageConfig,
     ChunkSpec, DataFileSource, ScanTask, ScanTaskRef,
 };
@@ -177,36 +179,12 @@ pub fn split_by_row_groups(
                             curr_num_rows += rg.num_rows();
 
                             if curr_size_bytes >= min_size_bytes || i == file.row_groups.len() - 1 {
-                                let mut new_source = source.clone();
-
-                                match &mut new_source {
-                                    DataFileSource::AnonymousDataFile {
-                                        chunk_spec,
-                                        size_bytes,
-                                        ..
-                                    }
-                                    | DataFileSource::CatalogDataFile {
-                                        chunk_spec,
-                                        size_bytes,
-                                        ..
-                                    } | DataFileSource::DatabaseDataSource { chunk_spec, size_bytes, .. } => {
-                                        *chunk_spec = Some(ChunkSpec::Parquet(curr_row_groups));
-                                        *size_bytes = Some(curr_size_bytes as u64);
-                                    }
-                                };
-                                match &mut new_source {
-                                    DataFileSource::AnonymousDataFile {
-                                        metadata: Some(metadata),
-                                        ..
-                                    }
-                                    | DataFileSource::CatalogDataFile {
-                                        metadata,
-                                        ..
-                                    } | DataFileSource::DatabaseDataSource { metadata: Some(metadata), .. } => {
-                                        metadata.length = curr_num_rows;
-                                    }
-                                    _ => (),
-                                }
+                                let new_source = with_chunk_spec(
+                                    source,
+                                    ChunkSpec::Parquet(curr_row_groups),
+                                    curr_size_bytes,
+                                    Some(curr_num_rows),
+                                );
 
                                 // Reset accumulators
                                 curr_row_groups = Vec::new();
@@ -233,3 +211,224 @@ pub fn split_by_row_groups(
         )
     }
 }
+
+/// Splits ORC scan tasks into tasks that each read a subset of the file's stripes, in the same way
+/// as [`split_by_row_groups`] does for Parquet row groups.
+pub fn split_by_stripes(
+    scan_tasks: BoxScanTaskIter,
+    max_tasks: usize,
+    min_size_bytes: usize,
+    max_size_bytes: usize,
+) -> BoxScanTaskIter {
+    let mut scan_tasks = itertools::peek_nth(scan_tasks);
+
+    // only split if we have a small amount of files
+    if scan_tasks.peek_nth(max_tasks).is_some() {
+        return Box::new(scan_tasks);
+    }
+    Box::new(
+        scan_tasks
+            .map(move |t| -> DaftResult<BoxScanTaskIter> {
+                let t = t?;
+
+                // Same conditions as for splitting Parquet tasks.
+                if let (
+                    FileFormatConfig::Orc(OrcSourceConfig {}),
+                    StorageConfig::Native(_),
+                    [source],
+                    Some(None),
+                    None,
+                ) = (
+                    t.file_format_config.as_ref(),
+                    t.storage_config.as_ref(),
+                    &t.sources[..],
+                    t.sources.get(0).map(DataFileSource::get_chunk_spec),
+                    t.pushdowns.limit,
+                ) && source
+                    .get_size_bytes()
+                    .map_or(true, |s| s > max_size_bytes as u64)
+                {
+                    let (io_runtime, io_client) = t.storage_config.get_io_client_and_runtime()?;
+
+                    let path = source.get_path();
+
+                    let io_stats = IOStatsContext::new(format!("split_by_stripes for {:#?}", path));
+
+                    let metadata = io_runtime.handle().block_on(read_orc_metadata(
+                        path,
+                        io_client,
+                        Some(io_stats),
+                    ))?;
+
+                    let mut new_tasks: Vec<DaftResult<ScanTaskRef>> = Vec::new();
+                    let mut curr_stripes = Vec::new();
+                    let mut curr_size_bytes = 0;
+                    let mut curr_num_rows = 0;
+
+                    for i in 0..metadata.num_stripes() {
+                        curr_stripes.push(i as i64);
+                        curr_size_bytes += metadata.stripe_range(i).len();
+                        curr_num_rows += metadata.stripe_num_rows(i);
+
+                        if curr_size_bytes >= min_size_bytes || i == metadata.num_stripes() - 1 {
+                            let new_source = with_chunk_spec(
+                                source,
+                                ChunkSpec::Orc(std::mem::take(&mut curr_stripes)),
+                                std::mem::take(&mut curr_size_bytes),
+                                Some(std::mem::take(&mut curr_num_rows)),
+                            );
+                            new_tasks.push(Ok(ScanTask::new(
+                                vec![new_source],
+                                t.file_format_config.clone(),
+                                t.schema.clone(),
+                                t.storage_config.clone(),
+                                t.pushdowns.clone(),
+                            )
+                            .into()));
+                        }
+                    }
+
+                    Ok(Box::new(new_tasks.into_iter()))
+                } else {
+                    Ok(Box::new(std::iter::once(Ok(t))))
+                }
+            })
+            .flat_map(|t| t.unwrap_or_else(|e| Box::new(std::iter::once(Err(e))))),
+    )
+}
+
+/// Splits scan tasks over large uncompressed CSV and NDJSON files into tasks that each read a byte
+/// range of roughly `target_size_bytes` of the file. Each range reads the records that start within
+/// it, so a record that straddles two ranges is read by the first one.
+///
+/// CSV files are only split if `split_csv` is set: where a CSV record starts can only be guessed
+/// without reading the file from the start, and the guess can be wrong when quoted fields contain
+/// line breaks.
+pub fn split_by_byte_ranges(
+    scan_tasks: BoxScanTaskIter,
+    split_csv: bool,
+    target_size_bytes: usize,
+) -> BoxScanTaskIter {
+    Box::new(
+        scan_tasks
+            .map(move |t| -> DaftResult<BoxScanTaskIter> {
+                let t = t?;
+
+                /* Only split CSV and NDJSON tasks if they:
+                    - are CSV tasks when CSV splitting is enabled, and don't skip leading lines or
+                      use a multi-byte delimiter, both of which need to see the start of the file
+                    - use native storage config
+                    - have one uncompressed source, with no specified chunk spec, metadata or limit
+                    - have a known size past the target size
+                */
+                let splittable_format = match t.file_format_config.as_ref() {
+                    FileFormatConfig::Csv(CsvSourceConfig {
+                        skip_rows,
+                        delimiter,
+                        ..
+                    }) => {
+                        split_csv
+                            && *skip_rows == 0
+                            && delimiter.as_ref().map_or(true, |d| d.len() == 1)
+                    }
+                    FileFormatConfig::Json(_) => true,
+                    _ => false,
+                };
+                if let (
+                    true,
+                    StorageConfig::Native(_),
+                    [source],
+                    Some(None),
+                    None,
+                ) = (
+                    splittable_format,
+                    t.storage_config.as_ref(),
+                    &t.sources[..],
+                    t.sources.get(0).map(DataFileSource::get_chunk_spec),
+                    t.pushdowns.limit,
+                ) && source.get_metadata().is_none()
+                    && CompressionCodec::from_uri(source.get_path()).is_none()
+                    && let Some(size_bytes) = source.get_size_bytes()
+                    && size_bytes > target_size_bytes as u64
+                {
+                    let size_bytes = size_bytes as usize;
+                    let num_chunks = (size_bytes + target_size_bytes - 1) / target_size_bytes;
+                    let chunk_size_bytes = (size_bytes + num_chunks - 1) / num_chunks;
+                    let new_tasks = (0..size_bytes)
+                        .step_by(chunk_size_bytes)
+                        .map(|start| {
+                            let end = (start + chunk_size_bytes).min(size_bytes);
+                            let new_source = with_chunk_spec(
+                                source,
+                                ChunkSpec::Bytes { start, end },
+                                end - start,
+                                None,
+                            );
+                            Ok(ScanTask::new(
+                                vec![new_source],
+                                t.file_format_config.clone(),
+                                t.schema.clone(),
+                                t.storage_config.clone(),
+                                t.pushdowns.clone(),
+                            )
+                            .into())
+                        })
+                        .collect::<Vec<_>>();
+
+                    Ok(Box::new(new_tasks.into_iter()))
+                } else {
+                    Ok(Box::new(std::iter::once(Ok(t))))
+                }
+            })
+            .flat_map(|t| t.unwrap_or_else(|e| Box::new(std::iter::once(Err(e))))),
+    )
+}
+
+/// Copy of `source` that only reads the given chunk of the file, updating the number of rows in its
+/// metadata if it's known.
+fn with_chunk_spec(
+    source: &DataFileSource,
+    new_chunk_spec: ChunkSpec,
+    new_size_bytes: usize,
+    num_rows: Option<usize>,
+) -> DataFileSource {
+    let mut new_source = source.clone();
+    match &mut new_source {
+        DataFileSource::AnonymousDataFile {
+            chunk_spec,
+            size_bytes,
+            ..
+        }
+        | DataFileSource::CatalogDataFile {
+            chunk_spec,
+            size_bytes,
+            ..
+        }
+        | DataFileSource::DatabaseDataSource {
+            chunk_spec,
+            size_bytes,
+            ..
+        } => {
+            *chunk_spec = Some(new_chunk_spec);
+            *size_bytes = Some(new_size_bytes as u64);
+        }
+    };
+    match (&mut new_source, num_rows) {
+        (
+            DataFileSource::AnonymousDataFile {
+                metadata: Some(metadata),
+                ..
+            }
+            | DataFileSource::CatalogDataFile { metadata, .. }
+            | DataFileSource::DatabaseDataSource {
+                metadata: Some(metadata),
+                ..
+            },
+            Some(num_rows),
+        ) => {
+            metadata.length = num_rows;
+        }
+        _ => (),
+    }
+    new_source
+}
@@ -5,11 +5,13 @@ use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter, Result};
 
+use daft_core::array::ops::as_arrow::AsArrow;
 use daft_core::array::ops::full::FullNull;
 use daft_core::utils::display_table::make_comfy_table;
 use num_traits::ToPrimitive;
 
 use daft_core::array::ops::GroupIndices;
+use daft_core::count_mode::CountMode;
 
 use common_error::{DaftError, DaftResult};
 use daft_core::datatypes::{BooleanArray, DataType, Field, UInt64Array};
@@ -17,7 +19,7 @@ use daft_core::schema::{Schema, SchemaRef};
 use daft_core::series::{IntoSeries, Series};
 
 use daft_dsl::functions::FunctionEvaluator;
-use daft_dsl::{col, null_lit, AggExpr, Expr};
+use daft_dsl::{col, null_lit, AggExpr, ApproxPercentileParams, Expr};
 #[cfg(feature = "python")]
 pub mod ffi;
 mod ops;
@@ -32,6 +34,7 @@ pub use python::register_modules;
 pub struct Table {
     pub schema: SchemaRef,
     columns: Vec<Series>,
+    num_rows: usize,
 }
 
 impl Table {
@@ -66,16 +69,47 @@ impl Table {
             })
             .collect();
 
+        let columns = columns?;
+        let num_rows = if columns.is_empty() { 0 } else { num_rows };
         Ok(Table {
             schema,
-            columns: columns?,
+            columns,
+            num_rows,
+        })
+    }
+
+    /// Creates a table with `num_rows` rows, which is kept even if the table has no columns, e.g.
+    /// when a file is read with an empty column projection.
+    pub fn new_with_size<S: Into<SchemaRef>>(
+        schema: S,
+        columns: Vec<Series>,
+        num_rows: usize,
+    ) -> DaftResult<Self> {
+        let schema: SchemaRef = schema.into();
+        if schema.fields.len() != columns.len() {
+            return Err(DaftError::SchemaMismatch(format!("While building a Table, we found that the number of fields did not match between the schema and the input columns.\n {:?}\n vs\n {:?}", schema.fields.len(), columns.len())));
+        }
+        for (field, series) in schema.fields.values().zip(columns.iter()) {
+            if field != series.field() {
+                return Err(DaftError::SchemaMismatch(format!("While building a Table, we found that the Schema Field and the Series Field  did not match. schema field: {field} vs series field: {}", series.field())));
+            }
+            if series.len() != num_rows {
+                return Err(DaftError::ValueError(format!("While building a Table, we found that the Series lengths did not match. Series named: {} had length: {} vs the expected length: {}", field.name, series.len(), num_rows)));
+            }
+        }
+        Ok(Table {
+            schema,
+            columns,
+            num_rows,
         })
     }
 
     pub fn new_unchecked<S: Into<SchemaRef>>(schema: S, columns: Vec<Series>) -> Self {
+        let num_rows = columns.first().map_or(0, |s| s.len());
         Table {
             schema: schema.into(),
             columns,
+            num_rows,
         }
     }
 
@@ -87,7 +121,11 @@ impl Table {
                     let series = Series::empty(field_name, &field.dtype);
                     columns.push(series)
                 }
-                Ok(Table { schema, columns })
+                Ok(Table {
+                    schema,
+                    columns,
+                    num_rows: 0,
+                })
             }
             None => Self::new(Schema::empty(), vec![]),
         }
@@ -108,11 +146,7 @@ impl Table {
     }
 
     pub fn len(&self) -> usize {
-        if self.num_columns() == 0 {
-            0
-        } else {
-            self.get_column_by_index(0).unwrap().len()
-        }
+        self.num_rows
     }
 
     pub fn is_empty(&self) -> bool {
@@ -120,20 +154,25 @@ impl Table {
     }
 
     pub fn slice(&self, start: usize, end: usize) -> DaftResult<Self> {
-        let new_series: DaftResult<Vec<_>> =
-            self.columns.iter().map(|s| s.slice(start, end)).collect();
+        let new_series = self
+            .columns
+            .iter()
+            .map(|s| s.slice(start, end))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let num_rows = match new_series.first() {
+            Some(s) => s.len(),
+            None => end.min(self.len()).saturating_sub(start),
+        };
         Ok(Table {
             schema: self.schema.clone(),
-            columns: new_series?,
+            columns: new_series,
+            num_rows,
         })
     }
 
     pub fn head(&self, num: usize) -> DaftResult<Self> {
         if num >= self.len() {
-            return Ok(Table {
-                schema: self.schema.clone(),
-                columns: self.columns.clone(),
-            });
+            return Ok(self.clone());
         }
         self.slice(0, num)
     }
@@ -249,16 +288,48 @@ impl Table {
         }
 
         let mask = mask.downcast::<BooleanArray>().unwrap();
-        let new_series: DaftResult<Vec<_>> = self.columns.iter().map(|s| s.filter(mask)).collect();
+        let new_series = self
+            .columns
+            .iter()
+            .map(|s| s.filter(mask))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let num_rows = match new_series.first() {
+            Some(s) => s.len(),
+            // A mask of length 1 is broadcast over every row, like in `Series::filter`.
+            None if mask.len() == 1 => {
+                if mask.get(0) == Some(true) {
+                    self.len()
+                } else {
+                    0
+                }
+            }
+            None if mask.len() == self.len() => {
+                // Null mask values filter out their rows, like false ones.
+                let mask = mask.as_arrow();
+                let selected = match mask.validity() {
+                    Some(validity) => mask.values() & validity,
+                    None => mask.values().clone(),
+                };
+                selected.len() - selected.unset_bits()
+            }
+            None => {
+                return Err(DaftError::ValueError(format!(
+                    "Lengths for mask_filter do not match, Table {} vs mask {}",
+                    self.len(),
+                    mask.len()
+                )))
+            }
+        };
         Ok(Table {
             schema: self.schema.clone(),
-            columns: new_series?,
+            columns: new_series,
+            num_rows,
         })
     }
 
     pub fn take(&self, idx: &Series) -> DaftResult<Self> {
         let new_series: DaftResult<Vec<_>> = self.columns.iter().map(|s| s.take(idx)).collect();
-        Ok(Table::new(self.schema.clone(), new_series?).unwrap())
+        Ok(Table::new_with_size(self.schema.clone(), new_series?, idx.len()).unwrap())
     }
 
     pub fn concat<T: AsRef<Table>>(tables: &[T]) -> DaftResult<Self> {
@@ -293,6 +364,7 @@ impl Table {
         Ok(Table {
             schema: first_table.schema.clone(),
             columns: new_series,
+            num_rows: tables.iter().map(|t| t.as_ref().len()).sum(),
         })
     }
 
@@ -306,7 +378,8 @@ impl Table {
             .iter()
             .map(|s| self.get_column(s).cloned())
             .collect::<DaftResult<Vec<_>>>()?;
-        Self::from_columns(series_by_name)
+        let fields = series_by_name.iter().map(|s| s.field().clone()).collect();
+        Self::new_with_size(Schema::new(fields)?, series_by_name, self.len())
     }
 
     pub fn get_column_by_index(&self, idx: usize) -> DaftResult<&Series> {
@@ -330,6 +403,56 @@ impl Table {
             }
             List(expr) => Series::agg_list(&self.eval_expression(expr)?, groups),
             Concat(expr) => Series::agg_concat(&self.eval_expression(expr)?, groups),
+            CountDistinct(expr) => {
+                let (values, groups) = self.eval_distinct_agg_input(expr, groups)?;
+                Series::count(&values, groups.as_ref(), CountMode::Valid)
+            }
+            SumDistinct(expr) => {
+                let (values, groups) = self.eval_distinct_agg_input(expr, groups)?;
+                Series::sum(&values, groups.as_ref())
+            }
+            ListDistinct(expr) => {
+                let (values, groups) = self.eval_distinct_agg_input(expr, groups)?;
+                Series::agg_list(&values, groups.as_ref())
+            }
+            ApproxSketch(expr) => Series::approx_sketch(&self.eval_expression(expr)?, groups),
+            MergeSketch(expr) => Series::merge_sketch(&self.eval_expression(expr)?, groups),
+            ApproxPercentile(ApproxPercentileParams {
+                child,
+                percentiles,
+                force_list_output,
+            }) => {
+                let percentiles = percentiles.iter().map(|p| p.0).collect::<Vec<_>>();
+                Series::approx_sketch(&self.eval_expression(child)?, groups)?
+                    .sketch_percentile(&percentiles, *force_list_output)
+            }
+            HllSketch(expr) => Series::hll_sketch(&self.eval_expression(expr)?, groups),
+            HllMerge(expr) => Series::hll_merge(&self.eval_expression(expr)?, groups),
+            Variance(expr, ddof) => {
+                Series::moments(&self.eval_expression(expr)?, groups)?.moments_variance(*ddof)
+            }
+            Stddev(expr, ddof) => {
+                Series::moments(&self.eval_expression(expr)?, groups)?.moments_stddev(*ddof)
+            }
+            Skew(expr) => Series::moments(&self.eval_expression(expr)?, groups)?.moments_skew(),
+            Covar(x, y, ddof) => {
+                Series::co_moments(&self.eval_expression(x)?, &self.eval_expression(y)?, groups)?
+                    .co_moments_covar(*ddof)
+            }
+            Corr(x, y) => {
+                Series::co_moments(&self.eval_expression(x)?, &self.eval_expression(y)?, groups)?
+                    .co_moments_corr()
+            }
+            Moments(expr) => Series::moments(&self.eval_expression(expr)?, groups),
+            CoMoments(x, y) => {
+                Series::co_moments(&self.eval_expression(x)?, &self.eval_expression(y)?, groups)
+            }
+            MergeMoments(expr) => Series::merge_moments(&self.eval_expression(expr)?, groups),
+            ApproxCountDistinct(expr) => {
+                Ok(Series::hll_sketch(&self.eval_expression(expr)?, groups)?
+                    .hll_cardinality()?
+                    .into_series())
+            }
             MapGroups { .. } => Err(DaftError::ValueError(
                 "MapGroups not supported via aggregation, use map_groups instead".to_string(),
             )),
@@ -342,6 +465,9 @@ impl Table {
         let series = match expr {
             Alias(child, name) => Ok(self.eval_expression(child)?.rename(name)),
             Agg(agg_expr) => self.eval_agg_expression(agg_expr, None),
+            Window(..) => Err(DaftError::ValueError(format!(
+                "Window functions can only be evaluated over a window, but got: {expr}"
+            ))),
             Cast(child, dtype) => self.eval_expression(child)?.cast(dtype),
             Column(name) => self.get_column(name).cloned(),
             Not(child) => !(self.eval_expression(child)?),
@@ -467,7 +593,18 @@ impl Table {
                 }
             })
             .collect();
-        self.eval_expression_list(&exprs)
+        let table = self.eval_expression_list(&exprs)?;
+        if table.len() == self.len() {
+            return Ok(table);
+        }
+        // Fill values are evaluated as single-row literals, so they only get broadcast to the
+        // table's length if there are other columns to take it from.
+        let columns = table
+            .columns
+            .iter()
+            .map(|s| s.broadcast(self.len()))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Self::new_with_size(table.schema, columns, self.len())
     }
 
     pub fn repr_html(&self) -> String {
@@ -583,10 +720,12 @@ mod test {
 
     use crate::Table;
     use common_error::DaftResult;
-    use daft_core::datatypes::{DataType, Float64Array, Int64Array};
+    use daft_core::datatypes::{
+        BooleanArray, DataType, Field, Float64Array, Int64Array, UInt64Array,
+    };
     use daft_core::schema::Schema;
     use daft_core::series::IntoSeries;
-    use daft_dsl::col;
+    use daft_dsl::{col, lit};
     #[test]
     fn add_int_and_float_expression() -> DaftResult<()> {
         let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
@@ -608,4 +747,78 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn table_without_columns_keeps_num_rows() -> DaftResult<()> {
+        let table = Table::new_with_size(Schema::empty(), vec![], 5)?;
+        assert_eq!(table.len(), 5);
+        assert!(Table::new(Schema::empty(), vec![])?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn table_without_columns_slice() -> DaftResult<()> {
+        let table = Table::new_with_size(Schema::empty(), vec![], 5)?;
+        assert_eq!(table.slice(1, 3)?.len(), 2);
+        assert_eq!(table.slice(3, 10)?.len(), 2);
+        assert_eq!(table.slice(7, 10)?.len(), 0);
+        assert_eq!(table.head(3)?.len(), 3);
+        assert_eq!(table.head(10)?.len(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn table_without_columns_filter() -> DaftResult<()> {
+        let table = Table::new_with_size(Schema::empty(), vec![], 4)?;
+        let mask = BooleanArray::from((
+            "mask",
+            [Some(true), None, Some(false), Some(true)].as_slice(),
+        ));
+        assert_eq!(table.mask_filter(&mask.into_series())?.len(), 2);
+
+        // Length 1 masks are broadcast over every row.
+        assert_eq!(table.filter(&[lit(true)])?.len(), 4);
+        assert_eq!(table.filter(&[lit(false)])?.len(), 0);
+
+        let mask = BooleanArray::from(("mask", [true, false].as_slice()));
+        assert!(table.mask_filter(&mask.into_series()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn table_without_columns_take() -> DaftResult<()> {
+        let table = Table::new_with_size(Schema::empty(), vec![], 5)?;
+        let idx = UInt64Array::from(("idx", vec![4, 0, 0])).into_series();
+        assert_eq!(table.take(&idx)?.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn table_without_columns_concat_and_projection() -> DaftResult<()> {
+        let table = Table::new_with_size(Schema::empty(), vec![], 5)?;
+        let other = Table::new_with_size(Schema::empty(), vec![], 2)?;
+        assert_eq!(Table::concat(&[&table, &other])?.len(), 7);
+        assert_eq!(table.get_columns::<&str>(&[])?.len(), 5);
+
+        let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
+        let schema = Schema::new(vec![a.field().clone()])?;
+        let table = Table::new(schema, vec![a])?;
+        assert_eq!(table.get_columns::<&str>(&[])?.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn table_without_columns_cast_to_schema_with_fill() -> DaftResult<()> {
+        let table = Table::new_with_size(Schema::empty(), vec![], 5)?;
+        let fill_map = [("a", lit(1))].into_iter().collect();
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64)])?;
+        let filled = table.cast_to_schema_with_fill(&schema, Some(&fill_map))?;
+        assert_eq!(filled.len(), 5);
+        assert_eq!(filled.get_column("a")?.len(), 5);
+
+        let filled = table.cast_to_schema(&schema)?;
+        assert_eq!(filled.len(), 5);
+        assert_eq!(filled.get_column("a")?.len(), 5);
+        Ok(())
+    }
 }
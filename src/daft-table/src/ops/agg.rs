@@ -1,4 +1,9 @@
-use daft_core::{array::ops::IntoGroups, datatypes::UInt64Array, series::IntoSeries, Series};
+use daft_core::{
+    array::ops::{GroupIndices, IntoGroups},
+    datatypes::UInt64Array,
+    series::IntoSeries,
+    Series,
+};
 use daft_dsl::{functions::FunctionExpr, AggExpr, Expr};
 
 use common_error::{DaftError, DaftResult};
@@ -63,6 +68,60 @@ impl Table {
         Self::from_columns([&groupkeys_table.columns[..], &grouped_cols].concat())
     }
 
+    /// Evaluates the input of a distinct aggregation and dedups its values within each group,
+    /// dropping nulls. Returns the deduped values and, for a grouped aggregation, the groups
+    /// of indices into them.
+    pub(crate) fn eval_distinct_agg_input(
+        &self,
+        expr: &Expr,
+        groups: Option<&GroupIndices>,
+    ) -> DaftResult<(Series, Option<GroupIndices>)> {
+        let values = self.eval_expression(expr)?;
+        let is_valid = |idx: usize| values.validity().map_or(true, |v| v.get_bit(idx));
+
+        match groups {
+            None => {
+                let (unique_indices, _) = values.make_groups()?;
+                let unique_valid_indices = unique_indices
+                    .into_iter()
+                    .filter(|idx| is_valid(*idx as usize))
+                    .collect::<Vec<_>>();
+                let indices_as_series = UInt64Array::from(("", unique_valid_indices)).into_series();
+                Ok((values.take(&indices_as_series)?, None))
+            }
+            Some(groups) => {
+                // Pair every value with the id of its group, so that values are only deduped
+                // against other values of the same group. Both columns are renamed, since the
+                // values can have any name, including the name of the group ids column.
+                let mut group_ids = vec![0u64; values.len()];
+                for (group_id, group) in groups.iter().enumerate() {
+                    for idx in group {
+                        group_ids[*idx as usize] = group_id as u64;
+                    }
+                }
+                let group_ids = UInt64Array::from(("group_ids", group_ids)).into_series();
+                let (unique_indices, _) =
+                    Self::from_columns(vec![group_ids, values.rename("values")])?.make_groups()?;
+
+                let mut keep = vec![false; values.len()];
+                for idx in unique_indices {
+                    keep[idx as usize] = is_valid(idx as usize);
+                }
+                let distinct_groups = groups
+                    .iter()
+                    .map(|group| {
+                        group
+                            .iter()
+                            .copied()
+                            .filter(|idx| keep[*idx as usize])
+                            .collect()
+                    })
+                    .collect();
+                Ok((values, Some(distinct_groups)))
+            }
+        }
+    }
+
     #[cfg(feature = "python")]
     pub fn map_groups(
         &self,
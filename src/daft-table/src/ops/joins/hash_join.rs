@@ -9,7 +9,9 @@ use common_error::{DaftError, DaftResult};
 
 use daft_core::array::ops::as_arrow::AsArrow;
 
-pub(super) fn hash_inner_join(left: &Table, right: &Table) -> DaftResult<(Series, Series)> {
+/// Validates the join keys of both sides, returning `false` if no rows can match because one of
+/// the key columns has the null dtype.
+fn check_join_keys(left: &Table, right: &Table) -> DaftResult<bool> {
     // TODO(sammy) add tests for mismatched types for multiple columns for joins
     if left.num_columns() != right.num_columns() {
         return Err(DaftError::ValueError(format!(
@@ -27,10 +29,7 @@ pub(super) fn hash_inner_join(left: &Table, right: &Table) -> DaftResult<(Series
     let has_null_type = left.columns.iter().any(|s| s.data_type().is_null())
         || right.columns.iter().any(|s| s.data_type().is_null());
     if has_null_type {
-        return Ok((
-            UInt64Array::empty("left_indices", &DataType::UInt64).into_series(),
-            UInt64Array::empty("right_indices", &DataType::UInt64).into_series(),
-        ));
+        return Ok(false);
     }
     let types_not_match = left
         .columns
@@ -43,6 +42,17 @@ pub(super) fn hash_inner_join(left: &Table, right: &Table) -> DaftResult<(Series
         ));
     }
 
+    Ok(true)
+}
+
+pub(super) fn hash_inner_join(left: &Table, right: &Table) -> DaftResult<(Series, Series)> {
+    if !check_join_keys(left, right)? {
+        return Ok((
+            UInt64Array::empty("left_indices", &DataType::UInt64).into_series(),
+            UInt64Array::empty("right_indices", &DataType::UInt64).into_series(),
+        ));
+    }
+
     // TODO(Clark): Build the probe table on the smaller table, rather than always building it on the left table.
     let probe_table = left.to_probe_hash_table()?;
 
@@ -72,3 +82,45 @@ pub(super) fn hash_inner_join(left: &Table, right: &Table) -> DaftResult<(Series
     let right_series = UInt64Array::from(("right_indices", right_idx));
     Ok((left_series.into_series(), right_series.into_series()))
 }
+
+/// Returns the indices of the left rows that have (semi) or don't have (anti) a match on the right.
+pub(super) fn hash_semi_anti_join(
+    left: &Table,
+    right: &Table,
+    is_anti: bool,
+) -> DaftResult<Series> {
+    if !check_join_keys(left, right)? {
+        let left_idx = if is_anti {
+            (0..left.len() as u64).collect()
+        } else {
+            vec![]
+        };
+        return Ok(UInt64Array::from(("left_indices", left_idx)).into_series());
+    }
+
+    let probe_table = right.to_probe_hash_table()?;
+
+    let l_hashes = left.hash_rows()?;
+    let is_equal = build_multi_array_is_equal(
+        right.columns.as_slice(),
+        left.columns.as_slice(),
+        false,
+        false,
+    )?;
+    let mut left_idx = vec![];
+    for (l_idx, h) in l_hashes.as_arrow().values_iter().enumerate() {
+        let is_match = probe_table
+            .raw_entry()
+            .from_hash(*h, |other| {
+                *h == other.hash && {
+                    let r_idx = other.idx;
+                    is_equal(r_idx as usize, l_idx)
+                }
+            })
+            .is_some();
+        if is_match != is_anti {
+            left_idx.push(l_idx as u64);
+        }
+    }
+    Ok(UInt64Array::from(("left_indices", left_idx)).into_series())
+}
@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use daft_core::{schema::Schema, utils::supertype::try_get_supertype, Series};
+use daft_core::{
+    array::ops::as_arrow::AsArrow, datatypes::UInt64Array, schema::Schema,
+    utils::supertype::try_get_supertype, IntoSeries, JoinType, Series,
+};
 
 use common_error::{DaftError, DaftResult};
 use daft_dsl::Expr;
@@ -35,6 +38,7 @@ pub fn infer_join_schema(
     right: &Schema,
     left_on: &[Expr],
     right_on: &[Expr],
+    how: JoinType,
 ) -> DaftResult<Schema> {
     if left_on.len() != right_on.len() {
         return Err(DaftError::ValueError(format!(
@@ -43,6 +47,20 @@ pub fn infer_join_schema(
             right_on.len()
         )));
     }
+    if how == JoinType::Cross && !left_on.is_empty() {
+        return Err(DaftError::ValueError(
+            "Cross joins must not have join keys".to_string(),
+        ));
+    }
+    if how != JoinType::Cross && left_on.is_empty() {
+        return Err(DaftError::ValueError(
+            "No columns were passed in to join on".to_string(),
+        ));
+    }
+    // Semi and anti joins only filter the left side.
+    if how.is_left_only() {
+        return Schema::new(left.fields.values().cloned().collect());
+    }
 
     let lfields = left_on
         .iter()
@@ -103,9 +121,108 @@ pub fn infer_join_schema(
     Schema::new(join_fields)
 }
 
+/// Returns the indices of the left rows that appear (semi) or don't appear (anti) in `matched_left_idx`.
+fn semi_anti_indices(
+    matched_left_idx: &Series,
+    left_len: usize,
+    is_anti: bool,
+) -> DaftResult<Series> {
+    let mut is_matched = vec![false; left_len];
+    for i in matched_left_idx.u64()?.as_arrow().values_iter() {
+        is_matched[*i as usize] = true;
+    }
+    let left_idx = is_matched
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, m)| (m != is_anti).then_some(i as u64))
+        .collect::<Vec<_>>();
+    Ok(UInt64Array::from(("left_indices", left_idx)).into_series())
+}
+
+/// Extends the matched index pairs of an inner join with the rows that `how` keeps even when they
+/// have no match, pairing each of them with a null index into the other side.
+fn add_unmatched_indices(
+    lidx: Series,
+    ridx: Series,
+    left_len: usize,
+    right_len: usize,
+    how: JoinType,
+) -> DaftResult<(Series, Series)> {
+    let keep_left = matches!(how, JoinType::Left | JoinType::Outer);
+    let keep_right = matches!(how, JoinType::Right | JoinType::Outer);
+    if !keep_left && !keep_right {
+        return Ok((lidx, ridx));
+    }
+    let matched_lidx = lidx.u64()?.as_arrow().values();
+    let matched_ridx = ridx.u64()?.as_arrow().values();
+    let mut left_indices = matched_lidx.iter().map(|i| Some(*i)).collect::<Vec<_>>();
+    let mut right_indices = matched_ridx.iter().map(|i| Some(*i)).collect::<Vec<_>>();
+
+    let mut add_unmatched = |matched: &[u64], len: usize, is_left: bool| {
+        let mut is_matched = vec![false; len];
+        for i in matched {
+            is_matched[*i as usize] = true;
+        }
+        for (i, _) in is_matched.iter().enumerate().filter(|(_, m)| !**m) {
+            let (keep, null) = if is_left {
+                (&mut left_indices, &mut right_indices)
+            } else {
+                (&mut right_indices, &mut left_indices)
+            };
+            keep.push(Some(i as u64));
+            null.push(None);
+        }
+    };
+    if keep_left {
+        add_unmatched(matched_lidx, left_len, true);
+    }
+    if keep_right {
+        add_unmatched(matched_ridx, right_len, false);
+    }
+
+    let to_series = |name: &str, indices: Vec<Option<u64>>| {
+        UInt64Array::from((
+            name,
+            Box::new(arrow2::array::PrimitiveArray::<u64>::from(indices)),
+        ))
+        .into_series()
+    };
+    Ok((
+        to_series("left_indices", left_indices),
+        to_series("right_indices", right_indices),
+    ))
+}
+
+/// Index pairs for the cartesian product of `left_len` rows with `right_len` rows.
+fn cross_join_indices(left_len: usize, right_len: usize) -> (Series, Series) {
+    let left_idx = (0..left_len as u64)
+        .flat_map(|i| std::iter::repeat(i).take(right_len))
+        .collect::<Vec<_>>();
+    let right_idx = (0..left_len)
+        .flat_map(|_| 0..right_len as u64)
+        .collect::<Vec<_>>();
+    (
+        UInt64Array::from(("left_indices", left_idx)).into_series(),
+        UInt64Array::from(("right_indices", right_idx)).into_series(),
+    )
+}
+
 impl Table {
-    pub fn hash_join(&self, right: &Self, left_on: &[Expr], right_on: &[Expr]) -> DaftResult<Self> {
-        self.join(right, left_on, right_on, hash_join::hash_inner_join)
+    pub fn hash_join(
+        &self,
+        right: &Self,
+        left_on: &[Expr],
+        right_on: &[Expr],
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        match how {
+            JoinType::Semi | JoinType::Anti => {
+                self.semi_anti_join(right, left_on, right_on, how, |l, r| {
+                    hash_join::hash_semi_anti_join(l, r, how == JoinType::Anti)
+                })
+            }
+            _ => self.join(right, left_on, right_on, how, hash_join::hash_inner_join),
+        }
     }
 
     pub fn sort_merge_join(
@@ -113,10 +230,11 @@ impl Table {
         right: &Self,
         left_on: &[Expr],
         right_on: &[Expr],
+        how: JoinType,
         is_sorted: bool,
     ) -> DaftResult<Self> {
-        if is_sorted {
-            self.join(right, left_on, right_on, merge_join::merge_inner_join)
+        if is_sorted || how == JoinType::Cross {
+            self.merge_join(right, left_on, right_on, how)
         } else {
             if left_on.is_empty() {
                 return Err(DaftError::ValueError(
@@ -142,26 +260,86 @@ impl Table {
                     .collect::<Vec<_>>()
                     .as_slice(),
             )?;
-            left.join(&right, left_on, right_on, merge_join::merge_inner_join)
+            left.merge_join(&right, left_on, right_on, how)
         }
     }
 
+    /// Merge-joins two tables that are already sorted on their join keys.
+    fn merge_join(
+        &self,
+        right: &Self,
+        left_on: &[Expr],
+        right_on: &[Expr],
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        match how {
+            JoinType::Semi | JoinType::Anti => {
+                self.semi_anti_join(right, left_on, right_on, how, |l, r| {
+                    let (lidx, _) = merge_join::merge_inner_join(l, r)?;
+                    semi_anti_indices(&lidx, l.len(), how == JoinType::Anti)
+                })
+            }
+            _ => self.join(right, left_on, right_on, how, merge_join::merge_inner_join),
+        }
+    }
+
+    /// Semi and anti joins, which keep the left rows with (semi) or without (anti) a match on the
+    /// right, given a function that computes the indices of those rows from the join keys.
+    fn semi_anti_join(
+        &self,
+        right: &Self,
+        left_on: &[Expr],
+        right_on: &[Expr],
+        how: JoinType,
+        semi_anti_join: impl Fn(&Table, &Table) -> DaftResult<Series>,
+    ) -> DaftResult<Self> {
+        infer_join_schema(&self.schema, &right.schema, left_on, right_on, how)?;
+        if self.is_empty() || (how == JoinType::Semi && right.is_empty()) {
+            return Self::empty(Some(self.schema.clone()));
+        }
+        if right.is_empty() {
+            return Ok(self.clone());
+        }
+        let ltable = self.eval_expression_list(left_on)?;
+        let rtable = right.eval_expression_list(right_on)?;
+
+        let (ltable, rtable) = match_types_for_tables(&ltable, &rtable)?;
+        let lidx = semi_anti_join(&ltable, &rtable)?;
+        self.take(&lidx)
+    }
+
     fn join(
         &self,
         right: &Self,
         left_on: &[Expr],
         right_on: &[Expr],
+        how: JoinType,
         inner_join: impl Fn(&Table, &Table) -> DaftResult<(Series, Series)>,
     ) -> DaftResult<Self> {
-        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on)?;
-        if self.is_empty() || right.is_empty() {
+        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on, how)?;
+        let is_empty = match how {
+            JoinType::Left => self.is_empty(),
+            JoinType::Right => right.is_empty(),
+            JoinType::Outer => self.is_empty() && right.is_empty(),
+            _ => self.is_empty() || right.is_empty(),
+        };
+        if is_empty {
             return Self::empty(Some(join_schema.into()));
         }
         let ltable = self.eval_expression_list(left_on)?;
         let rtable = right.eval_expression_list(right_on)?;
 
         let (ltable, rtable) = match_types_for_tables(&ltable, &rtable)?;
-        let (lidx, ridx) = inner_join(&ltable, &rtable)?;
+        let (lidx, ridx) = if how == JoinType::Cross {
+            cross_join_indices(self.len(), right.len())
+        } else {
+            let (lidx, ridx) = if self.is_empty() || right.is_empty() {
+                cross_join_indices(0, 0)
+            } else {
+                inner_join(&ltable, &rtable)?
+            };
+            add_unmatched_indices(lidx, ridx, self.len(), right.len(), how)?
+        };
         let mut join_fields = ltable
             .column_names()
             .iter()
@@ -173,7 +351,6 @@ impl Table {
             .take(&lidx)?
             .columns;
         drop(ltable);
-        drop(rtable);
 
         let mut names_so_far = HashSet::new();
 
@@ -205,6 +382,21 @@ impl Table {
         let right_to_left_keys: HashMap<&str, &str> =
             HashMap::from_iter(zipped_names.iter().copied());
 
+        // Rows that only exist on the right side take the values of join keys shared by both
+        // sides from the right.
+        if matches!(how, JoinType::Right | JoinType::Outer) {
+            for (i, (l, r)) in zipped_names.iter().enumerate() {
+                if l == r {
+                    let right_key = rtable
+                        .get_column_by_index(i)?
+                        .take(&ridx)?
+                        .cast(join_series[i].data_type())?;
+                    join_series[i] = join_series[i].fill_null(&right_key)?;
+                }
+            }
+        }
+        drop(rtable);
+
         // TODO(Clark): Parallelize with Rayon.
         for field in right.schema.fields.values() {
             // Skip fields if they were used in the join and have the same name as the corresponding left field
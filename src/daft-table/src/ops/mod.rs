@@ -6,5 +6,6 @@ mod joins;
 mod partition;
 mod search_sorted;
 mod sort;
+mod window;
 
 pub use joins::infer_join_schema;
@@ -0,0 +1,537 @@
+use std::{cmp::Ordering, collections::VecDeque, ops::Range};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::{
+        arrow2::comparison::build_multi_array_is_equal, as_arrow::AsArrow,
+        build_multi_array_bicompare,
+    },
+    count_mode::CountMode,
+    datatypes::{BooleanArray, DataType, Float64Array, Int64Array, UInt64Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{AggExpr, Expr, WindowExpr, WindowFrame};
+
+use crate::Table;
+
+impl Table {
+    /// Evaluates window functions and aggregations over the partitions of rows that share the same
+    /// `partition_by` values, with the rows of each partition ordered by `order_by`.
+    ///
+    /// Every expression in `to_window` must be a (possibly aliased) window function or aggregation;
+    /// aggregations are computed over the rows in `frame`. The result has all columns of this table,
+    /// sorted by partition and order keys, followed by a column per window expression.
+    pub fn window(
+        &self,
+        to_window: &[Expr],
+        partition_by: &[Expr],
+        order_by: &[Expr],
+        descending: &[bool],
+        frame: &WindowFrame,
+    ) -> DaftResult<Table> {
+        if order_by.len() != descending.len() {
+            return Err(DaftError::ValueError(format!(
+                "order_by and descending length must match, got {} vs {}",
+                order_by.len(),
+                descending.len()
+            )));
+        }
+        let sort_keys = [partition_by, order_by].concat();
+        let sorted = if sort_keys.is_empty() {
+            self.clone()
+        } else {
+            let sort_descending = std::iter::repeat(false)
+                .take(partition_by.len())
+                .chain(descending.iter().copied())
+                .collect::<Vec<_>>();
+            self.sort(&sort_keys, &sort_descending)?
+        };
+        let partitions = sorted.partition_ranges(partition_by)?;
+
+        let window_cols = to_window
+            .iter()
+            .map(|e| sorted.eval_window_expression(e, &partitions, order_by, frame))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Self::from_columns([&sorted.columns[..], &window_cols].concat())
+    }
+
+    /// Ranges of rows with equal `partition_by` values, for a table sorted by those values.
+    fn partition_ranges(&self, partition_by: &[Expr]) -> DaftResult<Vec<Range<usize>>> {
+        if self.is_empty() || partition_by.is_empty() {
+            return Ok(std::iter::once(0..self.len())
+                .filter(|range| !range.is_empty())
+                .collect());
+        }
+        let keys = self.eval_expression_list(partition_by)?;
+        let is_equal = build_multi_array_is_equal(&keys.columns, &keys.columns, true, true)?;
+        let mut ranges = vec![];
+        let mut start = 0;
+        for row in 1..self.len() {
+            if !is_equal(row - 1, row) {
+                ranges.push(start..row);
+                start = row;
+            }
+        }
+        ranges.push(start..self.len());
+        Ok(ranges)
+    }
+
+    fn eval_window_expression(
+        &self,
+        expr: &Expr,
+        partitions: &[Range<usize>],
+        order_by: &[Expr],
+        frame: &WindowFrame,
+    ) -> DaftResult<Series> {
+        match expr {
+            Expr::Alias(child, name) => Ok(self
+                .eval_window_expression(child, partitions, order_by, frame)?
+                .rename(name)),
+            Expr::Window(window_expr) => {
+                self.eval_window_function(window_expr, partitions, order_by)
+            }
+            Expr::Agg(agg_expr) => self.eval_window_agg(agg_expr, partitions, frame),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected a window function or aggregation to evaluate over a window, but got: {expr}"
+            ))),
+        }
+    }
+
+    fn eval_window_function(
+        &self,
+        window_expr: &WindowExpr,
+        partitions: &[Range<usize>],
+        order_by: &[Expr],
+    ) -> DaftResult<Series> {
+        use WindowExpr::*;
+        let name = window_expr.name()?;
+        match window_expr {
+            RowNumber | Rank | DenseRank => {
+                // Without an ordering, all rows of a partition are peers.
+                let order_keys = self.eval_expression_list(order_by)?;
+                let is_peer: Box<dyn Fn(usize, usize) -> bool> = if order_by.is_empty() {
+                    Box::new(|_, _| true)
+                } else {
+                    build_multi_array_is_equal(
+                        &order_keys.columns,
+                        &order_keys.columns,
+                        true,
+                        true,
+                    )?
+                };
+                let mut values = Vec::with_capacity(self.len());
+                for partition in partitions {
+                    let mut rank = 0u64;
+                    for row in partition.clone() {
+                        let row_number = (row - partition.start) as u64 + 1;
+                        rank = match window_expr {
+                            RowNumber => row_number,
+                            _ if row > partition.start && is_peer(row - 1, row) => rank,
+                            Rank => row_number,
+                            _ => rank + 1,
+                        };
+                        values.push(rank);
+                    }
+                }
+                Ok(UInt64Array::from((name, values)).into_series())
+            }
+            Lag {
+                input,
+                offset,
+                default,
+            }
+            | Lead {
+                input,
+                offset,
+                default,
+            } => {
+                let offset = usize::try_from(*offset).unwrap_or(usize::MAX);
+                let mut indices = Vec::with_capacity(self.len());
+                for partition in partitions {
+                    for row in partition.clone() {
+                        let idx = if matches!(window_expr, Lag { .. }) {
+                            row.checked_sub(offset)
+                                .filter(|idx| *idx >= partition.start)
+                        } else {
+                            row.checked_add(offset).filter(|idx| *idx < partition.end)
+                        };
+                        indices.push(idx.map(|idx| idx as u64));
+                    }
+                }
+                let in_partition = BooleanArray::from((
+                    name,
+                    indices
+                        .iter()
+                        .map(|idx| idx.is_some())
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                ));
+                let indices = UInt64Array::from((
+                    name,
+                    Box::new(arrow2::array::PrimitiveArray::<u64>::from(indices)),
+                ))
+                .into_series();
+                let shifted = self.eval_expression(input)?.take(&indices)?;
+                match default {
+                    Some(default) => {
+                        let default = self.eval_expression(default)?.cast(shifted.data_type())?;
+                        shifted.if_else(&default, &in_partition.into_series())
+                    }
+                    None => Ok(shifted),
+                }
+            }
+        }
+    }
+
+    fn eval_window_agg(
+        &self,
+        agg_expr: &AggExpr,
+        partitions: &[Range<usize>],
+        frame: &WindowFrame,
+    ) -> DaftResult<Series> {
+        use AggExpr::*;
+        let name = agg_expr.name()?;
+        let output_dtype = agg_expr.to_field(&self.schema)?.dtype;
+        match agg_expr {
+            Count(expr, mode) => {
+                let series = self.eval_expression(expr)?;
+                let arrow = series.to_arrow();
+                let counted = (0..series.len())
+                    .map(|i| match mode {
+                        CountMode::All => Some(1),
+                        CountMode::Valid => arrow.is_valid(i).then_some(1),
+                        CountMode::Null => arrow.is_null(i).then_some(1),
+                    })
+                    .collect::<Vec<Option<u64>>>();
+                let counts = frame_sums(&counted, partitions, frame, |a, b| a + b)
+                    .into_iter()
+                    .map(|count| count.unwrap_or(0))
+                    .collect::<Vec<_>>();
+                Ok(UInt64Array::from((name, counts)).into_series())
+            }
+            Sum(expr) => {
+                let series = self.eval_expression(expr)?;
+                match output_dtype {
+                    DataType::Int64 => {
+                        let values = series.cast(&DataType::Int64)?;
+                        let values = values.i64()?.as_arrow().iter().map(|v| v.copied());
+                        let sums = frame_sums(
+                            &values.collect::<Vec<_>>(),
+                            partitions,
+                            frame,
+                            i64::wrapping_add,
+                        );
+                        Ok(Int64Array::from((
+                            name,
+                            Box::new(arrow2::array::PrimitiveArray::from(sums)),
+                        ))
+                        .into_series())
+                    }
+                    DataType::UInt64 => {
+                        let values = series.cast(&DataType::UInt64)?;
+                        let values = values.u64()?.as_arrow().iter().map(|v| v.copied());
+                        let sums = frame_sums(
+                            &values.collect::<Vec<_>>(),
+                            partitions,
+                            frame,
+                            u64::wrapping_add,
+                        );
+                        Ok(UInt64Array::from((
+                            name,
+                            Box::new(arrow2::array::PrimitiveArray::from(sums)),
+                        ))
+                        .into_series())
+                    }
+                    _ => {
+                        let values = series.cast(&DataType::Float64)?;
+                        let values = values.f64()?.as_arrow().iter().map(|v| v.copied());
+                        let sums =
+                            frame_sums(&values.collect::<Vec<_>>(), partitions, frame, |a, b| {
+                                a + b
+                            });
+                        Float64Array::from((
+                            name,
+                            Box::new(arrow2::array::PrimitiveArray::from(sums)),
+                        ))
+                        .into_series()
+                        .cast(&output_dtype)
+                    }
+                }
+            }
+            Mean(expr) => {
+                let values = self.eval_expression(expr)?.cast(&DataType::Float64)?;
+                let values = values
+                    .f64()?
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.map(|v| (*v, 1u64)))
+                    .collect::<Vec<_>>();
+                let means = frame_sums(&values, partitions, frame, |a, b| (a.0 + b.0, a.1 + b.1))
+                    .into_iter()
+                    .map(|sum_and_count| sum_and_count.map(|(sum, count)| sum / count as f64))
+                    .collect::<Vec<_>>();
+                Ok(
+                    Float64Array::from((
+                        name,
+                        Box::new(arrow2::array::PrimitiveArray::from(means)),
+                    ))
+                    .into_series(),
+                )
+            }
+            Min(expr) | Max(expr) => {
+                let series = self.eval_expression(expr)?;
+                let indices =
+                    frame_arg_extrema(&series, partitions, frame, matches!(agg_expr, Max(_)))?;
+                let indices = UInt64Array::from((
+                    name,
+                    Box::new(arrow2::array::PrimitiveArray::<u64>::from(indices)),
+                ))
+                .into_series();
+                series.take(&indices)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "{agg_expr} is not supported as a window aggregation"
+            ))),
+        }
+    }
+}
+
+/// Rows of a partition of `len` rows that fall into the frame of `row`, relative to the partition start.
+fn frame_range(row: usize, len: usize, frame: &WindowFrame) -> Range<usize> {
+    let clamp = |offset: i64| (row as i64).saturating_add(offset).clamp(0, len as i64) as usize;
+    let start = frame.start.map_or(0, clamp);
+    let end = frame
+        .end
+        .map_or(len, |offset| clamp(offset.saturating_add(1)));
+    start..end.max(start)
+}
+
+/// Sums of the non-null `values` within the frame of every row, or `None` for frames without any.
+///
+/// Frames that are unbounded on either side are summed with running sums from that side, so that
+/// results are exact (no subtraction of partial sums); other frames are summed row by row.
+fn frame_sums<T: Copy>(
+    values: &[Option<T>],
+    partitions: &[Range<usize>],
+    frame: &WindowFrame,
+    add: impl Fn(T, T) -> T,
+) -> Vec<Option<T>> {
+    let add = |acc: Option<T>, value: &Option<T>| match (acc, value) {
+        (Some(acc), Some(value)) => Some(add(acc, *value)),
+        (acc, None) => acc,
+        (None, value) => *value,
+    };
+    let mut sums = Vec::with_capacity(values.len());
+    for partition in partitions {
+        let values = &values[partition.clone()];
+        let frames = (0..values.len()).map(|row| frame_range(row, values.len(), frame));
+        match (frame.start, frame.end) {
+            (None, _) => {
+                let mut prefix_sums = vec![None];
+                for value in values {
+                    prefix_sums.push(add(*prefix_sums.last().unwrap(), value));
+                }
+                sums.extend(frames.map(|range| prefix_sums[range.end]));
+            }
+            (_, None) => {
+                let mut suffix_sums = vec![None];
+                for value in values.iter().rev() {
+                    suffix_sums.push(add(*suffix_sums.last().unwrap(), value));
+                }
+                suffix_sums.reverse();
+                sums.extend(frames.map(|range| suffix_sums[range.start]));
+            }
+            _ => sums.extend(frames.map(|range| values[range].iter().fold(None, add))),
+        }
+    }
+    sums
+}
+
+/// Index of the minimum (or maximum) non-null value of `series` within the frame of every row.
+///
+/// Frame bounds only ever move forward, so candidates are kept in a monotonic deque, making this
+/// linear in the number of rows for any frame.
+fn frame_arg_extrema(
+    series: &Series,
+    partitions: &[Range<usize>],
+    frame: &WindowFrame,
+    is_max: bool,
+) -> DaftResult<Vec<Option<u64>>> {
+    // Comparing in descending order for the maximum makes "less" mean "better" in both cases.
+    let compare = build_multi_array_bicompare(&[series.clone()], &[series.clone()], &[is_max])?;
+    let arrow = series.to_arrow();
+    let mut result = Vec::with_capacity(series.len());
+    let mut candidates = VecDeque::new();
+    for partition in partitions {
+        candidates.clear();
+        let mut next = partition.start;
+        for row in partition.clone() {
+            let range = frame_range(row - partition.start, partition.len(), frame);
+            let (start, end) = (partition.start + range.start, partition.start + range.end);
+            while next < end {
+                if arrow.is_valid(next) {
+                    while let Some(&back) = candidates.back() && compare(back, next) != Ordering::Less {
+                        candidates.pop_back();
+                    }
+                    candidates.push_back(next);
+                }
+                next += 1;
+            }
+            while let Some(&front) = candidates.front() && front < start {
+                candidates.pop_front();
+            }
+            result.push(candidates.front().map(|idx| *idx as u64));
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{
+        array::ops::as_arrow::AsArrow,
+        count_mode::CountMode,
+        datatypes::{DataType, Int64Array, Utf8Array},
+        series::IntoSeries,
+    };
+    use daft_dsl::{col, lit, Expr, WindowExpr, WindowFrame};
+
+    use crate::Table;
+
+    fn to_vec(table: &Table, name: &str) -> DaftResult<Vec<Option<i64>>> {
+        let column = table.get_column(name)?.cast(&DataType::Int64)?;
+        Ok(column
+            .i64()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect())
+    }
+
+    #[test]
+    fn test_window_functions_per_partition() -> DaftResult<()> {
+        let table = Table::from_columns(vec![
+            Utf8Array::from(("g", ["a", "b", "a", "a", "b", "a"].as_slice())).into_series(),
+            Int64Array::from(("t", vec![3, 1, 1, 2, 2, 2])).into_series(),
+            Int64Array::from((
+                "x",
+                Box::new(arrow2::array::PrimitiveArray::from(vec![
+                    Some(10),
+                    Some(5),
+                    Some(1),
+                    None,
+                    Some(7),
+                    Some(4),
+                ])),
+            ))
+            .into_series(),
+        ])?;
+        let windowed = table.window(
+            &[
+                Expr::Window(WindowExpr::RowNumber).alias("row_number"),
+                Expr::Window(WindowExpr::Rank).alias("rank"),
+                Expr::Window(WindowExpr::DenseRank).alias("dense_rank"),
+                col("x").lag(1, Some(&lit(-1))).alias("lag"),
+                col("x").lead(1, None).alias("lead"),
+                col("x").sum().alias("running_sum"),
+                col("x").max().alias("running_max"),
+            ],
+            &[col("g")],
+            &[col("t")],
+            &[false],
+            &WindowFrame::default_for(true),
+        )?;
+        // Partition "a" is ordered [t=1, x=1], [t=2, x=null], [t=2, x=4], [t=3, x=10], partition "b" is
+        // [t=1, x=5], [t=2, x=7]. Rows with the same `t` may come in either order, so only check
+        // order-independent values for them.
+        assert_eq!(to_vec(&windowed, "t")?, [1, 2, 2, 3, 1, 2].map(Some));
+        assert_eq!(
+            to_vec(&windowed, "row_number")?,
+            [1, 2, 3, 4, 1, 2].map(Some)
+        );
+        assert_eq!(to_vec(&windowed, "rank")?, [1, 2, 2, 4, 1, 2].map(Some));
+        assert_eq!(
+            to_vec(&windowed, "dense_rank")?,
+            [1, 2, 2, 3, 1, 2].map(Some)
+        );
+        assert_eq!(to_vec(&windowed, "lag")?[0], Some(-1));
+        assert_eq!(to_vec(&windowed, "lag")?[4..], [Some(-1), Some(5)]);
+        assert_eq!(to_vec(&windowed, "lead")?[3..], [None, Some(7), None]);
+        assert_eq!(
+            to_vec(&windowed, "running_sum")?[2..],
+            [Some(5), Some(15), Some(5), Some(12)]
+        );
+        assert_eq!(
+            to_vec(&windowed, "running_max")?[2..],
+            [Some(4), Some(10), Some(5), Some(7)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_aggregations_over_moving_frames() -> DaftResult<()> {
+        let table = Table::from_columns(vec![Int64Array::from((
+            "x",
+            Box::new(arrow2::array::PrimitiveArray::from(vec![
+                Some(3),
+                Some(1),
+                None,
+                Some(4),
+                Some(1),
+                Some(5),
+            ])),
+        ))
+        .into_series()])?;
+        let window = |frame: WindowFrame| {
+            table.window(
+                &[
+                    col("x").sum().alias("sum"),
+                    col("x").min().alias("min"),
+                    col("x").max().alias("max"),
+                    col("x").count(CountMode::Valid).alias("count"),
+                ],
+                &[],
+                &[],
+                &[],
+                &frame,
+            )
+        };
+
+        let centered = window(WindowFrame::new(Some(-1), Some(1))?)?;
+        assert_eq!(
+            to_vec(&centered, "sum")?,
+            [Some(4), Some(4), Some(5), Some(5), Some(10), Some(6)]
+        );
+        assert_eq!(
+            to_vec(&centered, "min")?,
+            [Some(1), Some(1), Some(1), Some(1), Some(1), Some(1)]
+        );
+        assert_eq!(
+            to_vec(&centered, "max")?,
+            [Some(3), Some(3), Some(4), Some(4), Some(5), Some(5)]
+        );
+        assert_eq!(to_vec(&centered, "count")?, [2, 2, 2, 2, 3, 2].map(Some));
+
+        let preceding = window(WindowFrame::new(Some(-2), Some(-1))?)?;
+        assert_eq!(
+            to_vec(&preceding, "sum")?,
+            [None, Some(3), Some(4), Some(1), Some(4), Some(5)]
+        );
+        assert_eq!(
+            to_vec(&preceding, "min")?,
+            [None, Some(3), Some(1), Some(1), Some(4), Some(1)]
+        );
+
+        let following = window(WindowFrame::new(Some(0), None)?)?;
+        assert_eq!(
+            to_vec(&following, "sum")?,
+            [Some(14), Some(11), Some(10), Some(10), Some(6), Some(5)]
+        );
+        assert_eq!(
+            to_vec(&following, "max")?,
+            [Some(5), Some(5), Some(5), Some(5), Some(5), Some(5)]
+        );
+        Ok(())
+    }
+}
@@ -8,8 +8,9 @@ use common_error::DaftError;
 use daft_core::datatypes::Field;
 use daft_core::schema::Schema;
 use daft_core::series::Series;
+use daft_core::JoinType;
 
-use daft_dsl::python::PyExpr;
+use daft_dsl::{python::PyExpr, WindowFrame};
 
 use daft_core::python::schema::PySchema;
 use daft_core::python::series::PySeries;
@@ -95,19 +96,57 @@ impl PyTable {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn window(
+        &self,
+        py: Python,
+        to_window: Vec<PyExpr>,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+        frame_start: Option<i64>,
+        frame_end: Option<i64>,
+    ) -> PyResult<Self> {
+        let converted_to_window: Vec<daft_dsl::Expr> =
+            to_window.into_iter().map(|e| e.into()).collect();
+        let converted_partition_by: Vec<daft_dsl::Expr> =
+            partition_by.into_iter().map(|e| e.into()).collect();
+        let converted_order_by: Vec<daft_dsl::Expr> =
+            order_by.into_iter().map(|e| e.into()).collect();
+        let frame = WindowFrame::new(frame_start, frame_end)?;
+        py.allow_threads(|| {
+            Ok(self
+                .table
+                .window(
+                    converted_to_window.as_slice(),
+                    converted_partition_by.as_slice(),
+                    converted_order_by.as_slice(),
+                    descending.as_slice(),
+                    &frame,
+                )?
+                .into())
+        })
+    }
+
     pub fn hash_join(
         &self,
         py: Python,
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
     ) -> PyResult<Self> {
         let left_exprs: Vec<daft_dsl::Expr> = left_on.into_iter().map(|e| e.into()).collect();
         let right_exprs: Vec<daft_dsl::Expr> = right_on.into_iter().map(|e| e.into()).collect();
         py.allow_threads(|| {
             Ok(self
                 .table
-                .hash_join(&right.table, left_exprs.as_slice(), right_exprs.as_slice())?
+                .hash_join(
+                    &right.table,
+                    left_exprs.as_slice(),
+                    right_exprs.as_slice(),
+                    how,
+                )?
                 .into())
         })
     }
@@ -118,6 +157,7 @@ impl PyTable {
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
         is_sorted: bool,
     ) -> PyResult<Self> {
         let left_exprs: Vec<daft_dsl::Expr> = left_on.into_iter().map(|e| e.into()).collect();
@@ -129,6 +169,7 @@ impl PyTable {
                     &right.table,
                     left_exprs.as_slice(),
                     right_exprs.as_slice(),
+                    how,
                     is_sorted,
                 )?
                 .into())
@@ -9,8 +9,13 @@ import pytest
 import daft
 from daft import col
 from daft.context import get_context
+from daft.daft import ScanOperatorHandle
+from daft.dataframe import DataFrame
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
+from daft.io.scan import ScanOperator
+from daft.logical.builder import LogicalPlanBuilder
+from daft.logical.schema import Schema
 from daft.utils import freeze
 from tests.utils import sort_arrow_table
 
@@ -399,3 +404,41 @@ def test_agg_deprecation():
         df.collect()
 
         assert df.to_pydict() == {"b": [True, False], "a": [4, 2]}
+
+
+class EmptyScanOperator(ScanOperator):
+    """A scan operator without any scan tasks, which lets counts and min/max aggregations be pushed into it."""
+
+    def schema(self) -> Schema:
+        return Schema._from_field_name_and_types([("a", DataType.int64())])
+
+    def display_name(self) -> str:
+        return "EmptyScanOperator"
+
+    def partitioning_keys(self) -> list:
+        return []
+
+    def can_absorb_filter(self) -> bool:
+        return False
+
+    def can_absorb_limit(self) -> bool:
+        return False
+
+    def can_absorb_select(self) -> bool:
+        return False
+
+    def can_absorb_aggregation(self) -> bool:
+        return True
+
+    def multiline_display(self) -> list[str]:
+        return [self.display_name()]
+
+    def to_scan_tasks(self, pushdowns):
+        return iter([])
+
+
+def test_agg_pushdown_empty_input():
+    handle = ScanOperatorHandle.from_python_scan_operator(EmptyScanOperator())
+    df = DataFrame(LogicalPlanBuilder.from_tabular_scan(scan_operator=handle))
+    df = df.agg([col("a").count().alias("count"), col("a").max().alias("max")])
+    assert df.to_pydict() == {"count": [0], "max": [None]}
@@ -0,0 +1,42 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+
+# With 4096 registers, HyperLogLog estimates have a standard error of about 1.6%.
+RELATIVE_ACCURACY = 0.05
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_approx_count_distinct_global(make_df, repartition_nparts):
+    values = [i % 5000 for i in range(20000)] + [None] * 10
+    daft_df = make_df({"values": values}, repartition=repartition_nparts)
+    daft_df = daft_df.agg([col("values").approx_count_distinct().alias("distinct")])
+    res = daft_df.to_pydict()
+
+    assert len(res["distinct"]) == 1
+    assert res["distinct"][0] == pytest.approx(5000, rel=RELATIVE_ACCURACY)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_approx_count_distinct_small_cardinalities_are_exact(make_df, repartition_nparts):
+    daft_df = make_df(
+        {"values": ["a", "b", "a", None, "c", "b"]},
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.agg([col("values").approx_count_distinct().alias("distinct")])
+    assert daft_df.to_pydict() == {"distinct": [3]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_approx_count_distinct_groupby(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 3, 3],
+            "values": [1, 2, 2, 10, 10, None, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg([col("values").approx_count_distinct().alias("distinct")]).sort("group")
+    assert daft_df.to_pydict() == {"group": [1, 2, 3], "distinct": [2, 1, 0]}
@@ -0,0 +1,82 @@
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+from daft import col
+
+# DDSketch guarantees a relative error of at most 1% on every quantile it returns.
+RELATIVE_ACCURACY = 0.01
+
+
+def assert_approx_percentile(actual, values, percentile):
+    expected = np.quantile(values, percentile, method="lower")
+    assert actual == pytest.approx(expected, rel=RELATIVE_ACCURACY)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+@pytest.mark.parametrize("percentile", [0.0, 0.25, 0.5, 0.9, 1.0])
+def test_approx_percentiles_global(make_df, repartition_nparts, percentile):
+    values = list(range(1, 1001))
+    daft_df = make_df({"values": values}, repartition=repartition_nparts)
+    daft_df = daft_df.agg([col("values").approx_percentiles(percentile).alias("p")])
+    res = daft_df.to_pydict()
+
+    assert len(res["p"]) == 1
+    assert_approx_percentile(res["p"][0], values, percentile)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_approx_percentiles_global_list_output(make_df, repartition_nparts):
+    values = [float(v) for v in np.random.default_rng(0).lognormal(size=2000)]
+    percentiles = [0.1, 0.5, 0.99]
+    daft_df = make_df({"values": values}, repartition=repartition_nparts)
+    daft_df = daft_df.agg([col("values").approx_percentiles(percentiles).alias("p")])
+    res = daft_df.to_pydict()
+
+    assert len(res["p"]) == 1
+    assert len(res["p"][0]) == len(percentiles)
+    for actual, percentile in zip(res["p"][0], percentiles):
+        assert_approx_percentile(actual, values, percentile)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_approx_percentiles_groupby(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2, 3, 3],
+            "values": [1, 2, 3, -10, None, -30, None, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = (
+        daft_df.groupby("group")
+        .agg(
+            [
+                col("values").approx_percentiles(0.5).alias("median"),
+                col("values").approx_percentiles([0.0, 1.0]).alias("bounds"),
+            ]
+        )
+        .sort("group")
+    )
+    res = daft_df.to_pydict()
+
+    assert res["group"] == [1, 2, 3]
+    assert res["median"][0] == pytest.approx(2.0, rel=RELATIVE_ACCURACY)
+    assert res["median"][1] == pytest.approx(-30.0, rel=RELATIVE_ACCURACY)
+    assert res["median"][2] is None
+    assert res["bounds"][0] == [1.0, 3.0]
+    assert res["bounds"][1] == [-30.0, -10.0]
+    assert res["bounds"][2] is None
+
+
+def test_approx_percentiles_invalid_percentile(make_df):
+    daft_df = make_df({"values": [1, 2, 3]})
+    with pytest.raises(ValueError, match="percentile"):
+        daft_df.agg([col("values").approx_percentiles(1.5)]).collect()
+
+
+def test_approx_percentiles_non_numeric(make_df):
+    daft_df = make_df({"values": ["a", "b", "c"]})
+    with pytest.raises(ValueError):
+        daft_df.agg([col("values").approx_percentiles(0.5)]).collect()
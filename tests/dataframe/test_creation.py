@@ -638,6 +638,66 @@ def test_create_dataframe_csv_schema_hints_ignore_random_hint(
         assert len(pd_df) == len(valid_data)
 
 
+def test_create_dataframe_csv_custom_values() -> None:
+    with create_temp_filename() as fname:
+        with open(fname, "w") as f:
+            f.write("exported by vendor\n")
+            f.write("a::b::c\n")
+            f.write("1::Y::NA\n")
+            f.write("NA::N::x\n")
+
+        df = daft.read_csv(
+            fname,
+            delimiter="::",
+            skip_rows=1,
+            null_values=["NA"],
+            true_values=["Y"],
+            false_values=["N"],
+        )
+        assert df.schema()["a"].dtype == DataType.int64()
+        assert df.schema()["b"].dtype == DataType.bool()
+        assert df.to_pydict() == {"a": [1, None], "b": [True, False], "c": [None, "x"]}
+
+
+@pytest.mark.parametrize(
+    ["ragged_rows", "expected"],
+    [
+        ("fill", {"a": [1, 3, 4], "b": [2, None, 5]}),
+        ("reject", {"a": [1, None, None], "b": [2, None, None], "_rejected_row": [None, "3", "4,5,6"]}),
+    ],
+)
+def test_create_dataframe_csv_ragged_rows(ragged_rows, expected) -> None:
+    with create_temp_filename() as fname:
+        with open(fname, "w") as f:
+            f.write("a,b\n1,2\n3\n4,5,6\n")
+
+        df = daft.read_csv(fname, ragged_rows=ragged_rows)
+        assert df.to_pydict() == expected
+
+
+@pytest.mark.parametrize(
+    ["mode", "expected"],
+    [
+        ("drop_malformed", {"a": [1, 4], "b": [2, 5]}),
+        (
+            "permissive",
+            {
+                "a": [1, None, 4],
+                "b": [2, None, 5],
+                "_corrupt_record": [None, {"record": "3", "error": "Expected 2 fields, but found 1"}, None],
+            },
+        ),
+    ],
+)
+def test_create_dataframe_csv_parse_modes(mode, expected) -> None:
+    with create_temp_filename() as fname:
+        with open(fname, "w") as f:
+            f.write("a,b\n1,2\n3\n4,5\n")
+
+        df = daft.read_csv(fname, mode=mode)
+        assert df.to_pydict() == expected
+
+
 ###
 # JSON tests
 ###
@@ -875,6 +935,49 @@ def test_create_dataframe_json_empty_objects(input, expected) -> None:
         assert pydict == expected
 
 
+@pytest.mark.parametrize(
+    "data",
+    [
+        pytest.param('[{"a": 1, "b": "x"}, {"a": 2}, {"a": 3, "b": "z"}]', id="array"),
+        pytest.param('[\n  {"a": 1, "b": "x"},\n  {"a": 2},\n  {"a": 3, "b": "z"}\n]\n', id="multi_line_array"),
+        pytest.param('{\n  "a": 1,\n  "b": "x"\n}\n{\n  "a": 2\n}\n{"a": 3, "b": "z"}\n', id="multi_line_documents"),
+    ],
+)
+def test_create_dataframe_json_layouts(data) -> None:
+    with create_temp_filename() as fname:
+        with open(fname, "w") as f:
+            f.write(data)
+            f.flush()
+
+        df = daft.read_json(fname)
+        assert df.to_pydict() == {"a": [1, 2, 3], "b": ["x", None, "z"]}
+
+
+@pytest.mark.parametrize(
+    ["mode", "expected"],
+    [
+        ("drop_malformed", {"a": [1, 3], "b": ["x", None]}),
+        ("permissive", {"a": [1, None, None, 3], "b": ["x", None, "y", None]}),
+    ],
+)
+def test_create_dataframe_json_parse_modes(mode, expected) -> None:
+    lines = ['{"a": 1, "b": "x"}', "not json", '{"a": "oops", "b": "y"}', '{"a": 3}']
+    with create_temp_filename() as fname:
+        with open(fname, "w") as f:
+            for line in lines:
+                f.write(line)
+                f.write("\n")
+            f.flush()
+
+        df = daft.read_json(fname, schema_hints={"a": DataType.int64()}, mode=mode)
+        pydict = df.to_pydict()
+        corrupt_records = pydict.pop("_corrupt_record", None)
+
+        assert pydict == expected
+        if mode == "permissive":
+            assert [r["record"] if r is not None else None for r in corrupt_records] == [None, lines[1], lines[2], None]
+
+
 ###
 # Parquet tests
 ###
@@ -0,0 +1,131 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_distinct_aggs_global(make_df, repartition_nparts):
+    daft_df = make_df(
+        {"values": [1, 2, 2, None, 3, 1, None]},
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.agg(
+        [
+            col("values").count_distinct().alias("count_distinct"),
+            col("values").sum_distinct().alias("sum_distinct"),
+            col("values").agg_list_distinct().alias("list_distinct"),
+            col("values").sum().alias("sum"),
+        ]
+    )
+    res = daft_df.to_pydict()
+
+    assert res["count_distinct"] == [3]
+    assert res["sum_distinct"] == [6]
+    assert res["sum"] == [9]
+    assert len(res["list_distinct"]) == 1
+    assert sorted(res["list_distinct"][0]) == [1, 2, 3]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_distinct_aggs_groupby(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2, 3],
+            "values": [1, 1, 2, 5, None, 5, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = (
+        daft_df.groupby("group")
+        .agg(
+            [
+                col("values").count_distinct().alias("count_distinct"),
+                col("values").sum_distinct().alias("sum_distinct"),
+                col("values").agg_list_distinct().alias("list_distinct"),
+                col("values").count().alias("count"),
+            ]
+        )
+        .sort("group")
+    )
+    res = daft_df.to_pydict()
+
+    assert res["group"] == [1, 2, 3]
+    assert res["count_distinct"] == [2, 1, 0]
+    assert res["sum_distinct"] == [3, 5, None]
+    assert res["count"] == [3, 2, 0]
+    assert [sorted(values) for values in res["list_distinct"]] == [[1, 2], [5], []]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_count_distinct_strings(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": ["a", "a", "b", "b", "b"],
+            "values": ["x", "x", "x", "y", "z"],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg([col("values").count_distinct()]).sort("group")
+    assert daft_df.to_pydict() == {"group": ["a", "b"], "values": [1, 3]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_only_distinct_aggs_groupby(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2, 3, 1],
+            "values": [1, 1, 2, 5, None, 5, None, 2],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = (
+        daft_df.groupby("group")
+        .agg(
+            [
+                col("values").count_distinct().alias("count_distinct"),
+                col("values").sum_distinct().alias("sum_distinct"),
+                col("values").agg_list_distinct().alias("list_distinct"),
+            ]
+        )
+        .sort("group")
+    )
+    res = daft_df.to_pydict()
+
+    assert res["group"] == [1, 2, 3]
+    assert res["count_distinct"] == [2, 1, 0]
+    assert res["sum_distinct"] == [3, 5, None]
+    assert [sorted(values) for values in res["list_distinct"]] == [[1, 2], [5], []]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_only_distinct_aggs_global(make_df, repartition_nparts):
+    daft_df = make_df(
+        {"values": [1, 2, 2, None, 3, 1, None, 3]},
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.agg(
+        [
+            col("values").count_distinct().alias("count_distinct"),
+            col("values").sum_distinct().alias("sum_distinct"),
+        ]
+    )
+    assert daft_df.to_pydict() == {"count_distinct": [3], "sum_distinct": [6]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_distinct_aggs_column_named_group_id(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": ["a", "a", "b", "b", "b"],
+            "group_id": [1, 1, 1, 2, 3],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = (
+        daft_df.groupby("group")
+        .agg([col("group_id").count_distinct(), col("group_id").sum().alias("sum")])
+        .sort("group")
+    )
+    assert daft_df.to_pydict() == {"group": ["a", "b"], "group_id": [1, 3], "sum": [2, 6]}
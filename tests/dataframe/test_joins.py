@@ -414,3 +414,72 @@ def test_inner_join_null_type_column(join_strategy, make_df):
 
     with pytest.raises((ExpressionTypeError, ValueError)):
         daft_df.join(daft_df2, on="id", how="inner", strategy=join_strategy)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+@pytest.mark.parametrize(
+    "join_strategy", [None, "hash", "sort_merge", "sort_merge_aligned_boundaries", "broadcast"], indirect=True
+)
+@pytest.mark.parametrize(
+    "how, expected",
+    [
+        (
+            "left",
+            {"id": [1, 3, None], "values_left": ["a1", "c1", "b1"], "values_right": ["a2", "c2", None]},
+        ),
+        (
+            "right",
+            {"id": [1, 2, 3, 4], "values_left": ["a1", None, "c1", None], "values_right": ["a2", "b2", "c2", "d2"]},
+        ),
+        (
+            "outer",
+            {
+                "id": [1, 2, 3, 4, None],
+                "values_left": ["a1", None, "c1", None, "b1"],
+                "values_right": ["a2", "b2", "c2", "d2", None],
+            },
+        ),
+        ("semi", {"id": [1, 3], "values_left": ["a1", "c1"]}),
+        ("anti", {"id": [None], "values_left": ["b1"]}),
+    ],
+)
+def test_non_inner_join(join_strategy, make_df, repartition_nparts, how, expected):
+    daft_df = make_df(
+        {
+            "id": [1, None, 3],
+            "values_left": ["a1", "b1", "c1"],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df2 = make_df(
+        {
+            "id": [1, 2, 3, 4],
+            "values_right": ["a2", "b2", "c2", "d2"],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.join(daft_df2, on="id", how=how, strategy=join_strategy)
+
+    if how == "outer" and join_strategy == "broadcast":
+        with pytest.raises(ValueError, match="Broadcast join does not support Outer joins"):
+            daft_df.collect()
+        return
+    assert sort_arrow_table(pa.Table.from_pydict(daft_df.to_pydict()), "id") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "id"
+    )
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_cross_join(make_df, repartition_nparts):
+    daft_df = make_df({"A": [1, 2, 3]}, repartition=repartition_nparts)
+    daft_df2 = make_df({"A": ["a", "b"], "B": [True, False]}, repartition=repartition_nparts)
+
+    joined = daft_df.join(daft_df2, how="cross").sort(["A", "right.A"]).to_pydict()
+    assert joined == {
+        "A": [1, 1, 2, 2, 3, 3],
+        "right.A": ["a", "b", "a", "b", "a", "b"],
+        "B": [True, False, True, False, True, False],
+    }
+
+    with pytest.raises(ValueError, match="Cross joins do not take"):
+        daft_df.join(daft_df2, on="A", how="cross")
@@ -0,0 +1,86 @@
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+from daft import col
+
+
+def skew(values):
+    values = np.asarray(values, dtype=np.float64)
+    deviations = values - values.mean()
+    return (deviations**3).mean() / (deviations**2).mean() ** 1.5
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_statistical_aggregations_global(make_df, repartition_nparts):
+    rng = np.random.default_rng(0)
+    xs = [float(v) for v in rng.lognormal(size=1000)]
+    ys = [2.0 * x + float(noise) for x, noise in zip(xs, rng.normal(size=1000))]
+    daft_df = make_df({"x": xs, "y": ys}, repartition=repartition_nparts)
+    daft_df = daft_df.agg(
+        [
+            col("x").variance().alias("var"),
+            col("x").variance(ddof=0).alias("var_pop"),
+            col("x").stddev().alias("std"),
+            col("x").skew().alias("skew"),
+            col("x").covar(col("y")).alias("covar"),
+            col("x").corr(col("y")).alias("corr"),
+        ]
+    )
+    res = daft_df.to_pydict()
+
+    assert res["var"] == [pytest.approx(np.var(xs, ddof=1))]
+    assert res["var_pop"] == [pytest.approx(np.var(xs, ddof=0))]
+    assert res["std"] == [pytest.approx(np.std(xs, ddof=1))]
+    assert res["skew"] == [pytest.approx(skew(xs))]
+    assert res["covar"] == [pytest.approx(np.cov(xs, ys, ddof=1)[0][1])]
+    assert res["corr"] == [pytest.approx(np.corrcoef(xs, ys)[0][1])]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_statistical_aggregations_groupby(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 2, 3],
+            "x": [1.0, 2.0, 4.0, None, 5.0, 5.0, 5.0, 1.0],
+            "y": [2.0, 4.0, 8.0, 1.0, None, 1.0, 2.0, 3.0],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = (
+        daft_df.groupby("group")
+        .agg(
+            [
+                col("x").variance().alias("var"),
+                col("x").stddev(ddof=0).alias("std_pop"),
+                col("x").covar(col("y")).alias("covar"),
+                col("x").corr(col("y")).alias("corr"),
+            ]
+        )
+        .sort("group")
+    )
+    res = daft_df.to_pydict()
+
+    assert res["group"] == [1, 2, 3]
+    assert res["var"][0] == pytest.approx(np.var([1.0, 2.0, 4.0], ddof=1))
+    assert res["var"][1] == pytest.approx(0.0)
+    # A single value has no sample variance.
+    assert res["var"][2] is None
+    assert res["std_pop"][2] == pytest.approx(0.0)
+    # Only rows where both columns are non-null are used.
+    assert res["covar"][0] == pytest.approx(np.cov([1.0, 2.0, 4.0], [2.0, 4.0, 8.0], ddof=1)[0][1])
+    assert res["covar"][1] == pytest.approx(0.0)
+    assert res["corr"][0] == pytest.approx(1.0)
+
+
+def test_statistical_aggregations_all_null(make_df):
+    daft_df = make_df({"x": [None, None]}).select(col("x").cast(float))
+    res = daft_df.agg([col("x").variance().alias("var"), col("x").skew().alias("skew")]).to_pydict()
+    assert res == {"var": [None], "skew": [None]}
+
+
+def test_statistical_aggregations_non_numeric(make_df):
+    daft_df = make_df({"x": ["a", "b", "c"]})
+    with pytest.raises(ValueError):
+        daft_df.agg([col("x").variance()]).collect()
@@ -0,0 +1,108 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_window_functions_partitioned(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 2, 1, 2, 1, 1],
+            "ts": [2, 1, 1, 2, 3, 3],
+            "values": [20, 100, 10, 200, 30, 40],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.window(
+        daft.row_number().alias("row_number"),
+        daft.rank().alias("rank"),
+        daft.dense_rank().alias("dense_rank"),
+        col("ts").lag(1).alias("prev_ts"),
+        col("ts").lead(1, -1).alias("next_ts"),
+        partition_by="group",
+        order_by="ts",
+    ).select("group", "ts", "row_number", "rank", "dense_rank", "prev_ts", "next_ts")
+    expected = {
+        "group": [1, 1, 1, 1, 2, 2],
+        "ts": [1, 2, 3, 3, 1, 2],
+        "row_number": [1, 2, 3, 4, 1, 2],
+        "rank": [1, 2, 3, 3, 1, 2],
+        "dense_rank": [1, 2, 3, 3, 1, 2],
+        "prev_ts": [None, 1, 2, 3, None, 1],
+        "next_ts": [2, 3, 3, -1, 2, -1],
+    }
+
+    assert daft_df.sort(["group", "row_number"]).to_pydict() == expected
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_window_aggregations(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2],
+            "ts": [1, 2, 3, 1, 2],
+            "values": [1, None, 3, 10, 20],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.window(
+        col("values").sum().alias("running_sum"),
+        col("values").count().alias("running_count"),
+        partition_by="group",
+        order_by="ts",
+    )
+    daft_df = daft_df.window(
+        col("values").mean().alias("moving_mean"),
+        col("values").max().alias("moving_max"),
+        partition_by="group",
+        order_by="ts",
+        frame=(-1, 1),
+    )
+    daft_df = daft_df.window(col("values").min().alias("group_min"), partition_by="group")
+    expected = {
+        "group": [1, 1, 1, 2, 2],
+        "ts": [1, 2, 3, 1, 2],
+        "values": [1, None, 3, 10, 20],
+        "running_sum": [1, 1, 4, 10, 30],
+        "running_count": [1, 1, 2, 1, 2],
+        "moving_mean": [1.0, 2.0, 3.0, 15.0, 15.0],
+        "moving_max": [1, 3, 3, 20, 20],
+        "group_min": [1, 1, 1, 10, 10],
+    }
+
+    assert daft_df.sort(["group", "ts"]).to_pydict() == expected
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_window_unpartitioned_descending(make_df, repartition_nparts):
+    daft_df = make_df({"values": [3, 1, 2, 5, 4]}, repartition=repartition_nparts)
+    daft_df = daft_df.window(
+        daft.row_number().alias("row_number"),
+        col("values").sum().alias("running_sum"),
+        order_by="values",
+        desc=True,
+    )
+    expected = {
+        "values": [5, 4, 3, 2, 1],
+        "row_number": [1, 2, 3, 4, 5],
+        "running_sum": [5, 9, 12, 14, 15],
+    }
+
+    assert daft_df.to_pydict() == expected
+
+
+def test_window_function_outside_window(make_df):
+    daft_df = make_df({"values": [1, 2, 3]})
+    with pytest.raises(ValueError, match="Window functions are only supported in df.window()"):
+        daft_df.select(daft.row_number())
+    with pytest.raises(ValueError, match="Window functions are only supported in df.window()"):
+        daft_df.where(col("values").lag(1) > 1)
+
+
+def test_window_unsupported_aggregation(make_df):
+    daft_df = make_df({"values": [1, 2, 3]})
+    with pytest.raises(Exception, match="Expected a window function or a count, sum, mean, min or max aggregation"):
+        daft_df.window(col("values").agg_list())
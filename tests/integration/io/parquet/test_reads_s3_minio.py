@@ -60,3 +60,30 @@ def test_minio_parquet_ignore_marker_files(minio_io_config):
 
         read = daft.read_parquet(f"s3://{bucket_name}/**", io_config=minio_io_config)
         assert read.to_pydict() == {"x": [1, 2, 3, 4] * 3}
+
+
+@pytest.mark.integration()
+@pytest.mark.parametrize(
+    "pred",
+    [
+        daft.col("x") == 4321,
+        daft.col("x") < 100,
+        (daft.col("x") > 5000) & (daft.col("x") <= 5005),
+        daft.col("y") == "y_7",
+        daft.col("x").is_in([1, 9999, 20000]),
+    ],
+)
+def test_minio_parquet_read_with_page_index_pruning(minio_io_config, pred):
+    bucket_name = "data-engineering-prod"
+    with minio_create_bucket(minio_io_config, bucket_name=bucket_name) as fs:
+        path = f"s3://{bucket_name}/page_index.parquet"
+        data = {"x": list(range(10_000)), "y": [f"y_{i % 10}" for i in range(10_000)]}
+        pa_table = pa.Table.from_pydict(data)
+        # Small data pages give each row group many pages that can be skipped with the page index.
+        pq.write_table(
+            pa_table, path, filesystem=fs, row_group_size=4096, data_page_size=1024, write_page_index=True
+        )
+
+        with_pushdown = daft.table.MicroPartition.read_parquet(path, predicate=pred, io_config=minio_io_config)
+        after = daft.table.MicroPartition.read_parquet(path, io_config=minio_io_config).filter([pred])
+        assert with_pushdown.to_arrow() == after.to_arrow()
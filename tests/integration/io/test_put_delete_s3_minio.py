@@ -0,0 +1,24 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.daft import io_copy, io_delete, io_put
+
+from .conftest import minio_create_bucket
+
+
+@pytest.mark.integration()
+def test_put_copy_delete_minio(minio_io_config):
+    bucket_name = "put-bucket"
+    with minio_create_bucket(minio_io_config, bucket_name=bucket_name) as fs:
+        data = b"hello world" * 1024
+        io_put(f"s3://{bucket_name}/nested/a.bin", data, io_config=minio_io_config)
+        assert fs.cat(f"s3://{bucket_name}/nested/a.bin") == data
+
+        io_copy(f"s3://{bucket_name}/nested/a.bin", f"s3://{bucket_name}/b.bin", io_config=minio_io_config)
+        assert fs.cat(f"s3://{bucket_name}/b.bin") == data
+
+        io_delete(f"s3://{bucket_name}/nested/a.bin", io_config=minio_io_config)
+        fs.invalidate_cache()
+        assert not fs.exists(f"s3://{bucket_name}/nested/a.bin")
+        assert fs.exists(f"s3://{bucket_name}/b.bin")
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import uuid
 
+import numpy as np
 import pytest
 import s3fs
 
@@ -41,3 +42,18 @@ def test_writing_parquet(minio_io_config, bucket, protocol):
     )
     results.collect()
     assert len(results) == 3
+
+
+@pytest.mark.integration()
+def test_writing_parquet_multipart_upload(minio_io_config, bucket):
+    # Random integers barely compress, so this is written as a multipart upload of several parts.
+    num_rows = 4 * 1024 * 1024
+    data = {"foo": np.random.default_rng(0).integers(0, 2**62, size=num_rows)}
+    df = daft.from_pydict(data)
+    path = f"s3://{bucket}/parquet-multipart-writes-{uuid.uuid4()}"
+    results = df.write_parquet(path, io_config=minio_io_config)
+    results.collect()
+    assert len(results) == 1
+
+    read_back = daft.read_parquet(f"{path}/*.parquet", io_config=minio_io_config).to_pydict()
+    assert read_back["foo"] == data["foo"].tolist()
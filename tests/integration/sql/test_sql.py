@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import datetime
+import decimal
 import math
 
 import pandas as pd
@@ -8,9 +9,10 @@ import pytest
 import sqlalchemy
 
 import daft
+from daft import DataType
 from daft.context import set_execution_config
 from tests.conftest import assert_df_equals
-from tests.integration.sql.conftest import TEST_TABLE_NAME
+from tests.integration.sql.conftest import TEST_TABLE_NAME, URLS
 
 
 @pytest.fixture(scope="session")
@@ -227,3 +229,21 @@ def test_sql_connection_factory_ok(test_db, pdf) -> None:
 def test_sql_bad_connection_factory() -> None:
     with pytest.raises(ValueError):
         daft.read_sql(f"SELECT * FROM {TEST_TABLE_NAME}", lambda: "bad_conn")
+
+
+@pytest.mark.integration()
+def test_sql_read_postgres_numeric() -> None:
+    url = next(url for url in URLS if url.startswith("postgresql"))
+    with sqlalchemy.create_engine(url).begin() as conn:
+        conn.execute(sqlalchemy.text("DROP TABLE IF EXISTS numeric_example"))
+        conn.execute(sqlalchemy.text("CREATE TABLE numeric_example (id INTEGER, price NUMERIC(10, 2), amount NUMERIC)"))
+        conn.execute(
+            sqlalchemy.text("INSERT INTO numeric_example VALUES (1, 1.25, 0.5), (2, NULL, NULL), (3, -99999999.99, 3)")
+        )
+
+    df = daft.read_sql("SELECT * FROM numeric_example", url)
+    assert df.schema()["price"].dtype == DataType.decimal128(10, 2)
+    assert df.schema()["amount"].dtype == DataType.decimal128(38, 10)
+    data = df.sort("id").to_pydict()
+    assert data["price"] == [decimal.Decimal("1.25"), None, decimal.Decimal("-99999999.99")]
+    assert data["amount"] == [decimal.Decimal("0.5"), None, decimal.Decimal("3")]
@@ -0,0 +1,128 @@
+from __future__ import annotations
+
+import datetime
+import decimal
+
+import fastavro
+import pytest
+
+import daft
+from daft import DataType, col
+
+SCHEMA = {
+    "type": "record",
+    "name": "test",
+    "fields": [
+        {"name": "id", "type": "long"},
+        {"name": "name", "type": ["null", "string"]},
+        {"name": "score", "type": "double"},
+        {"name": "tags", "type": {"type": "array", "items": "int"}},
+        {"name": "attrs", "type": {"type": "map", "values": "long"}},
+        {"name": "color", "type": {"type": "enum", "name": "Color", "symbols": ["RED", "GREEN"]}},
+        {"name": "value", "type": ["int", "string"]},
+        {
+            "name": "point",
+            "type": {"type": "record", "name": "Point", "fields": [{"name": "x", "type": "float"}]},
+        },
+        {"name": "day", "type": {"type": "int", "logicalType": "date"}},
+        {"name": "ts", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+        {"name": "amount", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+    ],
+}
+
+
+def _record(i: int) -> dict:
+    return {
+        "id": i,
+        "name": f"name-{i}" if i % 7 else None,
+        "score": i / 10,
+        "tags": [i, i + 1],
+        "attrs": {"a": i},
+        "color": "RED" if i % 2 else "GREEN",
+        "value": i if i % 3 else str(i),
+        "point": {"x": float(i)},
+        "day": datetime.date(2024, 1, 1) + datetime.timedelta(days=i),
+        "ts": datetime.datetime(2024, 1, 1, tzinfo=datetime.timezone.utc) + datetime.timedelta(seconds=i),
+        "amount": decimal.Decimal(i) / 100,
+    }
+
+
+def _write(path: str, schema: dict, records: list[dict], codec: str = "null") -> None:
+    with open(path, "wb") as f:
+        fastavro.writer(f, fastavro.parse_schema(schema), records, codec=codec, sync_interval=256)
+
+
+@pytest.mark.parametrize("codec", ["null", "deflate", "snappy"])
+def test_read_avro(tmp_path, codec):
+    path = str(tmp_path / "data.avro")
+    _write(path, SCHEMA, [_record(i) for i in range(100)], codec=codec)
+
+    df = daft.read_avro(path)
+    schema = df.schema()
+    assert df.column_names == [field["name"] for field in SCHEMA["fields"]]
+    assert schema["name"].dtype == DataType.string()
+    assert schema["tags"].dtype == DataType.list(DataType.int32())
+    assert schema["attrs"].dtype == DataType.map(DataType.string(), DataType.int64())
+    assert schema["color"].dtype == DataType.string()
+    assert schema["value"].dtype == DataType.struct({"member0": DataType.int32(), "member1": DataType.string()})
+    assert schema["day"].dtype == DataType.date()
+    assert schema["ts"].dtype == DataType.timestamp("us", "+00:00")
+    assert schema["amount"].dtype == DataType.decimal128(10, 2)
+
+    data = df.to_pydict()
+    assert data["id"] == list(range(100))
+    assert data["name"] == [f"name-{i}" if i % 7 else None for i in range(100)]
+    assert data["tags"] == [[i, i + 1] for i in range(100)]
+    assert data["color"][:2] == ["GREEN", "RED"]
+    assert data["value"][:2] == [{"member0": None, "member1": "0"}, {"member0": 1, "member1": None}]
+    assert data["point"][3] == {"x": 3.0}
+    assert data["day"][1] == datetime.date(2024, 1, 2)
+    assert data["amount"][5] == decimal.Decimal("0.05")
+
+
+def test_read_avro_projection_limit_and_filter(tmp_path):
+    path = str(tmp_path / "data.avro")
+    _write(path, SCHEMA, [_record(i) for i in range(100)])
+
+    df = daft.read_avro(path).select("score", "id").limit(20)
+    assert df.to_pydict() == {"score": [i / 10 for i in range(20)], "id": list(range(20))}
+
+    df = daft.read_avro(path).where(col("id") >= 90).select("name")
+    assert df.to_pydict() == {"name": [f"name-{i}" if i % 7 else None for i in range(90, 100)]}
+
+
+def test_read_avro_schema_evolution(tmp_path):
+    old_schema = {
+        "type": "record",
+        "name": "test",
+        "fields": [{"name": "id", "type": "int"}, {"name": "dropped", "type": "string"}],
+    }
+    new_schema = {
+        "type": "record",
+        "name": "test",
+        "fields": [{"name": "id", "type": "long"}, {"name": "added", "type": ["null", "string"]}],
+    }
+    _write(str(tmp_path / "0.avro"), old_schema, [{"id": i, "dropped": "x"} for i in range(3)])
+    _write(str(tmp_path / "1.avro"), new_schema, [{"id": i, "added": f"a{i}"} for i in range(3, 6)])
+
+    # The schema is inferred from the first file, and the other files are read with it.
+    df = daft.read_avro([str(tmp_path / "1.avro"), str(tmp_path / "0.avro")])
+    assert df.schema()["id"].dtype == DataType.int64()
+    assert df.sort("id").to_pydict() == {
+        "id": list(range(6)),
+        "added": [None, None, None, "a3", "a4", "a5"],
+    }
+
+
+def test_read_avro_multiple_files_with_schema_hints(tmp_path):
+    for i in range(3):
+        _write(str(tmp_path / f"part-{i}.avro"), SCHEMA, [_record(j) for j in range(10)])
+
+    df = daft.read_avro(str(tmp_path / "*.avro"), schema_hints={"id": DataType.float64()})
+    assert df.schema()["id"].dtype == DataType.float64()
+    assert len(df.collect()) == 30
+
+
+def test_read_avro_empty_list_of_paths():
+    with pytest.raises(ValueError, match="empty list of Avro filepaths"):
+        daft.read_avro([])
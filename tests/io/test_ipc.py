@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.feather as feather
+import pyarrow.ipc as ipc
+import pytest
+
+import daft
+from daft import DataType, col
+
+
+@pytest.fixture(scope="function")
+def arrow_table() -> pa.Table:
+    return pa.table(
+        {
+            "id": pa.array(list(range(100)), type=pa.int64()),
+            "name": pa.array([f"name-{i}" if i % 7 else None for i in range(100)], type=pa.large_string()),
+            "score": pa.array([i / 10 for i in range(100)], type=pa.float64()),
+            "tags": pa.array([[i, i + 1] for i in range(100)], type=pa.list_(pa.int32())),
+        }
+    )
+
+
+def _write(table: pa.Table, path: str, variant: str) -> None:
+    if variant == "file":
+        with ipc.new_file(path, table.schema) as writer:
+            writer.write_table(table, max_chunksize=16)
+    elif variant == "stream":
+        with ipc.new_stream(path, table.schema) as writer:
+            writer.write_table(table, max_chunksize=16)
+    else:
+        feather.write_feather(table, path, compression="uncompressed", chunksize=16)
+
+
+@pytest.mark.parametrize("variant", ["file", "stream", "feather"])
+def test_read_ipc(tmp_path, arrow_table, variant):
+    path = str(tmp_path / "data.arrow")
+    _write(arrow_table, path, variant)
+
+    df = daft.read_ipc(path)
+    assert df.column_names == arrow_table.column_names
+    assert df.to_arrow() == daft.from_arrow(arrow_table).to_arrow()
+
+
+@pytest.mark.parametrize("variant", ["file", "stream"])
+def test_read_ipc_projection_limit_and_filter(tmp_path, arrow_table, variant):
+    path = str(tmp_path / "data.arrow")
+    _write(arrow_table, path, variant)
+
+    df = daft.read_ipc(path).select("score", "id").limit(20)
+    assert df.to_pydict() == {"score": [i / 10 for i in range(20)], "id": list(range(20))}
+
+    df = daft.read_ipc(path).where(col("id") >= 90).select("name")
+    assert df.to_pydict() == {"name": [f"name-{i}" if i % 7 else None for i in range(90, 100)]}
+
+
+def test_read_ipc_multiple_files_with_schema_hints(tmp_path, arrow_table):
+    for i in range(3):
+        _write(arrow_table, str(tmp_path / f"part-{i}.arrow"), "file")
+
+    df = daft.read_ipc(str(tmp_path / "*.arrow"), schema_hints={"id": DataType.float64()})
+    assert df.schema()["id"].dtype == DataType.float64()
+    assert len(df.collect()) == 300
+
+
+def test_read_ipc_feather_v1_unsupported(tmp_path, arrow_table):
+    path = str(tmp_path / "data.feather")
+    feather.write_feather(arrow_table.select(["id", "score"]), path, version=1)
+
+    with pytest.raises(Exception):
+        daft.read_ipc(path).collect()
+
+
+def test_read_ipc_empty_list_of_paths():
+    with pytest.raises(ValueError, match="empty list of Arrow IPC filepaths"):
+        daft.read_ipc([])
@@ -0,0 +1,93 @@
+from __future__ import annotations
+
+import contextlib
+
+import pyarrow as pa
+import pyarrow.orc as pa_orc
+import pytest
+
+import daft
+from daft import DataType, col
+
+
+def _table(start: int, end: int) -> pa.Table:
+    ids = list(range(start, end))
+    return pa.table(
+        {
+            "id": pa.array(ids, type=pa.int64()),
+            "small": pa.array([i % 100 for i in ids], type=pa.int32()),
+            "score": pa.array([i / 10 for i in ids], type=pa.float64()),
+            "flag": pa.array([i % 2 == 0 for i in ids], type=pa.bool_()),
+            "name": pa.array([f"name-{i}" if i % 7 else None for i in ids], type=pa.string()),
+        }
+    )
+
+
+def _write(path: str, table: pa.Table, **kwargs) -> None:
+    pa_orc.write_table(table, path, **kwargs)
+
+
+@contextlib.contextmanager
+def split_scan_tasks():
+    old_execution_config = daft.context.get_context().daft_execution_config
+
+    try:
+        daft.set_execution_config(scan_tasks_min_size_bytes=0, scan_tasks_max_size_bytes=0)
+        yield
+    finally:
+        daft.set_execution_config(old_execution_config)
+
+
+def test_read_orc(tmp_path):
+    path = str(tmp_path / "data.orc")
+    _write(path, _table(0, 100))
+
+    df = daft.read_orc(path)
+    schema = df.schema()
+    assert df.column_names == ["id", "small", "score", "flag", "name"]
+    assert schema["id"].dtype == DataType.int64()
+    assert schema["small"].dtype == DataType.int32()
+    assert schema["flag"].dtype == DataType.bool()
+    assert schema["name"].dtype == DataType.string()
+    assert df.to_arrow() == _table(0, 100)
+
+
+def test_read_orc_projection_limit_and_filter(tmp_path):
+    path = str(tmp_path / "data.orc")
+    _write(path, _table(0, 100))
+
+    df = daft.read_orc(path).select("score", "id").limit(20)
+    assert df.to_pydict() == {"score": [i / 10 for i in range(20)], "id": list(range(20))}
+
+    df = daft.read_orc(path).where(col("id") >= 90).select("name")
+    assert df.to_pydict() == {"name": [f"name-{i}" if i % 7 else None for i in range(90, 100)]}
+
+
+def test_read_orc_split_and_pruned_by_stripes(tmp_path):
+    path = str(tmp_path / "data.orc")
+    _write(path, _table(0, 10_000), stripe_size=1024, batch_size=500)
+    num_stripes = pa_orc.ORCFile(path).nstripes
+    assert num_stripes > 1
+
+    with split_scan_tasks():
+        df = daft.read_orc(path)
+        assert df.num_partitions() == num_stripes
+        assert df.sort("id").to_pydict() == _table(0, 10_000).to_pydict()
+
+        # Stripes whose statistics exclude the filter are skipped, which must not change the result.
+        df = daft.read_orc(path).where((col("id") > 4_000) & (col("id") <= 4_010))
+        assert df.sort("id").to_pydict()["id"] == list(range(4_001, 4_011))
+
+
+def test_read_orc_multiple_files_with_schema_hints(tmp_path):
+    for i in range(3):
+        _write(str(tmp_path / f"part-{i}.orc"), _table(i * 10, (i + 1) * 10))
+
+    df = daft.read_orc(str(tmp_path / "*.orc"), schema_hints={"id": DataType.float64()})
+    assert df.schema()["id"].dtype == DataType.float64()
+    assert df.sort("id").to_pydict()["id"] == [float(i) for i in range(30)]
+
+
+def test_read_orc_empty_list_of_paths():
+    with pytest.raises(ValueError, match="empty list of ORC filepaths"):
+        daft.read_orc([])
@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import contextlib
+import gzip
+import json
+
+import pytest
+
+import daft
+
+
+@contextlib.contextmanager
+def override_split_scan_tasks_configs(scan_tasks_max_size_bytes: int, split_csv_by_byte_ranges: bool = True):
+    old_execution_config = daft.context.get_context().daft_execution_config
+
+    try:
+        daft.set_execution_config(
+            scan_tasks_max_size_bytes=scan_tasks_max_size_bytes,
+            split_csv_by_byte_ranges=split_csv_by_byte_ranges,
+            # Don't merge the split scan tasks back together.
+            scan_tasks_min_size_bytes=0,
+        )
+        yield
+    finally:
+        daft.set_execution_config(old_execution_config)
+
+
+@pytest.mark.parametrize("scan_tasks_max_size_bytes", [1, 7, 16, 1024])
+def test_split_csv_by_byte_ranges(tmpdir, scan_tasks_max_size_bytes):
+    path = tmpdir / "file.csv"
+    path.write_text('a,b\n1,x\n2,"multi\nline"\n3,y\n4,"z"\n', "utf8")
+
+    with override_split_scan_tasks_configs(scan_tasks_max_size_bytes):
+        df = daft.read_csv(str(path))
+        if scan_tasks_max_size_bytes < 1024:
+            assert df.num_partitions() > 1
+        assert df.sort("a").to_pydict() == {"a": [1, 2, 3, 4], "b": ["x", "multi\nline", "y", "z"]}
+
+
+@pytest.mark.parametrize("scan_tasks_max_size_bytes", [1, 7, 16])
+def test_csv_is_not_split_by_default(tmpdir, scan_tasks_max_size_bytes):
+    # The quoted fields contain line breaks followed by what look like records, which straddle every split point.
+    path = tmpdir / "file.csv"
+    path.write_text('a,b\n1,"x\n5,y\n6,z"\n2,"w\n7,v"\n', "utf8")
+
+    with override_split_scan_tasks_configs(scan_tasks_max_size_bytes, split_csv_by_byte_ranges=False):
+        df = daft.read_csv(str(path))
+        assert df.num_partitions() == 1
+        assert df.to_pydict() == {"a": [1, 2], "b": ["x\n5,y\n6,z", "w\n7,v"]}
+
+
+@pytest.mark.parametrize("scan_tasks_max_size_bytes", [1, 7, 16, 1024])
+def test_split_json_by_byte_ranges(tmpdir, scan_tasks_max_size_bytes):
+    path = tmpdir / "file.jsonl"
+    path.write_text("".join(json.dumps({"a": i, "b": str(i) * i}) + "\n" for i in range(5)), "utf8")
+
+    with override_split_scan_tasks_configs(scan_tasks_max_size_bytes, split_csv_by_byte_ranges=False):
+        df = daft.read_json(str(path))
+        if scan_tasks_max_size_bytes < 1024:
+            assert df.num_partitions() > 1
+        assert df.sort("a").to_pydict() == {"a": list(range(5)), "b": [str(i) * i for i in range(5)]}
+
+
+def test_compressed_csv_is_not_split(tmpdir):
+    path = tmpdir / "file.csv.gz"
+    with gzip.open(str(path), "wt") as f:
+        f.write("a\n" + "".join(f"{i}\n" for i in range(100)))
+
+    with override_split_scan_tasks_configs(1):
+        df = daft.read_csv(str(path))
+        assert df.num_partitions() == 1
+        assert df.to_pydict() == {"a": list(range(100))}
@@ -0,0 +1,86 @@
+from __future__ import annotations
+
+import datetime
+import math
+import sqlite3
+
+import pytest
+
+import daft
+from daft import DataType, col
+
+NUM_ROWS = 100
+
+
+@pytest.fixture(scope="module")
+def sqlite_url(tmp_path_factory) -> str:
+    path = tmp_path_factory.mktemp("db") / "test.db"
+    with sqlite3.connect(path) as conn:
+        conn.execute(
+            "CREATE TABLE example (id INTEGER, score REAL, name VARCHAR(10), flag BOOLEAN, day DATE, ts DATETIME)"
+        )
+        conn.executemany(
+            "INSERT INTO example VALUES (?, ?, ?, ?, ?, ?)",
+            [
+                (
+                    i,
+                    i / 10,
+                    f"row_{i}" if i % 7 else None,
+                    i % 2,
+                    (datetime.date(2024, 1, 1) + datetime.timedelta(days=i)).isoformat(),
+                    (datetime.datetime(2024, 1, 1) + datetime.timedelta(hours=i)).isoformat(sep=" "),
+                )
+                for i in range(NUM_ROWS)
+            ],
+        )
+    return f"sqlite://{path}"
+
+
+def test_read_sql_sqlite(sqlite_url):
+    df = daft.read_sql("SELECT * FROM example", sqlite_url)
+    schema = df.schema()
+    assert schema["id"].dtype == DataType.int64()
+    assert schema["score"].dtype == DataType.float64()
+    assert schema["name"].dtype == DataType.string()
+    assert schema["flag"].dtype == DataType.bool()
+    assert schema["day"].dtype == DataType.date()
+    assert schema["ts"].dtype == DataType.timestamp("us")
+
+    data = df.sort("id").to_pydict()
+    assert data["id"] == list(range(NUM_ROWS))
+    assert data["name"][:2] == [None, "row_1"]
+    assert data["day"][1] == datetime.date(2024, 1, 2)
+    assert data["ts"][1] == datetime.datetime(2024, 1, 1, 1)
+
+
+def test_read_sql_sqlite_pushdowns(sqlite_url):
+    df = daft.read_sql("SELECT * FROM example", sqlite_url)
+    df = df.where((col("id") >= 10) & (col("day") < datetime.date(2024, 1, 21))).select("id", "name").limit(5)
+    assert df.to_pydict() == {"id": list(range(10, 15)), "name": [f"row_{i}" if i % 7 else None for i in range(10, 15)]}
+
+
+@pytest.mark.parametrize("partition_col", ["id", "score", "day", "ts"])
+@pytest.mark.parametrize("num_partitions", [1, 3])
+def test_read_sql_sqlite_partitioned(sqlite_url, partition_col, num_partitions):
+    df = daft.read_sql(
+        "SELECT * FROM example", sqlite_url, partition_col=partition_col, num_partitions=num_partitions
+    )
+    assert df.num_partitions() == num_partitions
+    assert df.sort("id").to_pydict()["id"] == list(range(NUM_ROWS))
+
+
+def test_read_sql_sqlite_partitioned_by_size(sqlite_url):
+    old_execution_config = daft.context.get_context().daft_execution_config
+    row_size_bytes = daft.read_sql("SELECT * FROM example", sqlite_url).schema().estimate_row_size_bytes()
+    try:
+        daft.set_execution_config(read_sql_partition_size_bytes=math.ceil(row_size_bytes * NUM_ROWS / 4))
+        df = daft.read_sql("SELECT * FROM example", sqlite_url, partition_col="id")
+        assert df.num_partitions() == 4
+        assert df.sort("id").to_pydict()["id"] == list(range(NUM_ROWS))
+    finally:
+        daft.set_execution_config(old_execution_config)
+
+
+def test_read_sql_sqlite_non_partitionable_column(sqlite_url):
+    with pytest.raises(ValueError, match="Failed to get partition bounds"):
+        daft.read_sql("SELECT * FROM example", sqlite_url, partition_col="name", num_partitions=2).collect()
@@ -0,0 +1,123 @@
+from __future__ import annotations
+
+import json
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+from daft.daft import (
+    CsvSourceConfig,
+    FileFormatConfig,
+    IOConfig,
+    JsonSourceConfig,
+    NativeStorageConfig,
+    ParquetSourceConfig,
+    Pushdowns,
+    ScanTask,
+    StorageConfig,
+)
+from daft.datatype import DataType
+from daft.logical.schema import Schema
+from daft.table.micropartition import MicroPartition
+from daft.table.table import Table
+
+NUM_ROWS = 100
+DATA = {"a": list(range(NUM_ROWS)), "b": [str(i) for i in range(NUM_ROWS)]}
+SCHEMA = Schema._from_field_name_and_types([("a", DataType.int64()), ("b", DataType.string())])
+
+
+@pytest.fixture(params=["parquet", "csv", "json"])
+def file_format(request):
+    return request.param
+
+
+@pytest.fixture
+def path(tmpdir, file_format):
+    path = str(tmpdir / f"file.{file_format}")
+    if file_format == "parquet":
+        papq.write_table(pa.table(DATA), path, row_group_size=10)
+    elif file_format == "csv":
+        with open(path, "w") as f:
+            f.write("a,b\n")
+            f.writelines(f'{a},"{b}"\n' for a, b in zip(DATA["a"], DATA["b"]))
+    else:
+        with open(path, "w") as f:
+            f.writelines(json.dumps({"a": a, "b": b}) + "\n" for a, b in zip(DATA["a"], DATA["b"]))
+    return path
+
+
+def scan_task(path: str, file_format: str, limit: int | None = None) -> ScanTask:
+    if file_format == "parquet":
+        file_format_config = FileFormatConfig.from_parquet_config(ParquetSourceConfig())
+    elif file_format == "csv":
+        file_format_config = FileFormatConfig.from_csv_config(
+            CsvSourceConfig(
+                has_headers=True,
+                double_quote=True,
+                delimiter=None,
+                quote=None,
+                escape_char=None,
+                comment=None,
+                chunk_size=64,
+            )
+        )
+    else:
+        file_format_config = FileFormatConfig.from_json_config(JsonSourceConfig(chunk_size=10))
+    return ScanTask.catalog_scan_task(
+        file=path,
+        file_format=file_format_config,
+        schema=SCHEMA._schema,
+        num_rows=NUM_ROWS,
+        storage_config=StorageConfig.native(NativeStorageConfig(True, IOConfig())),
+        size_bytes=None,
+        pushdowns=Pushdowns(limit=limit),
+        partition_values=None,
+        stats=None,
+    )
+
+
+def test_stream_scan_task_in_batches(path, file_format):
+    tables = list(MicroPartition._stream_scan_task(scan_task(path, file_format)))
+    assert len(tables) > 1
+    if file_format == "parquet":
+        # Parquet batches don't span row groups.
+        assert [len(table) for table in tables] == [10] * 10
+    assert Table.concat(tables).to_pydict() == DATA
+
+
+@pytest.mark.parametrize("limit", [0, 1, 15, NUM_ROWS * 2])
+def test_stream_scan_task_limit(path, file_format, limit):
+    tables = list(MicroPartition._stream_scan_task(scan_task(path, file_format, limit=limit)))
+    num_rows = min(limit, NUM_ROWS)
+    assert sum(len(table) for table in tables) == num_rows
+    if tables:
+        assert Table.concat(tables).to_pydict() == {k: v[:num_rows] for k, v in DATA.items()}
+
+
+def test_stream_scan_task_stop_early(path, file_format):
+    stream = MicroPartition._stream_scan_task(scan_task(path, file_format), max_tables_in_flight=1)
+    first = next(stream)
+    assert 0 < len(first) < NUM_ROWS
+    # Closing the stream stops the reads, without reading the remaining batches.
+    stream.close()
+    assert list(stream) == []
+
+
+def test_stream_scan_task_propagates_errors(tmpdir, file_format):
+    path = str(tmpdir / f"malformed.{file_format}")
+    with open(path, "w") as f:
+        if file_format == "parquet":
+            f.write("not a parquet file")
+        elif file_format == "csv":
+            f.write("a,b\n0,0\n1,1,1\n")
+        else:
+            f.write('{"a": 0, "b": "0"}\n{"a": 1, "b": \n')
+    with pytest.raises(ValueError):
+        list(MicroPartition._stream_scan_task(scan_task(path, file_format)))
+
+
+def test_stream_scan_task_missing_file(tmpdir, file_format):
+    tables = MicroPartition._stream_scan_task(scan_task(str(tmpdir / "missing"), file_format))
+    with pytest.raises((FileNotFoundError, ValueError)):
+        list(tables)
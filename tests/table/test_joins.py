@@ -282,3 +282,75 @@ def test_table_join_single_column_name_null(join_impl) -> None:
     result_sorted = result_table.sort([col("x")])
     assert result_sorted.get_column("y").to_pylist() == []
     assert result_sorted.get_column("right.y").to_pylist() == []
+
+
+@pytest.mark.parametrize("join_impl", ["hash_join", "sort_merge_join"])
+@pytest.mark.parametrize(
+    "how, expected",
+    [
+        (JoinType.Inner, [(1, "b", "x"), (1, "c", "x"), (4, "e", "z"), (4, "e", "w")]),
+        (
+            JoinType.Left,
+            [(0, "a", None), (1, "b", "x"), (1, "c", "x"), (None, "d", None), (4, "e", "z"), (4, "e", "w")],
+        ),
+        (JoinType.Right, [(1, "b", "x"), (1, "c", "x"), (2, None, "y"), (4, "e", "z"), (4, "e", "w")]),
+        (
+            JoinType.Outer,
+            [
+                (0, "a", None),
+                (1, "b", "x"),
+                (1, "c", "x"),
+                (2, None, "y"),
+                (None, "d", None),
+                (4, "e", "z"),
+                (4, "e", "w"),
+            ],
+        ),
+    ],
+)
+def test_table_join_outer_types(join_impl, how, expected) -> None:
+    left_table = MicroPartition.from_pydict({"x": [0, 1, 1, None, 4], "y": ["a", "b", "c", "d", "e"]})
+    right_table = MicroPartition.from_pydict({"x": [1, 2, 4, 4], "y": ["x", "y", "z", "w"]})
+
+    result_table = getattr(left_table, join_impl)(right_table, left_on=[col("x")], right_on=[col("x")], how=how)
+    assert result_table.column_names() == ["x", "y", "right.y"]
+    result = list(zip(*[result_table.get_column(name).to_pylist() for name in ["x", "y", "right.y"]]))
+    assert sorted(result, key=str) == sorted(expected, key=str)
+
+
+@pytest.mark.parametrize("join_impl", ["hash_join", "sort_merge_join"])
+@pytest.mark.parametrize("how, expected", [(JoinType.Semi, ["b", "c", "e"]), (JoinType.Anti, ["a", "d"])])
+def test_table_join_semi_anti(join_impl, how, expected) -> None:
+    left_table = MicroPartition.from_pydict({"x": [0, 1, 1, None, 4], "y": ["a", "b", "c", "d", "e"]})
+    right_table = MicroPartition.from_pydict({"x": [1, 2, 4, 4, None], "z": ["x", "y", "z", "w", "v"]})
+
+    result_table = getattr(left_table, join_impl)(right_table, left_on=[col("x")], right_on=[col("x")], how=how)
+    assert result_table.column_names() == ["x", "y"]
+    assert sorted(result_table.get_column("y").to_pylist()) == expected
+
+
+@pytest.mark.parametrize("join_impl", ["hash_join", "sort_merge_join"])
+@pytest.mark.parametrize("how", [JoinType.Left, JoinType.Outer, JoinType.Anti])
+def test_table_join_empty_right_keeps_left_rows(join_impl, how) -> None:
+    left_table = MicroPartition.from_pydict({"x": [0, 1], "y": ["a", "b"]})
+    right_table = MicroPartition.from_pydict({"x": [1], "z": ["x"]}).head(0)
+
+    result_table = getattr(left_table, join_impl)(right_table, left_on=[col("x")], right_on=[col("x")], how=how)
+    assert result_table.get_column("y").to_pylist() == ["a", "b"]
+    if how != JoinType.Anti:
+        assert result_table.get_column("z").to_pylist() == [None, None]
+
+
+@pytest.mark.parametrize("join_impl", ["hash_join", "sort_merge_join"])
+def test_table_join_cross(join_impl) -> None:
+    left_table = MicroPartition.from_pydict({"x": [0, 1]})
+    right_table = MicroPartition.from_pydict({"x": ["a", "b", "c"]})
+
+    result_table = getattr(left_table, join_impl)(right_table, left_on=[], right_on=[], how=JoinType.Cross)
+    assert result_table.column_names() == ["x", "right.x"]
+    assert sorted(zip(result_table.get_column("x").to_pylist(), result_table.get_column("right.x").to_pylist())) == [
+        (l, r) for l in [0, 1] for r in ["a", "b", "c"]
+    ]
+
+    with pytest.raises(ValueError, match="Cross joins must not have join keys"):
+        getattr(left_table, join_impl)(right_table, left_on=[col("x")], right_on=[col("x")], how=JoinType.Cross)